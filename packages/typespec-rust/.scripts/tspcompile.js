@@ -25,7 +25,7 @@ const httpSpecsGroup = {
   'spector_oauth2': {input: 'authentication/oauth2'},
   'spector_unionauth': {input: 'authentication/union'},
   'spector_documentation': {input: 'documentation'},
-  'spector_bytes': {input: 'encode/bytes'}, // TODO: nested arrays and "raw" request/responses (i.e. the orphan problem)
+  'spector_bytes': {input: 'encode/bytes'}, // TODO: nested arrays
   'spector_datetime': {input: 'encode/datetime'},
   'spector_duration': {input: 'encode/duration'},
   'spector_encarray': {input: 'encode/array'},
@@ -56,13 +56,13 @@ const httpSpecsGroup = {
   'spector_dictionary': {input: 'type/dictionary'}, // needs additional codegen work before we can add tests
   'spector_extensible': {input: 'type/enum/extensible'},
   'spector_fixed': {input: 'type/enum/fixed'},
-  'spector_empty': {input: 'type/model/empty'},
+  'spector_empty': {input: 'type/model/empty', args: ['emit-client-traits=true', 'emit-stub-server=true']},
   'spector_enumdisc': {input: 'type/model/inheritance/enum-discriminator'},
   'spector_nodisc': {input: 'type/model/inheritance/not-discriminated'},
   //'spector_nesteddisc': {input: 'type/model/inheritance/nested-discriminator'},
   'spector_recursive': {input: 'type/model/inheritance/recursive'},
   'spector_singledisc': {input: 'type/model/inheritance/single-discriminator'},
-  'spector_usage': {input: 'type/model/usage'},
+  'spector_usage': {input: 'type/model/usage', args: ['emit-idempotency-context=true']},
   'spector_visibility': {input: 'type/model/visibility'},
   'spector_addlprops': {input: 'type/property/additional-properties'},
   'spector_nullable': {input: 'type/property/nullable'},
@@ -100,20 +100,20 @@ const azureHttpSpecsGroup = {
   'spector_coreusage': {input: 'azure/client-generator-core/usage'},
   'spector_basic': {input: 'azure/core/basic'},
   'spector_lrorpc': {input: 'azure/core/lro/rpc'},
-  'spector_lrostd': {input: 'azure/core/lro/standard'},
+  'spector_lrostd': {input: 'azure/core/lro/standard', args: ['emit-idempotency-context=true']},
   'spector_coremodel': {input: 'azure/core/model'},
   'spector_corepage': {input: 'azure/core/page'},
   'spector_corescalar': {input: 'azure/core/scalar'},
   'spector_coretraits': {input: 'azure/core/traits'},
   'spector_azureduration': {input: 'azure/encode/duration'},
   'spector_azurepageable': {input: 'azure/payload/pageable'},
-  'spector_azurebasic': {input: 'azure/example/basic'},
+  'spector_azurebasic': {input: 'azure/example/basic', args: ['emit-example-tests=true']},
   'spector_armcommon': {input: 'azure/resource-manager/common-properties', args: ['emit-error-traits=true']},
   'spector_armlargeheader': {input: 'azure/resource-manager/large-header'},
   'spector_armmethodsub': {input: 'azure/resource-manager/method-subscription-id/client.tsp'},
   'spector_armnonresource': {input: 'azure/resource-manager/non-resource'},
-  'spector_armoptemplates': {input: 'azure/resource-manager/operation-templates'},
-  'spector_armresources': {input: 'azure/resource-manager/resources'},
+  'spector_armoptemplates': {input: 'azure/resource-manager/operation-templates', args: ['emit-client-traits=true']},
+  'spector_armresources': {input: 'azure/resource-manager/resources', args: ['emit-arm-resource-handles=true']},
   'spector_arm_multi_service': {input: 'azure/resource-manager/multi-service/client.tsp'},
   'spector_arm_multi_service_shared_models': {input: 'azure/resource-manager/multi-service-shared-models/client.tsp'},
   'spector_requestidheader': {input: 'azure/special-headers/client-request-id'},
@@ -165,6 +165,9 @@ function should_generate(name) {
 const alternate_types = pkgRoot + 'test/tsp/AlternateTypes';
 generate('alternate_types', alternate_types, 'test/other/alternate_types');
 
+const arm_scope_params = pkgRoot + 'test/tsp/ArmScopeParams';
+generate('arm_scope_params', arm_scope_params, 'test/other/arm_scope_params', ['arm-client-scope-params=true']);
+
 //const appconfiguration = pkgRoot + 'test/tsp/AppConfiguration/client.tsp';
 //generate('appconfiguration', appconfiguration, 'test/sdk/appconfiguration');
 
@@ -180,6 +183,12 @@ generate('blob_storage', blob_storage, 'test/sdk/blob_storage', ['temp-omit-doc-
 const serde_tests = pkgRoot + 'test/tsp/SerdeTests';
 generate('serde_tests', serde_tests, 'test/other/serde_tests');
 
+const strict_enums = pkgRoot + 'test/tsp/StrictEnums';
+generate('strict_enums', strict_enums, 'test/other/strict_enums', ['strict-extensible-enums=true']);
+
+const cookies = pkgRoot + 'test/tsp/Cookies';
+generate('cookies', cookies, 'test/other/cookies');
+
 const doc_tests = pkgRoot + 'test/tsp/DocTests';
 generate('doc_tests', doc_tests, 'test/other/doc_tests');
 
@@ -189,6 +198,18 @@ generate('enum_path_params', enum_path_params, 'test/other/enum_path_params');
 const colliding_locals = pkgRoot + 'test/tsp/CollidingLocals';
 generate('colliding_locals', colliding_locals, 'test/other/colliding_locals');
 
+const form_body = pkgRoot + 'test/tsp/FormBody';
+generate('form_body', form_body, 'test/other/form_body');
+
+const header_collections = pkgRoot + 'test/tsp/HeaderCollections';
+generate('header_collections', header_collections, 'test/other/header_collections');
+
+const http_metadata = pkgRoot + 'test/tsp/HttpMetadata';
+generate('http_metadata', http_metadata, 'test/other/http_metadata');
+
+const negotiated_response = pkgRoot + 'test/tsp/NegotiatedResponse';
+generate('negotiated_response', negotiated_response, 'test/other/negotiated_response');
+
 const lro = pkgRoot + 'test/tsp/lro';
 generate('lro', lro, 'test/other/lro');
 
@@ -201,6 +222,15 @@ generate('pub_crate', pub_crate, 'test/other/pub_crate');
 const client_option = pkgRoot + 'test/tsp/ClientOption';
 generate('client_option', client_option, 'test/other/client_option');
 
+const resumable_download = pkgRoot + 'test/tsp/ResumableDownload';
+generate('resumable_download', resumable_download, 'test/other/resumable_download');
+
+const batch_requests = pkgRoot + 'test/tsp/BatchRequests';
+generate('batch_requests', batch_requests, 'test/other/batch_requests', ['emit-request-builders=true']);
+
+const xml_namespaces = pkgRoot + 'test/tsp/XmlNamespaces';
+generate('xml_namespaces', xml_namespaces, 'test/other/xml_namespaces');
+
 loopSpec(httpSpecsGroup, httpSpecs)
 loopSpec(azureHttpSpecsGroup, azureHttpSpecs)
 
@@ -0,0 +1,282 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+
+import * as helpers from './helpers.js';
+import { Use } from './use.js';
+import * as rust from '../codemodel/index.js';
+import * as utils from '../utils/utils.js';
+
+/** the params of a resource ID method as they appear in a wrapper method */
+interface WrappedParams {
+  /** the params in the wrapper's signature (excluding self) */
+  sig: Array<string>;
+
+  /** the args passed to the resource ID method (excluding the resource ID) */
+  args: Array<string>;
+}
+
+/**
+ * returns the emitted ARM resource handle and collection types,
+ * or undefined if the module doesn't contain any.
+ *
+ * @param module the module for which to emit the ARM resource types
+ * @returns the ARM resources content or undefined
+ */
+export function emitArmResources(module: rust.ModuleContainer): helpers.Module | undefined {
+  if (module.armResources.length === 0) {
+    return undefined;
+  }
+
+  const use = new Use(module, 'clients');
+  use.add('azure_core', 'Result');
+  use.add('crate::generated::models', 'ResourceId');
+
+  const indent = new helpers.indentation();
+  const content = new Array<string>();
+  for (const armResource of module.armResources) {
+    content.push(emitHandle(indent, use, module, armResource));
+    if (armResource.collection) {
+      content.push(emitCollection(indent, use, module, armResource, armResource.collection));
+    }
+  }
+
+  return {
+    name: 'arm_resources',
+    content: helpers.contentPreamble() + use.text() + content.join('\n'),
+    visibility: 'pubUse',
+  };
+}
+
+/**
+ * emits the handle type for an ARM resource
+ *
+ * @param indent the indentation helper currently in scope
+ * @param use the use statement builder currently in scope
+ * @param module the module that contains the resource
+ * @param armResource the resource for which to emit the handle
+ * @returns the handle type and its impl block
+ */
+function emitHandle(indent: helpers.indentation, use: Use, module: rust.ModuleContainer, armResource: rust.ArmResource): string {
+  use.addForType(armResource.client);
+
+  let body = helpers.formatDocComment(armResource.docs);
+  body += '#[derive(Clone)]\n';
+  body += `pub struct ${armResource.name}<'a> {\n`;
+  body += `${indent.get()}client: &'a ${armResource.client.name},\n`;
+  body += `${indent.get()}id: ResourceId,\n`;
+  body += '}\n\n';
+
+  body += `impl<'a> ${armResource.name}<'a> {\n`;
+  body += `${indent.get()}/// Creates a handle to the resource with the specified ID.\n`;
+  body += `${indent.get()}pub fn new(client: &'a ${armResource.client.name}, id: ResourceId) -> Self {\n`;
+  body += `${indent.push().get()}Self { client, id }\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Returns the ID of the resource.\n`;
+  body += `${indent.get()}pub fn id(&self) -> &ResourceId {\n`;
+  body += `${indent.push().get()}&self.id\n`;
+  body += `${indent.pop().get()}}\n`;
+
+  // operations and child accessors are intermingled in name order
+  const methods = new Array<{ name: string, content: string }>();
+  for (const operation of armResource.operations) {
+    const calledMethod = operation.method.method;
+    const params = getWrappedParams(use, operation.method);
+    let content = `${indent.get()}/// Calls ${getDocLink(module, operation, armResource.client)} for this resource.\n`;
+    if (params.sig.length > 6) {
+      content += `${indent.get()}#[allow(clippy::too_many_arguments)]\n`;
+    }
+    content += `${indent.get()}pub ${calledMethod.kind === 'async' ? 'async ' : ''}fn ${calledMethod.name}(${['&self', ...params.sig].join(', ')}) -> ${helpers.getTypeDeclaration(operation.method.returns)} {\n`;
+    content += `${indent.push().get()}${getOperationClient(operation)}.${operation.method.name}(${['&self.id', ...params.args].join(', ')})${calledMethod.kind === 'async' ? '.await' : ''}\n`;
+    content += `${indent.pop().get()}}\n`;
+    methods.push({ name: calledMethod.name, content: content });
+  }
+
+  for (const child of armResource.children) {
+    let content: string;
+    if (child.resource.collection) {
+      content = `${indent.get()}/// Returns the ${getModelDocLink(module, child.resource.model)} resources nested under this resource.\n`;
+      content += `${indent.get()}pub fn ${child.name}(&self) -> ${child.resource.collection.name}<'a> {\n`;
+      content += `${indent.push().get()}${child.resource.collection.name}::new(self.client, self.id.clone())\n`;
+    } else {
+      content = `${indent.get()}/// Returns a handle to the ${getModelDocLink(module, child.resource.model)} resource nested under this resource.\n`;
+      content += `${indent.get()}pub fn ${child.name}(&self) -> Result<${child.resource.name}<'a>> {\n`;
+      content += `${indent.push().get()}Ok(${child.resource.name}::new(self.client, ${getChildId(child.resource, 'self.id', `"${child.resource.singletonName}"`)}))\n`;
+    }
+    content += `${indent.pop().get()}}\n`;
+    methods.push({ name: child.name, content: content });
+  }
+
+  methods.sort((a, b) => utils.sortAscending(a.name, b.name));
+  for (const method of methods) {
+    body += `\n${method.content}`;
+  }
+
+  body += '}\n';
+  return body;
+}
+
+/**
+ * emits the collection type for an ARM resource
+ *
+ * @param indent the indentation helper currently in scope
+ * @param use the use statement builder currently in scope
+ * @param module the module that contains the resource
+ * @param armResource the resource contained in the collection
+ * @param collection the collection to emit
+ * @returns the collection type and its impl block
+ */
+function emitCollection(indent: helpers.indentation, use: Use, module: rust.ModuleContainer, armResource: rust.ArmResource, collection: rust.ArmResourceCollection): string {
+  let body = helpers.formatDocComment(collection.docs);
+  body += '#[derive(Clone)]\n';
+  body += `pub struct ${collection.name}<'a> {\n`;
+  body += `${indent.get()}client: &'a ${armResource.client.name},\n`;
+  body += `${indent.get()}parent: ResourceId,\n`;
+  body += '}\n\n';
+
+  body += `impl<'a> ${collection.name}<'a> {\n`;
+  body += `${indent.get()}/// Creates the collection of resources contained in the specified parent, e.g. a resource group.\n`;
+  body += `${indent.get()}pub fn new(client: &'a ${armResource.client.name}, parent: ResourceId) -> Self {\n`;
+  body += `${indent.push().get()}Self { client, parent }\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Returns a handle to the resource with the specified name. No request is sent.\n`;
+  body += `${indent.get()}pub fn handle(&self, name: &str) -> Result<${armResource.name}<'a>> {\n`;
+  body += `${indent.push().get()}Ok(${armResource.name}::new(self.client, ${getChildId(armResource, 'self.parent', 'name')}))\n`;
+  body += `${indent.pop().get()}}\n`;
+
+  // the collection creates and reads resources by name through their handles
+  const methods = new Array<{ name: string, content: string }>();
+  for (const operation of armResource.operations) {
+    if (operation.kind !== 'createOrUpdate' && operation.kind !== 'read') {
+      continue;
+    }
+    const calledMethod = operation.method.method;
+    const params = getWrappedParams(use, operation.method);
+    const nameParam = helpers.getUniqueVarName(operation.method.params, ['name', 'resource_name']);
+    const name = operation.kind === 'read' ? 'get' : calledMethod.name;
+    let content = `${indent.get()}/// Calls ${getDocLink(module, operation, armResource.client)} for the resource with the specified name.\n`;
+    if (params.sig.length > 5) {
+      content += `${indent.get()}#[allow(clippy::too_many_arguments)]\n`;
+    }
+    content += `${indent.get()}pub ${calledMethod.kind === 'async' ? 'async ' : ''}fn ${name}(${['&self', `${nameParam}: &str`, ...params.sig].join(', ')}) -> ${helpers.getTypeDeclaration(operation.method.returns)} {\n`;
+    content += `${indent.push().get()}self.handle(${nameParam})?.${calledMethod.name}(${params.args.join(', ')})${calledMethod.kind === 'async' ? '.await' : ''}\n`;
+    content += `${indent.pop().get()}}\n`;
+    methods.push({ name: name, content: content });
+  }
+
+  if (collection.list) {
+    const params = getWrappedParams(use, collection.list.method);
+    let content = `${indent.get()}/// Calls ${getDocLink(module, collection.list, armResource.client)} for the resources in the collection.\n`;
+    if (params.sig.length > 6) {
+      content += `${indent.get()}#[allow(clippy::too_many_arguments)]\n`;
+    }
+    content += `${indent.get()}pub fn list(${['&self', ...params.sig].join(', ')}) -> ${helpers.getTypeDeclaration(collection.list.method.returns)} {\n`;
+    content += `${indent.push().get()}${getOperationClient(collection.list)}.${collection.list.method.name}(${['&self.parent', ...params.args].join(', ')})\n`;
+    content += `${indent.pop().get()}}\n`;
+    methods.push({ name: 'list', content: content });
+  }
+
+  methods.sort((a, b) => utils.sortAscending(a.name, b.name));
+  for (const method of methods) {
+    body += `\n${method.content}`;
+  }
+
+  body += `\n${indent.get()}/// Returns the ID of the resource or scope that contains the collection.\n`;
+  body += `${indent.get()}pub fn parent(&self) -> &ResourceId {\n`;
+  body += `${indent.push().get()}&self.parent\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += '}\n';
+  return body;
+}
+
+/**
+ * returns the expression that builds the ID of a resource from its parent's ID
+ *
+ * @param armResource the resource for which to build the ID
+ * @param parent the expression for the parent's ID
+ * @param name the expression for the resource's name
+ * @returns the ID expression
+ */
+function getChildId(armResource: rust.ArmResource, parent: string, name: string): string {
+  let id = parent;
+  for (let i = 0; i < armResource.scope.length; i += 2) {
+    id += `.child("${armResource.scope[i]}", "${armResource.scope[i + 1]}")?`;
+  }
+  return `${id}.child("${armResource.type}", ${name})?`;
+}
+
+/**
+ * returns the expression for the client that contains the operation
+ *
+ * @param operation the operation to call
+ * @returns the client expression
+ */
+function getOperationClient(operation: rust.ArmResourceOperation): string {
+  return operation.accessor ? `self.client.${operation.accessor.name}()` : 'self.client';
+}
+
+/**
+ * returns a doc link to the method called by the operation's resource ID method
+ *
+ * @param module the module that contains the resource
+ * @param operation the operation for which to create the doc link
+ * @param client the client from which the operation's client is obtained
+ * @returns the doc link
+ */
+function getDocLink(module: rust.ModuleContainer, operation: rust.ArmResourceOperation, client: rust.Client): string {
+  const operationClient = operation.accessor?.returns ?? client;
+  const methodName = operation.method.method.name;
+  return `[\`${operationClient.name}::${methodName}()\`](${utils.buildImportPath(module, operationClient.module)}::clients::${operationClient.name}::${methodName}())`;
+}
+
+/**
+ * returns a doc link to the model of a resource
+ *
+ * @param module the module that contains the resource
+ * @param model the model to link
+ * @returns the doc link
+ */
+function getModelDocLink(module: rust.ModuleContainer, model: rust.Model): string {
+  return `[\`${model.name}\`](${utils.buildImportPath(module, model.module)}::models::${model.name})`;
+}
+
+/**
+ * returns the params of a resource ID method that a wrapper passes through.
+ * these are the required method params and the options.
+ *
+ * @param use the use statement builder currently in scope
+ * @param method the resource ID method being wrapped
+ * @returns the wrapper's params and the args to pass
+ */
+function getWrappedParams(use: Use, method: rust.ResourceIdMethod): WrappedParams {
+  const params: WrappedParams = { sig: new Array<string>(), args: new Array<string>() };
+  use.addForType(method.returns);
+
+  const emittedGroups = new Set<string>();
+  for (const param of method.params) {
+    if (param.group) {
+      if (!emittedGroups.has(param.group.name)) {
+        emittedGroups.add(param.group.name);
+        params.sig.push(`${param.group.name}: ${helpers.getTypeDeclaration(param.group.type, 'anonymous')}`);
+        params.args.push(param.group.name);
+      }
+      continue;
+    }
+
+    const paramType = helpers.unwrapType(param.type);
+    if (paramType.kind === 'literal' || paramType.kind === 'enumValue' || param.location !== 'method' || param.optional) {
+      continue;
+    }
+
+    const sigType = param.kind === 'partialBody' ? param.paramType : param.type;
+    use.addForType(sigType);
+    params.sig.push(`${param.name}: ${helpers.getTypeDeclaration(sigType)}`);
+    params.args.push(param.name);
+  }
+
+  use.addForType(method.options.type);
+  params.sig.push(`${method.options.name}: ${helpers.getTypeDeclaration(method.options.type, 'anonymous')}`);
+  params.args.push(method.options.name);
+  return params;
+}
@@ -0,0 +1,288 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+
+import * as helpers from './helpers.js';
+import { Use } from './use.js';
+import * as rust from '../codemodel/index.js';
+
+/**
+ * returns the emitted multipart/mixed batch types, or undefined if
+ * no methods in the module build requests without sending them.
+ *
+ * @param module the module for which to emit the batch types
+ * @returns the batch types content or undefined
+ */
+export function emitBatch(module: rust.ModuleContainer): helpers.Module | undefined {
+  if (!module.clients.some((client) => client.methods.some((method) => method.kind === 'request'))) {
+    return undefined;
+  }
+
+  const use = new Use(module, 'modelsOther');
+  use.add('azure_core', 'Bytes', 'Result');
+  use.add('azure_core::error', 'Error', 'ErrorKind');
+  use.add('azure_core::http', 'Body', 'RawResponse', 'Request', 'Response', 'StatusCode');
+  use.add('azure_core::http::headers', 'HeaderName', 'Headers');
+  use.add('std', 'str');
+
+  const indent = new helpers.indentation();
+  let body = 'const CONTENT_ID: HeaderName = HeaderName::from_static("content-id");\n';
+  body += 'const CONTENT_LENGTH: HeaderName = HeaderName::from_static("content-length");\n';
+  body += 'const ERROR_CODE: HeaderName = HeaderName::from_static("x-ms-error-code");\n\n';
+
+  body += '/// A set of requests that are sent as a single `multipart/mixed` request.\n';
+  body += '///\n';
+  body += '/// Each request is encoded as an `application/http` part whose `content-id` is the index of the request in the\n';
+  body += '/// batch.\n';
+  body += 'pub struct BatchRequest {\n';
+  body += `${indent.get()}boundary: String,\n`;
+  body += `${indent.get()}requests: Vec<Request>,\n`;
+  body += '}\n\n';
+
+  body += 'impl BatchRequest {\n';
+  body += `${indent.get()}/// Creates an empty batch whose parts are delimited by \`boundary\`.\n`;
+  body += `${indent.get()}pub fn new(boundary: impl Into<String>) -> Self {\n`;
+  body += `${indent.push().get()}Self {\n`;
+  body += `${indent.push().get()}boundary: boundary.into(),\n`;
+  body += `${indent.get()}requests: Vec::new(),\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Adds a request to the batch and returns its index.\n`;
+  body += `${indent.get()}///\n`;
+  body += `${indent.get()}/// The index is used to retrieve the sub-response for the request with [\`BatchResponse::take()\`].\n`;
+  body += `${indent.get()}pub fn add(&mut self, request: Request) -> usize {\n`;
+  body += `${indent.push().get()}self.requests.push(request);\n`;
+  body += `${indent.get()}self.requests.len() - 1\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Returns the number of requests in the batch.\n`;
+  body += `${indent.get()}pub fn len(&self) -> usize {\n`;
+  body += `${indent.push().get()}self.requests.len()\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Returns \`true\` if the batch contains no requests.\n`;
+  body += `${indent.get()}pub fn is_empty(&self) -> bool {\n`;
+  body += `${indent.push().get()}self.requests.is_empty()\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Returns the \`content-type\` header value for the batch body.\n`;
+  body += `${indent.get()}pub fn content_type(&self) -> String {\n`;
+  body += `${indent.push().get()}format!("multipart/mixed; boundary={}", self.boundary)\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Encodes the requests as a \`multipart/mixed\` body.\n`;
+  body += `${indent.get()}///\n`;
+  body += `${indent.get()}/// Returns an error if a request has a streamed body.\n`;
+  body += `${indent.get()}pub fn into_body(self) -> Result<Bytes> {\n`;
+  body += `${indent.push().get()}let mut body = Vec::new();\n`;
+  body += `${indent.get()}for (index, request) in self.requests.iter().enumerate() {\n`;
+  body += `${indent.push().get()}let Body::Bytes(content) = request.body() else {\n`;
+  body += `${indent.push().get()}return Err(Error::with_message(ErrorKind::Other, "requests with streamed bodies can't be batched"));\n`;
+  body += `${indent.pop().get()}};\n`;
+  body += `${indent.get()}body.extend_from_slice(format!("--{}\\r\\n", self.boundary).as_bytes());\n`;
+  body += `${indent.get()}body.extend_from_slice(b"content-type: application/http\\r\\n");\n`;
+  body += `${indent.get()}body.extend_from_slice(b"content-transfer-encoding: binary\\r\\n");\n`;
+  body += `${indent.get()}body.extend_from_slice(format!("content-id: {index}\\r\\n\\r\\n").as_bytes());\n`;
+  body += `${indent.get()}let url = request.url();\n`;
+  body += `${indent.get()}let target = match url.query() {\n`;
+  body += `${indent.push().get()}Some(query) => format!("{}?{query}", url.path()),\n`;
+  body += `${indent.get()}None => url.path().to_string(),\n`;
+  body += `${indent.pop().get()}};\n`;
+  body += `${indent.get()}body.extend_from_slice(format!("{} {target} HTTP/1.1\\r\\n", request.method().as_str()).as_bytes());\n`;
+  body += `${indent.get()}for (name, value) in request.headers().iter() {\n`;
+  body += `${indent.push().get()}body.extend_from_slice(format!("{}: {}\\r\\n", name.as_str(), value.as_str()).as_bytes());\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}if !content.is_empty() && request.headers().get_optional_str(&CONTENT_LENGTH).is_none() {\n`;
+  body += `${indent.push().get()}body.extend_from_slice(format!("content-length: {}\\r\\n", content.len()).as_bytes());\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}body.extend_from_slice(b"\\r\\n");\n`;
+  body += `${indent.get()}body.extend_from_slice(content);\n`;
+  body += `${indent.get()}body.extend_from_slice(b"\\r\\n");\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}body.extend_from_slice(format!("--{}--\\r\\n", self.boundary).as_bytes());\n`;
+  body += `${indent.get()}Ok(body.into())\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += '}\n\n';
+
+  body += '/// The sub-responses of a `multipart/mixed` batch response.\n';
+  body += '#[derive(Debug)]\n';
+  body += 'pub struct BatchResponse {\n';
+  body += `${indent.get()}parts: Vec<Option<BatchPart>>,\n`;
+  body += `${indent.get()}has_content_ids: bool,\n`;
+  body += '}\n\n';
+
+  body += 'impl BatchResponse {\n';
+  body += `${indent.get()}/// Parses a \`multipart/mixed\` batch response.\n`;
+  body += `${indent.get()}///\n`;
+  body += `${indent.get()}/// The \`content_type\` is the \`content-type\` header value of the batch response. It contains the boundary that\n`;
+  body += `${indent.get()}/// delimits the parts of the \`body\`.\n`;
+  body += `${indent.get()}pub fn parse(content_type: &str, body: &[u8]) -> Result<Self> {\n`;
+  body += `${indent.push().get()}let boundary = content_type.split(';')\n`;
+  body += `${indent.push().get()}.find_map(|param| param.trim().strip_prefix("boundary="))\n`;
+  body += `${indent.get()}.map(|boundary| boundary.trim_matches('"'))\n`;
+  body += `${indent.get()}.ok_or_else(|| invalid_batch("the content-type doesn't contain a boundary"))?;\n`;
+  body += `${indent.pop().get()}let delimiter = format!("--{boundary}");\n`;
+  body += `${indent.get()}let mut parts = Vec::new();\n`;
+  body += `${indent.get()}// the preamble before the first delimiter is ignored\n`;
+  body += `${indent.get()}for section in split(body, delimiter.as_bytes()).skip(1) {\n`;
+  body += `${indent.push().get()}if section.starts_with(b"--") {\n`;
+  body += `${indent.push().get()}// the close delimiter ends the batch\n`;
+  body += `${indent.get()}break;\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}parts.push(Some(parse_part(section)?));\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}let has_content_ids = parts.iter().flatten().any(|part| part.content_id.is_some());\n`;
+  body += `${indent.get()}Ok(Self { parts, has_content_ids })\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Returns the number of sub-responses in the batch.\n`;
+  body += `${indent.get()}pub fn len(&self) -> usize {\n`;
+  body += `${indent.push().get()}self.parts.len()\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Returns \`true\` if the batch contains no sub-responses.\n`;
+  body += `${indent.get()}pub fn is_empty(&self) -> bool {\n`;
+  body += `${indent.push().get()}self.parts.is_empty()\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Removes and returns the sub-response for the request at \`index\` in the [\`BatchRequest\`].\n`;
+  body += `${indent.get()}///\n`;
+  body += `${indent.get()}/// Sub-responses are matched by their \`content-id\`. Only when the response contains no \`content-id\`s at all is\n`;
+  body += `${indent.get()}/// the sub-response at the same position used. Returns \`None\` if there's no such sub-response or it was already taken.\n`;
+  body += `${indent.get()}pub fn take(&mut self, index: usize) -> Option<BatchPart> {\n`;
+  body += `${indent.push().get()}let position = if self.has_content_ids {\n`;
+  body += `${indent.push().get()}let content_id = index.to_string();\n`;
+  body += `${indent.get()}self.parts.iter()\n`;
+  body += `${indent.push().get()}.position(|part| part.as_ref().and_then(BatchPart::content_id) == Some(content_id.as_str()))?\n`;
+  body += `${indent.pop().pop().get()}} else {\n`;
+  body += `${indent.push().get()}index\n`;
+  body += `${indent.pop().get()}};\n`;
+  body += `${indent.get()}self.parts.get_mut(position)?.take()\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += '}\n\n';
+
+  body += '/// A sub-response of a `multipart/mixed` batch response.\n';
+  body += '#[derive(Debug)]\n';
+  body += 'pub struct BatchPart {\n';
+  body += `${indent.get()}content_id: Option<String>,\n`;
+  body += `${indent.get()}status: StatusCode,\n`;
+  body += `${indent.get()}headers: Headers,\n`;
+  body += `${indent.get()}body: Bytes,\n`;
+  body += '}\n\n';
+
+  body += 'impl BatchPart {\n';
+  body += `${indent.get()}/// Returns the \`content-id\` of the part, if any.\n`;
+  body += `${indent.get()}pub fn content_id(&self) -> Option<&str> {\n`;
+  body += `${indent.push().get()}self.content_id.as_deref()\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Returns the status code of the sub-response.\n`;
+  body += `${indent.get()}pub fn status(&self) -> StatusCode {\n`;
+  body += `${indent.push().get()}self.status\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Returns the headers of the sub-response.\n`;
+  body += `${indent.get()}pub fn headers(&self) -> &Headers {\n`;
+  body += `${indent.push().get()}&self.headers\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Returns the body of the sub-response.\n`;
+  body += `${indent.get()}pub fn body(&self) -> &Bytes {\n`;
+  body += `${indent.push().get()}&self.body\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Converts the sub-response into the response type of the method that built its request.\n`;
+  body += `${indent.get()}///\n`;
+  body += `${indent.get()}/// Returns an [\`ErrorKind::HttpResponse\`] error if the status code of the sub-response doesn't indicate success.\n`;
+  body += `${indent.get()}pub fn into_response<T, F>(self) -> Result<Response<T, F>> {\n`;
+  body += `${indent.push().get()}let status = self.status;\n`;
+  body += `${indent.get()}let error_code = self.headers.get_optional_str(&ERROR_CODE).map(ToString::to_string);\n`;
+  body += `${indent.get()}let raw_response = self.into_raw_response();\n`;
+  body += `${indent.get()}if !status.is_success() {\n`;
+  body += `${indent.push().get()}return Err(Error::with_message(\n`;
+  body += `${indent.push().get()}ErrorKind::HttpResponse { status, error_code, raw_response: Some(Box::new(raw_response)) },\n`;
+  body += `${indent.get()}format!("batch sub-request failed with status {status}"),\n`;
+  body += `${indent.pop().get()}));\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}Ok(raw_response.into())\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Converts the sub-response into a [\`RawResponse\`].\n`;
+  body += `${indent.get()}pub fn into_raw_response(self) -> RawResponse {\n`;
+  body += `${indent.push().get()}RawResponse::from_bytes(self.status, self.headers, self.body)\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += '}\n\n';
+
+  body += '/// Returns an error for a malformed batch response.\n';
+  body += 'fn invalid_batch(message: &\'static str) -> Error {\n';
+  body += `${indent.get()}Error::with_message(ErrorKind::DataConversion, message)\n`;
+  body += '}\n\n';
+
+  body += '/// Returns the position of the first occurrence of `needle` in `bytes`.\n';
+  body += 'fn find(bytes: &[u8], needle: &[u8]) -> Option<usize> {\n';
+  body += `${indent.get()}bytes.windows(needle.len()).position(|window| window == needle)\n`;
+  body += '}\n\n';
+
+  body += '/// Splits `bytes` on each occurrence of `delimiter`.\n';
+  body += 'fn split<\'a>(bytes: &\'a [u8], delimiter: &\'a [u8]) -> impl Iterator<Item = &\'a [u8]> {\n';
+  body += `${indent.get()}let mut remaining = Some(bytes);\n`;
+  body += `${indent.get()}std::iter::from_fn(move || {\n`;
+  body += `${indent.push().get()}let bytes = remaining?;\n`;
+  body += `${indent.get()}match find(bytes, delimiter) {\n`;
+  body += `${indent.push().get()}Some(start) => {\n`;
+  body += `${indent.push().get()}remaining = Some(&bytes[start + delimiter.len()..]);\n`;
+  body += `${indent.get()}Some(&bytes[..start])\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}None => {\n`;
+  body += `${indent.push().get()}remaining = None;\n`;
+  body += `${indent.get()}Some(bytes)\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.pop().get()}})\n`;
+  body += '}\n\n';
+
+  body += '/// Splits a message into its headers and body at the first empty line.\n';
+  body += 'fn split_head(message: &[u8]) -> Result<(&str, &[u8])> {\n';
+  body += `${indent.get()}let (head, body) = if let Some(body) = message.strip_prefix(b"\\r\\n") {\n`;
+  body += `${indent.push().get()}(&[][..], body)\n`;
+  body += `${indent.pop().get()}} else {\n`;
+  body += `${indent.push().get()}let end = find(message, b"\\r\\n\\r\\n").ok_or_else(|| invalid_batch("a batch part is missing the end of its headers"))?;\n`;
+  body += `${indent.get()}(&message[..end], &message[end + 4..])\n`;
+  body += `${indent.pop().get()}};\n`;
+  body += `${indent.get()}let head = str::from_utf8(head).map_err(|_| invalid_batch("the headers of a batch part aren't valid UTF-8"))?;\n`;
+  body += `${indent.get()}Ok((head, body))\n`;
+  body += '}\n\n';
+
+  body += '/// Splits a header line into its name and value.\n';
+  body += 'fn parse_header(line: &str) -> Option<(&str, &str)> {\n';
+  body += `${indent.get()}let (name, value) = line.split_once(':')?;\n`;
+  body += `${indent.get()}Some((name.trim(), value.trim()))\n`;
+  body += '}\n\n';
+
+  body += '/// Parses an `application/http` part into a sub-response.\n';
+  body += 'fn parse_part(section: &[u8]) -> Result<BatchPart> {\n';
+  body += `${indent.get()}// the line break ending the delimiter line and the one preceding the next delimiter belong to the delimiters\n`;
+  body += `${indent.get()}let section = section.strip_prefix(b"\\r\\n").unwrap_or(section);\n`;
+  body += `${indent.get()}let section = section.strip_suffix(b"\\r\\n").unwrap_or(section);\n`;
+  body += `${indent.get()}let (part_head, message) = split_head(section)?;\n`;
+  body += `${indent.get()}let content_id = part_head.split("\\r\\n")\n`;
+  body += `${indent.push().get()}.filter_map(parse_header)\n`;
+  body += `${indent.get()}.find(|(name, _)| name.eq_ignore_ascii_case(CONTENT_ID.as_str()))\n`;
+  body += `${indent.get()}.map(|(_, value)| value.to_string());\n`;
+  body += `${indent.pop().get()}let (head, body) = split_head(message)?;\n`;
+  body += `${indent.get()}let mut lines = head.split("\\r\\n");\n`;
+  body += `${indent.get()}let status = lines.next()\n`;
+  body += `${indent.push().get()}.and_then(|status_line| status_line.split(' ').nth(1))\n`;
+  body += `${indent.get()}.and_then(|code| code.parse::<u16>().ok())\n`;
+  body += `${indent.get()}.ok_or_else(|| invalid_batch("a batch part is missing its status line"))?;\n`;
+  body += `${indent.pop().get()}let mut headers = Headers::new();\n`;
+  body += `${indent.get()}for (name, value) in lines.filter_map(parse_header) {\n`;
+  body += `${indent.push().get()}headers.insert(name.to_ascii_lowercase(), value.to_string());\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}Ok(BatchPart {\n`;
+  body += `${indent.push().get()}content_id,\n`;
+  body += `${indent.get()}status: StatusCode::from(status),\n`;
+  body += `${indent.get()}headers,\n`;
+  body += `${indent.get()}body: Bytes::copy_from_slice(body),\n`;
+  body += `${indent.pop().get()}})\n`;
+  body += '}\n';
+
+  let content = helpers.contentPreamble();
+  content += use.text();
+  content += body;
+
+  return {
+    name: 'batch',
+    content: content,
+    visibility: 'pubUse',
+  };
+}
@@ -20,6 +20,9 @@ export function emitCargoToml(crate: rust.Crate): string {
   content += 'rust-version.workspace = true\n\n';
   content += '[features]\n';
   content += 'default = ["azure_core/default"]\n';
+  for (const feature of [...crate.features].sort()) {
+    content += `${feature} = []\n`;
+  }
   if (crate.dependencies.length > 0) {
     content += '\n[dependencies]\n';
     for (const dependency of crate.dependencies) {
@@ -28,5 +31,12 @@ export function emitCargoToml(crate: rust.Crate): string {
       content += `${dependency.name} = { workspace = true${features} }\n`;
     }
   }
+  if (crate.devDependencies.length > 0) {
+    content += '\n[dev-dependencies]\n';
+    for (const dependency of crate.devDependencies) {
+      const features = dependency.features.length > 0 ? `, features = [${dependency.features.sort().map(f => `"${f}"`).join(', ')}]` : '';
+      content += `${dependency.name} = { workspace = true${features} }\n`;
+    }
+  }
   return content;
 }
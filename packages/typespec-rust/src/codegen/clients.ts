@@ -5,7 +5,7 @@
 
 // cspell: ignore conv
 
-import { emitHeaderTraitDocExample } from './docTests.js';
+import { emitHeaderTraitDocExample, emitMethodDocExamples } from './docTests.js';
 import { CodegenError } from './errors.js';
 import * as helpers from './helpers.js';
 import queryString from 'query-string';
@@ -105,65 +105,42 @@ export function emitClients(module: rust.ModuleContainer): ClientModules | undef
         const crate = helpers.getCrate(module);
         let hasAuthPolicy: boolean;
         if (crate.type === 'azure-arm') {
-          use.add('azure_core', 'Error');
-          use.add('azure_core::error', 'ErrorKind');
-          use.add('crate', 'Audience');
           // for ARM, derive endpoint and scope from cloud config
-          use.add('azure_core::cloud', 'CloudConfiguration');
+          use.add('crate::generated::models', 'arm_cloud_endpoint');
           use.add('azure_core::http::policies', 'auth::BearerTokenAuthorizationPolicy', 'Policy');
-          body += `${indent.push().get()}let (endpoint, scope) = ${helpers.buildMatch(indent, 'options.client_options.cloud.as_deref()', [
-            {
-              pattern: 'Some(CloudConfiguration::AzurePublic)',
-              body: (indent) => `${indent.get()}(\n`
-                + `${indent.push().get()}"https://management.azure.com".to_string(),`
-                + `\n${indent.get()}"https://management.azure.com/.default".to_string(),`
-                + `\n${indent.pop().get()})\n`
-            },
-            {
-              pattern: 'Some(CloudConfiguration::AzureGovernment)',
-              body: (indent) => `${indent.get()}`
-                + `(\n${indent.push().get()}"https://management.usgovcloudapi.net".to_string(),`
-                + `\n${indent.get()}"https://management.usgovcloudapi.net/.default".to_string(),`
-                + `\n${indent.pop().get()})\n`
-            },
-            {
-              pattern: 'Some(CloudConfiguration::AzureChina)',
-              body: (indent) => `${indent.get()}`
-                + `(\n${indent.push().get()}"https://management.chinacloudapi.cn".to_string(),`
-                + `\n${indent.get()}"https://management.chinacloudapi.cn/.default".to_string(),`
-                + `\n${indent.pop().get()})\n`
-            },
-            {
-              pattern: 'Some(CloudConfiguration::Custom(custom))',
-              body: (indent) => `${indent.get()}{\n`
-                + `${indent.push().get()}let audience = custom.audiences.get::<Audience>().ok_or_else(|| { Error::new(ErrorKind::Credential, "missing custom cloud configuration audience")})?;\n`
-                + `${indent.get()}(audience.to_string(), format!("{}/.default", audience))\n`
-                + `${indent.pop().get()}}\n`
-            },
-            {
-              pattern: '_',
-              body: (indent) => `${indent.get()}unreachable!()\n`
-            },
-          ])};\n`;
-          body += `${indent.get()}let endpoint = Url::parse(&endpoint)?;\n`;
+          body += `${indent.push().get()}let (endpoint, scope) = arm_cloud_endpoint(options.client_options.cloud.as_deref())?;\n`;
           body += `${indent.get()}let auth_policy: Arc<dyn Policy> = Arc::new(BearerTokenAuthorizationPolicy::new(credential, vec![scope]));\n`;
           hasAuthPolicy = true;
         } else {
           // by convention, the endpoint param is always the first ctor param
           const endpointParamName = constructor.params[0].name;
-          body += `${indent.push().get()}let ${client.constructable.endpoint ? 'mut ' : ''}${endpointParamName} = Url::parse(${endpointParamName})?;\n`;
+          // a raw endpoint is the complete endpoint so no supplemental path is required
+          const supplementalEndpoint = constructor.rawEndpoint ? undefined : client.constructable.endpoint;
+          body += `${indent.push().get()}let ${supplementalEndpoint ? 'mut ' : ''}${endpointParamName} = Url::parse(${endpointParamName})?;\n`;
           body += `${indent.get()}${helpers.buildIfBlock(indent, {
             condition: `!${endpointParamName}.scheme().starts_with("http")`,
             body: (indent) => `${indent.get()}return Err(azure_core::Error::with_message(azure_core::error::ErrorKind::Other, format!("{${endpointParamName}} must use http(s)")));\n`,
           })}`
 
           // construct the supplemental path and join it to the endpoint
-          if (client.constructable.endpoint) {
-            const supplementalEndpoint = client.constructable.endpoint;
+          if (supplementalEndpoint) {
             if (supplementalEndpoint.parameters.length > 0) {
+              use.add('percent_encoding', 'utf8_percent_encode');
+              crate.addDependency(new rust.CrateDependency('percent-encoding'));
+              for (const param of supplementalEndpoint.parameters) {
+                const paramValue = getClientSupplementalEndpointParamText(param);
+                if (paramValue) {
+                  // an empty value or dot segment would produce a malformed endpoint
+                  body += `${indent.get()}${helpers.buildIfBlock(indent, {
+                    condition: `matches!(${paramValue}, "" | "." | "..")`,
+                    body: (indent) => `${indent.get()}return Err(azure_core::Error::with_message(azure_core::error::ErrorKind::Other, "${param.name} must not be empty or a dot segment"));\n`,
+                  })}\n`;
+                }
+              }
               body += `${indent.get()}let mut host = String::from("${supplementalEndpoint.path}");\n`;
               for (const param of supplementalEndpoint.parameters) {
-                body += `${indent.get()}host = host.replace("{${param.segment}}", ${getClientSupplementalEndpointParamValue(param)});\n`;
+                const encodeSet = isEndpointHostSegment(supplementalEndpoint, param) ? 'ENDPOINT_HOST_ENCODE_SET' : 'ENDPOINT_PATH_ENCODE_SET';
+                body += `${indent.get()}host = host.replace("{${param.segment}}", &utf8_percent_encode(${getClientSupplementalEndpointParamValue(param)}, ${encodeSet}).to_string());\n`;
               }
               body += `${indent.push().get()}${endpointParamName} = ${endpointParamName}.join(&host)?;\n`;
             } else {
@@ -179,6 +156,20 @@ export function emitClients(module: rust.ModuleContainer): ClientModules | undef
           }
           hasAuthPolicy = authPolicy !== undefined;
         }
+
+        // the retry policy in azure_core doesn't honor the Idempotency in the
+        // context so the IdempotencyPolicy runs after it to gate retries
+        const perTryPolicies = new Array<string>();
+        if (crate.idempotencyContext) {
+          use.add(`${utils.buildImportPath(client.module, client.module)}::models`, 'IdempotencyPolicy');
+          use.add('azure_core::http::policies', 'Policy');
+          use.add('std::sync', 'Arc');
+          body += `${indent.get()}let idempotency_policy: Arc<dyn Policy> = Arc::new(IdempotencyPolicy);\n`;
+          perTryPolicies.push('idempotency_policy');
+        }
+        if (hasAuthPolicy) {
+          perTryPolicies.push('auth_policy');
+        }
         body += `${indent.get()}Ok(Self {\n`;
 
         indent.push();
@@ -231,7 +222,7 @@ export function emitClients(module: rust.ModuleContainer): ClientModules | undef
         body += `${indent.get()}option_env!("CARGO_PKG_VERSION"),\n`;
         body += `${indent.get()}options.client_options,\n`;
         body += `${indent.get()}Vec::default(),\n`;
-        body += `${indent.get()}${hasAuthPolicy ? 'vec![auth_policy]' : 'Vec::default()'}, None,\n`;
+        body += `${indent.get()}${perTryPolicies.length > 0 ? `vec![${perTryPolicies.join(', ')}]` : 'Vec::default()'}, None,\n`;
         body += `${indent.pop().get()}),\n`; // end Pipeline::new
         body += `${indent.pop().get()}})\n`; // end Ok
         body += `${indent.pop().get()}}\n`; // end constructor
@@ -252,6 +243,10 @@ export function emitClients(module: rust.ModuleContainer): ClientModules | undef
       body += `${indent.pop().get()}}\n\n`;
     }
 
+    if (client.emitSendRequest) {
+      body += getSendRequestMethod(indent, use, client);
+    }
+
     const crate = helpers.getCrate(module);
 
     for (let i = 0; i < client.methods.length; ++i) {
@@ -285,6 +280,20 @@ export function emitClients(module: rust.ModuleContainer): ClientModules | undef
             return getLroMethodBody(crate, indentation, use, client, method);
           };
           break;
+        case 'request':
+          methodBody = (indentation: helpers.indentation): string => {
+            return getRequestMethodBody(indentation, use, client, method);
+          };
+          break;
+        case 'resourceId':
+          // the called method is traced so there's no need to trace this one
+          if (method.method.kind === 'async') {
+            async = 'async ';
+          }
+          methodBody = (indentation: helpers.indentation): string => {
+            return getResourceIdMethodBody(indentation, method);
+          };
+          break;
         case 'clientaccessor':
           isSubclientNew = true;
           methodBody = (indentation: helpers.indentation): string => {
@@ -298,11 +307,15 @@ export function emitClients(module: rust.ModuleContainer): ClientModules | undef
         body += paramsDocs;
       }
 
-      // client accessors will never have response headers
-      if (method.kind !== 'clientaccessor' && method.responseHeaders) {
+      // client accessors, request builders, and resource ID methods will never have response headers
+      if (method.kind !== 'clientaccessor' && method.kind !== 'request' && method.kind !== 'resourceId' && method.responseHeaders) {
         body += getHeaderTraitDocComment(indent, crate, method);
       }
 
+      if ((method.kind === 'async' || method.kind === 'lro' || method.kind === 'pageable') && method.visibility === 'pub') {
+        body += emitMethodDocExamples(indent, client, method);
+      }
+
       const paramsInfo = getMethodParamsCountAndSig(method, use);
       if (paramsInfo.count > 7) {
         // clippy will by default warn on 7+ args in a method.
@@ -327,6 +340,43 @@ export function emitClients(module: rust.ModuleContainer): ClientModules | undef
 
     body += '}\n\n'; // end client impl
 
+    if (client.methodsTrait) {
+      body += getClientMethodsTrait(indent, use, client, client.methodsTrait);
+    }
+
+    if (client.constructable && client.constructable.suppressed === 'no' && client.constructable.endpoint && client.constructable.endpoint.parameters.length > 0
+      && client.constructable.constructors.some((constructor) => !constructor.rawEndpoint)) {
+      const supplementalEndpoint = client.constructable.endpoint;
+      // RFC 6570 simple string expansion encodes everything outside the unreserved set.
+      // we're a bit more lenient and only encode the characters that would alter the URL.
+      if (supplementalEndpoint.parameters.some((param) => isEndpointHostSegment(supplementalEndpoint, param))) {
+        use.add('percent_encoding', 'AsciiSet', 'CONTROLS');
+        // when joined to the endpoint, a leading segment containing a colon is parsed as a
+        // scheme and one containing an at sign or brackets can introduce a new authority.
+        body += '/// Characters that are percent-encoded when substituting parameters into the leading segment of the endpoint.\n';
+        body += '///\n';
+        body += '/// These values determine the scheme and host of the joined endpoint so more characters are encoded.\n';
+        body += 'const ENDPOINT_HOST_ENCODE_SET: &AsciiSet = &CONTROLS\n';
+        body += `${indent.get()}${[' ', '"', '#', '%', '&', '+', '/', ':', '<', '=', '>', '?', '@', '[', '\\\\', ']', '`', '{', '}'].map((c) => `.add(b'${c}')`).join('\n' + indent.get())};\n\n`;
+      }
+      if (supplementalEndpoint.parameters.some((param) => !isEndpointHostSegment(supplementalEndpoint, param))) {
+        use.add('percent_encoding', 'AsciiSet', 'CONTROLS');
+        body += '/// Characters that are percent-encoded when substituting parameters into path segments of the endpoint.\n';
+        body += 'const ENDPOINT_PATH_ENCODE_SET: &AsciiSet = &CONTROLS\n';
+        body += `${indent.get()}${[' ', '"', '#', '%', '&', '+', '/', '<', '=', '>', '?', '`', '{', '}'].map((c) => `.add(b'${c}')`).join('\n' + indent.get())};\n\n`;
+      }
+    }
+
+    if (client.methods.some((method) => method.kind !== 'clientaccessor' && method.params.some((param) => param.kind === 'cookieScalar'))) {
+      use.add('percent_encoding', 'AsciiSet', 'CONTROLS');
+      helpers.getCrate(module).addDependency(new rust.CrateDependency('percent-encoding'));
+      // RFC 6265 cookie-octet excludes controls, whitespace, DQUOTE, comma, semicolon, and backslash.
+      // % is also encoded so that the encoding is reversible.
+      body += '/// Characters that are percent-encoded in cookie values.\n';
+      body += 'const COOKIE_VALUE_ENCODE_SET: &AsciiSet = &CONTROLS\n';
+      body += `${indent.get()}${[' ', '"', '%', ',', ';', '\\\\'].map((c) => `.add(b'${c}')`).join('\n' + indent.get())};\n\n`;
+    }
+
     // Emit pub(crate) const declarations for fields with default value constants.
     //
     // These constants are ALWAYS emitted, even when the options type is suppressed.
@@ -430,15 +480,19 @@ function getMethodOptions(module: rust.ModuleContainer): helpers.Module | undefi
   // collect all struct blocks so they can be sorted by name
   const structBlocks: Array<{ name: string; body: string }> = [];
 
+  // streaming companion methods share their options with the buffered method
+  const seenOptions = new Set<rust.Struct>();
+
   for (const client of module.clients) {
     for (const method of client.methods) {
-      if (method.kind === 'clientaccessor') {
+      if (method.kind === 'clientaccessor' || seenOptions.has(method.options.type.type)) {
         continue;
       }
 
       // method options struct
       let block = '';
       const optionsStruct = method.options.type.type;
+      seenOptions.add(optionsStruct);
       block += helpers.formatDocComment(optionsStruct.docs);
       use.add('azure_core::fmt', 'SafeDebug');
       block += '#[derive(Clone, Default, SafeDebug)]\n';
@@ -555,6 +609,10 @@ function getParamsBlockDocComment(indent: helpers.indentation, callable: rust.Co
   };
 
   let paramsContent = '';
+  if (callable.kind === 'resourceId') {
+    paramsContent += helpers.formatDocComment(callable.resourceId.docs, false, formatParamBullet(callable.resourceId.name), indent);
+  }
+
   const documentedGroups = new Set<string>();
   for (const param of callable.params) {
     if ('group' in param && param.group) {
@@ -587,7 +645,7 @@ function getParamsBlockDocComment(indent: helpers.indentation, callable: rust.Co
 
   if (callable.kind === 'constructor') {
     paramsContent += helpers.formatDocComment({ summary: 'Optional configuration for the client.' }, false, formatParamBullet('options'), indent);
-  } else if (callable.kind !== 'clientaccessor') {
+  } else if (callable.kind !== 'clientaccessor' && (callable.kind !== 'request' || requestMethodTakesOptions(callable))) {
     paramsContent += helpers.formatDocComment({ summary: 'Optional parameters for the request.' }, false, formatParamBullet('options'), indent);
   }
 
@@ -627,18 +685,127 @@ function getConstructorParamsSig(params: Array<rust.ClientParameter>, options: r
   return paramsSig.join(', ');
 }
 
+/**
+ * emits the methods trait for a client, its impl for the client,
+ * and the mock implementation that's behind the mock feature.
+ *
+ * @param indent the indentation helper currently in scope
+ * @param use the use statement builder currently in scope
+ * @param client the client for which to emit the trait
+ * @param methodsTrait the trait to emit
+ * @returns the trait and its impls
+ */
+function getClientMethodsTrait(indent: helpers.indentation, use: Use, client: rust.Client, methodsTrait: rust.ClientMethodsTrait): string {
+  use.add('async_trait', 'async_trait');
+  const methods = client.methods.filter((method) => utils.isClientTraitMethod(method));
+
+  /** returns the fn declaration for a method with the specified param names */
+  const getFnDecl = function (method: rust.AsyncMethod | rust.PageableMethod | rust.LroMethod, paramNames?: (name: string) => string): string {
+    const paramsInfo = getMethodParamsCountAndSig(method, use);
+    let sig = paramsInfo.sig;
+    if (paramNames) {
+      sig = [formatParamTypeName(method.self), ...paramsInfo.params.map((param) => `${paramNames(param.name)}: ${param.type}`)].join(', ');
+    }
+    let decl = '';
+    if (paramsInfo.count > 7) {
+      decl += `${indent.get()}#[allow(clippy::too_many_arguments)]\n`;
+    }
+    decl += `${indent.get()}${method.kind === 'async' ? 'async ' : ''}fn ${method.name}(${sig}) -> ${helpers.getTypeDeclaration(method.returns)}`;
+    return decl;
+  };
+
+  let body = `/// The methods of [\`${client.name}\`] as a trait so that consumers can substitute a fake.\n`;
+  body += '///\n';
+  body += `/// An in-memory implementation, \`${methodsTrait.mockName}\`, is available with the \`mock\` feature.\n`;
+  body += '#[async_trait]\n';
+  body += `pub trait ${methodsTrait.name}: Send + Sync {\n`;
+  for (let i = 0; i < methods.length; ++i) {
+    const method = methods[i];
+    body += `${indent.get()}${helpers.formatDocComment(method.docs)}`;
+    body += `${getFnDecl(method)};\n`;
+    if (i + 1 < methods.length) {
+      body += '\n';
+    }
+  }
+  body += '}\n\n';
+
+  // the client's impl calls its inherent methods
+  body += '#[async_trait]\n';
+  body += `impl ${methodsTrait.name} for ${client.name} {\n`;
+  for (let i = 0; i < methods.length; ++i) {
+    const method = methods[i];
+    const paramsInfo = getMethodParamsCountAndSig(method, use);
+    body += `${getFnDecl(method)} {\n`;
+    body += `${indent.push().get()}${client.name}::${method.name}(${['self', ...paramsInfo.params.map((param) => param.name)].join(', ')})${method.kind === 'async' ? '.await' : ''}\n`;
+    body += `${indent.pop().get()}}\n`;
+    if (i + 1 < methods.length) {
+      body += '\n';
+    }
+  }
+  body += '}\n\n';
+
+  // the mock returns the queued responses for each method in order.
+  // its types are fully qualified as they're only used when the mock feature is enabled.
+  body += `/// An in-memory implementation of [\`${methodsTrait.name}\`] that returns canned responses.\n`;
+  body += '///\n';
+  body += '/// Each method returns the responses queued for it in order, and an error once none remain.\n';
+  body += '#[cfg(feature = "mock")]\n';
+  body += '#[derive(Default)]\n';
+  body += `pub struct ${methodsTrait.mockName} {\n`;
+  for (const method of methods) {
+    body += `${indent.get()}${method.name}: std::sync::Mutex<std::collections::VecDeque<${helpers.getTypeDeclaration(method.returns.type)}>>,\n`;
+  }
+  body += '}\n\n';
+
+  body += '#[cfg(feature = "mock")]\n';
+  body += `impl ${methodsTrait.mockName} {\n`;
+  for (let i = 0; i < methods.length; ++i) {
+    const method = methods[i];
+    body += `${indent.get()}/// Queues a response for [\`${methodsTrait.name}::${method.name}()\`].\n`;
+    body += `${indent.get()}pub fn push_${method.name}(&self, response: ${helpers.getTypeDeclaration(method.returns.type)}) {\n`;
+    body += `${indent.push().get()}self.${method.name}.lock().unwrap().push_back(response);\n`;
+    body += `${indent.pop().get()}}\n`;
+    if (i + 1 < methods.length) {
+      body += '\n';
+    }
+  }
+  body += '}\n\n';
+
+  body += '#[cfg(feature = "mock")]\n';
+  body += '#[async_trait]\n';
+  body += `impl ${methodsTrait.name} for ${methodsTrait.mockName} {\n`;
+  for (let i = 0; i < methods.length; ++i) {
+    const method = methods[i];
+    body += `${getFnDecl(method, (name) => `_${name}`)} {\n`;
+    body += `${indent.push().get()}self.${method.name}.lock().unwrap().pop_front().ok_or_else(|| azure_core::Error::with_message(azure_core::error::ErrorKind::Other, "no response is queued for ${methodsTrait.mockName}::${method.name}()"))\n`;
+    body += `${indent.pop().get()}}\n`;
+    if (i + 1 < methods.length) {
+      body += '\n';
+    }
+  }
+  body += '}\n\n';
+
+  return body;
+}
+
 /**
  * creates the parameter signature for a client method
  * e.g. "foo: i32, bar: String, options: MethodOptions".
- * also returns the number of parameters in the sig.
+ * also returns the number of parameters in the sig and
+ * the names and types of the params other than self.
  * 
  * @param method the Rust method for which to create the param sig
  * @param use the use statement builder currently in scope
- * @returns the method params count and sig
+ * @returns the method params count, sig, and params
  */
-function getMethodParamsCountAndSig(method: rust.MethodType, use: Use): { count: number, sig: string } {
+function getMethodParamsCountAndSig(method: rust.MethodType, use: Use): { count: number, sig: string, params: Array<{ name: string, type: string }> } {
   const paramsSig = new Array<string>();
   paramsSig.push(formatParamTypeName(method.self));
+  const params = new Array<{ name: string, type: string }>();
+  const addParam = function (name: string, type: string): void {
+    paramsSig.push(`${name}: ${type}`);
+    params.push({ name: name, type: type });
+  };
 
   let count = 1; // self
   if (method.kind === 'clientaccessor') {
@@ -646,17 +813,23 @@ function getMethodParamsCountAndSig(method: rust.MethodType, use: Use): { count:
     // of optionality nor do they contain literals
     for (const param of method.params) {
       use.addForType(param.type);
-      paramsSig.push(`${param.name}: ${formatParamTypeName(param)}`);
+      addParam(param.name, formatParamTypeName(param));
       ++count;
     }
   } else {
+    if (method.kind === 'resourceId') {
+      use.addForType(method.resourceId.type);
+      addParam(method.resourceId.name, formatParamTypeName(method.resourceId));
+      ++count;
+    }
+
     const emittedGroups = new Set<string>();
     for (const param of method.params) {
       if (param.group) {
         if (!emittedGroups.has(param.group.name)) {
           emittedGroups.add(param.group.name);
           // required parameter group appears as a single struct parameter in the method signature
-          paramsSig.push(`${param.group.name}: ${helpers.getTypeDeclaration(param.group.type, 'anonymous')}`);
+          addParam(param.group.name, helpers.getTypeDeclaration(param.group.type, 'anonymous'));
           ++count;
         }
         continue;
@@ -675,16 +848,18 @@ function getMethodParamsCountAndSig(method: rust.MethodType, use: Use): { count:
       // don't add client or optional params to the method param sig
       if (param.location === 'method' && !param.optional) {
         use.addForType(param.kind === 'partialBody' ? param.paramType : param.type);
-        paramsSig.push(`${param.name}: ${formatParamTypeName(param)}`);
+        addParam(param.name, formatParamTypeName(param));
         ++count;
       }
     }
 
-    paramsSig.push(`${method.options.name}: ${helpers.getTypeDeclaration(method.options.type, 'anonymous')}`);
-    ++count;
+    if (method.kind !== 'request' || requestMethodTakesOptions(method)) {
+      addParam(method.options.name, helpers.getTypeDeclaration(method.options.type, 'anonymous'));
+      ++count;
+    }
   }
 
-  return { count: count, sig: paramsSig.join(', ') };
+  return { count: count, sig: paramsSig.join(', '), params: params };
 }
 
 /**
@@ -707,7 +882,9 @@ function getHeaderTraitDocComment(indent: helpers.indentation, module: rust.Modu
   let returnType: string;
   switch (method.returns.type.kind) {
     case 'asyncResponse':
+    case 'negotiatedResponse':
     case 'response':
+    case 'resumableDownload':
       returnType = method.returns.type.name;
       break;
     default:
@@ -845,9 +1022,18 @@ interface MethodParamGroups {
   /** the body parameter if applicable */
   body?: rust.BodyParameter;
 
+  /** cookie parameters. can be empty */
+  cookie: Array<rust.CookieScalarParameter>;
+
+  /** encoded bytes body parameter. can be undefined */
+  encodedBytesBody?: rust.EncodedBytesBodyParameter;
+
   /** header parameters. can be empty */
   header: Array<HeaderParamType>;
 
+  /** form-urlencoded body parameter. can be undefined */
+  formBody?: rust.FormBodyParameter;
+
   /** partial body parameters. can be empty */
   partialBody: Array<rust.PartialBodyParameter>;
 
@@ -856,6 +1042,9 @@ interface MethodParamGroups {
 
   /** query parameters. can be empty */
   query: Array<QueryParamType>;
+
+  /** streamed body parameter. can be undefined */
+  streamBody?: rust.StreamBodyParameter;
 }
 
 /**
@@ -867,12 +1056,16 @@ interface MethodParamGroups {
 function getMethodParamGroup(method: ClientMethod): MethodParamGroups {
   // collect and sort all the header/path/query params
   let apiVersionParam: ApiVersionParamType | undefined;
+  const cookieParams = new Array<rust.CookieScalarParameter>();
   const headerParams = new Array<HeaderParamType>();
   const pathParams = new Array<PathParamType>();
   const queryParams = new Array<QueryParamType>();
   const partialBodyParams = new Array<rust.PartialBodyParameter>();
   for (const param of method.params) {
     switch (param.kind) {
+      case 'cookieScalar':
+        cookieParams.push(param);
+        break;
       case 'headerScalar':
       case 'headerCollection':
       case 'headerHashMap':
@@ -897,27 +1090,48 @@ function getMethodParamGroup(method: ClientMethod): MethodParamGroups {
     }
   }
 
+  cookieParams.sort((a: rust.CookieScalarParameter, b: rust.CookieScalarParameter) => { return utils.sortAscending(a.cookie, b.cookie); });
   headerParams.sort((a: HeaderParamType, b: HeaderParamType) => { return utils.sortAscending(a.header, b.header); });
   pathParams.sort((a: PathParamType, b: PathParamType) => { return utils.sortAscending(a.segment, b.segment); });
   queryParams.sort((a: QueryParamType, b: QueryParamType) => { return utils.sortAscending(a.key, b.key); });
 
   let bodyParam: rust.BodyParameter | undefined;
+  let encodedBytesBodyParam: rust.EncodedBytesBodyParameter | undefined;
+  let formBodyParam: rust.FormBodyParameter | undefined;
+  let streamBodyParam: rust.StreamBodyParameter | undefined;
   for (const param of method.params) {
-    if (param.kind === 'body') {
-      if (bodyParam) {
+    if (param.kind === 'body' || param.kind === 'encodedBytesBody' || param.kind === 'formBody' || param.kind === 'streamBody') {
+      if (bodyParam || encodedBytesBodyParam || formBodyParam || streamBodyParam) {
         throw new CodegenError('InternalError', `method ${method.name} has multiple body parameters`);
       }
-      bodyParam = param;
+      switch (param.kind) {
+        case 'body':
+          bodyParam = param;
+          break;
+        case 'encodedBytesBody':
+          encodedBytesBodyParam = param;
+          break;
+        case 'formBody':
+          formBodyParam = param;
+          break;
+        case 'streamBody':
+          streamBodyParam = param;
+          break;
+      }
     }
   }
 
   return {
     apiVersion: apiVersionParam,
     body: bodyParam,
+    cookie: cookieParams,
+    encodedBytesBody: encodedBytesBodyParam,
+    formBody: formBodyParam,
     header: headerParams,
     partialBody: partialBodyParams,
     path: pathParams,
     query: queryParams,
+    streamBody: streamBodyParam,
   };
 }
 
@@ -939,7 +1153,7 @@ function getParamValueHelper(indent: helpers.indentation, param: rust.MethodPara
     let asRefOrClone = ''; // Empty value is ok as well, depending on what is needed.
     if (param.type.kind === 'requestContent') {
       asRefOrClone = '.clone()';
-    } else if (nonCopyableType(param.type) || isEnumString(param.type)) {
+    } else if (param.kind === 'formBody' || nonCopyableType(param.type) || isEnumString(param.type)) {
       asRefOrClone = '.as_ref()';
     }
     // optional params are in the unwrapped options local var
@@ -1203,6 +1417,55 @@ function applyHeaderParams(indent: helpers.indentation, use: Use, method: Client
     }, optionsPrefix);
   }
 
+  body += applyCookieParams(indent, use, method, paramGroups, inClosure, requestVarName, optionsPrefix);
+
+  return body;
+}
+
+/**
+ * emits the code for combining all cookie params into a single Cookie header.
+ * cookie values are percent-encoded with COOKIE_VALUE_ENCODE_SET as they can't contain characters like ; or ,
+ *
+ * @param indent the indentation helper currently in scope
+ * @param use the use statement builder currently in scope
+ * @param method the method for which we're building the body
+ * @param paramGroups the param groups for the provided method
+ * @param inClosure indicates if the request is being constructed within a closure (e.g. pageable methods)
+ * @param requestVarName name for the request variable
+ * @param optionsPrefix Syntax to access the options structure, including the dot
+ * @returns the code which sets the Cookie header for the request or the empty string
+ */
+function applyCookieParams(indent: helpers.indentation, use: Use, method: ClientMethod, paramGroups: MethodParamGroups, inClosure: boolean, requestVarName: string, optionsPrefix: string): string {
+  if (paramGroups.cookie.length === 0) {
+    return '';
+  }
+
+  use.add('percent_encoding', 'utf8_percent_encode');
+  const getCookiePair = function (cookieParam: rust.CookieScalarParameter): string {
+    // never borrow the value as it's borrowed here to coerce it to a &str
+    const cookieValue = getHeaderPathQueryParamValue(use, cookieParam, !inClosure && !cookieParam.optional, true);
+    // literals are a &str and optional non-copyable values are already borrowed via as_ref()
+    const borrowed = cookieParam.type.kind === 'literal' || (cookieParam.optional && nonCopyableType(cookieParam.type));
+    return `format!("${cookieParam.cookie}={}", utf8_percent_encode(${borrowed ? '' : '&'}${cookieValue}, COOKIE_VALUE_ENCODE_SET))`;
+  };
+
+  const requiredCookies = paramGroups.cookie.filter((cookieParam) => !cookieParam.optional || cookieParam.type.kind === 'literal');
+  const optionalCookies = paramGroups.cookie.filter((cookieParam) => !requiredCookies.includes(cookieParam));
+
+  if (optionalCookies.length === 0) {
+    // all cookies are always present so we can join them directly
+    return `${indent.get()}${requestVarName}.insert_header("cookie", [${requiredCookies.map(getCookiePair).join(', ')}].join("; "));\n`;
+  }
+
+  const cookiesVarName = helpers.getUniqueVarName(method.params, ['cookies', 'cookie_values']);
+  let body = `${indent.get()}let mut ${cookiesVarName} = ${requiredCookies.length > 0 ? `vec![${requiredCookies.map(getCookiePair).join(', ')}]` : 'Vec::new()'};\n`;
+  for (const cookieParam of optionalCookies) {
+    body += getParamValueHelper(indent, cookieParam, () => `${indent.get()}${cookiesVarName}.push(${getCookiePair(cookieParam)});\n`, optionsPrefix);
+  }
+  body += indent.get() + helpers.buildIfBlock(indent, {
+    condition: `!${cookiesVarName}.is_empty()`,
+    body: (indent) => `${indent.get()}${requestVarName}.insert_header("cookie", ${cookiesVarName}.join("; "));\n`,
+  }) + '\n';
   return body;
 }
 
@@ -1229,7 +1492,13 @@ function constructPartialBodyRequestContent(indent: helpers.indentation, use: Us
   const requestContentType = paramGroups.partialBody[0].type;
   use.addForType(requestContentType);
 
-  let body = `${indent.get()}let ${bodyVarName}: ${inClosure ? 'Result<' : ''}${helpers.getTypeDeclaration(requestContentType)}${inClosure ? '>' : ''} = ${requestContentType.content.name} {\n`;
+  // form-urlencoded bodies are encoded from a local instance of the model
+  const isFormBody = requestContentType.format === 'FormFormat';
+  const formModelVarName = helpers.getUniqueVarName(paramGroups.partialBody, ['form_model', 'form_model_var']);
+
+  let body = isFormBody
+    ? `${indent.get()}let ${formModelVarName} = ${requestContentType.content.name} {\n`
+    : `${indent.get()}let ${bodyVarName}: ${inClosure ? 'Result<' : ''}${helpers.getTypeDeclaration(requestContentType)}${inClosure ? '>' : ''} = ${requestContentType.content.name} {\n`;
   indent.push();
   for (const partialBodyParam of paramGroups.partialBody) {
     if (partialBodyParam.type.content !== requestContentType.content) {
@@ -1256,6 +1525,16 @@ function constructPartialBodyRequestContent(indent: helpers.indentation, use: Us
     body += `${indent.get()}${initializer},\n`;
   }
 
+  if (isFormBody) {
+    body += `${indent.pop().get()}};\n`;
+    const formBodyVarName = inClosure ? 'form_body' : bodyVarName;
+    body += encodeFormBody(indent, use, requestContentType, paramGroups.partialBody[0].collectionFormats ?? new Map<string, rust.ExtendedCollectionFormat>(), formModelVarName, formBodyVarName);
+    if (inClosure) {
+      body += `${indent.get()}let ${bodyVarName}: Result<${helpers.getTypeDeclaration(requestContentType)}> = Ok(${formBodyVarName});\n`;
+    }
+    return body;
+  }
+
   body += `${indent.pop().get()}}.try_into()`;
   if (inClosure) {
     body += `;\n`;
@@ -1265,6 +1544,86 @@ function constructPartialBodyRequestContent(indent: helpers.indentation, use: Us
   return body;
 }
 
+/**
+ * emits the code that encodes a flat model into an application/x-www-form-urlencoded
+ * request body. creates a local named bodyVarName that contains the RequestContent.
+ *
+ * @param indent the indentation helper currently in scope
+ * @param use the use statement builder currently in scope
+ * @param requestContent the request content that the model is encoded into
+ * @param collectionFormats the collection formats for any Vec<T> fields keyed by serde name
+ * @param modelVarName the name of the local that contains the model (can be a reference)
+ * @param bodyVarName the name of the local that will contain the RequestContent
+ * @returns the form encoding code
+ */
+function encodeFormBody(indent: helpers.indentation, use: Use, requestContent: rust.RequestContent<rust.Model>, collectionFormats: Map<string, rust.ExtendedCollectionFormat>, modelVarName: string, bodyVarName: string): string {
+  use.add('url', 'form_urlencoded');
+  use.addForType(requestContent);
+
+  // returns an expression that converts the borrowed item to a String
+  const getFormString = function (type: rust.Type, item: string): string {
+    if (type.kind === 'offsetDateTime') {
+      const encoding = helpers.getDateTimeEncodingMethod(type.encoding, 'to', use);
+      return type.encoding === 'unix_time' ? `${item}.${encoding}.to_string()` : `${encoding}(${item})`;
+    }
+    return `${item}.to_string()`;
+  };
+
+  // returns an expression that borrows the item as a &str
+  const getFormValue = function (type: rust.Type, item: string): string {
+    switch (type.kind) {
+      case 'String':
+        return item;
+      case 'enum':
+        return isEnumString(type) ? `${item}.as_ref()` : `&${getFormString(type, item)}`;
+      case 'literal':
+        return `"${type.value}"`;
+      default:
+        return `&${getFormString(type, item)}`;
+    }
+  };
+
+  let body = `${indent.get()}let mut form_serializer = form_urlencoded::Serializer::new(String::new());\n`;
+  for (const field of requestContent.content.fields) {
+    if (field.kind === 'additionalProperties') {
+      throw new CodegenError('InternalError', `unexpected additional properties in form-urlencoded body type ${requestContent.content.name}`);
+    }
+
+    // for optional fields, the value is borrowed from the Option<T>.
+    // it's bound to a fixed name so that it can't shadow any locals (e.g. form_serializer).
+    const isOptional = field.type.kind === 'option';
+    const fieldType = field.type.kind === 'option' ? field.type.type : field.type;
+    const fieldRef = isOptional ? 'value' : `&${modelVarName}.${field.name}`;
+
+    let setter: (indent: helpers.indentation) => string;
+    if (fieldType.kind === 'Vec') {
+      const format = collectionFormats.get(field.serde) ?? 'multi';
+      if (format === 'multi') {
+        setter = (indent) => `${indent.get()}for item in ${fieldRef} {\n${indent.push().get()}form_serializer.append_pair("${field.serde}", ${getFormValue(fieldType.type, 'item')});\n${indent.pop().get()}}\n`;
+      } else {
+        const delimiter = helpers.getCollectionDelimiter(format);
+        const joined = fieldType.type.kind === 'String'
+          ? `${isOptional ? fieldRef : `${modelVarName}.${field.name}`}.join("${delimiter}")`
+          : `${fieldRef}.iter().map(|item| ${getFormString(fieldType.type, 'item')}).collect::<Vec<String>>().join("${delimiter}")`;
+        setter = (indent) => `${indent.get()}form_serializer.append_pair("${field.serde}", &${joined});\n`;
+      }
+    } else {
+      setter = (indent) => `${indent.get()}form_serializer.append_pair("${field.serde}", ${getFormValue(fieldType, fieldRef)});\n`;
+    }
+
+    if (isOptional) {
+      body += `${indent.get()}${helpers.buildIfBlock(indent, {
+        condition: `let Some(${fieldRef}) = &${modelVarName}.${field.name}`,
+        body: setter,
+      })}\n`;
+    } else {
+      body += setter(indent);
+    }
+  }
+  body += `${indent.get()}let ${bodyVarName}: ${helpers.getTypeDeclaration(requestContent)} = form_serializer.finish().into_bytes().into();\n`;
+  return body;
+}
+
 /**
  * emits the code for building the HTTP request.
  * assumes that there's a local var 'url' which is the Url.
@@ -1284,7 +1643,7 @@ function constructRequest(indent: helpers.indentation, use: Use, method: ClientM
   // when constructing the request var name we need to ensure
   // that it doesn't collide with any parameter name.
   const requestVarName = helpers.getUniqueVarName(method.params, ['request', 'core_req']);
-  let body = `${indent.get()}let ${(forceMut || paramGroups.header.length > 0) ? 'mut ' : ''}${requestVarName} = Request::new(${urlVarName}${cloneUrl ? '.clone()' : ''}, Method::${utils.capitalize(method.httpMethod)});\n`;
+  let body = `${indent.get()}let ${(forceMut || paramGroups.header.length > 0 || paramGroups.cookie.length > 0) ? 'mut ' : ''}${requestVarName} = Request::new(${urlVarName}${cloneUrl ? '.clone()' : ''}, Method::${utils.capitalize(method.httpMethod)});\n`;
 
   body += applyHeaderParams(indent, use, method, paramGroups, inClosure, requestVarName);
 
@@ -1300,6 +1659,42 @@ function constructRequest(indent: helpers.indentation, use: Use, method: ClientM
       bodyParamContent += `${indent.get()}${requestVarName}.set_body(${bodyParam.name}${inClosure ? '.clone()' : ''});\n`;
       return bodyParamContent;
     });
+  } else if (paramGroups.encodedBytesBody) {
+    if (inClosure) {
+      throw new CodegenError('InternalError', 'encoded bytes body request construction in closures is not supported');
+    }
+    const encodedBytesBodyParam = paramGroups.encodedBytesBody;
+    body += getParamValueHelper(indent, encodedBytesBodyParam, () => {
+      let bodyParamContent = '';
+      if (optionalContentTypeParam) {
+        bodyParamContent = `${indent.get()}${requestVarName}.insert_header("${optionalContentTypeParam.header.toLowerCase()}", ${getHeaderPathQueryParamValue(use, optionalContentTypeParam, true, false)});\n`;
+      }
+      // the bytes are sent as a JSON string containing the encoded bytes
+      const encodedBytes = <rust.EncodedBytes>helpers.unwrapType(encodedBytesBodyParam.type);
+      use.add('azure_core::json', 'to_json');
+      bodyParamContent += `${indent.get()}${requestVarName}.set_body(to_json(&${helpers.getBytesEncodingMethod(encodedBytes.encoding, 'encode', use)}(${encodedBytesBodyParam.name}))?);\n`;
+      return bodyParamContent;
+    });
+  } else if (paramGroups.formBody) {
+    const formBodyParam = paramGroups.formBody;
+    body += getParamValueHelper(indent, formBodyParam, () => {
+      let bodyParamContent = '';
+      if (optionalContentTypeParam) {
+        bodyParamContent = `${indent.get()}${requestVarName}.insert_header("${optionalContentTypeParam.header.toLowerCase()}", ${getHeaderPathQueryParamValue(use, optionalContentTypeParam, !inClosure, false)});\n`;
+      }
+      bodyParamContent += encodeFormBody(indent, use, formBodyParam.content, formBodyParam.collectionFormats, formBodyParam.name, 'body');
+      bodyParamContent += `${indent.get()}${requestVarName}.set_body(body);\n`;
+      return bodyParamContent;
+    });
+  } else if (paramGroups.streamBody) {
+    if (inClosure) {
+      throw new CodegenError('InternalError', 'streamed body request construction in closures is not supported');
+    }
+    // streamed bodies are always required so there's no optional content-type to apply
+    const streamBodyParam = paramGroups.streamBody;
+    // the stream's length is sent up front so the body can be streamed without buffering
+    body += `${indent.get()}${requestVarName}.insert_header("content-length", ${streamBodyParam.name}.len().to_string());\n`;
+    body += `${indent.get()}${requestVarName}.set_body(${streamBodyParam.name});\n`;
   } else if (paramGroups.partialBody.length > 0) {
     if (inClosure) {
       throw new CodegenError('InternalError', 'partial body request construction in closures must use a dedicated fallible path');
@@ -1406,6 +1801,163 @@ function emitEmptyPathParamCheck(indent: helpers.indentation, param: PathParamTy
   });
 }
 
+/**
+ * returns true if the request builder method takes the options of its async method.
+ * the options are only consumed by optional params as the request isn't sent, so
+ * request builders for methods without optional params don't take them.
+ *
+ * @param method the request builder method
+ * @returns true if the method takes the options param
+ */
+function requestMethodTakesOptions(method: rust.RequestMethod): boolean {
+  return method.params.some((param) => param.optional && param.location === 'method');
+}
+
+/**
+ * constructs the body for a method that builds the request
+ * for an async client method without sending it
+ * 
+ * @param indent the indentation helper currently in scope
+ * @param use the use statement builder currently in scope
+ * @param client the client to which the method belongs
+ * @param method the method for the body to build
+ * @returns the contents of the method body
+ */
+function getRequestMethodBody(indent: helpers.indentation, use: Use, client: rust.Client, method: rust.RequestMethod): string {
+  use.add('azure_core::http', 'Method', 'Request');
+
+  const urlVarName = helpers.getUniqueVarName(method.params, ['url', 'url_var']);
+  const paramGroups = getMethodParamGroup(method.method);
+  let body = checkEmptyRequiredPathParams(indent, paramGroups.path);
+  if (requestMethodTakesOptions(method)) {
+    body += `${indent.get()}let options = options.unwrap_or_default();\n`;
+  }
+  body += `${indent.get()}let ${urlVarNeedsMut(paramGroups, method.method)}${urlVarName} = self.${client.endpoint.name}.clone();\n`;
+
+  body += constructUrl(indent, use, method.method, paramGroups, urlVarName);
+  const hasBody = paramGroups.body !== undefined || paramGroups.encodedBytesBody !== undefined || paramGroups.formBody !== undefined || paramGroups.streamBody !== undefined || paramGroups.partialBody.length > 0;
+  const requestResult = constructRequest(indent, use, method.method, paramGroups, false, urlVarName, false, hasBody);
+  body += requestResult.content;
+  body += `${indent.get()}Ok(${requestResult.requestVarName})\n`;
+  return body;
+}
+
+/**
+ * emits the send_request method which sends a caller-provided
+ * request through the client's pipeline.
+ *
+ * @param indent the indentation helper currently in scope
+ * @param use the use statement builder currently in scope
+ * @param client the client for which to emit the method
+ * @returns the send_request method
+ */
+function getSendRequestMethod(indent: helpers.indentation, use: Use, client: rust.Client): string {
+  use.add('azure_core', 'Result');
+  use.add('azure_core::http', 'ClientMethodOptions', 'RawResponse', 'Request');
+
+  let body = `${indent.get()}/// Sends the request through the client's pipeline.\n`;
+  body += `${indent.get()}///\n`;
+  body += `${indent.get()}/// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).\n`;
+  body += `${indent.get()}/// This allows sending requests that were built or modified by the caller, e.g. to add a parameter\n`;
+  body += `${indent.get()}/// the client doesn't support yet. The [\`RawResponse\`] can be converted to a typed\n`;
+  body += `${indent.get()}/// [\`Response<T>\`](azure_core::http::Response) with \`into()\`.\n`;
+  body += `${indent.get()}///\n`;
+  body += `${indent.get()}/// Any 2xx status code is treated as success. Other status codes return an error of kind\n`;
+  body += `${indent.get()}/// [\`ErrorKind::HttpResponse\`](azure_core::error::ErrorKind::HttpResponse).\n`;
+  const idempotencyContext = helpers.getCrate(client.module).idempotencyContext;
+  if (idempotencyContext) {
+    body += `${indent.get()}///\n`;
+    body += `${indent.get()}/// If the context in \`options\` doesn't contain an [\`Idempotency\`] value, one is added based on the\n`;
+    body += `${indent.get()}/// request's method. POST and PATCH requests are treated as non-idempotent and won't be retried.\n`;
+  }
+  body += `${indent.get()}///\n`;
+  body += `${indent.get()}/// # Arguments\n`;
+  body += `${indent.get()}///\n`;
+  body += `${indent.get()}/// * \`request\` - The request to send.\n`;
+  body += `${indent.get()}/// * \`options\` - Optional parameters for the request.\n`;
+  body += `${indent.get()}pub async fn send_request(&self, mut request: Request, options: Option<ClientMethodOptions<'_>>) -> Result<RawResponse> {\n`;
+  body += `${indent.push().get()}let options = options.unwrap_or_default();\n`;
+  if (idempotencyContext) {
+    use.add('azure_core::http', 'Method');
+    use.add(`${utils.buildImportPath(client.module, client.module)}::models`, 'Idempotency');
+    body += `${indent.get()}let mut ctx = options.context.to_borrowed();\n`;
+    body += `${indent.get()}if ctx.value::<Idempotency>().is_none() {\n`;
+    body += `${indent.push().get()}let idempotent = !matches!(request.method(), Method::Post | Method::Patch);\n`;
+    body += `${indent.get()}ctx = ctx.with_value(Idempotency::new(idempotent, idempotent));\n`;
+    body += `${indent.pop().get()}}\n`;
+  } else {
+    body += `${indent.get()}let ctx = options.context.to_borrowed();\n`;
+  }
+  body += `${indent.get()}self.pipeline.send(&ctx, &mut request, None).await\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  return body;
+}
+
+/**
+ * constructs the body for a method that calls an ARM operation
+ * with its path params taken from a resource ID
+ * 
+ * @param indent the indentation helper currently in scope
+ * @param method the method for the body to build
+ * @returns the contents of the method body
+ */
+function getResourceIdMethodBody(indent: helpers.indentation, method: rust.ResourceIdMethod): string {
+  const pathParamsVarName = helpers.getUniqueVarName(method.params, ['path_params', 'resource_id_params']);
+  let body = `let ${pathParamsVarName} = ${method.resourceId.name}.path_params("${method.httpPath}")?;\n`;
+
+  // client params can't be changed per call so the resource ID must match them
+  for (const param of method.method.params) {
+    if (param.kind !== 'pathScalar' || param.location !== 'client') {
+      continue;
+    }
+    body += `${indent.get()}${helpers.buildIfBlock(indent, {
+      condition: `!${pathParamsVarName}["${param.segment}"].eq_ignore_ascii_case(&self.${param.name})`,
+      body: (indent) => `${indent.get()}return Err(azure_core::Error::with_message(azure_core::error::ErrorKind::DataConversion, format!("resource ID {${method.resourceId.name}} doesn't match the client's ${param.name}")));\n`,
+    })}\n`;
+  }
+
+  // pass the params in the same order as the called method's signature
+  const args = new Array<string>();
+  const emittedGroups = new Set<string>();
+  for (const param of method.method.params) {
+    const paramType = helpers.unwrapType(param.type);
+    if (param.group) {
+      if (!emittedGroups.has(param.group.name)) {
+        emittedGroups.add(param.group.name);
+        args.push(param.group.name);
+      }
+    } else if (paramType.kind === 'literal' || paramType.kind === 'enumValue' || param.location !== 'method' || param.optional) {
+      continue;
+    } else if (param.kind === 'pathScalar') {
+      args.push(`${pathParamsVarName}["${param.segment}"]`);
+    } else {
+      args.push(param.name);
+    }
+  }
+  args.push(method.options.name);
+
+  body += `${indent.get()}self.${method.method.name}(${args.join(', ')})${method.method.kind === 'async' ? '.await' : ''}\n`;
+  return body;
+}
+
+/**
+ * returns the Idempotency value that a method adds to its request's context.
+ * non-idempotent methods have an allow_retry field in their options.
+ *
+ * @param use the use statement builder currently in scope
+ * @param client the client to which the method belongs
+ * @param method the method for which to create the value
+ * @param localVar when true, the idempotency comes from a local var named idempotent (e.g. for LRO polling requests)
+ * @returns the Idempotency struct literal
+ */
+function getIdempotencyValue(use: Use, client: rust.Client, method: rust.AsyncMethod | rust.LroMethod | rust.PageableMethod, localVar = false): string {
+  use.add(`${utils.buildImportPath(client.module, client.module)}::models`, 'Idempotency');
+  if (localVar) {
+    return `Idempotency { idempotent, retry_allowed: idempotent || options.allow_retry }`;
+  }
+  return `Idempotency { idempotent: ${method.idempotent}, retry_allowed: ${method.idempotent ? 'true' : 'options.allow_retry'} }`;
+}
+
 /**
  * constructs the body for an async client method
  * 
@@ -1422,7 +1974,11 @@ function getAsyncMethodBody(indent: helpers.indentation, use: Use, client: rust.
   const paramGroups = getMethodParamGroup(method);
   let body = checkEmptyRequiredPathParams(indent, paramGroups.path);
   body += 'let options = options.unwrap_or_default();\n';
-  body += `${indent.get()}let ctx = options.method_options.context.to_borrowed();\n`;
+  if (helpers.getCrate(client.module).idempotencyContext) {
+    body += `${indent.get()}let ctx = options.method_options.context.to_borrowed().with_value(${getIdempotencyValue(use, client, method)});\n`;
+  } else {
+    body += `${indent.get()}let ctx = options.method_options.context.to_borrowed();\n`;
+  }
   body += `${indent.get()}let ${urlVarNeedsMut(paramGroups, method)}${urlVarName} = self.${client.endpoint.name}.clone();\n`;
 
   body += constructUrl(indent, use, method, paramGroups, urlVarName);
@@ -1432,17 +1988,81 @@ function getAsyncMethodBody(indent: helpers.indentation, use: Use, client: rust.
   let pipelineMethod: string;
   switch (method.returns.type.kind) {
     case 'asyncResponse':
+    case 'resumableDownload':
       pipelineMethod = 'stream';
       break;
+    case 'negotiatedResponse':
     case 'response':
       pipelineMethod = 'send';
       break;
   }
   body += `${indent.get()}let rsp = self.pipeline.${pipelineMethod}(&ctx, &mut ${requestResult.requestVarName}, ${getPipelineOptions(indent, use, method)}).await?;\n`;
+  if (method.returns.type.kind === 'negotiatedResponse') {
+    body += getNegotiatedResponse(indent, use, client, method.returns.type);
+    return body;
+  } else if (method.returns.type.kind === 'resumableDownload') {
+    // the download is resumed by reissuing a copy of the request so it's moved into the stream
+    body += `${indent.get()}Ok(${method.returns.type.name}::new(self.pipeline.clone(), ctx.into_owned(), ${requestResult.requestVarName}, rsp, "${method.returns.type.rangeHeader}"))\n`;
+    return body;
+  }
+  if (method.returns.type.kind === 'response' && method.returns.type.format === 'TextFormat') {
+    // reject textual responses that aren't encoded as UTF-8
+    use.add(`${utils.buildImportPath(client.module, client.module)}::models`, 'check_text_charset');
+    body += `${indent.get()}check_text_charset(rsp.headers())?;\n`;
+  }
   body += `${indent.get()}Ok(rsp.into())\n`;
   return body;
 }
 
+/**
+ * returns the code that maps a response to the variant of a
+ * negotiated response that matches the response's content type.
+ *
+ * @param indent the indentation helper currently in scope
+ * @param use the use statement builder currently in scope
+ * @param client the client to which the method belongs
+ * @param negotiatedResponse the negotiated response type returned by the method
+ * @returns the code that returns the negotiated response
+ */
+function getNegotiatedResponse(indent: helpers.indentation, use: Use, client: rust.Client, negotiatedResponse: rust.NegotiatedResponse): string {
+  use.add('azure_core::error', 'Error', 'ErrorKind');
+  use.add('azure_core::http::headers', 'CONTENT_TYPE');
+  use.addForType(negotiatedResponse);
+
+  // media types are case-insensitive and can include parameters (e.g. charset)
+  let body = `${indent.get()}let content_type = rsp\n`;
+  body += `${indent.push().get()}.headers()\n`;
+  body += `${indent.get()}.get_optional_str(&CONTENT_TYPE)\n`;
+  body += `${indent.get()}.and_then(|v| v.split(';').next())\n`;
+  body += `${indent.get()}.unwrap_or_default()\n`;
+  body += `${indent.get()}.trim()\n`;
+  body += `${indent.get()}.to_ascii_lowercase();\n`;
+  indent.pop();
+
+  const arms = new Array<helpers.matchArm>();
+  for (const variant of negotiatedResponse.variants) {
+    use.addForType(variant.type);
+    arms.push({
+      pattern: `"${variant.contentType.toLowerCase()}"`,
+      body: (indent) => {
+        let armBody = '';
+        if (variant.type.kind === 'response' && variant.type.format === 'TextFormat') {
+          use.add(`${utils.buildImportPath(client.module, client.module)}::models`, 'check_text_charset');
+          armBody += `${indent.get()}check_text_charset(rsp.headers())?;\n`;
+        }
+        armBody += `${indent.get()}Ok(${negotiatedResponse.name}::${variant.name}(${variant.type.kind === 'rawResponse' ? 'rsp' : 'rsp.into()'}))\n`;
+        return armBody;
+      },
+    });
+  }
+  arms.push({
+    pattern: '_',
+    body: (indent) => `${indent.get()}Err(Error::with_message(ErrorKind::DataConversion, format!("unexpected content type '{content_type}'")))\n`,
+  });
+
+  return `${indent.get()}${helpers.buildMatch(indent, 'content_type.as_str()', arms)}\n`;
+}
+
 /** describes a field emitted in a local helper struct. */
 interface HelperStructField {
   name: string;
@@ -1706,12 +2326,17 @@ function getPageableMethodBody(indent: helpers.indentation, use: Use, client: ru
   const requestResult = constructRequest(indent, use, method, paramGroups, true, srcUrlVar, cloneUrl);
   body += requestResult.content;
   body += `${indent.get()}let pipeline = pipeline.clone();\n`;
+  let ctxVar = '&pager_options.context';
+  if (helpers.getCrate(client.module).idempotencyContext) {
+    body += `${indent.get()}let ctx = pager_options.context.with_value(${getIdempotencyValue(use, client, method)});\n`;
+    ctxVar = '&ctx';
+  }
   body += `${indent.get()}Box::pin(`;
   if (method.strategy?.kind === 'nextLink') {
     body += `{\n${indent.push().get()}let first_url = first_url.clone();\n${indent.get()}`;
   }
   body += `async move {\n`;
-  body += `${indent.push().get()}let rsp${rspType} = pipeline.send(&pager_options.context, &mut ${requestResult.requestVarName}, ${getPipelineOptions(indent, use, method)}).await?${rspInto};\n`;
+  body += `${indent.push().get()}let rsp${rspType} = pipeline.send(${ctxVar}, &mut ${requestResult.requestVarName}, ${getPipelineOptions(indent, use, method)}).await?${rspInto};\n`;
 
   // check if we need to extract the next link field from the response model
   if (method.strategy && (method.strategy.kind === 'nextLink' || method.strategy.responseToken.kind === 'nextLink')) {
@@ -1852,7 +2477,7 @@ function getLroMethodBody(crate: rust.Crate, indent: helpers.indentation, use: U
     let mutRequest = '';
     // if the only header is optional Content-Type it will not be used
     // by applyHeaderParams() in this case so don't make request mutable
-    if (forceMut || paramGroups.header.length > 1 || (paramGroups.header.length === 1 && !isOptionalContentTypeHeader(paramGroups.header[0]))) {
+    if (forceMut || paramGroups.cookie.length > 0 || paramGroups.header.length > 1 || (paramGroups.header.length === 1 && !isOptionalContentTypeHeader(paramGroups.header[0]))) {
       mutRequest = 'mut ';
     }
 
@@ -1862,6 +2487,12 @@ function getLroMethodBody(crate: rust.Crate, indent: helpers.indentation, use: U
 
   body += `${indent.get()}Ok(${method.returns.type.name}::new(\n`
   body += `${indent.push().get()}move |poller_state: PollerState, poller_options| {\n`;
+  const idempotencyContext = helpers.getCrate(client.module).idempotencyContext;
+  if (idempotencyContext && !method.idempotent) {
+    // only the initial request has side effects. the polling requests are idempotent
+    body += `${indent.push().get()}let idempotent = !matches!(poller_state, PollerState::Initial);\n`;
+    indent.pop();
+  }
   const fallibleInitialPollerRequest = paramGroups.partialBody.length > 0
     ? constructFallibleInitialPollerRequest(indent, use, method, paramGroups, initialRequestResult.requestVarName)
     : undefined;
@@ -1869,7 +2500,7 @@ function getLroMethodBody(crate: rust.Crate, indent: helpers.indentation, use: U
     pattern: `PollerState::More(continuation)`,
     body: (indent) => {
       const mutNextLink = paramGroups.apiVersion?.kind === 'queryScalar' ? 'mut ' : '';
-      const continuationMatchExpr = (!paramGroups.body && paramGroups.partialBody.length === 0) ? 'continuation.clone()' : 'continuation';
+      const continuationMatchExpr = (!paramGroups.body && !paramGroups.encodedBytesBody && !paramGroups.formBody && !paramGroups.streamBody && paramGroups.partialBody.length === 0) ? 'continuation.clone()' : 'continuation';
       let body = `${indent.get()}let (${mutNextLink}next_link, final_link) = ${helpers.buildMatch(indent, continuationMatchExpr, [{
         pattern: 'PollerContinuation::Links { next_link, final_link }',
         body: (indent) => `${indent.get()}(next_link, final_link)\n`,
@@ -1901,7 +2532,11 @@ function getLroMethodBody(crate: rust.Crate, indent: helpers.indentation, use: U
       return body;
     },
   }])};\n`;
-  body += `${indent.get()}let ctx = poller_options.context.clone();\n`
+  if (idempotencyContext) {
+    body += `${indent.get()}let ctx = poller_options.context.clone().with_value(${getIdempotencyValue(use, client, method, !method.idempotent)});\n`;
+  } else {
+    body += `${indent.get()}let ctx = poller_options.context.clone();\n`;
+  }
   body += `${indent.get()}let pipeline = pipeline.clone();\n`
 
   if (method.finalResultStrategy.kind === 'header' && method.finalResultStrategy.headerName !== pollingStepHeaderName) {
@@ -1913,7 +2548,7 @@ function getLroMethodBody(crate: rust.Crate, indent: helpers.indentation, use: U
   if (method.finalResultStrategy.kind === 'originalUri') {
     body += `${indent.get()}let final_link = url.clone();\n`
 
-    for (const headerParam of paramGroups.header.filter(h => h.type.kind !== 'literal' && !isOptionalContentTypeHeader(h))) {
+    for (const headerParam of [...paramGroups.header.filter(h => !isOptionalContentTypeHeader(h)), ...paramGroups.cookie].filter(h => h.type.kind !== 'literal')) {
       if (headerParam.type.kind !== 'enum') {
         const optionsPrefix = headerParam.optional ? 'options.' : '';
         body += `${indent.get()}let ${headerParam.name} = ${optionsPrefix}${headerParam.name}.clone();\n`
@@ -2122,6 +2757,42 @@ function getLroMethodBody(crate: rust.Crate, indent: helpers.indentation, use: U
   return body;
 }
 
+/**
+ * returns true if the param's segment precedes the first forward slash of the supplemental endpoint.
+ * when joined to the endpoint, this segment can change the scheme and host so it's encoded more strictly.
+ *
+ * @param endpoint the supplemental endpoint that contains the param
+ * @param param the supplemental endpoint param
+ * @returns true if the param is part of the leading segment
+ */
+function isEndpointHostSegment(endpoint: rust.SupplementalEndpoint, param: rust.ClientSupplementalEndpointParameter): boolean {
+  const slash = endpoint.path.indexOf('/');
+  return slash < 0 || endpoint.path.indexOf(`{${param.segment}}`) < slash;
+}
+
+/**
+ * returns the textual value of a supplemental endpoint param as a &str.
+ * params whose values can't be empty or a dot segment (e.g. fixed enums
+ * and numeric types) return undefined as they don't require validation.
+ *
+ * @param param the supplemental endpoint param
+ * @returns the &str expression or undefined
+ */
+function getClientSupplementalEndpointParamText(param: rust.ClientSupplementalEndpointParameter): string | undefined {
+  const paramName = `${param.optional ? 'options.' : ''}${param.name}`;
+  const unwrappedType = helpers.unwrapType(param.type);
+  switch (unwrappedType.kind) {
+    case 'String':
+      return `${paramName}.as_str()`;
+    case 'enum':
+      return unwrappedType.extensible ? `${paramName}.as_ref()` : undefined;
+    case 'str':
+      return paramName;
+    default:
+      return undefined;
+  }
+}
+
 /**
  * contains the code to use when populating a client endpoint parameter value
  * from a parameter of that type.
@@ -2168,7 +2839,7 @@ function getClientSupplementalEndpointParamValue(param: rust.ClientSupplementalE
  * @param overrideParamName optional value to use as the parameter name instead of param.name
  * @returns the code to use for the param's value
  */
-function getHeaderPathQueryParamValue(use: Use, param: HeaderParamType | PathParamType | QueryParamType, fromSelf: boolean, neverBorrow: boolean, overrideParamName?: string): string {
+function getHeaderPathQueryParamValue(use: Use, param: rust.CookieScalarParameter | HeaderParamType | PathParamType | QueryParamType, fromSelf: boolean, neverBorrow: boolean, overrideParamName?: string): string {
   let paramName = param.name;
   // when fromSelf is false we assume that there's a local with the same name.
   // e.g. in pageable methods where we need to clone the params so they can be
@@ -2219,7 +2890,7 @@ function getHeaderPathQueryParamValue(use: Use, param: HeaderParamType | PathPar
   // we want multi to hit the else case so the necessary conversions etc can happen
   if ((param.kind === 'headerCollection' || param.kind === 'queryCollection') && param.format !== 'multi') {
     if (paramType.kind === 'String' || paramType.kind === 'str') {
-      paramValue = `${paramName}.join("${getCollectionDelimiter(param.format)}")`;
+      paramValue = `${paramName}.join("${helpers.getCollectionDelimiter(param.format)}")`;
     } else {
       // convert the items to strings
       let strConv: string;
@@ -2234,7 +2905,7 @@ function getHeaderPathQueryParamValue(use: Use, param: HeaderParamType | PathPar
           strConv = '|i| i.to_string()';
       }
 
-      paramValue = `${paramName}.iter().map(${strConv}).collect::<Vec<String>>().join("${getCollectionDelimiter(param.format)}")`;
+      paramValue = `${paramName}.iter().map(${strConv}).collect::<Vec<String>>().join("${helpers.getCollectionDelimiter(param.format)}")`;
     }
   } else {
     switch (paramType.kind) {
@@ -2283,6 +2954,7 @@ function getHeaderPathQueryParamValue(use: Use, param: HeaderParamType | PathPar
   }
 
   switch (param.kind) {
+    case 'cookieScalar':
     case 'headerCollection':
     case 'headerHashMap':
     case 'headerScalar':
@@ -2295,25 +2967,6 @@ function getHeaderPathQueryParamValue(use: Use, param: HeaderParamType | PathPar
   return `${mustBorrow && !neverBorrow ? '&' : ''}${paramValue}`;
 }
 
-/**
- * returns the delimiter character for the provided format type
- * 
- * @param format the format collection type
- * @returns the delimiter character
- */
-function getCollectionDelimiter(format: rust.CollectionFormat): string {
-  switch (format) {
-    case 'csv':
-      return ',';
-    case 'pipes':
-      return '|';
-    case 'ssv':
-      return ' ';
-    case 'tsv':
-      return '\t';
-  }
-}
-
 /** returns true if the type isn't copyable thus needs to be cloned or borrowed */
 function nonCopyableType(type: rust.Type): boolean {
   const unwrappedType = utils.unwrapOption(type);
@@ -2345,6 +2998,7 @@ function getPipelineOptions(indent: helpers.indentation, use: Use, method: Clien
     let pipelineOptions: string;
     switch (method.returns.type.kind) {
       case 'asyncResponse':
+      case 'resumableDownload':
         pipelineOptions = 'PipelineStreamOptions';
         break;
       default:
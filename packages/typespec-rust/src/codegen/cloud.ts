@@ -0,0 +1,60 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+
+import * as helpers from './helpers.js';
+import { Use } from './use.js';
+import * as rust from '../codemodel/index.js';
+
+/**
+ * returns the emitted helper that resolves the Azure Resource Manager
+ * endpoint for a cloud, or undefined if the module isn't the root of
+ * an Azure Resource Manager crate.
+ *
+ * @param module the module for which to emit the helper
+ * @returns the cloud endpoint content or undefined
+ */
+export function emitCloudEndpoints(module: rust.ModuleContainer): helpers.Module | undefined {
+  if (module.kind !== 'crate' || module.type !== 'azure-arm') {
+    return undefined;
+  }
+
+  const use = new Use(module, 'modelsOther');
+  use.add('azure_core', 'Result');
+  use.add('azure_core::cloud', 'CloudConfiguration');
+  use.add('azure_core::error', 'Error', 'ErrorKind');
+  use.add('azure_core::http', 'Url');
+  use.add('crate', 'Audience');
+
+  const indent = new helpers.indentation();
+  let body = '/// Returns the Azure Resource Manager endpoint for the specified cloud and the scope for its access tokens.\n';
+  body += '///\n';
+  body += '/// Returns an error if no cloud is specified or the cloud isn\'t supported.\n';
+  body += 'pub(crate) fn arm_cloud_endpoint(cloud: Option<&CloudConfiguration>) -> Result<(Url, String)> {\n';
+  body += `${indent.get()}let audience: &str = match cloud {\n`;
+  indent.push();
+  for (const cloudEndpoint of module.cloudEndpoints) {
+    body += `${indent.get()}Some(CloudConfiguration::${cloudEndpoint.cloud}) => "${cloudEndpoint.endpoint}",\n`;
+  }
+  body += `${indent.get()}Some(CloudConfiguration::Custom(custom)) => custom.audiences.get::<Audience>().ok_or_else(|| Error::new(ErrorKind::Credential, "missing custom cloud configuration audience"))?,\n`;
+  body += `${indent.get()}Some(_) => {\n`;
+  body += `${indent.push().get()}return Err(Error::with_message(ErrorKind::Other, "the cloud configuration isn't supported by this client"));\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}None => {\n`;
+  body += `${indent.push().get()}return Err(Error::with_message(ErrorKind::Other, "no cloud configuration was specified in the client options"));\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.pop().get()}};\n`;
+  body += `${indent.get()}Ok((Url::parse(audience)?, format!("{audience}/.default")))\n`;
+  body += '}\n';
+
+  let content = helpers.contentPreamble();
+  content += use.text();
+  content += body;
+
+  return {
+    name: 'cloud',
+    content: content,
+    visibility: 'pubCrate',
+  };
+}
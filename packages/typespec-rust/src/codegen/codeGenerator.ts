@@ -3,18 +3,28 @@
  *  Licensed under the MIT License. See License.txt in the project root for license information.
  *--------------------------------------------------------------------------------------------*/
 // cspell: ignore cargotoml
+import { emitArmResources } from './armResources.js';
+import { emitBatch } from './batch.js';
 import { emitCargoToml } from './cargotoml.js';
 import { emitClients } from './clients.js';
+import { emitCloudEndpoints } from './cloud.js';
 import { Context } from './context.js';
 import { emitEnums } from './enums.js';
+import { emitExampleTests } from './examples.js';
+import { emitFormats } from './formats.js';
 import { emitUnions } from './unions.js';
 import { Module } from './helpers.js';
 import { emitLibRs } from './lib.js';
 import { emitHeaderTraits } from './headerTraits.js';
+import { emitIdempotency } from './idempotency.js';
 import { emitClientsModRs, emitGeneratedModRs, emitModelsModRs, emitSubModRs } from './mod.js';
 import { emitModels } from './models.js';
+import { emitResourceId } from './resourceId.js';
+import { emitNegotiatedResponses, emitResumableDownload } from './responses.js';
+import { emitStubServer, StubServerContent } from './stubServer.js';
 
 import * as rust from '../codemodel/index.js';
+import * as utils from '../utils/utils.js';
 
 /** a file to emit */
 export interface File {
@@ -58,6 +68,24 @@ export class CodeGenerator {
     return emitLibRs(this.crate);
   }
 
+  /**
+   * generates the companion stub server crate
+   * 
+   * @returns the content for the stub server crate or undefined if it isn't to be emitted
+   */
+  emitStubServer(): StubServerContent | undefined {
+    return emitStubServer(this.crate);
+  }
+
+  /**
+   * generates the integration tests that replay the operation examples
+   * 
+   * @returns the test files. can be empty
+   */
+  emitExampleTests(): Array<File> {
+    return emitExampleTests(this.crate);
+  }
+
   /**
    * generates all clients, models, and any helper content
    * 
@@ -85,6 +113,12 @@ export class CodeGenerator {
 
       const clientModules = emitClients(module);
       if (clientModules) {
+        const armResources = emitArmResources(module);
+        if (armResources) {
+          // the resource handles live alongside the clients they wrap
+          clientModules.modules.push(armResources);
+          clientModules.modules.sort((a: Module, b: Module) => { return utils.sortAscending(a.name, b.name); });
+        }
         files.push(...clientModules.modules.map((module) => { return { name: `${dir}${clientsSubDir}/${module.name}.rs`, content: module.content }; }));
         files.push({ name: `${dir}${clientsSubDir}/mod.rs`, content: emitClientsModRs(clientModules.modules.map((module) => module.name)) });
         addModelsFile(dir, files, modelsModRS, clientModules.options);
@@ -107,6 +141,13 @@ export class CodeGenerator {
       addModelsFile(dir, files, modelsModRS, models.xmlHelpers);
 
       addModelsFile(dir, files, modelsModRS, emitHeaderTraits(module));
+      addModelsFile(dir, files, modelsModRS, emitFormats(module));
+      addModelsFile(dir, files, modelsModRS, emitNegotiatedResponses(module));
+      addModelsFile(dir, files, modelsModRS, emitResumableDownload(module));
+      addModelsFile(dir, files, modelsModRS, emitIdempotency(module));
+      addModelsFile(dir, files, modelsModRS, emitBatch(module));
+      addModelsFile(dir, files, modelsModRS, emitResourceId(module));
+      addModelsFile(dir, files, modelsModRS, emitCloudEndpoints(module));
 
       if (modelsModRS.length > 0) {
         files.push({ name: `${dir}${modelsSubDir}/mod.rs`, content: emitModelsModRs(modelsModRS) })
@@ -197,6 +238,12 @@ function sortContent(content: rust.ModuleContainer): void {
     }
   }
 
+  content.armResources.sort((a: rust.ArmResource, b: rust.ArmResource) => { return sortAscending(a.name, b.name); });
+  for (const armResource of content.armResources) {
+    armResource.operations.sort((a: rust.ArmResourceOperation, b: rust.ArmResourceOperation) => { return sortAscending(a.method.method.name, b.method.method.name); });
+    armResource.children.sort((a: rust.ArmResourceChild, b: rust.ArmResourceChild) => { return sortAscending(a.name, b.name); });
+  }
+
   content.subModules.sort((a: rust.SubModule, b: rust.SubModule) => sortAscending(a.name, b.name));
 
   for (const subModule of content.subModules) {
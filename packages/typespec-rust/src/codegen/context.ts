@@ -74,7 +74,7 @@ export class Context {
 
         for (const param of method.params) {
           if (param.kind === 'body' || param.kind === 'partialBody') {
-            if (param.type.format === 'NoFormat' || param.type.format === 'BinaryFormat') {
+            if (!helpers.isModelPayloadFormat(param.type.format)) {
               // no body format to propagate
               continue;
             }
@@ -92,11 +92,19 @@ export class Context {
             break;
           }
           case 'response': {
-            if (method.returns.type.format !== 'NoFormat' && method.returns.type.format !== 'BinaryFormat') {
+            if (helpers.isModelPayloadFormat(method.returns.type.format)) {
               recursiveAddBodyFormat(method.returns.type.content, helpers.convertResponseFormat(method.returns.type.format));
             }
             break;
           }
+          case 'negotiatedResponse': {
+            for (const variant of method.returns.type.variants) {
+              if (variant.type.kind === 'response' && helpers.isModelPayloadFormat(variant.type.format)) {
+                recursiveAddBodyFormat(variant.type.content, helpers.convertResponseFormat(variant.type.format));
+              }
+            }
+            break;
+          }
         }
       }
     }
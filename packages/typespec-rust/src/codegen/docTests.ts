@@ -3,6 +3,7 @@
 *  Licensed under the MIT License. See License.txt in the project root for license information.
 *--------------------------------------------------------------------------------------------*/
 
+import { getExampleCall } from './examples.js';
 import * as helpers from './helpers.js';
 import * as rust from '../codemodel/index.js';
 import * as utils from '../utils/utils.js';
@@ -64,7 +65,7 @@ export function emitHeaderTraitDocExample(trait: rust.ResponseHeadersTrait, inde
   // show first 3 headers as examples
   const exampleHeaders = trait.headers.slice(0, 3);
   for (const header of exampleHeaders) {
-    if (header.kind === 'responseHeaderScalar') {
+    if (header.kind !== 'responseHeaderHashMap') {
       headerDocs += `${indent.get()}///     if let Some(${header.name}) = response.${header.name}()? {\n`;
       headerDocs += `${indent.get()}///         println!("${header.header}: {:?}", ${header.name});\n`;
       headerDocs += `${indent.get()}///     }\n`;
@@ -79,3 +80,61 @@ export function emitHeaderTraitDocExample(trait: rust.ResponseHeadersTrait, inde
 
   return headerDocs;
 }
+
+/**
+ * emit doc examples for calling a method using the values
+ * from its operation examples. examples with values that
+ * can't be expressed in Rust are omitted.
+ *
+ * @param indent the indentation helper in scope
+ * @param client the client that contains the method
+ * @param method the method for which to emit the examples
+ * @returns the examples text or the empty string if there are no examples
+ */
+export function emitMethodDocExamples(indent: helpers.indentation, client: rust.Client, method: rust.AsyncMethod | rust.PageableMethod | rust.LroMethod): string {
+  const crateName = helpers.getCrate(client.module).name;
+  let examples = '';
+  for (const example of method.examples) {
+    const imports = new Set<string>();
+    const call = getExampleCall(client, method, example, {
+      add: (module: string, ...types: Array<string>) => {
+        for (const type of types) {
+          imports.add(`${module}::${type}`);
+        }
+      },
+    });
+    if (!call) {
+      continue;
+    }
+
+    imports.add('azure_core::Result');
+    imports.add(`${utils.buildImportPath(client.module, client.module, crateName)}::clients::${client.name}`);
+
+    examples += `${indent.get()}///\n`;
+    examples += `${indent.get()}/// ${helpers.emitBackTicks(3)}no_run\n`;
+    examples += Array.from(imports).sort().map(imp => `${indent.get()}/// use ${imp};\n`).join('');
+    examples += `${indent.get()}///\n`;
+    examples += `${indent.get()}/// async fn example(client: &${client.name}) -> Result<()> {\n`;
+    examples += `${indent.get()}///     // ${example.name}\n`;
+    switch (method.kind) {
+      case 'async':
+        examples += `${indent.get()}///     let response = ${call}.await?;\n`;
+        break;
+      case 'lro':
+        examples += `${indent.get()}///     let poller = ${call}?;\n`;
+        break;
+      case 'pageable':
+        examples += `${indent.get()}///     let pager = ${call}?;\n`;
+        break;
+    }
+    examples += `${indent.get()}///     Ok(())\n`;
+    examples += `${indent.get()}/// }\n`;
+    examples += `${indent.get()}/// ${helpers.emitBackTicks(3)}\n`;
+  }
+
+  if (examples.length === 0) {
+    return '';
+  }
+
+  return `${indent.get()}///\n${indent.get()}/// # Examples\n${examples}`;
+}
@@ -139,7 +139,17 @@ function emitEnumPublicDefinitions(indent: helpers.indentation, rustEnum: rust.E
     body += indent.get() + `${docs.substring(0, docs.length - 1)}\n`;
   }
   // extensible enums that are numeric can derive Copy
-  body += indent.get() + `#[derive(Clone, ${rustEnum.extensible && rustEnum.type === 'String' ? '' : 'Copy, '}Debug, Eq, PartialEq)]\n`;
+  const derives = ['Clone', 'Debug', 'Eq', 'PartialEq'];
+  if (!rustEnum.extensible || rustEnum.type !== 'String') {
+    derives.push('Copy');
+  }
+  for (const customization of rustEnum.customizations) {
+    if (customization.kind === 'derive' && !derives.includes(customization.name)) {
+      derives.push(customization.name);
+    }
+  }
+  body += indent.get() + `#[derive(${derives.sort().join(', ')})]\n`;
+  body += helpers.emitCustomAttributes(rustEnum.customizations, indent.get());
   body += indent.get() + `${helpers.emitVisibility(rustEnum.visibility)}enum ${rustEnum.name} {\n`;
   indent.push();
   for (let i = 0; i < rustEnum.values.length; ++i) {
@@ -221,9 +231,14 @@ function emitStringEnumImplDefinitions(indent: helpers.indentation, use: Use, ru
   indent.push();
   for (let i = 0; i < rustEnum.values.length; ++i) {
     const value = rustEnum.values[i];
-    body += indent.get() + `"${value.value}" => ${rustEnum.name}::${value.name},\n`;
+    if (rustEnum.caseInsensitive) {
+      body += indent.get() + `_ if s.eq_ignore_ascii_case("${value.value}") => ${rustEnum.name}::${value.name},\n`;
+    } else {
+      body += indent.get() + `"${value.value}" => ${rustEnum.name}::${value.name},\n`;
+    }
   }
   if (rustEnum.extensible) {
+    // unknown values retain their original spelling so they round-trip unchanged
     body += indent.get() + `_ => ${rustEnum.name}::UnknownValue(s.to_string()),\n`;
   }
   else {
@@ -255,6 +270,16 @@ function emitStringEnumImplDefinitions(indent: helpers.indentation, use: Use, ru
   body += indent.pop().get() + `}\n`; // end fn
   body += indent.pop().get() + `}\n\n`; // end impl
 
+  if (rustEnum.caseInsensitive) {
+    body += indent.get() + `impl PartialEq<str> for ${rustEnum.name} {\n`;
+    indent.push();
+    body += indent.get() + `fn eq(&self, other: &str) -> bool {\n`;
+    indent.push();
+    body += indent.get() + `self.as_ref().eq_ignore_ascii_case(other)\n`;
+    body += indent.pop().get() + `}\n`; // end fn
+    body += indent.pop().get() + `}\n\n`; // end impl
+  }
+
   use.add("std::fmt", "Display", "Formatter");
   body += indent.get() + `impl Display for ${rustEnum.name} {\n`;
   indent.push();
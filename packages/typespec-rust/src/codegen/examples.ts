@@ -0,0 +1,515 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+
+import type { File } from './codeGenerator.js';
+import * as helpers from './helpers.js';
+import { Use } from './use.js';
+import * as rust from '../codemodel/index.js';
+import * as utils from '../utils/utils.js';
+
+/** ExampleImports collects the imports required by an example */
+export type ExampleImports = Pick<Use, 'add'>;
+
+/**
+ * returns a call to the specified method with the values from
+ * the example, e.g. client.get_widget("name", None).
+ * optional params are omitted (i.e. options is always None).
+ *
+ * @param client the client that contains the method
+ * @param method the method to call
+ * @param example the example that contains the param values
+ * @param use the use statement builder for the calling code
+ * @returns the call or undefined if the example can't be expressed in Rust
+ */
+export function getExampleCall(client: rust.Client, method: rust.AsyncMethod | rust.PageableMethod | rust.LroMethod, example: rust.MethodExample, use: ExampleImports): string | undefined {
+  const args = new Array<string>();
+  for (const param of method.params) {
+    const paramType = helpers.unwrapType(param.type);
+    if (paramType.kind === 'literal' || paramType.kind === 'enumValue' || param.location === 'client' || param.optional) {
+      // none of these are in the method sig
+      continue;
+    } else if (param.group) {
+      // param groups aren't supported
+      return undefined;
+    }
+
+    const value = example.params.get(param);
+    if (value === undefined) {
+      return undefined;
+    }
+
+    const arg = getExampleValue(client.module, param.kind === 'partialBody' ? param.paramType : param.type, value, use);
+    if (!arg) {
+      return undefined;
+    }
+    args.push(arg);
+  }
+
+  args.push('None');
+  return `client.${method.name}(${args.join(', ')})`;
+}
+
+/**
+ * returns a Rust expression for an example value of the specified type.
+ * if the expression contains a conversion, it uses the ? operator.
+ *
+ * @param module the module that contains the method being called
+ * @param type the type of the value
+ * @param value the example value
+ * @param use the use statement builder for the calling code
+ * @returns the expression or undefined if the value can't be expressed in Rust
+ */
+function getExampleValue(module: rust.ModuleContainer, type: rust.Type, value: rust.ExampleValue, use: ExampleImports): string | undefined {
+  const crate = helpers.getCrate(module);
+  const getItems = function (itemType: rust.Type, values: rust.ExampleValue): Array<string> | undefined {
+    if (!Array.isArray(values)) {
+      return undefined;
+    }
+    const items = new Array<string>();
+    for (const item of values) {
+      const itemValue = getExampleValue(module, itemType, item, use);
+      if (!itemValue) {
+        return undefined;
+      }
+      items.push(itemValue);
+    }
+    return items;
+  };
+
+  switch (type.kind) {
+    case 'box': {
+      const inner = getExampleValue(module, type.type, value, use);
+      return inner ? `Box::new(${inner})` : undefined;
+    }
+    case 'enum': {
+      const enumValue = type.values.find((each) => each.value === value);
+      if (!enumValue || type.visibility !== 'pub') {
+        return undefined;
+      }
+      use.add(`${utils.buildImportPath(crate, type.module, crate.name)}::models`, type.name);
+      return `${type.name}::${enumValue.name}`;
+    }
+    case 'hashmap': {
+      if (value === null || typeof value !== 'object' || Array.isArray(value)) {
+        return undefined;
+      }
+      const entries = new Array<string>();
+      for (const [key, entryValue] of Object.entries(value)) {
+        const entry = getExampleValue(module, type.type, entryValue, use);
+        if (!entry) {
+          return undefined;
+        }
+        entries.push(`(${getStringLiteral(key)}.to_string(), ${entry})`);
+      }
+      use.add('std::collections', 'HashMap');
+      return entries.length > 0 ? `HashMap::from([${entries.join(', ')}])` : 'HashMap::new()';
+    }
+    case 'model': {
+      // output-only models are non_exhaustive so they can't be constructed
+      if (type.visibility !== 'pub' || (type.flags & rust.ModelFlags.Input) === 0 || (type.flags & rust.ModelFlags.PolymorphicBase) !== 0 || value === null || typeof value !== 'object' || Array.isArray(value)) {
+        return undefined;
+      }
+      const fields = new Array<string>();
+      const consumed = new Set<string>();
+      let omitted = false;
+      for (const field of type.fields) {
+        if (field.kind === 'additionalProperties') {
+          return undefined;
+        }
+        const fieldValue = value[field.serde];
+        if (fieldValue === undefined) {
+          omitted = true;
+          continue;
+        } else if (field.visibility !== 'pub') {
+          return undefined;
+        }
+        const fieldExpr = getExampleValue(module, field.type, fieldValue, use);
+        if (!fieldExpr) {
+          return undefined;
+        }
+        fields.push(`${field.name}: ${fieldExpr}`);
+        consumed.add(field.serde);
+      }
+      if (Object.keys(value).some((key) => !consumed.has(key))) {
+        // the example contains values that aren't in the model
+        return undefined;
+      }
+      if (omitted) {
+        fields.push('..Default::default()');
+      }
+      use.add(`${utils.buildImportPath(crate, type.module, crate.name)}::models`, type.name);
+      return `${type.name} { ${fields.join(', ')} }`;
+    }
+    case 'option':
+      if (value === null) {
+        return 'None';
+      } else {
+        const inner = getExampleValue(module, type.type, value, use);
+        return inner ? `Some(${inner})` : undefined;
+      }
+    case 'ref':
+      switch (type.type.kind) {
+        case 'slice': {
+          const items = getItems(type.type.type, value);
+          return items ? `&[${items.join(', ')}]` : undefined;
+        }
+        case 'str':
+          return typeof value === 'string' ? getStringLiteral(value) : undefined;
+        default:
+          return undefined;
+      }
+    case 'requestContent': {
+      if (type.format !== 'JsonFormat' || type.content.kind !== 'model') {
+        return undefined;
+      }
+      const content = getExampleValue(module, type.content, value, use);
+      return content ? `${content}.try_into()?` : undefined;
+    }
+    case 'scalar':
+      return getScalarValue(type, value);
+    case 'str':
+      return typeof value === 'string' ? getStringLiteral(value) : undefined;
+    case 'String':
+      return typeof value === 'string' ? `${getStringLiteral(value)}.to_string()` : undefined;
+    case 'Vec': {
+      const items = getItems(type.type, value);
+      return items ? `vec![${items.join(', ')}]` : undefined;
+    }
+    default:
+      return undefined;
+  }
+}
+
+/**
+ * returns a Rust literal for an example scalar value
+ *
+ * @param type the scalar type
+ * @param value the example value
+ * @returns the literal or undefined if the value doesn't match the type
+ */
+function getScalarValue(type: rust.Scalar, value: rust.ExampleValue): string | undefined {
+  if (type.type === 'bool') {
+    return typeof value === 'boolean' ? `${value}` : undefined;
+  }
+
+  // string encoded scalars can appear as strings in examples
+  const num = typeof value === 'string' && type.stringEncoding ? Number(value) : value;
+  if (typeof num !== 'number' || !Number.isFinite(num)) {
+    return undefined;
+  }
+
+  switch (type.type) {
+    case 'f32':
+    case 'f64':
+      return Number.isInteger(num) ? `${num}.0` : `${num}`;
+    default:
+      return Number.isInteger(num) ? `${num}` : undefined;
+  }
+}
+
+/**
+ * returns the Rust string literal for the specified value
+ *
+ * @param value the value of the string
+ * @returns the string literal
+ */
+function getStringLiteral(value: string): string {
+  // JSON escapes are valid in Rust except for \b, \f, and \uXXXX
+  return JSON.stringify(value)
+    .replace(/\\b/g, '\\u{8}')
+    .replace(/\\f/g, '\\u{c}')
+    .replace(/\\u([0-9a-fA-F]{4})/g, '\\u{$1}');
+}
+
+/**
+ * returns the Rust raw string literal for the specified value
+ *
+ * @param value the value of the string
+ * @returns the raw string literal
+ */
+function getRawStringLiteral(value: string): string {
+  let hashes = '#';
+  while (value.includes(`"${hashes}`)) {
+    hashes += '#';
+  }
+  return `r${hashes}"${value}"${hashes}`;
+}
+
+/** ExampleRequest contains the parts of an example request that are verified by the transport */
+interface ExampleRequest {
+  /** the path with the example values substituted or undefined if it can't be determined */
+  path?: string;
+
+  /** the query params sent by the example call as name/value pairs */
+  query: Array<[string, string]>;
+
+  /** the headers sent by the example call as name/value pairs */
+  headers: Array<[string, string]>;
+}
+
+/**
+ * returns the path, query params, and headers that the example call sends.
+ * optional and client params aren't passed by the call so they're skipped.
+ *
+ * @param method the method being called
+ * @param example the example that contains the param values
+ * @returns the parts of the request to verify
+ */
+function getExampleRequest(method: rust.AsyncMethod, example: rust.MethodExample): ExampleRequest {
+  const [path, query] = method.httpPath.split('?');
+  const request: ExampleRequest = { path: path, query: [], headers: [] };
+  if (query) {
+    // constant query params that are part of the path
+    for (const [name, value] of new URLSearchParams(query)) {
+      request.query.push([name, value]);
+    }
+  }
+
+  for (const param of method.params) {
+    const paramType = helpers.unwrapType(param.type);
+    if (param.location === 'client' || (param.optional && paramType.kind !== 'literal' && paramType.kind !== 'enumValue')) {
+      continue;
+    }
+
+    const text = getExampleText(param.type, example.params.get(param));
+    switch (param.kind) {
+      case 'headerScalar':
+        if (text !== undefined) {
+          request.headers.push([param.header.toLowerCase(), text]);
+        }
+        break;
+      case 'pathCollection':
+      case 'pathHashMap':
+        request.path = undefined;
+        break;
+      case 'pathScalar':
+        // only values that aren't percent-encoded are substituted
+        if (text !== undefined && /^[\w.~-]+$/.test(text)) {
+          request.path = request.path?.replace(`{${param.segment}}`, text);
+        } else {
+          request.path = undefined;
+        }
+        break;
+      case 'queryScalar':
+        if (text !== undefined) {
+          request.query.push([param.key, text]);
+        }
+        break;
+    }
+  }
+
+  request.query.sort((a, b) => utils.sortAscending(a[0], b[0]));
+  request.headers.sort((a, b) => utils.sortAscending(a[0], b[0]));
+  return request;
+}
+
+/**
+ * returns the text that's sent on the wire for an example scalar value
+ *
+ * @param type the type of the value
+ * @param value the example value
+ * @returns the text or undefined if it can't be determined
+ */
+function getExampleText(type: rust.Type, value: rust.ExampleValue | undefined): string | undefined {
+  const unwrappedType = helpers.unwrapType(type);
+  switch (unwrappedType.kind) {
+    case 'enumValue':
+    case 'literal':
+      return `${unwrappedType.value}`;
+    case 'enum':
+    case 'str':
+    case 'String':
+      return typeof value === 'string' ? value : undefined;
+    case 'scalar':
+      if (unwrappedType.type === 'bool') {
+        return typeof value === 'boolean' ? `${value}` : undefined;
+      }
+      if (unwrappedType.type === 'f32' || unwrappedType.type === 'f64') {
+        // floats can be formatted differently so they're skipped
+        return undefined;
+      }
+      return typeof value === 'number' ? `${value}` : undefined;
+    default:
+      return undefined;
+  }
+}
+
+/**
+ * emits the tests that replay the method examples for the clients in the crate.
+ * tests are only emitted for clients that can be constructed without a credential.
+ *
+ * @param crate the crate for which to emit the tests
+ * @returns the test files. can be empty
+ */
+export function emitExampleTests(crate: rust.Crate): Array<File> {
+  const files = new Array<File>();
+  if (!crate.exampleTests) {
+    return files;
+  }
+
+  const recursiveEmitTests = function (module: rust.ModuleContainer): void {
+    for (const client of module.clients) {
+      const content = getExampleTests(client);
+      if (content) {
+        files.push({ name: `tests/${utils.snakeCaseName(client.name)}_examples.rs`, content: content });
+      }
+    }
+    for (const subModule of module.subModules) {
+      recursiveEmitTests(subModule);
+    }
+  };
+  recursiveEmitTests(crate);
+
+  return files;
+}
+
+/**
+ * returns the tests that replay the method examples for the specified client
+ *
+ * @param client the client for which to emit the tests
+ * @returns the tests or undefined if there are no tests for the client
+ */
+function getExampleTests(client: rust.Client): string | undefined {
+  const constructable = client.constructable;
+  const constructor = constructable?.constructors.find((each) => each.name === 'with_no_credential' && each.params.length === 1);
+  if (!constructable || constructable.suppressed !== 'no' || !constructor) {
+    return undefined;
+  }
+
+  const crate = helpers.getCrate(client.module);
+  const use = new Use(client.module, 'modelsOther');
+  const indent = new helpers.indentation();
+  const testNames = new Set<string>();
+
+  let tests = '';
+  for (const method of client.methods) {
+    if (method.kind !== 'async' || method.visibility !== 'pub' || method.returns.type.kind !== 'response') {
+      continue;
+    }
+
+    const response = method.returns.type;
+    const returnsModel = response.format === 'JsonFormat' && response.content.kind === 'model';
+    if (!returnsModel && response.content.kind !== 'unit' && response.content.kind !== 'marker') {
+      continue;
+    }
+    const hasJsonBody = method.params.some((param) => (param.kind === 'body' || param.kind === 'partialBody') && param.type.format === 'JsonFormat');
+
+    for (const example of method.examples) {
+      if (!example.response || (method.statusCodes.length > 0 && !method.statusCodes.includes(example.response.statusCode))) {
+        continue;
+      }
+      const call = getExampleCall(client, method, example, use);
+      if (!call) {
+        continue;
+      }
+
+      let testName = `${method.name}_${utils.snakeCaseName(example.name)}`;
+      for (let i = 2; testNames.has(testName); ++i) {
+        testName = `${method.name}_${utils.snakeCaseName(example.name)}_${i}`;
+      }
+      testNames.add(testName);
+
+      const request = getExampleRequest(method, example);
+      const getPairs = (pairs: Array<[string, string]>) => pairs.map(([name, value]) => `(${getStringLiteral(name)}, ${getStringLiteral(value)})`).join(', ');
+      const requestBody = hasJsonBody && example.requestBody !== undefined ? `Some(${getRawStringLiteral(JSON.stringify(example.requestBody))})` : 'None';
+      const responseBody = returnsModel && example.response.body !== undefined ? JSON.stringify(example.response.body) : '';
+      const headers = Array.from(example.response.headers.entries()).map(([name, value]) => `(${getStringLiteral(name.toLowerCase())}, ${getStringLiteral(value)})`);
+
+      tests += `/// ${example.name}\n`;
+      tests += '#[tokio::test]\n';
+      tests += `async fn ${testName}() -> Result<()> {\n`;
+      tests += `${indent.get()}let client = new_client(ExampleTransport {\n`;
+      tests += `${indent.push().get()}method: Method::${utils.capitalize(method.httpMethod)},\n`;
+      tests += `${indent.get()}path: ${request.path !== undefined ? `Some(${getStringLiteral(request.path)})` : 'None'},\n`;
+      tests += `${indent.get()}query: &[${getPairs(request.query)}],\n`;
+      tests += `${indent.get()}request_headers: &[${getPairs(request.headers)}],\n`;
+      tests += `${indent.get()}request_body: ${requestBody},\n`;
+      tests += `${indent.get()}status: ${example.response.statusCode},\n`;
+      tests += `${indent.get()}headers: &[${headers.join(', ')}],\n`;
+      tests += `${indent.get()}response_body: ${getRawStringLiteral(responseBody)},\n`;
+      tests += `${indent.pop().get()}})?;\n`;
+      tests += `${indent.get()}let response = ${call}.await?;\n`;
+      tests += `${indent.get()}assert_eq!(response.status(), StatusCode::from(${example.response.statusCode}));\n`;
+      if (responseBody.length > 0) {
+        tests += `${indent.get()}let actual = serde_json::to_value(response.into_model()?).unwrap();\n`;
+        tests += `${indent.get()}assert_eq!(actual, serde_json::from_str::<Value>(${getRawStringLiteral(responseBody)}).unwrap());\n`;
+      }
+      tests += `${indent.get()}Ok(())\n`;
+      tests += '}\n\n';
+    }
+  }
+
+  if (tests.length === 0) {
+    return undefined;
+  }
+
+  const clientPath = `${utils.buildImportPath(crate, client.module, crate.name)}::clients`;
+  use.add(clientPath, client.name, constructable.options.type.name);
+  use.add('async_trait', 'async_trait');
+  use.add('azure_core', 'Result');
+  use.add('azure_core::http', 'AsyncRawResponse', 'Body', 'ClientOptions', 'Context', 'Method', 'Request', 'StatusCode');
+  use.add('azure_core::http::headers', 'HeaderName', 'Headers');
+  use.add('azure_core::http::policies', 'Policy', 'PolicyResult');
+  use.add('serde_json', 'Value');
+  use.add('std::sync', 'Arc');
+
+  let content = helpers.contentPreamble();
+  content += use.text();
+
+  content += '/// Verifies the request and replays the example response in place of the transport.\n';
+  content += '#[derive(Debug)]\n';
+  content += 'struct ExampleTransport {\n';
+  content += `${indent.get()}method: Method,\n`;
+  content += `${indent.get()}path: Option<&'static str>,\n`;
+  content += `${indent.get()}query: &'static [(&'static str, &'static str)],\n`;
+  content += `${indent.get()}request_headers: &'static [(&'static str, &'static str)],\n`;
+  content += `${indent.get()}request_body: Option<&'static str>,\n`;
+  content += `${indent.get()}status: u16,\n`;
+  content += `${indent.get()}headers: &'static [(&'static str, &'static str)],\n`;
+  content += `${indent.get()}response_body: &'static str,\n`;
+  content += '}\n\n';
+
+  content += '#[async_trait]\n';
+  content += 'impl Policy for ExampleTransport {\n';
+  content += `${indent.get()}async fn send(&self, _ctx: &Context, request: &mut Request, _next: &[Arc<dyn Policy>]) -> PolicyResult {\n`;
+  content += `${indent.push().get()}assert_eq!(request.method(), self.method);\n`;
+  content += `${indent.get()}if let Some(path) = self.path {\n`;
+  content += `${indent.push().get()}assert!(request.url().path().ends_with(path), "unexpected path {}", request.url().path());\n`;
+  content += `${indent.pop().get()}}\n`;
+  content += `${indent.get()}for (name, value) in self.query {\n`;
+  content += `${indent.push().get()}assert!(request.url().query_pairs().any(|(k, v)| k == *name && v == *value), "missing query param {name}={value}");\n`;
+  content += `${indent.pop().get()}}\n`;
+  content += `${indent.get()}for (name, value) in self.request_headers {\n`;
+  content += `${indent.push().get()}assert_eq!(request.headers().get_optional_str(&HeaderName::from(*name)), Some(*value), "unexpected value for header {name}");\n`;
+  content += `${indent.pop().get()}}\n`;
+  content += `${indent.get()}if let Some(expected) = self.request_body {\n`;
+  content += `${indent.push().get()}let Body::Bytes(actual) = request.body() else {\n`;
+  content += `${indent.push().get()}panic!("expected a buffered request body");\n`;
+  content += `${indent.pop().get()}};\n`;
+  content += `${indent.get()}assert_eq!(serde_json::from_slice::<Value>(actual).unwrap(), serde_json::from_str::<Value>(expected).unwrap());\n`;
+  content += `${indent.pop().get()}}\n`;
+  content += `${indent.get()}let mut headers = Headers::new();\n`;
+  content += `${indent.get()}for (name, value) in self.headers {\n`;
+  content += `${indent.push().get()}headers.insert(*name, *value);\n`;
+  content += `${indent.pop().get()}}\n`;
+  content += `${indent.get()}Ok(AsyncRawResponse::from_bytes(StatusCode::from(self.status), headers, self.response_body))\n`;
+  content += `${indent.pop().get()}}\n`;
+  content += '}\n\n';
+
+  content += `/// Creates a [\`${client.name}\`] that sends its requests to the transport.\n`;
+  content += `fn new_client(transport: ExampleTransport) -> Result<${client.name}> {\n`;
+  content += `${indent.get()}let options = ${constructable.options.type.name} {\n`;
+  content += `${indent.push().get()}client_options: ClientOptions {\n`;
+  content += `${indent.push().get()}per_call_policies: vec![Arc::new(transport)],\n`;
+  content += `${indent.get()}..Default::default()\n`;
+  content += `${indent.pop().get()}},\n`;
+  content += `${indent.get()}..Default::default()\n`;
+  content += `${indent.pop().get()}};\n`;
+  content += `${indent.get()}${client.name}::with_no_credential("https://example.com", Some(options))\n`;
+  content += '}\n\n';
+
+  content += tests.trimEnd() + '\n';
+  return content;
+}
@@ -0,0 +1,181 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+
+import * as helpers from './helpers.js';
+import { Use } from './use.js';
+import * as rust from '../codemodel/index.js';
+
+/**
+ * returns the emitted payload formats, or undefined if the module
+ * doesn't send or receive any payloads that require a custom format.
+ *
+ * @param module the module for which to emit formats
+ * @returns the formats content or undefined
+ */
+export function emitFormats(module: rust.ModuleContainer): helpers.Module | undefined {
+  const formats = getCustomFormats(module);
+  if (formats.size === 0) {
+    return undefined;
+  }
+
+  const use = new Use(module, 'modelsOther');
+  use.add('azure_core::http', 'Format');
+
+  let body = '';
+  if (formats.has('Base64Format')) {
+    body += getBase64Format(use, 'Base64Format', 'std');
+  }
+
+  if (formats.has('Base64UrlFormat')) {
+    body += getBase64Format(use, 'Base64UrlFormat', 'url');
+  }
+
+  if (formats.has('FormFormat')) {
+    body += '/// The format for `application/x-www-form-urlencoded` request bodies.\n';
+    body += '#[derive(Clone, Debug, Default)]\n';
+    body += 'pub struct FormFormat;\n\n';
+    body += 'impl Format for FormFormat {}\n\n';
+  }
+
+  if (formats.has('TextFormat')) {
+    body += getTextFormat(use);
+  }
+
+  let content = helpers.contentPreamble();
+  content += use.text();
+  content += body;
+
+  return {
+    name: 'formats',
+    content: content,
+    visibility: 'pubUse',
+  };
+}
+
+/**
+ * returns the definition for a format of JSON strings containing base64 encoded bytes
+ *
+ * @param use the use statement builder currently in scope
+ * @param name the name of the format
+ * @param encoding the base64 encoding used by the format
+ * @returns the format content
+ */
+function getBase64Format(use: Use, name: 'Base64Format' | 'Base64UrlFormat', encoding: rust.BytesEncoding): string {
+  use.add('azure_core', 'Result');
+  use.add('azure_core::error', 'Error', 'ErrorKind');
+  use.add('azure_core::http::response', 'DeserializeWith', 'ResponseBody');
+
+  const indent = new helpers.indentation();
+  let body = `/// The format for payloads that are a JSON string containing ${encoding === 'url' ? 'base64url' : 'base64'} encoded bytes.\n`;
+  body += '#[derive(Clone, Debug, Default)]\n';
+  body += `pub struct ${name};\n\n`;
+  body += `impl Format for ${name} {}\n\n`;
+  body += `impl DeserializeWith<${name}> for Vec<u8> {\n`;
+  body += `${indent.get()}fn deserialize_with(body: ResponseBody) -> Result<Self> {\n`;
+  body += `${indent.push().get()}let encoded: String = body.json()?;\n`;
+  body += `${indent.get()}${helpers.getBytesEncodingMethod(encoding, 'decode', use)}(encoded).map_err(|err| Error::new(ErrorKind::DataConversion, err))\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += '}\n\n';
+  return body;
+}
+
+/**
+ * returns the TextFormat definition along with its UTF-8 deserialization and charset validation
+ *
+ * @param use the use statement builder currently in scope
+ * @returns the TextFormat content
+ */
+function getTextFormat(use: Use): string {
+  use.add('azure_core', 'Result');
+  use.add('azure_core::error', 'Error', 'ErrorKind');
+  use.add('azure_core::http::headers', 'Headers', 'CONTENT_TYPE');
+  use.add('azure_core::http::response', 'DeserializeWith', 'ResponseBody');
+
+  const indent = new helpers.indentation();
+  let body = '/// The format for textual payloads (e.g. `text/plain`) which are exchanged as UTF-8 strings.\n';
+  body += '#[derive(Clone, Debug, Default)]\n';
+  body += 'pub struct TextFormat;\n\n';
+  body += 'impl Format for TextFormat {}\n\n';
+  body += 'impl DeserializeWith<TextFormat> for String {\n';
+  body += `${indent.get()}fn deserialize_with(body: ResponseBody) -> Result<Self> {\n`;
+  body += `${indent.push().get()}body.into_string()\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += '}\n\n';
+
+  body += '/// Returns an error if the `content-type` of a textual response specifies a charset that isn\'t UTF-8.\n';
+  body += '///\n';
+  body += '/// A missing charset is treated as UTF-8 as is US-ASCII, which is a subset of UTF-8.\n';
+  body += 'pub(crate) fn check_text_charset(headers: &Headers) -> Result<()> {\n';
+  body += `${indent.get()}let Some(content_type) = headers.get_optional_str(&CONTENT_TYPE) else {\n`;
+  body += `${indent.push().get()}return Ok(());\n`;
+  body += `${indent.pop().get()}};\n`;
+  body += `${indent.get()}for param in content_type.split(';').skip(1) {\n`;
+  body += `${indent.push().get()}let Some((name, value)) = param.split_once('=') else {\n`;
+  body += `${indent.push().get()}continue;\n`;
+  body += `${indent.pop().get()}};\n`;
+  body += `${indent.get()}if !name.trim().eq_ignore_ascii_case("charset") {\n`;
+  body += `${indent.push().get()}continue;\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}let charset = value.trim().trim_matches('"');\n`;
+  body += `${indent.get()}${helpers.buildIfBlock(indent, {
+    condition: '!charset.eq_ignore_ascii_case("utf-8") && !charset.eq_ignore_ascii_case("us-ascii")',
+    body: (indent) => `${indent.get()}return Err(Error::with_message(ErrorKind::DataConversion, format!("unsupported charset '{charset}' for textual response")));\n`,
+  })}\n`;
+  body += `${indent.pop().get()}}\n`; // end for
+  body += `${indent.get()}Ok(())\n`;
+  body += '}\n';
+  return body;
+}
+
+/** the payload formats that are emitted into the crate */
+type CustomFormat = 'Base64Format' | 'Base64UrlFormat' | 'FormFormat' | 'TextFormat';
+
+/**
+ * returns the payload formats used by methods in the module that are emitted into the crate
+ *
+ * @param module the module to inspect
+ * @returns the custom formats used in the module
+ */
+function getCustomFormats(module: rust.ModuleContainer): Set<CustomFormat> {
+  const formats = new Set<CustomFormat>();
+  const addFormat = function (format: rust.PayloadFormatType): void {
+    switch (format) {
+      case 'Base64Format':
+      case 'Base64UrlFormat':
+      case 'FormFormat':
+      case 'TextFormat':
+        formats.add(format);
+        break;
+    }
+  };
+
+  for (const client of module.clients) {
+    for (const method of client.methods) {
+      if (method.kind === 'clientaccessor') {
+        continue;
+      } else if (method.returns.type.kind === 'response') {
+        addFormat(method.returns.type.format);
+      } else if (method.returns.type.kind === 'negotiatedResponse') {
+        for (const variant of method.returns.type.variants) {
+          if (variant.type.kind === 'response') {
+            addFormat(variant.type.format);
+          }
+        }
+      }
+      for (const param of method.params) {
+        switch (param.kind) {
+          case 'body':
+          case 'partialBody':
+            addFormat(param.type.format);
+            break;
+          case 'formBody':
+            addFormat(param.content.format);
+            break;
+        }
+      }
+    }
+  }
+  return formats;
+}
@@ -31,7 +31,10 @@ export function emitHeaderTraits(module: rust.ModuleContainer): helpers.Module |
           entry = new Array<rust.ResponseHeadersTrait>();
           srcTraits.set(method.responseHeaders.name, entry);
         }
-        entry.push(method.responseHeaders);
+        if (!entry.includes(method.responseHeaders)) {
+          // streaming companion methods share the trait with the buffered method
+          entry.push(method.responseHeaders);
+        }
       }
     }
   }
@@ -118,6 +121,7 @@ export function emitHeaderTraits(module: rust.ModuleContainer): helpers.Module |
         // empty HashMap communicates the same thing.
         resultType = getTypeDeclaration(header.type);
         break;
+      case 'responseHeaderCollection':
       case 'responseHeaderScalar':
         resultType = `Option<${getTypeDeclaration(header.type)}>`;
         break;
@@ -139,6 +143,7 @@ export function emitHeaderTraits(module: rust.ModuleContainer): helpers.Module |
         // we add a trailing - as the entire str prefix will be stripped off
         body += `const ${getHeaderConstName(header)}: &str = "${headerValue}-";\n`;
         break;
+      case 'responseHeaderCollection':
       case 'responseHeaderScalar':
         body += `const ${getHeaderConstName(header)}: HeaderName = HeaderName::from_static("${headerValue}");\n`;
     }
@@ -173,6 +178,10 @@ export function emitHeaderTraits(module: rust.ModuleContainer): helpers.Module |
     body += '}\n\n';
   }
 
+  if (headers.find(h => isSetCookieHeader(h))) {
+    body += getSplitSetCookieFn();
+  }
+
   let content = helpers.contentPreamble();
   content += use.text();
   content += body;
@@ -224,6 +233,29 @@ function getHeaderDeserialization(indent: helpers.indentation, use: Use, header:
 
   use.add('azure_core::http', 'headers::Headers');
 
+  if (header.kind === 'responseHeaderCollection') {
+    // split the delimited list and parse each element
+    const values = `h.as_str().split("${helpers.getCollectionDelimiter(header.format)}").map(|v| v.trim())`;
+    const elementType = header.type.type;
+    switch (elementType.kind) {
+      case 'offsetDateTime': {
+        const timeParse = helpers.getDateTimeEncodingMethod(elementType.encoding, 'parse', use);
+        return `${indent.get()}Headers::get_optional_with(self.headers(), &${headerConstName}, |h| ${values}.map(${timeParse}).collect::<Result<Vec<_>>>())\n`;
+      }
+      case 'enum':
+      case 'scalar':
+      case 'String':
+        return `${indent.get()}Headers::get_optional_with(self.headers(), &${headerConstName}, |h| ${values}.map(str::parse).collect::<std::result::Result<Vec<_>, _>>())\n`;
+      default:
+        throw new CodegenError('InternalError', `unexpected response header collection element kind ${elementType.kind}`);
+    }
+  }
+
+  if (isSetCookieHeader(header)) {
+    use.add('std::convert', 'Infallible');
+    return `${indent.get()}Headers::get_optional_with(self.headers(), &${headerConstName}, |h| Ok::<_, Infallible>(split_set_cookie(h.as_str())))\n`;
+  }
+
   switch (header.type.kind) {
     case 'encodedBytes': {
       const decoder = helpers.getBytesEncodingMethod(header.type.encoding, 'decode', use);
@@ -244,6 +276,46 @@ function getHeaderDeserialization(indent: helpers.indentation, use: Use, header:
   }
 }
 
+/**
+ * returns true if the header is the Set-Cookie header.
+ * 
+ * @param header the header to inspect
+ * @returns true if this is the Set-Cookie header
+ */
+function isSetCookieHeader(header: rust.ResponseHeader): header is rust.ResponseHeaderScalar {
+  return header.kind === 'responseHeaderScalar' && header.header === 'set-cookie';
+}
+
+/**
+ * returns the helper function used to split a folded Set-Cookie
+ * header value into its individual cookies.
+ * 
+ * @returns the split_set_cookie function definition
+ */
+function getSplitSetCookieFn(): string {
+  const indent = new helpers.indentation();
+  let content = '/// Splits a folded Set-Cookie header value into its individual cookies.\n';
+  content += '///\n';
+  content += '/// Commas within an attribute value (e.g. Expires) are not treated as delimiters.\n';
+  content += 'fn split_set_cookie(value: &str) -> Vec<String> {\n';
+  content += `${indent.get()}let mut cookies: Vec<String> = Vec::new();\n`;
+  content += `${indent.get()}for chunk in value.split(',') {\n`;
+  content += `${indent.push().get()}// a new cookie always starts with a name=value pair\n`;
+  content += `${indent.get()}let is_new_cookie = chunk.split(';').next().is_some_and(|pair| pair.contains('='));\n`;
+  content += `${indent.get()}${helpers.buildIfBlock(indent, {
+    condition: '!is_new_cookie',
+    body: (indent) => `${indent.get()}${helpers.buildIfBlock(indent, {
+      condition: 'let Some(last) = cookies.last_mut()',
+      body: (indent) => `${indent.get()}last.push(',');\n${indent.get()}last.push_str(chunk);\n${indent.get()}continue;\n`,
+    })}\n`,
+  })}\n`;
+  content += `${indent.get()}cookies.push(chunk.trim().to_owned());\n`;
+  content += `${indent.pop().get()}}\n`; // end for
+  content += `${indent.get()}cookies\n`;
+  content += '}\n';
+  return content;
+}
+
 /**
  * returns the mod private {...} section used to seal the header traits.
  * 
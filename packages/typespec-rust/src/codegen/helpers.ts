@@ -203,6 +203,8 @@ export function getTypeDeclaration(type: rust.Client | rust.ResponseHeadersTrait
     case 'client':
     case 'decimal':
     case 'marker':
+    case 'negotiatedResponse':
+    case 'resumableDownload':
       return type.name;
     case 'clientMethodOptions':
     case 'pollerOptions':
@@ -256,6 +258,8 @@ export function getTypeDeclaration(type: rust.Client | rust.ResponseHeadersTrait
       return type.kind;
     case 'scalar':
       return type.type;
+    case 'seekableStream':
+      return `Box<dyn ${type.name}>`;
     case 'slice':
       return `[${getTypeDeclaration(type.type)}]`;
     case 'discriminatedUnion':
@@ -356,8 +360,25 @@ export function annotationDerive(serde: boolean, ...extra: Array<string>): strin
   // remove any empty values
   extra = extra.filter(entry => entry.trim() !== '');
   derive.push(...extra);
-  derive.sort();
-  return `#[derive(${derive.join(', ')})]\n`;
+  // customizations can add derives that are already present
+  return `#[derive(${Array.from(new Set(derive)).sort().join(', ')})]\n`;
+}
+
+/**
+ * emits the attributes added to a type or field through customizations
+ * 
+ * @param customizations the customizations for the type or field
+ * @param indentation the indentation to prefix each attribute
+ * @returns the attributes text, can be the empty string
+ */
+export function emitCustomAttributes(customizations: Array<rust.ModelFieldCustomizations | rust.TypeCustomizations>, indentation: string): string {
+  let attributes = '';
+  for (const customization of customizations) {
+    if (customization.kind === 'attribute') {
+      attributes += `${indentation}#[${customization.content}]\n`;
+    }
+  }
+  return attributes;
 }
 
 /**
@@ -536,9 +557,38 @@ export function getPollerStatusExpression(receiver: string, statusField?: rust.M
   return 'PollerStatus::Succeeded';
 }
 
+/**
+ * returns the delimiter character for the provided format type
+ * 
+ * @param format the format collection type
+ * @returns the delimiter character
+ */
+export function getCollectionDelimiter(format: rust.CollectionFormat): string {
+  switch (format) {
+    case 'csv':
+      return ',';
+    case 'pipes':
+      return '|';
+    case 'ssv':
+      return ' ';
+    case 'tsv':
+      return '\t';
+  }
+}
+
 /** the wire format used */
 export type ModelFormat = 'json' | 'xml';
 
+/**
+ * returns true if the format is for modeled payloads (i.e. JSON or XML)
+ *
+ * @param format the format to inspect
+ * @returns true if format is a ModelPayloadFormatType
+ */
+export function isModelPayloadFormat(format: rust.PayloadFormatType): format is rust.ModelPayloadFormatType {
+  return format === 'JsonFormat' || format === 'XmlFormat';
+}
+
 /**
  * converts a ResponseFormat to json or xml
  * 
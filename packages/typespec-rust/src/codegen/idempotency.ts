@@ -0,0 +1,103 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+
+import * as helpers from './helpers.js';
+import { Use } from './use.js';
+import * as rust from '../codemodel/index.js';
+
+/**
+ * returns the emitted Idempotency type that client methods add to the
+ * request's context and the IdempotencyPolicy that clients add to their
+ * pipeline, or undefined if the module has no clients that send requests
+ * or the idempotency context isn't enabled for the crate.
+ *
+ * @param module the module for which to emit the type
+ * @returns the idempotency content or undefined
+ */
+export function emitIdempotency(module: rust.ModuleContainer): helpers.Module | undefined {
+  const sendsRequests = (client: rust.Client) => client.constructable || client.emitSendRequest || client.methods.some((method) => method.kind === 'async' || method.kind === 'lro' || method.kind === 'pageable');
+  if (!helpers.getCrate(module).idempotencyContext || !module.clients.some(sendsRequests)) {
+    return undefined;
+  }
+
+  const use = new Use(module, 'modelsOther');
+  use.add('async_trait', 'async_trait');
+  use.add('azure_core::error', 'Error', 'ErrorKind');
+  use.add('azure_core::http', 'Context', 'Request');
+  use.add('azure_core::http::headers', 'HeaderName');
+  use.add('azure_core::http::policies', 'Policy', 'PolicyResult');
+  use.add('std::sync', 'Arc');
+
+  const indent = new helpers.indentation();
+  let body = 'const ERROR_CODE: HeaderName = HeaderName::from_static("x-ms-error-code");\n\n';
+  body += '/// Indicates if the request for an operation can be safely retried.\n';
+  body += '///\n';
+  body += '/// Client methods add this to the request\'s [`Context`] so that the client\'s [`IdempotencyPolicy`]\n';
+  body += '/// can keep the retry policy from retrying requests with side effects that a retry would duplicate.\n';
+  body += '/// Other policies retrieve it with `ctx.value::<Idempotency>()`.\n';
+  body += '#[derive(Clone, Copy, Debug, Eq, PartialEq)]\n';
+  body += '#[non_exhaustive]\n';
+  body += 'pub struct Idempotency {\n';
+  body += `${indent.get()}/// Indicates if sending the request more than once has the same effect as sending it once.\n`;
+  body += `${indent.get()}pub idempotent: bool,\n\n`;
+  body += `${indent.get()}/// Indicates if the request can be retried. This is always true for idempotent requests.\n`;
+  body += `${indent.get()}/// For all other requests, it's set by the \`allow_retry\` field in the method's options.\n`;
+  body += `${indent.get()}pub retry_allowed: bool,\n`;
+  body += '}\n';
+
+  body += '\nimpl Idempotency {\n';
+  body += `${indent.get()}/// Creates a new \`Idempotency\` to add to the context of a request sent with \`send_request\`.\n`;
+  body += `${indent.get()}pub fn new(idempotent: bool, retry_allowed: bool) -> Self {\n`;
+  body += `${indent.push().get()}Self {\n`;
+  body += `${indent.push().get()}idempotent,\n`;
+  body += `${indent.get()}retry_allowed,\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += '}\n\n';
+
+  body += '/// Keeps the retry policy from retrying requests whose [`Idempotency`] doesn\'t allow retries.\n';
+  body += '///\n';
+  body += '/// The retry policy in `azure_core` doesn\'t read the [`Idempotency`] in a request\'s context, so clients\n';
+  body += '/// add this policy after it. When a request can\'t be retried, server errors and I/O errors, after which\n';
+  body += '/// the request might have been processed, are returned as errors that the retry policy doesn\'t retry.\n';
+  body += '#[derive(Clone, Debug, Default)]\n';
+  body += 'pub struct IdempotencyPolicy;\n\n';
+  body += '#[async_trait]\n';
+  body += 'impl Policy for IdempotencyPolicy {\n';
+  body += `${indent.get()}async fn send(&self, ctx: &Context, request: &mut Request, next: &[Arc<dyn Policy>]) -> PolicyResult {\n`;
+  body += `${indent.push().get()}let result = next[0].send(ctx, request, &next[1..]).await;\n`;
+  body += `${indent.get()}if !matches!(ctx.value::<Idempotency>(), Some(Idempotency { retry_allowed: false, .. })) {\n`;
+  body += `${indent.push().get()}return result;\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}match result {\n`;
+  body += `${indent.push().get()}Ok(response) if response.status().is_server_error() => {\n`;
+  body += `${indent.push().get()}let status = response.status();\n`;
+  body += `${indent.get()}let error_code = response.headers().get_optional_str(&ERROR_CODE).map(ToString::to_string);\n`;
+  body += `${indent.get()}let raw_response = response.try_into_raw_response().await?;\n`;
+  body += `${indent.get()}Err(Error::with_message(\n`;
+  body += `${indent.push().get()}ErrorKind::HttpResponse {\n`;
+  body += `${indent.push().get()}status,\n`;
+  body += `${indent.get()}error_code,\n`;
+  body += `${indent.get()}raw_response: Some(Box::new(raw_response)),\n`;
+  body += `${indent.pop().get()}},\n`;
+  body += `${indent.get()}format!("the request isn't idempotent and wasn't retried after it failed with status {status}"),\n`;
+  body += `${indent.pop().get()}))\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}Err(err) if matches!(err.kind(), ErrorKind::Io) => Err(Error::new(ErrorKind::Other, err)),\n`;
+  body += `${indent.get()}result => result,\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += '}\n';
+
+  let content = helpers.contentPreamble();
+  content += use.text();
+  content += body;
+
+  return {
+    name: 'idempotency',
+    content: content,
+    visibility: 'pubUse',
+  };
+}
@@ -104,12 +104,13 @@ function emitModelDefinitions(module: rust.ModuleContainer, context: Context): h
       // it's not necessary and will cause compilation failures
       // when the type contains something that doesn't have a
       // default impl (e.g. enum types).
-      body += helpers.annotationDerive(!hasXmlAddlProps, model.flags !== rust.ModelFlags.Unspecified ? 'Default' : '');
+      body += helpers.annotationDerive(!hasXmlAddlProps, model.flags !== rust.ModelFlags.Unspecified ? 'Default' : '', ...getCustomDerives(model));
     } else {
       // rust.ModelFlags.PolymorphicBase only needs this
       use.add('serde', 'Serialize');
-      body += '#[derive(Serialize)]\n';
+      body += `#[derive(${Array.from(new Set(['Serialize', ...getCustomDerives(model)])).sort().join(', ')})]\n`;
     }
+    body += helpers.emitCustomAttributes(model.customizations, '');
 
     if ((model.flags & rust.ModelFlags.Output) !== 0 && (model.flags & rust.ModelFlags.Input) === 0) {
       // output-only models get the non_exhaustive annotation
@@ -130,6 +131,14 @@ function emitModelDefinitions(module: rust.ModuleContainer, context: Context): h
       }
     }
 
+    // namespace declarations are emitted by serializing through a helper type.
+    // models with a lifetime can't be converted into it and are reported by the adapter.
+    const xmlNamespaces = bodyFormat === 'xml' && !hasXmlAddlProps && !model.lifetime && model.flags !== rust.ModelFlags.PolymorphicBase ? getXMLNamespaceDeclarations(model) : [];
+    const xmlNamespacesTypeName = `${model.name}WithXmlNamespaces`;
+    if (xmlNamespaces.length > 0) {
+      body += `#[serde(into = "${xmlNamespacesTypeName}")]\n`;
+    }
+
     if (!hasXmlAddlProps && model.xmlName) {
       body += `#[serde(rename = "${model.xmlName}")]\n`;
     } else if (discriminator) {
@@ -150,7 +159,8 @@ function emitModelDefinitions(module: rust.ModuleContainer, context: Context): h
       body += `#[serde(rename = "${duMember.discriminantValue}", tag = "${discriminator.serde}")]\n`;
     }
 
-    body += `${helpers.emitVisibility(model.visibility)}struct ${helpers.getTypeDeclaration(model)} {\n`;
+    let fields = '';
+    const fieldNames = new Array<string>();
 
     for (const field of model.fields) {
       if (field.kind === 'modelField' && (field.flags & rust.ModelFieldFlags.Discriminator)) {
@@ -165,19 +175,25 @@ function emitModelDefinitions(module: rust.ModuleContainer, context: Context): h
       }
 
       use.addForType(field.type);
-      body += helpers.formatDocComment(field.docs);
+      fieldNames.push(field.name);
+      fields += helpers.formatDocComment(field.docs);
 
       if (field.kind === 'additionalProperties') {
         if (bodyFormat === 'json') {
-          body += `#[serde(flatten)]\n`;
+          fields += `#[serde(flatten)]\n`;
         }
-        body += `${indent.get()}${helpers.emitVisibility(field.visibility)}${field.name}: ${helpers.getTypeDeclaration(field.type)},\n\n`;
+        fields += `${indent.get()}${helpers.emitVisibility(field.visibility)}${field.name}: ${helpers.getTypeDeclaration(field.type)},\n\n`;
         continue;
       }
 
       const serdeParams = new Set<string>();
       const fieldRename = getSerDeRename(field);
-      if (fieldRename) {
+      const qualifiedName = bodyFormat === 'xml' ? getXMLQualifiedName(field) : undefined;
+      if (qualifiedName) {
+        // serialize the prefixed name and accept both forms when deserializing
+        serdeParams.add(`alias = "${qualifiedName}"`);
+        serdeParams.add(`rename(deserialize = "${fieldRename ?? field.name}", serialize = "${qualifiedName}")`);
+      } else if (fieldRename) {
         serdeParams.add(`rename = "${fieldRename}"`);
       }
 
@@ -237,12 +253,19 @@ function emitModelDefinitions(module: rust.ModuleContainer, context: Context): h
       }
 
       if (!hasXmlAddlProps && serdeParams.size > 0) {
-        body += `${indent.get()}#[serde(${Array.from(serdeParams).sort().join(', ')})]\n`;
+        fields += `${indent.get()}#[serde(${Array.from(serdeParams).sort().join(', ')})]\n`;
       }
-      body += `${indent.get()}${helpers.emitVisibility(field.visibility)}${field.name}: ${helpers.getTypeDeclaration(field.type)},\n\n`;
+      fields += helpers.emitCustomAttributes(field.customizations, indent.get());
+      fields += `${indent.get()}${helpers.emitVisibility(field.visibility)}${field.name}: ${helpers.getTypeDeclaration(field.type)},\n\n`;
     }
 
+    body += `${helpers.emitVisibility(model.visibility)}struct ${helpers.getTypeDeclaration(model)} {\n`;
+    body += fields;
     body += '}\n\n';
+
+    if (xmlNamespaces.length > 0) {
+      body += getXMLNamespacesType(indent, model, xmlNamespacesTypeName, xmlNamespaces, fields, fieldNames);
+    }
   }
 
   let content = helpers.contentPreamble();
@@ -393,6 +416,91 @@ function getSerDeRename(field: rust.ModelField): string | undefined {
   return `${xmlAttr(field)}${fieldName}`;
 }
 
+/**
+ * returns the prefixed XML name for a field in a namespace with a prefix
+ * or undefined if the field's name isn't qualified.
+ *
+ * @param field the field for which to return the qualified name
+ * @returns the qualified name or undefined
+ */
+function getXMLQualifiedName(field: rust.ModelField): string | undefined {
+  if (!field.xmlNamespace?.prefix || field.xmlKind === 'text') {
+    return undefined;
+  }
+  return `${xmlAttr(field)}${field.xmlNamespace.prefix}:${field.serde}`;
+}
+
+/**
+ * returns the distinct XML namespaces used by a model and its fields.
+ * the first namespace for a given prefix wins.
+ *
+ * @param model the model for which to return the namespaces
+ * @returns the namespaces to declare on the model's element. can be empty
+ */
+function getXMLNamespaceDeclarations(model: rust.Model): Array<rust.XMLNamespace> {
+  const declarations = new Array<rust.XMLNamespace>();
+  const addDeclaration = function (xmlNamespace?: rust.XMLNamespace): void {
+    if (xmlNamespace && !declarations.find((each) => each.prefix === xmlNamespace.prefix)) {
+      declarations.push(xmlNamespace);
+    }
+  };
+
+  addDeclaration(model.xmlNamespace);
+  for (const field of model.fields) {
+    if (field.kind === 'modelField' && field.xmlKind !== 'text') {
+      addDeclaration(field.xmlNamespace);
+    }
+  }
+  return declarations;
+}
+
+/**
+ * returns the serialize-only type used to write the xmlns declarations for a model.
+ * it has the same fields as the model plus an attribute per declaration.
+ *
+ * @param indent the indentation helper currently in scope
+ * @param model the model with XML namespaces
+ * @param typeName the name of the type to emit
+ * @param xmlNamespaces the namespaces to declare
+ * @param fields the emitted fields of the model
+ * @param fieldNames the names of the emitted fields of the model
+ * @returns the type definition and its From impl
+ */
+function getXMLNamespacesType(indent: helpers.indentation, model: rust.Model, typeName: string, xmlNamespaces: Array<rust.XMLNamespace>, fields: string, fieldNames: Array<string>): string {
+  let elementName = model.xmlName ?? model.name;
+  if (model.xmlNamespace?.prefix) {
+    elementName = `${model.xmlNamespace.prefix}:${elementName}`;
+  }
+
+  const getDeclFieldName = (xmlNamespace: rust.XMLNamespace) => xmlNamespace.prefix ? `xmlns_${utils.snakeCaseName(xmlNamespace.prefix)}` : 'xmlns';
+
+  let body = `/// Serializes [\`${model.name}\`] along with its XML namespace declarations.\n`;
+  body += '#[derive(Serialize)]\n';
+  body += `#[serde(rename = "${elementName}")]\n`;
+  body += `struct ${typeName} {\n`;
+  for (const xmlNamespace of xmlNamespaces) {
+    body += `${indent.get()}#[serde(rename = "@xmlns${xmlNamespace.prefix ? `:${xmlNamespace.prefix}` : ''}")]\n`;
+    body += `${indent.get()}${getDeclFieldName(xmlNamespace)}: &'static str,\n\n`;
+  }
+  body += fields;
+  body += '}\n\n';
+
+  body += `impl From<${model.name}> for ${typeName} {\n`;
+  body += `${indent.get()}fn from(value: ${model.name}) -> Self {\n`;
+  body += `${indent.push().get()}Self {\n`;
+  indent.push();
+  for (const xmlNamespace of xmlNamespaces) {
+    body += `${indent.get()}${getDeclFieldName(xmlNamespace)}: "${xmlNamespace.namespace}",\n`;
+  }
+  for (const fieldName of fieldNames) {
+    body += `${indent.get()}${fieldName}: value.${fieldName},\n`;
+  }
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += '}\n\n';
+  return body;
+}
+
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 // XML helpers infrastructure
 ///////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1501,3 +1609,19 @@ function encodeAsString(type: rust.Type): boolean {
   }
   return type.stringEncoding;
 }
+
+/**
+ * returns the derives added to the model through customizations
+ * 
+ * @param model the model for which to get the derives
+ * @returns the derives, can be empty
+ */
+function getCustomDerives(model: rust.Model): Array<string> {
+  const derives = new Array<string>();
+  for (const customization of model.customizations) {
+    if (customization.kind === 'derive') {
+      derives.push(customization.name);
+    }
+  }
+  return derives;
+}
@@ -0,0 +1,173 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+
+import * as helpers from './helpers.js';
+import { Use } from './use.js';
+import * as rust from '../codemodel/index.js';
+
+/**
+ * returns the emitted ResourceId type, or undefined if the
+ * module isn't the root of an Azure Resource Manager crate.
+ *
+ * @param module the module for which to emit the ResourceId type
+ * @returns the ResourceId content or undefined
+ */
+export function emitResourceId(module: rust.ModuleContainer): helpers.Module | undefined {
+  if (module.kind !== 'crate' || module.type !== 'azure-arm') {
+    return undefined;
+  }
+
+  const use = new Use(module, 'modelsOther');
+  use.add('azure_core', 'Result');
+  use.add('azure_core::error', 'Error', 'ErrorKind');
+  use.add('std::collections', 'HashMap');
+  use.add('std::fmt', 'Display', 'Formatter');
+  use.add('std::str', 'FromStr');
+
+  const indent = new helpers.indentation();
+  let body = '/// An Azure Resource Manager resource ID.\n';
+  body += '///\n';
+  body += '/// A resource ID is a sequence of `{type}/{name}` pairs, e.g.\n';
+  body += '/// `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/{namespace}/{type}/{name}`.\n';
+  body += '#[derive(Clone, Debug, Eq, PartialEq)]\n';
+  body += 'pub struct ResourceId {\n';
+  body += `${indent.get()}segments: Vec<String>,\n`;
+  body += '}\n\n';
+  body += 'impl ResourceId {\n';
+  body += `${indent.get()}/// Returns the subscription ID or \`None\` if the resource isn't scoped to a subscription.\n`;
+  body += `${indent.get()}pub fn subscription_id(&self) -> Option<&str> {\n`;
+  body += `${indent.push().get()}self.value_of(0, "subscriptions")\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Returns the resource group name or \`None\` if the resource isn't scoped to a resource group.\n`;
+  body += `${indent.get()}pub fn resource_group_name(&self) -> Option<&str> {\n`;
+  body += `${indent.push().get()}self.value_of(1, "resourceGroups")\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Returns the namespace of the resource provider, e.g. \`Microsoft.Compute\`.\n`;
+  body += `${indent.get()}///\n`;
+  body += `${indent.get()}/// Returns \`None\` for subscriptions, resource groups, and other resources that aren't owned by a resource provider.\n`;
+  body += `${indent.get()}pub fn provider_namespace(&self) -> Option<&str> {\n`;
+  body += `${indent.push().get()}self.providers_index().map(|index| self.segments[index + 1].as_str())\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Returns the fully qualified resource type, e.g. \`Microsoft.Compute/virtualMachines/extensions\`.\n`;
+  body += `${indent.get()}pub fn resource_type(&self) -> String {\n`;
+  body += `${indent.push().get()}match self.providers_index() {\n`;
+  body += `${indent.push().get()}Some(index) => {\n`;
+  body += `${indent.push().get()}let mut resource_type = self.segments[index + 1].clone();\n`;
+  body += `${indent.get()}for pair in self.segments[index + 2..].chunks(2) {\n`;
+  body += `${indent.push().get()}resource_type.push('/');\n`;
+  body += `${indent.get()}resource_type.push_str(&pair[0]);\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}resource_type\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}None => format!("Microsoft.Resources/{}", self.segments[self.segments.len() - 2]),\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Returns the name of the resource.\n`;
+  body += `${indent.get()}pub fn name(&self) -> &str {\n`;
+  body += `${indent.push().get()}&self.segments[self.segments.len() - 1]\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Returns the ID of the parent resource or \`None\` if the resource has no parent.\n`;
+  body += `${indent.get()}///\n`;
+  body += `${indent.get()}/// The parent of a top-level resource is the scope that contains it, e.g. its resource group.\n`;
+  body += `${indent.get()}pub fn parent(&self) -> Option<ResourceId> {\n`;
+  body += `${indent.push().get()}let mut segments = self.segments.clone();\n`;
+  body += `${indent.get()}segments.truncate(segments.len() - 2);\n`;
+  body += `${indent.get()}if segments.len() >= 2 && segments[segments.len() - 2].eq_ignore_ascii_case("providers") {\n`;
+  body += `${indent.push().get()}segments.truncate(segments.len() - 2);\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}if segments.is_empty() {\n`;
+  body += `${indent.push().get()}return None;\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}Some(ResourceId { segments })\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Returns the ID of a child resource with the specified type and name.\n`;
+  body += `${indent.get()}///\n`;
+  body += `${indent.get()}/// The type is the last segment of the child's resource type, e.g. \`extensions\`.\n`;
+  body += `${indent.get()}pub fn child(&self, resource_type: &str, name: &str) -> Result<ResourceId> {\n`;
+  body += `${indent.push().get()}if resource_type.is_empty() || resource_type.contains('/') || name.is_empty() || name.contains('/') {\n`;
+  body += `${indent.push().get()}return Err(invalid_resource_id(format!("invalid child resource {resource_type}/{name}")));\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}let mut segments = self.segments.clone();\n`;
+  body += `${indent.get()}segments.push(resource_type.to_string());\n`;
+  body += `${indent.get()}segments.push(name.to_string());\n`;
+  body += `${indent.get()}Ok(ResourceId { segments })\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Matches the resource ID against a path template and returns the values of its parameters.\n`;
+  body += `${indent.get()}///\n`;
+  body += `${indent.get()}/// The resource ID must match the start of the template. Any remaining segments in the template\n`;
+  body += `${indent.get()}/// must be literals (e.g. a collection or action name). Literal segments are compared without\n`;
+  body += `${indent.get()}/// regard to case.\n`;
+  body += `${indent.get()}pub fn path_params<'a>(&'a self, template: &'a str) -> Result<HashMap<&'a str, &'a str>> {\n`;
+  body += `${indent.push().get()}let mut template_segments = template.trim_matches('/').split('/');\n`;
+  body += `${indent.get()}let mut params = HashMap::new();\n`;
+  body += `${indent.get()}for segment in &self.segments {\n`;
+  body += `${indent.push().get()}let Some(template_segment) = template_segments.next() else {\n`;
+  body += `${indent.push().get()}return Err(invalid_resource_id(format!("resource ID {self} doesn't match {template}")));\n`;
+  body += `${indent.pop().get()}};\n`;
+  body += `${indent.get()}match template_segment.strip_prefix('{').and_then(|param| param.strip_suffix('}')) {\n`;
+  body += `${indent.push().get()}Some(param) => {\n`;
+  body += `${indent.push().get()}params.insert(param, segment.as_str());\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}None if template_segment.eq_ignore_ascii_case(segment) => {}\n`;
+  body += `${indent.get()}None => {\n`;
+  body += `${indent.push().get()}return Err(invalid_resource_id(format!("resource ID {self} doesn't match {template}")));\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}if template_segments.any(|segment| segment.starts_with('{')) {\n`;
+  body += `${indent.push().get()}return Err(invalid_resource_id(format!("resource ID {self} doesn't match {template}")));\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}Ok(params)\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Returns the value of the \`{type}/{name}\` pair at \`index\` if its type is \`key\`.\n`;
+  body += `${indent.get()}fn value_of(&self, index: usize, key: &str) -> Option<&str> {\n`;
+  body += `${indent.push().get()}match self.segments.get(index * 2) {\n`;
+  body += `${indent.push().get()}Some(segment) if segment.eq_ignore_ascii_case(key) => Some(self.segments[index * 2 + 1].as_str()),\n`;
+  body += `${indent.get()}_ => None,\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Returns the index of the last \`providers\` segment.\n`;
+  body += `${indent.get()}fn providers_index(&self) -> Option<usize> {\n`;
+  body += `${indent.push().get()}(0..self.segments.len())\n`;
+  body += `${indent.push().get()}.step_by(2)\n`;
+  body += `${indent.get()}.rev()\n`;
+  body += `${indent.get()}.find(|index| self.segments[*index].eq_ignore_ascii_case("providers"))\n`;
+  body += `${indent.pop().pop().get()}}\n`;
+  body += '}\n\n';
+  body += 'impl Display for ResourceId {\n';
+  body += `${indent.get()}fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {\n`;
+  body += `${indent.push().get()}for segment in &self.segments {\n`;
+  body += `${indent.push().get()}write!(f, "/{segment}")?;\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}Ok(())\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += '}\n\n';
+  body += 'impl FromStr for ResourceId {\n';
+  body += `${indent.get()}type Err = Error;\n`;
+  body += `${indent.get()}fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {\n`;
+  body += `${indent.push().get()}let Some(path) = s.strip_prefix('/') else {\n`;
+  body += `${indent.push().get()}return Err(invalid_resource_id(format!("resource ID {s} must start with '/'")));\n`;
+  body += `${indent.pop().get()}};\n`;
+  body += `${indent.get()}let segments: Vec<String> = path.trim_end_matches('/').split('/').map(str::to_string).collect();\n`;
+  body += `${indent.get()}if segments.iter().any(String::is_empty) || segments.len() % 2 == 1 {\n`;
+  body += `${indent.push().get()}return Err(invalid_resource_id(format!("resource ID {s} must contain {{type}}/{{name}} pairs")));\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}Ok(ResourceId { segments })\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += '}\n\n';
+  body += 'fn invalid_resource_id(message: String) -> Error {\n';
+  body += `${indent.get()}Error::with_message(ErrorKind::DataConversion, message)\n`;
+  body += '}\n';
+
+  let content = helpers.contentPreamble();
+  content += use.text();
+  content += body;
+
+  return {
+    name: 'resource_id',
+    content: content,
+    visibility: 'pubUse',
+  };
+}
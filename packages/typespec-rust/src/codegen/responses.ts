@@ -0,0 +1,258 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+
+import * as helpers from './helpers.js';
+import { Use } from './use.js';
+import * as rust from '../codemodel/index.js';
+
+/**
+ * returns the emitted negotiated responses, or undefined if
+ * no methods in the module negotiate their response content type.
+ *
+ * @param module the module for which to emit negotiated responses
+ * @returns the negotiated responses content or undefined
+ */
+export function emitNegotiatedResponses(module: rust.ModuleContainer): helpers.Module | undefined {
+  const responses = new Array<rust.NegotiatedResponse>();
+  for (const client of module.clients) {
+    for (const method of client.methods) {
+      if (method.kind === 'async' && method.returns.type.kind === 'negotiatedResponse') {
+        responses.push(method.returns.type);
+      }
+    }
+  }
+
+  if (responses.length === 0) {
+    return undefined;
+  }
+
+  const use = new Use(module, 'modelsOther');
+  const indent = new helpers.indentation();
+
+  let body = '';
+  for (const response of responses) {
+    body += helpers.formatDocComment(response.docs);
+    body += `${helpers.emitVisibility(response.visibility)}enum ${response.name} {\n`;
+    for (const variant of response.variants) {
+      use.addForType(variant.type);
+      body += `${indent.get()}/// The response when the service returns \`${variant.contentType}\` content.\n`;
+      body += `${indent.get()}${variant.name}(${helpers.getTypeDeclaration(variant.type)}),\n`;
+    }
+    body += '}\n\n';
+  }
+
+  let content = helpers.contentPreamble();
+  content += use.text();
+  content += body;
+
+  return {
+    name: 'responses',
+    content: content,
+    visibility: 'pubUse',
+  };
+}
+
+/**
+ * returns the emitted resumable download stream, or undefined if
+ * no methods in the module return a resumable download.
+ *
+ * @param module the module for which to emit the resumable download
+ * @returns the resumable download content or undefined
+ */
+export function emitResumableDownload(module: rust.ModuleContainer): helpers.Module | undefined {
+  let resumableDownload: rust.ResumableDownload | undefined;
+  for (const client of module.clients) {
+    for (const method of client.methods) {
+      if (method.kind === 'async' && method.returns.type.kind === 'resumableDownload') {
+        resumableDownload = method.returns.type;
+        break;
+      }
+    }
+  }
+
+  if (!resumableDownload) {
+    return undefined;
+  }
+
+  const use = new Use(module, 'modelsOther');
+  use.add('azure_core', 'Bytes', 'Result');
+  use.add('azure_core::error', 'CheckSuccessOptions', 'Error', 'ErrorKind');
+  use.add('azure_core::http', 'AsyncRawResponse', 'Context', 'Pipeline', 'PipelineStreamOptions', 'Request', 'StatusCode');
+  use.add('azure_core::http::headers', 'HeaderName', 'Headers');
+  use.add('futures::stream', 'self', 'BoxStream');
+  use.add('futures', 'Stream', 'StreamExt');
+  use.add('std', 'pin::Pin', 'task::Poll');
+
+  const indent = new helpers.indentation();
+  let body = '/// The number of times a download is resumed before the error is returned.\n';
+  body += 'const MAX_RESUMES: usize = 3;\n\n';
+  body += 'const ETAG: HeaderName = HeaderName::from_static("etag");\n';
+  body += 'const IF_MATCH: HeaderName = HeaderName::from_static("if-match");\n\n';
+
+  body += '/// A byte stream over a binary response body.\n';
+  body += '///\n';
+  body += '/// If reading the response body fails with a transient error, the request is reissued for the bytes that\n';
+  body += '/// haven\'t been received and the stream continues where it left off. The resumed request includes the\n';
+  body += '/// `if-match` header with the original ETag so the stream fails instead of mixing different versions.\n';
+  body += 'pub struct ResumableDownload {\n';
+  body += `${indent.get()}status: StatusCode,\n`;
+  body += `${indent.get()}headers: Headers,\n`;
+  body += `${indent.get()}stream: BoxStream<'static, Result<Bytes>>,\n`;
+  body += '}\n\n';
+
+  body += 'impl ResumableDownload {\n';
+  body += `${indent.get()}pub(crate) fn new(pipeline: Pipeline, ctx: Context<'static>, request: Request, response: AsyncRawResponse, range_header: &'static str) -> Self {\n`;
+  body += `${indent.push().get()}let status = response.status();\n`;
+  body += `${indent.get()}let headers = response.headers().clone();\n`;
+  body += `${indent.get()}let range_header = HeaderName::from_static(range_header);\n`;
+  body += `${indent.get()}let state = DownloadState {\n`;
+  body += `${indent.push().get()}range: parse_range(request.headers().get_optional_str(&range_header)),\n`;
+  body += `${indent.get()}etag: headers.get_optional_str(&ETAG).map(ToString::to_string),\n`;
+  body += `${indent.get()}body: response.into_body().boxed(),\n`;
+  body += `${indent.get()}received: 0,\n`;
+  body += `${indent.get()}resumes: 0,\n`;
+  body += `${indent.get()}pipeline,\n`;
+  body += `${indent.get()}ctx,\n`;
+  body += `${indent.get()}request,\n`;
+  body += `${indent.get()}range_header,\n`;
+  body += `${indent.pop().get()}};\n`;
+  body += `${indent.get()}Self {\n`;
+  body += `${indent.push().get()}status,\n`;
+  body += `${indent.get()}headers,\n`;
+  body += `${indent.get()}stream: stream::unfold(Some(state), next_chunk).boxed(),\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.pop().get()}}\n\n`; // end new
+
+  body += `${indent.get()}/// Returns the status code of the initial response.\n`;
+  body += `${indent.get()}pub fn status(&self) -> StatusCode {\n`;
+  body += `${indent.push().get()}self.status\n`;
+  body += `${indent.pop().get()}}\n\n`;
+
+  body += `${indent.get()}/// Returns the headers of the initial response.\n`;
+  body += `${indent.get()}pub fn headers(&self) -> &Headers {\n`;
+  body += `${indent.push().get()}&self.headers\n`;
+  body += `${indent.pop().get()}}\n\n`;
+
+  body += `${indent.get()}/// Invokes \`progress\` with the total number of bytes received after each chunk is received.\n`;
+  body += `${indent.get()}pub fn on_progress<F>(self, progress: F) -> Self\n`;
+  body += `${indent.get()}where\n`;
+  body += `${indent.push().get()}F: Fn(u64) + Send + 'static,\n`;
+  body += `${indent.pop().get()}{\n`;
+  body += `${indent.push().get()}let mut received = 0u64;\n`;
+  body += `${indent.get()}let stream = self.stream.inspect(move |chunk| {\n`;
+  body += `${indent.push().get()}if let Ok(chunk) = chunk {\n`;
+  body += `${indent.push().get()}received += chunk.len() as u64;\n`;
+  body += `${indent.get()}progress(received);\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.pop().get()}});\n`;
+  body += `${indent.get()}Self {\n`;
+  body += `${indent.push().get()}stream: stream.boxed(),\n`;
+  body += `${indent.get()}..self\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.pop().get()}}\n\n`; // end on_progress
+
+  body += `${indent.get()}/// Reads the remainder of the stream into memory.\n`;
+  body += `${indent.get()}pub async fn collect(mut self) -> Result<Bytes> {\n`;
+  body += `${indent.push().get()}let mut body = Vec::new();\n`;
+  body += `${indent.get()}while let Some(chunk) = self.stream.next().await {\n`;
+  body += `${indent.push().get()}body.extend_from_slice(&chunk?);\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}Ok(body.into())\n`;
+  body += `${indent.pop().get()}}\n`; // end collect
+  body += '}\n\n'; // end impl
+
+  body += 'impl Stream for ResumableDownload {\n';
+  body += `${indent.get()}type Item = Result<Bytes>;\n\n`;
+  body += `${indent.get()}fn poll_next(mut self: Pin<&mut Self>, cx: &mut std::task::Context<'_>) -> Poll<Option<Self::Item>> {\n`;
+  body += `${indent.push().get()}self.stream.poll_next_unpin(cx)\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += '}\n\n';
+
+  body += '/// The state used to read and resume a download.\n';
+  body += 'struct DownloadState {\n';
+  body += `${indent.get()}pipeline: Pipeline,\n`;
+  body += `${indent.get()}ctx: Context<'static>,\n`;
+  body += `${indent.get()}request: Request,\n`;
+  body += `${indent.get()}range_header: HeaderName,\n`;
+  body += `${indent.get()}/// the first and optional last byte requested. None if the download can't be resumed.\n`;
+  body += `${indent.get()}range: Option<(u64, Option<u64>)>,\n`;
+  body += `${indent.get()}etag: Option<String>,\n`;
+  body += `${indent.get()}body: BoxStream<'static, Result<Bytes>>,\n`;
+  body += `${indent.get()}received: u64,\n`;
+  body += `${indent.get()}resumes: usize,\n`;
+  body += '}\n\n';
+
+  body += 'impl DownloadState {\n';
+  body += `${indent.get()}/// Reissues the request for the bytes that haven't been received.\n`;
+  body += `${indent.get()}async fn resume(&mut self) -> Result<()> {\n`;
+  body += `${indent.push().get()}let Some((first, last)) = self.range else {\n`;
+  body += `${indent.push().get()}return Err(Error::with_message(ErrorKind::Other, "the download can't be resumed for the requested range"));\n`;
+  body += `${indent.pop().get()}};\n`;
+  body += `${indent.get()}let first = first + self.received;\n`;
+  body += `${indent.get()}let range = match last {\n`;
+  body += `${indent.push().get()}Some(last) => format!("bytes={first}-{last}"),\n`;
+  body += `${indent.get()}None => format!("bytes={first}-"),\n`;
+  body += `${indent.pop().get()}};\n`;
+  body += `${indent.get()}let mut request = self.request.clone();\n`;
+  body += `${indent.get()}request.insert_header(self.range_header.clone(), range);\n`;
+  body += `${indent.get()}if let Some(etag) = self.etag.as_ref() {\n`;
+  body += `${indent.push().get()}request.insert_header(IF_MATCH, etag.clone());\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}let rsp = self.pipeline.stream(&self.ctx, &mut request, Some(PipelineStreamOptions {\n`;
+  body += `${indent.push().get()}check_success: CheckSuccessOptions { success_codes: &[206] },\n`;
+  body += `${indent.get()}..Default::default()\n`;
+  body += `${indent.pop().get()}})).await?;\n`;
+  body += `${indent.get()}self.body = rsp.into_body().boxed();\n`;
+  body += `${indent.get()}Ok(())\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += '}\n\n';
+
+  body += '/// Returns the next chunk of the download, resuming the download after transient failures.\n';
+  body += 'async fn next_chunk(state: Option<DownloadState>) -> Option<(Result<Bytes>, Option<DownloadState>)> {\n';
+  body += `${indent.get()}let mut state = state?;\n`;
+  body += `${indent.get()}loop {\n`;
+  body += `${indent.push().get()}match state.body.next().await {\n`;
+  body += `${indent.push().get()}Some(Ok(chunk)) => {\n`;
+  body += `${indent.push().get()}state.received += chunk.len() as u64;\n`;
+  body += `${indent.get()}return Some((Ok(chunk), Some(state)));\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}Some(Err(err)) if state.resumes < MAX_RESUMES && state.range.is_some() && matches!(err.kind(), ErrorKind::Io) => {\n`;
+  body += `${indent.push().get()}state.resumes += 1;\n`;
+  body += `${indent.get()}if let Err(err) = state.resume().await {\n`;
+  body += `${indent.push().get()}return Some((Err(err), None));\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.get()}Some(Err(err)) => return Some((Err(err), None)),\n`;
+  body += `${indent.get()}None => return None,\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += '}\n\n';
+
+  body += '/// Returns the first and optional last byte of a `bytes=first-[last]` range.\n';
+  body += '///\n';
+  body += '/// No range starts at the first byte. Returns None for ranges that can\'t be resumed (e.g. suffix ranges).\n';
+  body += 'fn parse_range(range: Option<&str>) -> Option<(u64, Option<u64>)> {\n';
+  body += `${indent.get()}let Some(range) = range else {\n`;
+  body += `${indent.push().get()}return Some((0, None));\n`;
+  body += `${indent.pop().get()}};\n`;
+  body += `${indent.get()}let (first, last) = range.trim().strip_prefix("bytes=")?.split_once('-')?;\n`;
+  body += `${indent.get()}let first = first.trim().parse().ok()?;\n`;
+  body += `${indent.get()}let last = match last.trim() {\n`;
+  body += `${indent.push().get()}"" => None,\n`;
+  body += `${indent.get()}last => Some(last.parse().ok()?),\n`;
+  body += `${indent.pop().get()}};\n`;
+  body += `${indent.get()}Some((first, last))\n`;
+  body += '}\n';
+
+  let content = helpers.contentPreamble();
+  content += use.text();
+  content += body;
+
+  return {
+    name: 'resumable_download',
+    content: content,
+    visibility: 'pubUse',
+  };
+}
@@ -0,0 +1,494 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+
+import * as helpers from './helpers.js';
+import { Use } from './use.js';
+import * as rust from '../codemodel/index.js';
+import * as utils from '../utils/utils.js';
+
+/** the content of the stub server crate */
+export interface StubServerContent {
+  /** the contents of the stub server's Cargo.toml file */
+  cargoToml: string;
+
+  /** the contents of the stub server's lib.rs file */
+  libRs: string;
+}
+
+/**
+ * emits the companion stub server crate for the provided crate.
+ * the stub server depends on the crate via a path dependency on
+ * its parent directory.
+ *
+ * @param crate the crate for which to emit the stub server
+ * @returns the stub server content or undefined if it isn't to be emitted
+ */
+export function emitStubServer(crate: rust.Crate): StubServerContent | undefined {
+  if (!crate.stubServer) {
+    return undefined;
+  }
+
+  // the crate name as it appears in a Rust path
+  const crateIdent = crate.name.replace(/-/g, '_');
+  const use = new Use(crate, 'modelsOther');
+  const indent = new helpers.indentation();
+
+  const operations = new Array<stubOperation>();
+  const clients = new Array<rust.Client>();
+  const recursiveAddOperations = function (module: rust.ModuleContainer): void {
+    for (const client of module.clients) {
+      let hasOperations = false;
+      for (const method of client.methods) {
+        if (method.kind !== 'async' && method.kind !== 'pageable' && method.kind !== 'lro') {
+          continue;
+        }
+        operations.push(getStubOperation(crate, crateIdent, use, client, method));
+        hasOperations = true;
+      }
+      if (hasOperations) {
+        clients.push(client);
+      }
+    }
+    for (const subModule of module.subModules) {
+      recursiveAddOperations(subModule);
+    }
+  };
+  recursiveAddOperations(crate);
+
+  use.add('axum', 'Router');
+  use.add('std::collections', 'HashMap');
+  use.add('std', 'io');
+  use.add('std::net', 'Ipv4Addr');
+  use.add('tokio::net', 'TcpListener');
+  use.add('tokio::task', 'JoinHandle');
+
+  // the bounds for a type that handles all of the operations
+  const handlerBounds = clients.length > 0 ? [...clients.map((client) => `${client.name}Handler`), '\'static'] : ['Send', 'Sync', '\'static'];
+
+  let body = '/// The parts of a request that aren\'t passed to a handler as a model.\n';
+  body += '#[derive(Debug, Default)]\n';
+  body += 'pub struct StubRequest {\n';
+  body += `${indent.get()}/// The values of the path parameters keyed by parameter name.\n`;
+  body += `${indent.get()}pub path: HashMap<String, String>,\n\n`;
+  body += `${indent.get()}/// The query parameters in the order they appear in the URL.\n`;
+  body += `${indent.get()}pub query: Vec<(String, String)>,\n\n`;
+  body += `${indent.get()}/// The request headers.\n`;
+  body += `${indent.get()}pub headers: HeaderMap,\n`;
+  body += '}\n\n';
+
+  body += '/// The response returned by a handler.\n';
+  body += '#[derive(Debug)]\n';
+  body += 'pub struct StubResponse<T> {\n';
+  body += `${indent.get()}/// The HTTP status code.\n`;
+  body += `${indent.get()}pub status: StatusCode,\n\n`;
+  body += `${indent.get()}/// The response headers.\n`;
+  body += `${indent.get()}pub headers: HeaderMap,\n\n`;
+  body += `${indent.get()}/// The response body. \`None\` sends an empty body.\n`;
+  body += `${indent.get()}pub body: Option<T>,\n`;
+  body += '}\n\n';
+
+  use.add('axum::http', 'HeaderValue');
+  body += 'impl<T> StubResponse<T> {\n';
+  body += `${indent.get()}/// Creates a response with the specified status code and no body.\n`;
+  body += `${indent.get()}pub fn new(status: StatusCode) -> Self {\n`;
+  body += `${indent.push().get()}Self {\n`;
+  body += `${indent.push().get()}status,\n`;
+  body += `${indent.get()}headers: HeaderMap::new(),\n`;
+  body += `${indent.get()}body: None,\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Creates a response with the specified status code and body.\n`;
+  body += `${indent.get()}pub fn with_body(status: StatusCode, body: T) -> Self {\n`;
+  body += `${indent.push().get()}Self {\n`;
+  body += `${indent.push().get()}status,\n`;
+  body += `${indent.get()}headers: HeaderMap::new(),\n`;
+  body += `${indent.get()}body: Some(body),\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Adds the specified header to the response.\n`;
+  body += `${indent.get()}///\n`;
+  body += `${indent.get()}/// Panics if \`name\` isn't lowercase or \`value\` isn't a valid header value.\n`;
+  body += `${indent.get()}pub fn with_header(mut self, name: &'static str, value: impl Into<String>) -> Self {\n`;
+  body += `${indent.push().get()}let value = HeaderValue::try_from(value.into()).expect("invalid header value");\n`;
+  body += `${indent.get()}self.headers.insert(name, value);\n`;
+  body += `${indent.get()}self\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += '}\n\n';
+
+  for (const client of clients) {
+    body += getHandlerTrait(indent, client, operations.filter((operation) => operation.client === client));
+  }
+
+  // only the first operation for a route and verb is routed
+  const routes = new Map<string, Map<rust.HTTPMethod, stubOperation>>();
+  const unrouted = new Array<stubOperation>();
+  for (const operation of operations) {
+    let verbs = routes.get(operation.path);
+    if (!verbs) {
+      verbs = new Map<rust.HTTPMethod, stubOperation>();
+      routes.set(operation.path, verbs);
+    }
+    if (verbs.has(operation.method.httpMethod)) {
+      unrouted.push(operation);
+      continue;
+    }
+    verbs.set(operation.method.httpMethod, operation);
+  }
+
+  body += '/// Returns a [`Router`] that routes requests to the operations of `handler`.\n';
+  if (unrouted.length > 0) {
+    body += '///\n';
+    body += '/// The following operations share a route with another operation and aren\'t routed.\n';
+    for (const operation of unrouted) {
+      body += `/// * \`${operation.client.name}Handler::${operation.method.name}\`\n`;
+    }
+  }
+  body += 'pub fn router<H>(handler: H) -> Router\n';
+  body += 'where\n';
+  body += `${indent.get()}H: ${handlerBounds.join(' + ')},\n`;
+  body += '{\n';
+  body += `${indent.get()}Router::new()\n`;
+  indent.push();
+  for (const [path, verbs] of routes) {
+    const methodRouters = new Array<string>();
+    for (const [verb, operation] of verbs) {
+      if (methodRouters.length === 0) {
+        use.add('axum::routing', verb);
+      }
+      methodRouters.push(`${verb}(${operation.fnName}::<H>)`);
+    }
+    body += `${indent.get()}.route("${path}", ${methodRouters.join('.')})\n`;
+  }
+  body += `${indent.get()}.with_state(Arc::new(handler))\n`;
+  indent.pop();
+  body += '}\n\n';
+
+  body += '/// An in-process HTTP server that routes requests to a handler.\n';
+  body += '///\n';
+  body += '/// The server stops when it\'s dropped.\n';
+  body += 'pub struct StubServer {\n';
+  body += `${indent.get()}endpoint: String,\n`;
+  body += `${indent.get()}task: JoinHandle<()>,\n`;
+  body += '}\n\n';
+
+  body += 'impl StubServer {\n';
+  body += `${indent.get()}/// Starts a server for \`handler\` on an unused local port.\n`;
+  body += `${indent.get()}pub async fn start<H>(handler: H) -> io::Result<Self>\n`;
+  body += `${indent.get()}where\n`;
+  body += `${indent.push().get()}H: ${handlerBounds.join(' + ')},\n`;
+  body += `${indent.pop().get()}{\n`;
+  body += `${indent.push().get()}let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;\n`;
+  body += `${indent.get()}let endpoint = format!("http://{}", listener.local_addr()?);\n`;
+  body += `${indent.get()}let router = router(handler);\n`;
+  body += `${indent.get()}let task = tokio::spawn(async move {\n`;
+  body += `${indent.push().get()}let _ = axum::serve(listener, router).await;\n`;
+  body += `${indent.pop().get()}});\n`;
+  body += `${indent.get()}Ok(Self { endpoint, task })\n`;
+  body += `${indent.pop().get()}}\n\n`;
+  body += `${indent.get()}/// Returns the endpoint to pass to a client constructor.\n`;
+  body += `${indent.get()}pub fn endpoint(&self) -> &str {\n`;
+  body += `${indent.push().get()}&self.endpoint\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += '}\n\n';
+
+  body += 'impl Drop for StubServer {\n';
+  body += `${indent.get()}fn drop(&mut self) {\n`;
+  body += `${indent.push().get()}self.task.abort();\n`;
+  body += `${indent.pop().get()}}\n`;
+  body += '}\n\n';
+
+  for (const [, verbs] of routes) {
+    for (const [, operation] of verbs) {
+      body += getRouteFn(indent, use, operation);
+    }
+  }
+
+  body += getHelperFns(indent, use, operations.filter((operation) => !unrouted.includes(operation)));
+  use.add('std::sync', 'Arc');
+
+  let libRs = helpers.contentPreamble();
+  libRs += use.text();
+  libRs += 'pub use async_trait::async_trait;\n';
+  libRs += 'pub use axum::http::{HeaderMap, StatusCode};\n\n';
+  libRs += body.trimEnd() + '\n';
+
+  return {
+    cargoToml: getCargoToml(crate, crate.stubServer, operations),
+    libRs: libRs,
+  };
+}
+
+/** the payload of a request or response */
+interface stubPayload {
+  /** the Rust type of the payload */
+  type: string;
+
+  /** how the payload is (de)serialized */
+  kind: 'empty' | 'json' | 'raw';
+}
+
+/** an operation served by the stub server */
+interface stubOperation {
+  /** the client that contains the operation */
+  client: rust.Client;
+
+  /** the client method for the operation */
+  method: rust.AsyncMethod | rust.PageableMethod | rust.LroMethod;
+
+  /** the name of the route fn */
+  fnName: string;
+
+  /** the route for the operation */
+  path: string;
+
+  /** indicates if the route contains path parameters */
+  hasPathParams: boolean;
+
+  /** the name and payload of the request body, if any */
+  body?: { name: string, payload: stubPayload };
+
+  /** the payload of the response */
+  response: stubPayload;
+}
+
+/**
+ * returns the stub server's view of the specified method
+ *
+ * @param crate the crate that contains the client
+ * @param crateIdent the crate name as it appears in a Rust path
+ * @param use the use statement builder currently in scope
+ * @param client the client that contains the method
+ * @param method the method for which to return the operation
+ * @returns the operation for the method
+ */
+function getStubOperation(crate: rust.Crate, crateIdent: string, use: Use, client: rust.Client, method: rust.AsyncMethod | rust.PageableMethod | rust.LroMethod): stubOperation {
+  /** returns the payload for a type in the specified format */
+  const getPayload = function (type: rust.Type, format: rust.PayloadFormatType): stubPayload {
+    if (type.kind === 'unit' || type.kind === 'marker') {
+      return { type: '()', kind: 'empty' };
+    }
+    // only public models are reachable from the stub server.
+    // everything else is passed through as raw bytes.
+    if (format === 'JsonFormat' && type.kind === 'model' && type.visibility === 'pub' && (type.flags & rust.ModelFlags.PolymorphicBase) === 0) {
+      use.add(`${utils.buildImportPath(crate, type.module, crateIdent)}::models`, type.name);
+      return { type: type.name, kind: 'json' };
+    }
+    use.add('axum::body', 'Bytes');
+    return { type: 'Bytes', kind: 'raw' };
+  };
+
+  let body: { name: string, payload: stubPayload } | undefined;
+  for (const param of method.params) {
+    switch (param.kind) {
+      case 'body':
+        body = { name: param.name, payload: getPayload(param.type.content, param.type.format) };
+        break;
+      case 'partialBody':
+        // all of the spread params are fields within the same model
+        body = { name: 'body', payload: getPayload(param.type.content, param.type.format) };
+        break;
+      case 'encodedBytesBody':
+      case 'formBody':
+      case 'streamBody':
+        body = { name: param.name, payload: getPayload(param.type, 'BinaryFormat') };
+        break;
+    }
+  }
+
+  let response: stubPayload;
+  const result = method.returns.type;
+  switch (result.kind) {
+    case 'pageIterator':
+    case 'pager':
+    case 'poller':
+      response = getPayload(result.type.content, result.type.format);
+      break;
+    case 'response':
+      response = getPayload(result.content, result.format);
+      break;
+    default:
+      response = getPayload(result, 'BinaryFormat');
+  }
+
+  // the query string in a path (e.g. ?comp=blob) isn't part of the route
+  const path = method.httpPath.split('?')[0];
+  return {
+    client: client,
+    method: method,
+    fnName: `${utils.snakeCaseName(client.name)}_${method.name}`,
+    path: path.length > 0 ? path : '/',
+    hasPathParams: path.includes('{'),
+    body: body,
+    response: response,
+  };
+}
+
+/**
+ * returns the handler trait for the operations of the specified client
+ *
+ * @param indent the indentation helper currently in scope
+ * @param client the client for which to emit the handler trait
+ * @param operations the operations of the client
+ * @returns the handler trait
+ */
+function getHandlerTrait(indent: helpers.indentation, client: rust.Client, operations: Array<stubOperation>): string {
+  let body = `/// Handles the operations of \`${client.name}\`.\n`;
+  body += '///\n';
+  body += '/// Operations that aren\'t implemented respond with `501 Not Implemented`.\n';
+  body += '#[async_trait]\n';
+  body += `pub trait ${client.name}Handler: Send + Sync {\n`;
+  for (let i = 0; i < operations.length; ++i) {
+    const operation = operations[i];
+    const bodyParam = operation.body ? `, _${operation.body.name}: ${operation.body.payload.type}` : '';
+    body += `${indent.get()}/// Handles \`${operation.method.httpMethod.toUpperCase()} ${operation.method.httpPath}\`.\n`;
+    body += `${indent.get()}async fn ${operation.method.name}(&self, _request: StubRequest${bodyParam}) -> StubResponse<${operation.response.type}> {\n`;
+    body += `${indent.push().get()}StubResponse::new(StatusCode::NOT_IMPLEMENTED)\n`;
+    body += `${indent.pop().get()}}\n`;
+    if (i + 1 < operations.length) {
+      body += '\n';
+    }
+  }
+  body += '}\n\n';
+  return body;
+}
+
+/**
+ * returns the axum handler fn for the specified operation
+ *
+ * @param indent the indentation helper currently in scope
+ * @param use the use statement builder currently in scope
+ * @param operation the operation for which to emit the fn
+ * @returns the route fn
+ */
+function getRouteFn(indent: helpers.indentation, use: Use, operation: stubOperation): string {
+  use.add('axum::extract', 'Query', 'State');
+  use.add('axum::response', 'Response');
+
+  let body = `async fn ${operation.fnName}<H: ${operation.client.name}Handler>(\n`;
+  body += `${indent.get()}State(handler): State<Arc<H>>,\n`;
+  if (operation.hasPathParams) {
+    use.add('axum::extract', 'Path');
+    body += `${indent.get()}Path(path): Path<HashMap<String, String>>,\n`;
+  }
+  body += `${indent.get()}Query(query): Query<Vec<(String, String)>>,\n`;
+  body += `${indent.get()}headers: HeaderMap,\n`;
+  if (operation.body) {
+    use.add('axum::body', 'Bytes');
+    body += `${indent.get()}body: Bytes,\n`;
+  }
+  body += ') -> Response {\n';
+  body += `${indent.get()}let request = StubRequest {\n`;
+  body += `${indent.push().get()}${operation.hasPathParams ? 'path' : 'path: HashMap::new()'},\n`;
+  body += `${indent.get()}query,\n`;
+  body += `${indent.get()}headers,\n`;
+  body += `${indent.pop().get()}};\n`;
+
+  const args = ['request'];
+  if (operation.body) {
+    if (operation.body.payload.kind === 'json') {
+      body += `${indent.get()}let body = match json_request(&body) {\n`;
+      body += `${indent.push().get()}Ok(body) => body,\n`;
+      body += `${indent.get()}Err(response) => return response,\n`;
+      body += `${indent.pop().get()}};\n`;
+    }
+    args.push('body');
+  }
+  body += `${indent.get()}${operation.response.kind}_response(handler.${operation.method.name}(${args.join(', ')}).await)\n`;
+  body += '}\n\n';
+  return body;
+}
+
+/**
+ * returns the helper fns used by the route fns of the specified operations
+ *
+ * @param indent the indentation helper currently in scope
+ * @param use the use statement builder currently in scope
+ * @param operations the routed operations
+ * @returns the helper fns
+ */
+function getHelperFns(indent: helpers.indentation, use: Use, operations: Array<stubOperation>): string {
+  let body = '';
+  if (operations.some((operation) => operation.response.kind === 'empty')) {
+    use.add('axum::response', 'IntoResponse');
+    body += '/// Converts a response without a body.\n';
+    body += 'fn empty_response(response: StubResponse<()>) -> Response {\n';
+    body += `${indent.get()}(response.status, response.headers).into_response()\n`;
+    body += '}\n\n';
+  }
+
+  if (operations.some((operation) => operation.body?.payload.kind === 'json')) {
+    use.add('axum::response', 'IntoResponse');
+    use.add('serde::de', 'DeserializeOwned');
+    body += '/// Deserializes a JSON request body, returning a `400 Bad Request` response on failure.\n';
+    body += 'fn json_request<T: DeserializeOwned>(body: &Bytes) -> Result<T, Response> {\n';
+    body += `${indent.get()}serde_json::from_slice(body).map_err(|err| (StatusCode::BAD_REQUEST, err.to_string()).into_response())\n`;
+    body += '}\n\n';
+  }
+
+  if (operations.some((operation) => operation.response.kind === 'json')) {
+    use.add('axum::http::header', 'CONTENT_TYPE');
+    use.add('axum::response', 'IntoResponse');
+    use.add('serde', 'Serialize');
+    body += '/// Converts a response with a JSON body.\n';
+    body += 'fn json_response<T: Serialize>(response: StubResponse<T>) -> Response {\n';
+    body += `${indent.get()}let mut headers = response.headers;\n`;
+    body += `${indent.get()}let body = match response.body.as_ref().map(serde_json::to_vec).transpose() {\n`;
+    body += `${indent.push().get()}Ok(body) => body,\n`;
+    body += `${indent.get()}Err(err) => return (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),\n`;
+    body += `${indent.pop().get()}};\n`;
+    body += `${indent.get()}if body.is_some() && !headers.contains_key(CONTENT_TYPE) {\n`;
+    body += `${indent.push().get()}headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));\n`;
+    body += `${indent.pop().get()}}\n`;
+    body += `${indent.get()}(response.status, headers, body.unwrap_or_default()).into_response()\n`;
+    body += '}\n\n';
+  }
+
+  if (operations.some((operation) => operation.response.kind === 'raw')) {
+    use.add('axum::response', 'IntoResponse');
+    body += '/// Converts a response with a raw body.\n';
+    body += 'fn raw_response(response: StubResponse<Bytes>) -> Response {\n';
+    body += `${indent.get()}(response.status, response.headers, response.body.unwrap_or_default()).into_response()\n`;
+    body += '}\n\n';
+  }
+
+  return body;
+}
+
+/**
+ * returns the Cargo.toml file for the stub server crate
+ *
+ * @param crate the crate for which to emit the stub server
+ * @param stubServer the stub server crate
+ * @param operations the operations served by the stub server
+ * @returns the contents of the Cargo.toml file
+ */
+function getCargoToml(crate: rust.Crate, stubServer: rust.StubServer, operations: Array<stubOperation>): string {
+  const usesJson = operations.some((operation) => operation.body?.payload.kind === 'json' || operation.response.kind === 'json');
+
+  // dependency versions are managed by the workspace's Cargo.toml file
+  const dependencies = new Array<[string, string]>(
+    ['async-trait', '{ workspace = true }'],
+    ['axum', '{ workspace = true }'],
+    [crate.name, '{ path = ".." }'],
+    ['tokio', '{ workspace = true, features = ["net", "rt"] }'],
+  );
+  if (usesJson) {
+    dependencies.push(['serde', '{ workspace = true }'], ['serde_json', '{ workspace = true }']);
+  }
+  dependencies.sort((a, b) => utils.sortAscending(a[0], b[0]));
+
+  let content = `[package]\nname = "${stubServer.name}"\nversion = "${crate.version}"\n`;
+  content += 'authors.workspace = true\n';
+  content += 'edition.workspace = true\n';
+  content += 'license.workspace = true\n';
+  content += 'repository.workspace = true\n';
+  content += 'rust-version.workspace = true\n';
+  content += 'publish = false\n\n';
+  content += '[dependencies]\n';
+  for (const [name, value] of dependencies) {
+    content += `${name} = ${value}\n`;
+  }
+  return content;
+}
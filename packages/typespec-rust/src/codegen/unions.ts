@@ -85,9 +85,10 @@ export function emitUnions(module: rust.ModuleContainer, context: Context): Unio
         body += `${indent.get()}${getPolymorphicUnknownVariant(indent, use, extensiblePolymorphicDU, rustUnion.discriminant, false)},\n`;
       }
     } else {
-      use.add('serde', 'Deserialize', 'Serialize');
+      // Deserialize is implemented in unions_serde.rs so failures can report the attempted variants
+      use.add('serde', 'Serialize');
       use.add('azure_core::fmt', 'SafeDebug');
-      body += `#[derive(Clone, Deserialize, SafeDebug, Serialize)]\n`;
+      body += `#[derive(Clone, SafeDebug, Serialize)]\n`;
       body += `#[serde(untagged)]\n`;
       body += `${helpers.emitVisibility(rustUnion.visibility)}enum ${rustUnion.name} {\n`;
 
@@ -174,10 +175,15 @@ function getPolymorphicUnknownVariant(indent: helpers.indentation, use: Use, bas
  */
 function emitUnionImpls(module: rust.ModuleContainer, context: Context): helpers.Module | undefined {
   const use = new Use(module, 'modelsOther');
+  const indent = new helpers.indentation();
   const entries = new Array<string>();
 
   for (const rustUnion of module.unions) {
-    if (rustUnion.kind !== 'discriminatedUnion') continue;
+    if (rustUnion.kind === 'untaggedUnion') {
+      use.addForType(rustUnion);
+      entries.push(getUntaggedUnionImpls(indent, use, rustUnion));
+      continue;
+    }
     const forReq = context.getTryFromForRequestContent(rustUnion, use);
 
     // helpers aren't required for all types, so only
@@ -204,6 +210,69 @@ function emitUnionImpls(module: rust.ModuleContainer, context: Context): helpers
   };
 }
 
+/**
+ * returns the variant accessors and From impls for an untagged union.
+ *
+ * @param indent the indentation helper currently in scope
+ * @param use the use statement builder currently in scope
+ * @param rustUnion the untagged union for which to emit the impls
+ * @returns the impls for the untagged union
+ */
+function getUntaggedUnionImpls(indent: helpers.indentation, use: Use, rustUnion: rust.UntaggedUnion): string {
+  let body = `impl ${rustUnion.name} {\n`;
+  for (let i = 0; i < rustUnion.variants.length; ++i) {
+    const variant = rustUnion.variants[i];
+    use.addForType(variant.type);
+    const variantType = helpers.getTypeDeclaration(variant.type);
+    const variantLink = `[\`${rustUnion.name}::${variant.name}\`]`;
+    const accessorName = utils.snakeCaseName(variant.name);
+
+    body += `${indent.get()}/// Returns \`true\` if this is the ${variantLink} variant.\n`;
+    body += `${indent.get()}pub fn is_${accessorName}(&self) -> bool {\n`;
+    body += `${indent.push().get()}matches!(self, Self::${variant.name}(_))\n`;
+    body += `${indent.pop().get()}}\n\n`;
+
+    body += `${indent.get()}/// Returns a reference to the value if this is the ${variantLink} variant.\n`;
+    body += `${indent.get()}pub fn as_${accessorName}(&self) -> Option<&${variantType}> {\n`;
+    body += `${indent.push().get()}match self {\n`;
+    body += `${indent.push().get()}Self::${variant.name}(value) => Some(value),\n`;
+    if (rustUnion.variants.length > 1) {
+      body += `${indent.get()}_ => None,\n`;
+    }
+    body += `${indent.pop().get()}}\n`;
+    body += `${indent.pop().get()}}\n\n`;
+
+    body += `${indent.get()}/// Returns the value if this is the ${variantLink} variant.\n`;
+    body += `${indent.get()}pub fn into_${accessorName}(self) -> Option<${variantType}> {\n`;
+    body += `${indent.push().get()}match self {\n`;
+    body += `${indent.push().get()}Self::${variant.name}(value) => Some(value),\n`;
+    if (rustUnion.variants.length > 1) {
+      body += `${indent.get()}_ => None,\n`;
+    }
+    body += `${indent.pop().get()}}\n`;
+    body += `${indent.pop().get()}}\n`;
+    if (i + 1 < rustUnion.variants.length) {
+      body += '\n';
+    }
+  }
+  body += '}\n\n';
+
+  for (const variant of rustUnion.variants) {
+    const variantType = helpers.getTypeDeclaration(variant.type);
+    // From would be ambiguous for variants that wrap the same type
+    if (rustUnion.variants.filter((each) => helpers.getTypeDeclaration(each.type) === variantType).length > 1) {
+      continue;
+    }
+    body += `impl From<${variantType}> for ${rustUnion.name} {\n`;
+    body += `${indent.get()}fn from(value: ${variantType}) -> Self {\n`;
+    body += `${indent.push().get()}Self::${variant.name}(value)\n`;
+    body += `${indent.pop().get()}}\n`;
+    body += '}\n\n';
+  }
+
+  return body;
+}
+
 /**
  * returns the content for unions_serde.rs.
  * if no helpers are required, undefined is returned.
@@ -221,7 +290,10 @@ function emitUnionSerde(module: rust.ModuleContainer): helpers.Module | undefine
 
   let body = '';
   for (const rustUnion of module.unions) {
-    if (rustUnion.kind !== 'discriminatedUnion') continue;
+    if (rustUnion.kind === 'untaggedUnion') {
+      body += getUntaggedUnionDeserialize(indent, use, rustUnion);
+      continue;
+    }
     if (!isPolymorphicDU(rustUnion.unionKind) || rustUnion.unionKind.kind !== 'discriminatedUnionBase') {
       continue;
     }
@@ -270,6 +342,41 @@ function emitUnionSerde(module: rust.ModuleContainer): helpers.Module | undefine
   };
 }
 
+/**
+ * returns the Deserialize impl for an untagged union.
+ * the variants are attempted in order (i.e. from the most to the least specific
+ * type) and the error reports each attempted variant and why it didn't match.
+ *
+ * @param indent the indentation helper currently in scope
+ * @param use the use statement builder currently in scope
+ * @param rustUnion the untagged union for which to emit the impl
+ * @returns the Deserialize impl for the untagged union
+ */
+function getUntaggedUnionDeserialize(indent: helpers.indentation, use: Use, rustUnion: rust.UntaggedUnion): string {
+  use.addForType(rustUnion);
+  use.add('azure_core', 'Value');
+  use.add('serde', 'Deserialize', 'Deserializer');
+
+  let body = `impl<'de> Deserialize<'de> for ${rustUnion.name} {\n`;
+  body += `${indent.get()}fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>\n`;
+  body += `${indent.get()}where\n`;
+  body += `${indent.push().get()}D: Deserializer<'de>,\n`;
+  body += `${indent.pop().get()}{\n`;
+  // buffer the content so each variant can be attempted
+  body += `${indent.push().get()}let value = Value::deserialize(deserializer)?;\n`;
+  body += `${indent.get()}let mut errors = Vec::new();\n`;
+  for (const variant of rustUnion.variants) {
+    body += `${indent.get()}match Deserialize::deserialize(&value).map(Self::${variant.name}) {\n`;
+    body += `${indent.push().get()}Ok(variant) => return Ok(variant),\n`;
+    body += `${indent.get()}Err(err) => errors.push(format!("${variant.name}: {err}")),\n`;
+    body += `${indent.pop().get()}}\n`;
+  }
+  body += `${indent.get()}Err(serde::de::Error::custom(format!("data did not match any variant of untagged enum ${rustUnion.name} ({})", errors.join("; "))))\n`;
+  body += `${indent.pop().get()}}\n`; // end fn
+  body += '}\n\n'; // end impl
+  return body;
+}
+
 /** narrows duKind to the applicable DU type within the conditional block */
 function isPolymorphicDU(duKind?: rust.DiscriminatedUnionKind): duKind is rust.DiscriminatedUnionBase | rust.DiscriminatedUnionSealed {
   if (!duKind) {
@@ -101,17 +101,19 @@ export class Use {
         this.addForType(type.type);
         break;
       case 'marker':
+      case 'negotiatedResponse':
+      case 'resumableDownload':
         switch (this.scope) {
           case 'clients':
-            // marker types are always in the same module as their client method
+            // marker types, negotiated responses, and resumable downloads are always in the same module as their client method
             this.add(`${utils.buildImportPath(this.module, this.module)}::models`, type.name);
             break;
           case 'modelsOther':
             this.add('super', type.name);
             break;
           default:
-            // marker types are only referenced from clients and model
-            // helpers so we should never get here (if we do it's a bug)
+            // marker types, negotiated responses, and resumable downloads are only referenced
+            // from clients and model helpers so we should never get here (if we do it's a bug)
             throw new CodegenError('InternalError', `unexpected scope ${this.scope}`);
         }
         break;
@@ -137,17 +139,8 @@ export class Use {
         }
         break;
       case 'requestContent':
-        if (type.format !== 'JsonFormat') {
-          // JsonFormat is the default so no need to bring it into scope
-          this.add('azure_core::http', type.format);
-        }
-        this.addForType(type.content);
-        break;
       case 'response':
-        if (type.format !== 'JsonFormat') {
-          // JsonFormat is the default so no need to bring it into scope
-          this.add('azure_core::http', type.format);
-        }
+        this.addForFormat(type.format);
         this.addForType(type.content);
         break;
       case 'responseHeadersTrait':
@@ -171,6 +164,35 @@ export class Use {
     }
   }
 
+  /**
+   * adds the specified payload format type if not already in the list
+   *
+   * @param format the payload format to bring into scope
+   */
+  addForFormat(format: rust.PayloadFormatType): void {
+    switch (format) {
+      case 'JsonFormat':
+        // JsonFormat is the default so no need to bring it into scope
+        return;
+      case 'Base64Format':
+      case 'Base64UrlFormat':
+      case 'FormFormat':
+      case 'TextFormat':
+        // these formats are emitted into the models of the module that uses them
+        switch (this.scope) {
+          case 'clients':
+            this.add(`${utils.buildImportPath(this.module, this.module)}::models`, format);
+            break;
+          default:
+            this.add('super', format);
+            break;
+        }
+        return;
+      default:
+        this.add('azure_core::http', format);
+    }
+  }
+
   /**
    * emits Rust use statements for the contents of this Use object
    *
@@ -37,6 +37,13 @@ export interface Client {
    */
   omitEndpointMethod: boolean;
 
+  /**
+   * indicates if the synthesized send_request() method should be emitted.
+   * it sends a caller-provided request through the client's pipeline.
+   * set unless disabled via the emit-send-request switch.
+   */
+  emitSendRequest: boolean;
+
   /**
    * fields contains the ctor parameters that are
    * persisted as fields on the client type and might
@@ -51,6 +58,13 @@ export interface Client {
   /** all the methods for this client */
   methods: Array<MethodType>;
 
+  /**
+   * the trait with the client's methods so that consumers
+   * can substitute a fake. only set when enabled via the
+   * emit-client-traits switch.
+   */
+  methodsTrait?: ClientMethodsTrait;
+
   /** the module to which this client belongs */
   module: ModuleContainer;
 
@@ -81,6 +95,15 @@ export interface ClientConstruction {
   suppressed: 'no' | 'ctor' | 'yes';
 }
 
+/** ClientMethodsTrait is a trait implemented by the client and its mock */
+export interface ClientMethodsTrait {
+  /** the name of the trait (e.g. SecretClientMethods) */
+  name: string;
+
+  /** the name of the in-memory implementation that's behind the mock feature (e.g. MockSecretClient) */
+  mockName: string;
+}
+
 /** ClientOptions is the struct containing optional client params */
 export interface ClientOptions extends types.Option {
   /** the client options type */
@@ -100,6 +123,13 @@ export interface Constructor {
   /** the modeled parameters. at minimum, an endpoint param */
   params: Array<ClientParameter>;
 
+  /**
+   * indicates that the endpoint param is the complete endpoint.
+   * when true, any supplemental endpoint construction is skipped.
+   * the default value is false.
+   */
+  rawEndpoint: boolean;
+
   /** any docs for the constructor */
   docs: types.Docs;
 }
@@ -145,6 +175,94 @@ export interface SupplementalEndpoint {
   parameters: Array<ClientSupplementalEndpointParameter>;
 }
 
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// ARM resources
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+/**
+ * ArmResource is an Azure Resource Manager resource type.
+ * its handle type wraps the resource ID methods of its operations.
+ */
+export interface ArmResource {
+  kind: 'armResource';
+
+  /** the name of the handle type */
+  name: string;
+
+  /** any docs for the handle type */
+  docs: types.Docs;
+
+  /** the model returned when reading the resource */
+  model: types.Model;
+
+  /** the path template of a resource instance */
+  path: string;
+
+  /** the client from which the operation group clients are obtained */
+  client: Client;
+
+  /**
+   * the literal type/name pairs between the parent's ID and the resource's type,
+   * e.g. ['providers', 'Microsoft.Compute'] for a top-level resource. can be empty
+   */
+  scope: Array<string>;
+
+  /** the last segment of the resource's type, e.g. virtualMachines */
+  type: string;
+
+  /** the fixed name of a singleton resource. undefined when the resource has a collection */
+  singletonName?: string;
+
+  /** the operations on a resource instance */
+  operations: Array<ArmResourceOperation>;
+
+  /** the collection containing the resource. undefined for singleton resources */
+  collection?: ArmResourceCollection;
+
+  /** the resources nested under this resource. can be empty */
+  children: Array<ArmResourceChild>;
+}
+
+/** ArmResourceChild is the accessor on a handle for a nested resource */
+export interface ArmResourceChild {
+  /** the name of the accessor method */
+  name: string;
+
+  /** the nested resource */
+  resource: ArmResource;
+}
+
+/** ArmResourceCollection is the collection of ARM resources contained in a parent */
+export interface ArmResourceCollection {
+  kind: 'armResourceCollection';
+
+  /** the name of the collection type */
+  name: string;
+
+  /** any docs for the collection type */
+  docs: types.Docs;
+
+  /** the operation that lists the resources in the collection */
+  list?: ArmResourceOperation;
+}
+
+/** ArmResourceOperationKind contains the kinds of ARM resource operations */
+export type ArmResourceOperationKind = 'createOrUpdate' | 'delete' | 'list' | 'read' | 'update';
+
+/** ArmResourceOperation is an operation on an ARM resource or its collection */
+export interface ArmResourceOperation {
+  kind: ArmResourceOperationKind;
+
+  /**
+   * the accessor that returns the operation group client.
+   * undefined when the method belongs to the resource's client
+   */
+  accessor?: ClientAccessor;
+
+  /** the resource ID method that's called */
+  method: ResourceIdMethod;
+}
+
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 // methods
 ///////////////////////////////////////////////////////////////////////////////////////////////////
@@ -153,7 +271,7 @@ export interface SupplementalEndpoint {
 export type HTTPMethod = 'delete' | 'get' | 'head' | 'patch' | 'post' | 'put';
 
 /** Method defines the possible method types */
-export type MethodType = AsyncMethod | ClientAccessor | PageableMethod | LroMethod;
+export type MethodType = AsyncMethod | ClientAccessor | PageableMethod | LroMethod | RequestMethod | ResourceIdMethod;
 
 /** AsyncMethod is an async Rust method */
 export interface AsyncMethod extends HTTPMethodBase {
@@ -163,7 +281,7 @@ export interface AsyncMethod extends HTTPMethodBase {
   params: Array<MethodParameter>;
 
   /** the type returned by the method */
-  returns: types.Result<types.AsyncResponse | types.Response>;
+  returns: types.Result<types.AsyncResponse | types.NegotiatedResponse | types.Response | types.ResumableDownload>;
 }
 
 /** ClientAccessor is a method that returns a sub-client instance. */
@@ -230,6 +348,37 @@ export interface LroMethod extends HTTPMethodBase {
   finalResultStrategy: LroFinalResultStrategyKind;
 }
 
+/** RequestMethod is a method that builds the request for an AsyncMethod without sending it. */
+export interface RequestMethod extends HTTPMethodBase {
+  kind: 'request';
+
+  /** the params passed to the method (excluding self). can be empty */
+  params: Array<MethodParameter>;
+
+  /** the prepared request */
+  returns: types.Result<types.ExternalType>;
+
+  /** the method whose request is built */
+  method: AsyncMethod;
+}
+
+/** ResourceIdMethod is a method that calls an ARM operation with its path params taken from a resource ID. */
+export interface ResourceIdMethod extends HTTPMethodBase {
+  kind: 'resourceId';
+
+  /** the params passed to the method (excluding self and the resource ID). can be empty */
+  params: Array<MethodParameter>;
+
+  /** the resource ID from which the path params are taken */
+  resourceId: method.Parameter;
+
+  /** the type returned by the method. this is the same as the called method's */
+  returns: types.Result<types.AsyncResponse | types.NegotiatedResponse | types.Response | types.ResumableDownload | types.PageIterator | types.Pager | types.Poller>;
+
+  /** the method that's called */
+  method: AsyncMethod | LroMethod | PageableMethod;
+}
+
 /** PageableStrategyContinuationToken indicates a pageable method uses the continuation token strategy */
 export interface PageableStrategyContinuationToken {
   kind: 'continuationToken';
@@ -262,6 +411,36 @@ export interface PageableStrategyNextLink {
 /** PageableStrategyKind contains different strategies for fetching subsequent pages */
 export type PageableStrategyKind = PageableStrategyContinuationToken | PageableStrategyNextLink;
 
+/** ExampleValue is a value from an operation example in its JSON form */
+export type ExampleValue = null | boolean | number | string | Array<ExampleValue> | { [key: string]: ExampleValue };
+
+/** MethodExample is an example call to a method and the service's response */
+export interface MethodExample {
+  /** the name of the example */
+  name: string;
+
+  /** the values of the method's params. params without a value are omitted */
+  params: Map<MethodParameter, ExampleValue>;
+
+  /** the example request body, if any */
+  requestBody?: ExampleValue;
+
+  /** the example response, if any */
+  response?: ExampleResponse;
+}
+
+/** ExampleResponse is the response in a method example */
+export interface ExampleResponse {
+  /** the HTTP status code */
+  statusCode: number;
+
+  /** the response header values keyed by header name. can be empty */
+  headers: Map<string, string>;
+
+  /** the response body, if any */
+  body?: ExampleValue;
+}
+
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 // parameters
 ///////////////////////////////////////////////////////////////////////////////////////////////////
@@ -304,7 +483,7 @@ export type ExtendedCollectionFormat = CollectionFormat | 'multi';
 export type ParameterLocation = 'client' | 'method';
 
 /** MethodParameter defines the possible method parameter types */
-export type MethodParameter = BodyParameter | HeaderCollectionParameter | HeaderHashMapParameter | HeaderScalarParameter | PartialBodyParameter | PathCollectionParameter | PathHashMapParameter | PathScalarParameter | QueryCollectionParameter | QueryHashMapParameter | QueryScalarParameter;
+export type MethodParameter = BodyParameter | CookieScalarParameter | EncodedBytesBodyParameter | FormBodyParameter | HeaderCollectionParameter | HeaderHashMapParameter | HeaderScalarParameter | PartialBodyParameter | PathCollectionParameter | PathHashMapParameter | PathScalarParameter | QueryCollectionParameter | QueryHashMapParameter | QueryScalarParameter | StreamBodyParameter;
 
 /** BodyParameter is a param that's passed via the HTTP request body */
 export interface BodyParameter extends HTTPParameterBase {
@@ -314,6 +493,65 @@ export interface BodyParameter extends HTTPParameterBase {
   type: types.RequestContent;
 }
 
+/**
+ * EncodedBytesBodyParameter is a bytes param that's sent as the
+ * HTTP request body in a JSON string containing the base64 encoded bytes.
+ */
+export interface EncodedBytesBodyParameter extends HTTPParameterBase {
+  kind: 'encodedBytesBody';
+
+  /** the type of the body param */
+  type: types.EncodedBytes | types.Ref<types.EncodedBytes>;
+}
+
+/**
+ * FormBodyParameter is a flat model param that's sent as an
+ * application/x-www-form-urlencoded HTTP request body.
+ */
+export interface FormBodyParameter extends HTTPParameterBase {
+  kind: 'formBody';
+
+  /** the model type of the body param */
+  type: types.Model;
+
+  /** the request content into which the model is encoded */
+  content: types.RequestContent<types.Model, 'FormFormat'>;
+
+  /**
+   * the collection format for Vec<T> fields keyed by the field's serde name.
+   * fields without an entry repeat the key for each item (i.e. multi).
+   */
+  collectionFormats: Map<string, ExtendedCollectionFormat>;
+}
+
+/**
+ * StreamBodyParameter is a binary request body that's streamed
+ * from a seekable stream instead of being buffered in memory.
+ */
+export interface StreamBodyParameter extends HTTPParameterBase {
+  kind: 'streamBody';
+
+  /** the type of the body param */
+  type: types.SeekableStream;
+}
+
+/** CookieScalarParameterType defines the possible types for a CookieScalarParameter */
+export type CookieScalarParameterType = Exclude<types.WireType, types.HashMap | types.JsonValue | types.Model | types.Slice | types.StringSlice | types.Vector>;
+
+/**
+ * CookieScalarParameter is a scalar param that's sent as a cookie.
+ * all cookie params for a method are combined into a single Cookie header.
+ */
+export interface CookieScalarParameter extends HTTPParameterBase {
+  kind: 'cookieScalar';
+
+  /** the name of the cookie */
+  cookie: string;
+
+  /** the type of the param */
+  type: CookieScalarParameterType;
+}
+
 /** HeaderCollectionParameterType defines the possible types for a HeaderCollectionParameter */
 export type HeaderCollectionParameterType = types.Ref<types.Slice> | types.Vector;
 
@@ -393,6 +631,12 @@ export interface PartialBodyParameter extends HTTPParameterBase {
   /** the model in which the partial param is placed */
   type: types.RequestContent<types.Model>;
 
+  /**
+   * the collection format for Vec<T> fields of a form-urlencoded model keyed
+   * by the field's serde name. only applicable when the format is FormFormat.
+   */
+  collectionFormats?: Map<string, ExtendedCollectionFormat>;
+
   /** the name of the field over the wire in model.fields for this param */
   serde: string;
 }
@@ -521,7 +765,27 @@ export interface QueryScalarParameter extends HTTPParameterBase {
 }
 
 /** ResponseHeader defines the possible typed headers returned in a HTTP response */
-export type ResponseHeader = ResponseHeaderHashMap | ResponseHeaderScalar;
+export type ResponseHeader = ResponseHeaderCollection | ResponseHeaderHashMap | ResponseHeaderScalar;
+
+/** ResponseHeaderCollection is a typed header returned in a HTTP response that contains a delimited list of values */
+export interface ResponseHeaderCollection {
+  kind: 'responseHeaderCollection';
+
+  /** the name to use for the trait method */
+  name: string;
+
+  /** the header in the HTTP response */
+  header: string;
+
+  /** the collection of header values */
+  type: types.Vector;
+
+  /** the format of the collection */
+  format: CollectionFormat;
+
+  /** any docs for the header */
+  docs: types.Docs;
+}
 
 /**
  * ResponseHeaderHashMap is a collection of typed header responses.
@@ -629,6 +893,17 @@ interface HTTPMethodBase extends method.Method<types.Type> {
 
   /** the HTTP path for the request */
   httpPath: string;
+
+  /** the examples for the method from the tsp. can be empty */
+  examples: Array<MethodExample>;
+
+  /**
+   * indicates if sending the request more than once has the same
+   * effect as sending it once. defaults to true for all verbs except
+   * POST and PATCH and can be refined by the adapter (e.g. repeatability
+   * headers or the idempotent clientOption decorator).
+   */
+  idempotent: boolean;
 }
 
 /** base type for HTTP-based method parameters */
@@ -657,6 +932,8 @@ class HTTPMethodBase extends method.Method<types.Type> implements HTTPMethodBase
     super(name, languageIndependentName, visibility, impl, self);
     this.httpMethod = httpMethod;
     this.httpPath = httpPath;
+    this.examples = new Array<MethodExample>();
+    this.idempotent = httpMethod !== 'patch' && httpMethod !== 'post';
     this.docs = {};
   }
 }
@@ -673,6 +950,44 @@ class HTTPParameterBase extends method.Parameter {
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 
+export class ArmResource implements ArmResource {
+  constructor(name: string, model: types.Model, path: string, client: Client, scope: Array<string>, type: string) {
+    this.kind = 'armResource';
+    this.name = name;
+    this.docs = {};
+    this.model = model;
+    this.path = path;
+    this.client = client;
+    this.scope = scope;
+    this.type = type;
+    this.operations = new Array<ArmResourceOperation>();
+    this.children = new Array<ArmResourceChild>();
+  }
+}
+
+export class ArmResourceChild implements ArmResourceChild {
+  constructor(name: string, resource: ArmResource) {
+    this.name = name;
+    this.resource = resource;
+  }
+}
+
+export class ArmResourceCollection implements ArmResourceCollection {
+  constructor(name: string) {
+    this.kind = 'armResourceCollection';
+    this.name = name;
+    this.docs = {};
+  }
+}
+
+export class ArmResourceOperation implements ArmResourceOperation {
+  constructor(kind: ArmResourceOperationKind, method: ResourceIdMethod, accessor?: ClientAccessor) {
+    this.kind = kind;
+    this.method = method;
+    this.accessor = accessor;
+  }
+}
+
 export class AsyncMethod extends HTTPMethodBase implements AsyncMethod {
   constructor(name: string, languageIndependentName: string, client: Client, visibility: types.Visibility, options: ParameterGroup<types.Option<types.Struct>>, httpMethod: HTTPMethod, httpPath: string) {
     super(name, languageIndependentName, httpMethod, httpPath, visibility, client.name, new method.Self(false, true));
@@ -694,6 +1009,7 @@ export class Client implements Client {
     this.kind = 'client';
     this.name = name;
     this.omitEndpointMethod = false;
+    this.emitSendRequest = false;
     this.fields = new Array<types.StructField>();
     this.methods = new Array<MethodType>();
     this.module = module;
@@ -718,6 +1034,13 @@ export class ClientConstruction implements ClientConstruction {
   }
 }
 
+export class ClientMethodsTrait implements ClientMethodsTrait {
+  constructor(name: string, mockName: string) {
+    this.name = name;
+    this.mockName = mockName;
+  }
+}
+
 export class ClientOptions extends types.Option implements ClientOptions {
   constructor(type: types.Struct) {
     super(type);
@@ -736,6 +1059,7 @@ export class Constructor implements Constructor {
     this.kind = 'constructor';
     this.name = name;
     this.params = new Array<ClientParameter>();
+    this.rawEndpoint = false;
     this.docs = {};
   }
 }
@@ -762,6 +1086,37 @@ export class ClientSupplementalEndpointParameter extends ClientParameterBase imp
   }
 }
 
+export class CookieScalarParameter extends HTTPParameterBase implements CookieScalarParameter {
+  constructor(name: string, cookie: string, location: ParameterLocation, optional: boolean, type: CookieScalarParameterType) {
+    super(name, location, optional, type);
+    this.kind = 'cookieScalar';
+    this.cookie = cookie;
+  }
+}
+
+export class EncodedBytesBodyParameter extends HTTPParameterBase implements EncodedBytesBodyParameter {
+  constructor(name: string, location: ParameterLocation, optional: boolean, type: types.EncodedBytes | types.Ref<types.EncodedBytes>) {
+    super(name, location, optional, type);
+    this.kind = 'encodedBytesBody';
+  }
+}
+
+export class ExampleResponse implements ExampleResponse {
+  constructor(statusCode: number) {
+    this.statusCode = statusCode;
+    this.headers = new Map<string, string>();
+  }
+}
+
+export class FormBodyParameter extends HTTPParameterBase implements FormBodyParameter {
+  constructor(name: string, location: ParameterLocation, optional: boolean, type: types.Model, content: types.RequestContent<types.Model, 'FormFormat'>) {
+    super(name, location, optional, type);
+    this.kind = 'formBody';
+    this.content = content;
+    this.collectionFormats = new Map<string, ExtendedCollectionFormat>();
+  }
+}
+
 export class HeaderCollectionParameter extends HTTPParameterBase implements HeaderCollectionParameter {
   constructor(name: string, header: string, location: ParameterLocation, optional: boolean, type: HeaderCollectionParameterType, format: CollectionFormat) {
     super(name, location, optional, type);
@@ -820,6 +1175,37 @@ export class LroMethod extends HTTPMethodBase implements LroMethod {
   }
 }
 
+export class MethodExample implements MethodExample {
+  constructor(name: string) {
+    this.name = name;
+    this.params = new Map<MethodParameter, ExampleValue>();
+  }
+}
+
+export class RequestMethod extends HTTPMethodBase implements RequestMethod {
+  constructor(name: string, client: Client, asyncMethod: AsyncMethod) {
+    super(name, asyncMethod.languageIndependentName, asyncMethod.httpMethod, asyncMethod.httpPath, asyncMethod.visibility, client.name, new method.Self(false, true));
+    this.kind = 'request';
+    this.params = asyncMethod.params;
+    this.options = asyncMethod.options;
+    this.method = asyncMethod;
+    this.idempotent = asyncMethod.idempotent;
+  }
+}
+
+export class ResourceIdMethod extends HTTPMethodBase implements ResourceIdMethod {
+  constructor(name: string, client: Client, resourceId: method.Parameter, calledMethod: AsyncMethod | LroMethod | PageableMethod) {
+    super(name, calledMethod.languageIndependentName, calledMethod.httpMethod, calledMethod.httpPath, calledMethod.visibility, client.name, new method.Self(false, true));
+    this.kind = 'resourceId';
+    this.params = new Array<MethodParameter>();
+    this.options = calledMethod.options;
+    this.resourceId = resourceId;
+    this.returns = calledMethod.returns;
+    this.method = calledMethod;
+    this.idempotent = calledMethod.idempotent;
+  }
+}
+
 export class PageableStrategyContinuationToken implements PageableStrategyContinuationToken {
   constructor(requestToken: HeaderScalarParameter | QueryScalarParameter, responseToken: ResponseHeaderScalar | PageableStrategyNextLink) {
     this.kind = 'continuationToken';
@@ -915,6 +1301,17 @@ export class QueryScalarParameter extends HTTPParameterBase implements QueryScal
   }
 }
 
+export class ResponseHeaderCollection implements ResponseHeaderCollection {
+  constructor(name: string, header: string, type: types.Vector, format: CollectionFormat) {
+    this.kind = 'responseHeaderCollection';
+    this.name = name;
+    this.header = header;
+    this.type = type;
+    this.format = format;
+    this.docs = {};
+  }
+}
+
 export class ResponseHeaderHashMap implements ResponseHeaderHashMap {
   constructor(name: string, header: string) {
     this.kind = 'responseHeaderHashMap';
@@ -947,6 +1344,13 @@ export class ResponseHeadersTrait implements ResponseHeadersTrait {
   }
 }
 
+export class StreamBodyParameter extends HTTPParameterBase implements StreamBodyParameter {
+  constructor(name: string, location: ParameterLocation, optional: boolean, type: types.SeekableStream) {
+    super(name, location, optional, type);
+    this.kind = 'streamBody';
+  }
+}
+
 export class SupplementalEndpoint implements SupplementalEndpoint {
   constructor(path: string) {
     this.path = path;
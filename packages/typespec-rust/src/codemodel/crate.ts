@@ -22,9 +22,27 @@ export interface Crate extends ModuleBase {
   /** the target service type */
   type: ServiceType;
 
+  /** the Azure Resource Manager endpoints for known clouds. empty for data-plane crates */
+  cloudEndpoints: Array<CloudEndpoint>;
+
   /** the Crates on which this Crate depends */
   dependencies: Array<CrateDependency>;
 
+  /** the Crates on which the Crate's tests depend. can be empty */
+  devDependencies: Array<CrateDependency>;
+
+  /** indicates if tests that replay the method examples are to be emitted */
+  exampleTests: boolean;
+
+  /** additional Cargo features defined by the Crate. they don't enable other features. can be empty */
+  features: Array<string>;
+
+  /** indicates if async methods add their idempotency to the request's context for retry policies */
+  idempotencyContext: boolean;
+
+  /** the companion stub server crate. only set when the stub server is to be emitted */
+  stubServer?: StubServer;
+
   /** any sub-modules. can be empty */
   subModules: Array<SubModule>;
 }
@@ -32,6 +50,15 @@ export interface Crate extends ModuleBase {
 /** ServiceType defines the possible service types */
 export type ServiceType = 'azure-arm' | 'data-plane';
 
+/** CloudEndpoint is the Azure Resource Manager endpoint for a cloud */
+export interface CloudEndpoint {
+  /** the name of the azure_core::cloud::CloudConfiguration variant (e.g. AzurePublic) */
+  cloud: string;
+
+  /** the endpoint of Azure Resource Manager in the cloud. it's also the audience for access tokens */
+  endpoint: string;
+}
+
 /**
  * CrateDependency is an external Crate dependency
  * note that dependency versions are centralized which is
@@ -45,6 +72,16 @@ export interface CrateDependency {
   features: Array<string>;
 }
 
+/**
+ * StubServer is a companion test-support Crate that serves
+ * the operations of the Crate's clients from an in-process
+ * HTTP server. it's emitted into the stub_server directory.
+ */
+export interface StubServer {
+  /** the name of the stub server Crate */
+  name: string;
+}
+
 /** defines the container for emitted content */
 export type ModuleContainer = Crate | SubModule;
 
@@ -75,6 +112,9 @@ export interface SubModule extends ModuleBase {
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 
 interface ModuleBase {
+  /** armResources contains the ARM resource handle types for this module. can be empty */
+  armResources: Array<client.ArmResource>;
+
   /** clients contains all the clients for this module. can be empty */
   clients: Array<client.Client>;
 
@@ -90,6 +130,7 @@ interface ModuleBase {
 
 class ModuleBase implements ModuleBase {
   constructor() {
+    this.armResources = new Array<client.ArmResource>();
     this.clients = new Array<client.Client>();
     this.enums = new Array<types.Enum>();
     this.models = new Array<types.Model>();
@@ -107,7 +148,12 @@ export class Crate extends ModuleBase implements Crate {
     this.name = name;
     this.version = version;
     this.type = type;
+    this.cloudEndpoints = new Array<CloudEndpoint>();
     this.dependencies = new Array<CrateDependency>();
+    this.devDependencies = new Array<CrateDependency>();
+    this.exampleTests = false;
+    this.idempotencyContext = false;
+    this.features = new Array<string>();
     this.subModules = new Array<SubModule>();
   }
 
@@ -127,6 +173,13 @@ export class Crate extends ModuleBase implements Crate {
   }
 }
 
+export class CloudEndpoint implements CloudEndpoint {
+  constructor(cloud: string, endpoint: string) {
+    this.cloud = cloud;
+    this.endpoint = endpoint;
+  }
+}
+
 export class CrateDependency implements CrateDependency {
   constructor(name: string, features = new Array<string>()) {
     this.name = name;
@@ -134,6 +187,12 @@ export class CrateDependency implements CrateDependency {
   }
 }
 
+export class StubServer implements StubServer {
+  constructor(name: string) {
+    this.name = name;
+  }
+}
+
 export class SubModule extends ModuleBase implements SubModule {
   constructor(name: string, parent: ModuleContainer) {
     super();
@@ -4,7 +4,18 @@
 *--------------------------------------------------------------------------------------------*/
 
 /** ModelFieldCustomizations defines the set of customizations for model fields. */
-export type ModelFieldCustomizations = DeserializeWith | SerializeWith;
+export type ModelFieldCustomizations = Attribute | DeserializeWith | SerializeWith;
+
+/** TypeCustomizations defines the set of customizations for enums and models. */
+export type TypeCustomizations = Attribute | Derive;
+
+/** Attribute is an additional attribute for the item. */
+export interface Attribute {
+  kind: 'attribute';
+
+  /** the contents of the attribute without the enclosing #[] (e.g. serde(deny_unknown_fields)) */
+  content: string;
+}
 
 /** DeserializeWith indicates a field should use the specified deserializer function. */
 export interface DeserializeWith {
@@ -14,6 +25,14 @@ export interface DeserializeWith {
   name: string;
 }
 
+/** Derive is an additional trait to derive for a type. */
+export interface Derive {
+  kind: 'derive';
+
+  /** the name of the derive macro (e.g. PartialEq) */
+  name: string;
+}
+
 /** SerializeWith indicates a field should use the specified serializer function. */
 export interface SerializeWith {
   kind: 'serializeWith';
@@ -25,6 +44,13 @@ export interface SerializeWith {
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 
+export class Attribute {
+  constructor(content: string) {
+    this.kind = 'attribute';
+    this.content = content;
+  }
+}
+
 export class DeserializeWith {
   constructor(name: string) {
     this.kind = 'deserializeWith';
@@ -32,6 +58,13 @@ export class DeserializeWith {
   }
 }
 
+export class Derive {
+  constructor(name: string) {
+    this.kind = 'derive';
+    this.name = name;
+  }
+}
+
 export class SerializeWith {
   constructor(name: string) {
     this.kind = 'serializeWith';
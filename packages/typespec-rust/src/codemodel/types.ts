@@ -4,7 +4,7 @@
 *--------------------------------------------------------------------------------------------*/
 
 import { Crate, CrateDependency, ModuleContainer } from './crate.js';
-import { ModelFieldCustomizations } from './customizations.js';
+import { ModelFieldCustomizations, TypeCustomizations } from './customizations.js';
 
 /** Docs contains the values used in doc comment generation. */
 export interface Docs {
@@ -16,7 +16,7 @@ export interface Docs {
 }
 
 /** SdkType defines types used in generated code but do not directly participate in serde */
-export type SdkType =  Arc | AsyncResponse | Box | ClientMethodOptions | ImplTrait | MarkerType | Option | PageIterator | Pager | PagerOptions | Poller | PollerOptions | RawResponse | RequestContent | Response | Result | Struct | TokenCredential | Unit;
+export type SdkType =  Arc | AsyncResponse | Box | ClientMethodOptions | ImplTrait | MarkerType | NegotiatedResponse | Option | PageIterator | Pager | PagerOptions | Poller | PollerOptions | RawResponse | RequestContent | Response | Result | ResumableDownload | SeekableStream | Struct | TokenCredential | Unit;
 
 /** WireType defines types that go across the wire */
 export type WireType = Bytes | Decimal | DiscriminatedUnion | EncodedBytes | Enum | EnumValue | Etag | ExternalType | HashMap | JsonValue | Literal | Model | OffsetDateTime | RefBase | SafeInt | Scalar | Slice | StringSlice | StringType | UntaggedUnion | Url | Vector;
@@ -111,11 +111,20 @@ export interface Enum {
   /** indicates if the enum is extensible or not */
   extensible: boolean;
 
+  /**
+   * indicates if known values of an extensible string
+   * enum are matched without regard to case
+   */
+  caseInsensitive: boolean;
+
   /** the underlying type of the enum */
   type: EnumType;
 
   /** the module to which this enum belongs */
   module: ModuleContainer;
+
+  /** any customizations for this enum. can be empty */
+  customizations: Array<TypeCustomizations>;
 }
 
 /** EnumValue is an enum value for a specific Enum */
@@ -272,6 +281,42 @@ export interface MarkerType {
   visibility: Visibility;
 }
 
+/**
+ * NegotiatedResponse is a generated enum for methods whose responses
+ * have different content types. it contains a variant per content type
+ * and the caller selects the content type via the method's accept param.
+ */
+export interface NegotiatedResponse {
+  kind: 'negotiatedResponse';
+
+  /** the name of the response enum */
+  name: string;
+
+  /** any docs for the response enum */
+  docs: Docs;
+
+  /** indicates the visibility of the response enum */
+  visibility: Visibility;
+
+  /** the module to which this response enum belongs */
+  module: ModuleContainer;
+
+  /** the variants, one per content type. contains at least two entries */
+  variants: Array<NegotiatedResponseVariant>;
+}
+
+/** NegotiatedResponseVariant is a variant within a NegotiatedResponse */
+export interface NegotiatedResponseVariant {
+  /** the name of the variant */
+  name: string;
+
+  /** the content type for this variant (e.g. application/json) */
+  contentType: string;
+
+  /** the response type for this content type. RawResponse is used for binary content */
+  type: RawResponse | Response;
+}
+
 /** ModelFieldType contains the types of model fields */
 export type ModelFieldType = ModelField | ModelAdditionalProperties;
 
@@ -288,11 +333,17 @@ export interface Model extends StructBase {
   /** the module to which this model belongs */
   module: ModuleContainer;
 
+  /** any customizations for this model. can be empty */
+  customizations: Array<TypeCustomizations>;
+
   /**
    * the name of the type over the wire if it's
    * different from the type's name.
    */
   xmlName?: string;
+
+  /** the XML namespace for the type's element */
+  xmlNamespace?: XMLNamespace;
 }
 
 /** ModelAdditionalProperties is a field that contains unnamed key/value pairs */
@@ -321,6 +372,9 @@ export interface ModelField extends StructFieldBase {
 
   /** contains XML-specific serde info */
   xmlKind?: XMLKind;
+
+  /** the XML namespace for the field's element or attribute */
+  xmlNamespace?: XMLNamespace;
 }
 
 /** ModelFieldFlags contains bit flags describing field usage */
@@ -486,10 +540,10 @@ export interface RequestContent<T extends WireType = WireType, Format extends Pa
 }
 
 /** ResponseFormat is the format of the response body */
-export type PayloadFormatType = 'BinaryFormat' | 'JsonFormat' | 'NoFormat' | 'XmlFormat';
+export type PayloadFormatType = 'Base64Format' | 'Base64UrlFormat' | 'BinaryFormat' | 'FormFormat' | 'JsonFormat' | 'NoFormat' | 'TextFormat' | 'XmlFormat';
 
-/** ModelPayloadFormatType is a PayloadFormatType for modeled payloads (i.e. excludes binary and no-format) */
-export type ModelPayloadFormatType = Exclude<PayloadFormatType, 'BinaryFormat' | 'NoFormat'>;
+/** ModelPayloadFormatType is a PayloadFormatType for modeled payloads (i.e. excludes base64, binary, form, no-format, and text) */
+export type ModelPayloadFormatType = Exclude<PayloadFormatType, 'Base64Format' | 'Base64UrlFormat' | 'BinaryFormat' | 'FormFormat' | 'NoFormat' | 'TextFormat'>;
 
 /** ResponseTypes defines the type constraint when creating a Response<T> */
 export type ResponseTypes = MarkerType | Unit | WireType;
@@ -506,7 +560,7 @@ export interface Response<T extends ResponseTypes = ResponseTypes, Format extend
 }
 
 /** ResultTypes defines the type constraint when creating a Result<T> */
-export type ResultTypes = AsyncResponse | PageIterator | Pager | Poller | Response;
+export type ResultTypes = AsyncResponse | NegotiatedResponse | PageIterator | Pager | Poller | Response | ResumableDownload;
 
 /** Result is a Rust Result<T> from azure_core */
 export interface Result<T extends ResultTypes = ResultTypes> extends External {
@@ -516,6 +570,29 @@ export interface Result<T extends ResultTypes = ResultTypes> extends External {
   type: T;
 }
 
+/**
+ * ResumableDownload is a generated byte stream over a binary response body.
+ * if the body stream fails with a transient error, the request is reissued
+ * with an adjusted range header for the bytes that haven't been received.
+ */
+export interface ResumableDownload {
+  kind: 'resumableDownload';
+
+  /** the name of the stream type */
+  name: string;
+
+  /** the module to which the stream type belongs */
+  module: ModuleContainer;
+
+  /** the name of the header used to request a range of bytes (e.g. range or x-ms-range) */
+  rangeHeader: string;
+}
+
+/** SeekableStream is a Box<dyn azure_core::stream::SeekableStream> */
+export interface SeekableStream extends External {
+  kind: 'seekableStream';
+}
+
 /** SafeInt is a serde_json::Number type */
 export interface SafeInt extends External {
   kind: 'safeint';
@@ -635,6 +712,18 @@ export interface Vector {
 /** XMLKind contains info used for generating XML-specific serde */
 export type XMLKind = 'attribute' | 'text' | 'unwrappedList';
 
+/** XMLNamespace is an XML namespace and its optional prefix */
+export interface XMLNamespace {
+  /** the namespace URI */
+  namespace: string;
+
+  /**
+   * the prefix for qualified names (e.g. ns1 in ns1:name).
+   * when undefined, the namespace is the default namespace.
+   */
+  prefix?: string;
+}
+
 ///////////////////////////////////////////////////////////////////////////////////////////////////
 // exported base types
 ///////////////////////////////////////////////////////////////////////////////////////////////////
@@ -681,7 +770,7 @@ class External extends QualifiedType implements External {
     if (pathSep > 0) {
       crateName = crateName.substring(0, pathSep);
     }
-    if (crateName !== 'crate') {
+    if (crateName !== 'crate' && crateName !== 'std') {
       crate.addDependency(new CrateDependency(crateName, features));
     }
   }
@@ -810,8 +899,10 @@ export class Enum implements Enum {
     this.visibility = visibility;
     this.values = new Array<EnumValue>();
     this.extensible = extensible;
+    this.caseInsensitive = false;
     this.type = type;
     this.module = module;
+    this.customizations = new Array<TypeCustomizations>();
     this.docs = {};
   }
 }
@@ -886,6 +977,25 @@ export class MarkerType implements MarkerType {
   }
 }
 
+export class NegotiatedResponse implements NegotiatedResponse {
+  constructor(name: string, visibility: Visibility, module: ModuleContainer) {
+    this.kind = 'negotiatedResponse';
+    this.name = name;
+    this.docs = {};
+    this.visibility = visibility;
+    this.module = module;
+    this.variants = new Array<NegotiatedResponseVariant>();
+  }
+}
+
+export class NegotiatedResponseVariant implements NegotiatedResponseVariant {
+  constructor(name: string, contentType: string, type: RawResponse | Response) {
+    this.name = name;
+    this.contentType = contentType;
+    this.type = type;
+  }
+}
+
 export class DiscriminatedUnion implements DiscriminatedUnion {
   constructor(name: string, visibility: Visibility, discriminant: string, module: ModuleContainer) {
     this.kind = 'discriminatedUnion';
@@ -953,6 +1063,7 @@ export class Model extends StructBase implements Model {
     this.fields = new Array<ModelFieldType>();
     this.flags = flags;
     this.module = module;
+    this.customizations = new Array<TypeCustomizations>();
   }
 }
 
@@ -1073,6 +1184,15 @@ export class Result<T> extends External implements Result<T> {
   }
 }
 
+export class ResumableDownload implements ResumableDownload {
+  constructor(module: ModuleContainer, rangeHeader: string) {
+    this.kind = 'resumableDownload';
+    this.name = 'ResumableDownload';
+    this.module = module;
+    this.rangeHeader = rangeHeader;
+  }
+}
+
 export class SafeInt extends External implements SafeInt {
   constructor(crate: Crate, stringEncoding: boolean) {
     super(crate, 'Number', 'serde_json');
@@ -1081,6 +1201,13 @@ export class SafeInt extends External implements SafeInt {
   }
 }
 
+export class SeekableStream extends External implements SeekableStream {
+  constructor(crate: Crate) {
+    super(crate, 'SeekableStream', 'azure_core::stream');
+    this.kind = 'seekableStream';
+  }
+}
+
 export class Scalar implements Scalar {
   constructor(type: ScalarType, stringEncoding: boolean) {
     this.kind = 'scalar';
@@ -1148,3 +1275,10 @@ export class Vector implements Vector {
     this.type = type;
   }
 }
+
+export class XMLNamespace implements XMLNamespace {
+  constructor(namespace: string, prefix?: string) {
+    this.namespace = namespace;
+    this.prefix = prefix;
+  }
+}
@@ -0,0 +1,456 @@
+/*---------------------------------------------------------------------------------------------
+ *  Copyright (c) Microsoft Corporation. All rights reserved.
+ *  Licensed under the MIT License. See License.txt in the project root for license information.
+ *--------------------------------------------------------------------------------------------*/
+
+import * as rust from './codemodel/index.js';
+import * as fs from 'fs';
+import * as path from 'path';
+import { parse as parseYaml } from 'yaml';
+
+/** ErrorCode defines the types of errors */
+export type ErrorCode =
+  /** the customization file is malformed or contains an invalid customization */
+  'InvalidArgument';
+
+/**
+ * CustomizationError is thrown when the customization
+ * file can't be read or contains an invalid customization.
+ */
+export class CustomizationError extends Error {
+  readonly code: ErrorCode;
+
+  constructor(code: ErrorCode, message: string) {
+    super(message);
+    this.code = code;
+  }
+}
+
+/** Customizations contains the contents of a customization file */
+export interface Customizations {
+  /** customizations for models, keyed by the model's emitted name */
+  models?: Record<string, ModelCustomization>;
+
+  /** customizations for enums, keyed by the enum's emitted name */
+  enums?: Record<string, EnumCustomization>;
+
+  /** customizations for clients, keyed by the client's emitted name */
+  clients?: Record<string, ClientCustomization>;
+}
+
+/** the visibility values as written in a customization file */
+export type Visibility = 'pub' | 'pub(crate)';
+
+/** EnumCustomization contains the customizations for an enum */
+export interface EnumCustomization {
+  /** the new name for the enum */
+  name?: string;
+
+  /** the new visibility for the enum */
+  visibility?: Visibility;
+
+  /** additional traits to derive (e.g. Hash) */
+  derives?: Array<string>;
+
+  /** additional attributes without the enclosing #[] */
+  attributes?: Array<string>;
+}
+
+/** ModelCustomization contains the customizations for a model */
+export interface ModelCustomization extends EnumCustomization {
+  /** customizations for the model's fields, keyed by the field's emitted name */
+  fields?: Record<string, FieldCustomization>;
+}
+
+/** FieldCustomization contains the customizations for a model field */
+export interface FieldCustomization {
+  /** the new name for the field. the name over the wire is unchanged */
+  name?: string;
+
+  /** the new visibility for the field */
+  visibility?: Visibility;
+
+  /** the path of the Rust type to use for the field (e.g. my_crate::Timestamp) */
+  type?: string;
+
+  /** the fully qualified name of the deserializer function */
+  deserializeWith?: string;
+
+  /** the fully qualified name of the serializer function */
+  serializeWith?: string;
+
+  /** additional attributes without the enclosing #[] */
+  attributes?: Array<string>;
+}
+
+/** ClientCustomization contains the customizations for a client */
+export interface ClientCustomization {
+  /** customizations for the client's methods, keyed by the method's emitted name */
+  methods?: Record<string, MethodCustomization>;
+}
+
+/** MethodCustomization contains the customizations for a client method */
+export interface MethodCustomization {
+  /** the new name for the method */
+  name?: string;
+
+  /** the new visibility for the method */
+  visibility?: Visibility;
+}
+
+/**
+ * reads and validates the customization file at the specified path.
+ * files with a .json extension are parsed as JSON, all others as YAML.
+ *
+ * @param filePath the path to the customization file
+ * @returns the validated customizations
+ */
+export function loadCustomizations(filePath: string): Customizations {
+  let text: string;
+  try {
+    text = fs.readFileSync(filePath, 'utf-8');
+  } catch (error) {
+    throw new CustomizationError('InvalidArgument', `failed to read customization file ${filePath}: ${(<Error>error).message}`);
+  }
+
+  let contents: unknown;
+  try {
+    contents = path.extname(filePath).toLowerCase() === '.json' ? JSON.parse(text) : parseYaml(text);
+  } catch (error) {
+    throw new CustomizationError('InvalidArgument', `failed to parse customization file ${filePath}: ${(<Error>error).message}`);
+  }
+
+  // an empty YAML file parses to null
+  if (contents === null || contents === undefined) {
+    return {};
+  }
+
+  return validateCustomizations(contents);
+}
+
+/**
+ * validates that contents has the shape of a customization file
+ *
+ * @param contents the parsed contents of a customization file
+ * @returns the validated customizations
+ */
+export function validateCustomizations(contents: unknown): Customizations {
+  const root = checkObject(contents, '', ['clients', 'enums', 'models']);
+
+  const validateType = (value: unknown, location: string, extraKeys: Array<string>): Record<string, unknown> => {
+    const entry = checkObject(value, location, ['attributes', 'derives', 'name', 'visibility', ...extraKeys]);
+    checkIdentifier(entry['name'], `${location}.name`);
+    checkVisibility(entry['visibility'], `${location}.visibility`);
+    checkStringArray(entry['derives'], `${location}.derives`);
+    checkStringArray(entry['attributes'], `${location}.attributes`);
+    return entry;
+  };
+
+  for (const [modelName, model] of Object.entries(checkRecord(root['models'], 'models'))) {
+    const modelLocation = `models.${modelName}`;
+    const entry = validateType(model, modelLocation, ['fields']);
+    for (const [fieldName, field] of Object.entries(checkRecord(entry['fields'], `${modelLocation}.fields`))) {
+      const fieldLocation = `${modelLocation}.fields.${fieldName}`;
+      const fieldEntry = checkObject(field, fieldLocation, ['attributes', 'deserializeWith', 'name', 'serializeWith', 'type', 'visibility']);
+      checkIdentifier(fieldEntry['name'], `${fieldLocation}.name`);
+      checkVisibility(fieldEntry['visibility'], `${fieldLocation}.visibility`);
+      checkTypePath(fieldEntry['type'], `${fieldLocation}.type`);
+      checkString(fieldEntry['deserializeWith'], `${fieldLocation}.deserializeWith`);
+      checkString(fieldEntry['serializeWith'], `${fieldLocation}.serializeWith`);
+      checkStringArray(fieldEntry['attributes'], `${fieldLocation}.attributes`);
+    }
+  }
+
+  for (const [enumName, rustEnum] of Object.entries(checkRecord(root['enums'], 'enums'))) {
+    validateType(rustEnum, `enums.${enumName}`, []);
+  }
+
+  for (const [clientName, client] of Object.entries(checkRecord(root['clients'], 'clients'))) {
+    const clientLocation = `clients.${clientName}`;
+    const entry = checkObject(client, clientLocation, ['methods']);
+    for (const [methodName, method] of Object.entries(checkRecord(entry['methods'], `${clientLocation}.methods`))) {
+      const methodLocation = `${clientLocation}.methods.${methodName}`;
+      const methodEntry = checkObject(method, methodLocation, ['name', 'visibility']);
+      checkIdentifier(methodEntry['name'], `${methodLocation}.name`);
+      checkVisibility(methodEntry['visibility'], `${methodLocation}.visibility`);
+    }
+  }
+
+  return <Customizations>root;
+}
+
+/**
+ * applies the customizations to the crate and its sub-modules.
+ * customizations are matched by the names the emitter assigned
+ * to the items, so renamed items must be referred to by their
+ * original names.
+ *
+ * @param crate the crate to customize
+ * @param customizations the customizations to apply
+ * @returns a message for each customization that didn't match anything. can be empty
+ */
+export function applyCustomizations(crate: rust.Crate, customizations: Customizations): Array<string> {
+  const unmatched = new Array<string>();
+  const modules = getModules(crate);
+
+  for (const [modelName, modelCustomization] of Object.entries(customizations.models ?? {})) {
+    const models = modules.flatMap((module) => module.models.filter((model): model is rust.Model => model.kind === 'model' && model.name === modelName));
+    if (models.length === 0) {
+      unmatched.push(`models.${modelName} didn't match any model`);
+      continue;
+    }
+
+    for (const model of models) {
+      // fields are matched before renaming so the lookup uses the emitted names
+      for (const [fieldName, fieldCustomization] of Object.entries(modelCustomization.fields ?? {})) {
+        const field = model.fields.find((each): each is rust.ModelField => each.kind === 'modelField' && each.name === fieldName);
+        if (!field) {
+          unmatched.push(`models.${modelName}.fields.${fieldName} didn't match any field in model ${modelName}`);
+          continue;
+        }
+        customizeField(crate, model, field, fieldCustomization);
+      }
+
+      if (modelCustomization.name !== undefined && modelCustomization.name !== model.name) {
+        checkTypeNameAvailable(model.module, modelCustomization.name, `models.${modelName}.name`);
+        if (!model.xmlName && (model.flags & rust.ModelFlags.PolymorphicSubtype) === 0) {
+          // preserve the name of the type over the wire
+          model.xmlName = model.name;
+        }
+        model.name = modelCustomization.name;
+      }
+      customizeType(model, modelCustomization);
+    }
+  }
+
+  for (const [enumName, enumCustomization] of Object.entries(customizations.enums ?? {})) {
+    const enums = modules.flatMap((module) => module.enums.filter((rustEnum) => rustEnum.name === enumName));
+    if (enums.length === 0) {
+      unmatched.push(`enums.${enumName} didn't match any enum`);
+      continue;
+    }
+
+    for (const rustEnum of enums) {
+      if (enumCustomization.name !== undefined && enumCustomization.name !== rustEnum.name) {
+        checkTypeNameAvailable(rustEnum.module, enumCustomization.name, `enums.${enumName}.name`);
+        rustEnum.name = enumCustomization.name;
+      }
+      customizeType(rustEnum, enumCustomization);
+    }
+  }
+
+  for (const [clientName, clientCustomization] of Object.entries(customizations.clients ?? {})) {
+    const clients = modules.flatMap((module) => module.clients.filter((client) => client.name === clientName));
+    if (clients.length === 0) {
+      unmatched.push(`clients.${clientName} didn't match any client`);
+      continue;
+    }
+
+    for (const client of clients) {
+      for (const [methodName, methodCustomization] of Object.entries(clientCustomization.methods ?? {})) {
+        const method = client.methods.find((each) => each.name === methodName);
+        if (!method) {
+          unmatched.push(`clients.${clientName}.methods.${methodName} didn't match any method in client ${clientName}`);
+          continue;
+        }
+        customizeMethod(client, method, methodCustomization, `clients.${clientName}.methods.${methodName}`);
+      }
+    }
+  }
+
+  return unmatched;
+}
+
+/**
+ * applies the customizations to the model field
+ *
+ * @param crate the crate that contains the model
+ * @param model the model that contains the field
+ * @param field the field to customize
+ * @param customization the customizations for the field
+ */
+function customizeField(crate: rust.Crate, model: rust.Model, field: rust.ModelField, customization: FieldCustomization): void {
+  if (customization.name !== undefined && customization.name !== field.name) {
+    if (model.fields.some((each) => each.name === customization.name)) {
+      throw new CustomizationError('InvalidArgument', `models.${model.name}.fields.${field.name}.name: model ${model.name} already contains a field named ${customization.name}`);
+    }
+    // the field's name over the wire stays the same
+    field.name = customization.name;
+  }
+
+  if (customization.visibility) {
+    field.visibility = toVisibility(customization.visibility);
+  }
+
+  if (customization.type) {
+    const pathSep = customization.type.lastIndexOf('::');
+    const type = new rust.ExternalType(crate, customization.type.substring(pathSep + 2), customization.type.substring(0, pathSep));
+    field.type = field.type.kind === 'option' ? new rust.Option(type) : type;
+  }
+
+  if (customization.deserializeWith) {
+    field.customizations = field.customizations.filter((each) => each.kind !== 'deserializeWith');
+    field.customizations.push(new rust.DeserializeWith(customization.deserializeWith));
+  }
+
+  if (customization.serializeWith) {
+    field.customizations = field.customizations.filter((each) => each.kind !== 'serializeWith');
+    field.customizations.push(new rust.SerializeWith(customization.serializeWith));
+  }
+
+  for (const attribute of customization.attributes ?? []) {
+    field.customizations.push(new rust.Attribute(trimAttribute(attribute)));
+  }
+}
+
+/**
+ * applies the customizations to the client method
+ *
+ * @param client the client that contains the method
+ * @param method the method to customize
+ * @param customization the customizations for the method
+ * @param location the location of the customization within the file
+ */
+function customizeMethod(client: rust.Client, method: rust.MethodType, customization: MethodCustomization, location: string): void {
+  if (customization.name !== undefined && customization.name !== method.name) {
+    if (client.methods.some((each) => each.name === customization.name)) {
+      throw new CustomizationError('InvalidArgument', `${location}.name: client ${client.name} already contains a method named ${customization.name}`);
+    }
+
+    if (method.kind !== 'clientaccessor') {
+      // the options type links back to the method
+      const optionsType = method.options.type.type;
+      optionsType.docs.summary = optionsType.docs.summary?.replace(`${client.name}::${method.name}()`, `${client.name}::${customization.name}()`);
+    }
+    method.name = customization.name;
+  }
+
+  if (customization.visibility) {
+    method.visibility = toVisibility(customization.visibility);
+  }
+}
+
+/**
+ * applies the customizations common to enums and models
+ *
+ * @param type the enum or model to customize
+ * @param customization the customizations for the type
+ */
+function customizeType(type: rust.Enum | rust.Model, customization: EnumCustomization): void {
+  if (customization.visibility) {
+    type.visibility = toVisibility(customization.visibility);
+  }
+
+  for (const derive of customization.derives ?? []) {
+    if (!type.customizations.some((each) => each.kind === 'derive' && each.name === derive)) {
+      type.customizations.push(new rust.Derive(derive));
+    }
+  }
+
+  for (const attribute of customization.attributes ?? []) {
+    type.customizations.push(new rust.Attribute(trimAttribute(attribute)));
+  }
+}
+
+/**
+ * throws if a type named name already exists in module
+ *
+ * @param module the module that will contain the renamed type
+ * @param name the new name for the type
+ * @param location the location of the customization within the file
+ */
+function checkTypeNameAvailable(module: rust.ModuleContainer, name: string, location: string): void {
+  const names = [...module.enums, ...module.models, ...module.unions].map((each) => each.name);
+  if (names.includes(name)) {
+    throw new CustomizationError('InvalidArgument', `${location}: a type named ${name} already exists`);
+  }
+}
+
+/** returns the crate and all of its sub-modules */
+function getModules(crate: rust.Crate): Array<rust.ModuleContainer> {
+  const modules = new Array<rust.ModuleContainer>();
+  const recursiveAdd = (module: rust.ModuleContainer) => {
+    modules.push(module);
+    for (const subModule of module.subModules) {
+      recursiveAdd(subModule);
+    }
+  };
+  recursiveAdd(crate);
+  return modules;
+}
+
+/** converts the visibility from the customization file to its code model equivalent */
+function toVisibility(visibility: Visibility): rust.Visibility {
+  return visibility === 'pub' ? 'pub' : 'pubCrate';
+}
+
+/** removes any enclosing #[] from an attribute */
+function trimAttribute(attribute: string): string {
+  attribute = attribute.trim();
+  if (attribute.startsWith('#[') && attribute.endsWith(']')) {
+    attribute = attribute.substring(2, attribute.length - 1).trim();
+  }
+  return attribute;
+}
+
+///////////////////////////////////////////////////////////////////////////////////////////////////
+// validation helpers
+///////////////////////////////////////////////////////////////////////////////////////////////////
+
+function checkObject(value: unknown, location: string, keys: Array<string>): Record<string, unknown> {
+  if (typeof value !== 'object' || value === null || Array.isArray(value)) {
+    throw new CustomizationError('InvalidArgument', `${location || 'customization file'}: expected an object`);
+  }
+  for (const key of Object.keys(value)) {
+    if (!keys.includes(key)) {
+      throw new CustomizationError('InvalidArgument', `${location ? `${location}.` : ''}${key}: unknown customization. expected one of ${keys.join(', ')}`);
+    }
+  }
+  return <Record<string, unknown>>value;
+}
+
+function checkRecord(value: unknown, location: string): Record<string, unknown> {
+  if (value === undefined || value === null) {
+    return {};
+  }
+  if (typeof value !== 'object' || Array.isArray(value)) {
+    throw new CustomizationError('InvalidArgument', `${location}: expected an object`);
+  }
+  return <Record<string, unknown>>value;
+}
+
+function checkString(value: unknown, location: string): void {
+  if (value !== undefined && (typeof value !== 'string' || value.trim() === '')) {
+    throw new CustomizationError('InvalidArgument', `${location}: expected a non-empty string`);
+  }
+}
+
+function checkStringArray(value: unknown, location: string): void {
+  if (value === undefined) {
+    return;
+  }
+  if (!Array.isArray(value)) {
+    throw new CustomizationError('InvalidArgument', `${location}: expected an array of strings`);
+  }
+  value.forEach((entry, i) => checkString(entry, `${location}[${i}]`));
+}
+
+function checkIdentifier(value: unknown, location: string): void {
+  checkString(value, location);
+  if (value !== undefined && !/^[A-Za-z_][A-Za-z0-9_]*$/.test(<string>value)) {
+    throw new CustomizationError('InvalidArgument', `${location}: ${<string>value} isn't a valid Rust identifier`);
+  }
+}
+
+function checkTypePath(value: unknown, location: string): void {
+  checkString(value, location);
+  if (value !== undefined && !/^[A-Za-z_][A-Za-z0-9_]*(::[A-Za-z_][A-Za-z0-9_]*)+$/.test(<string>value)) {
+    throw new CustomizationError('InvalidArgument', `${location}: ${<string>value} must be a path to a Rust type (e.g. my_crate::MyType)`);
+  }
+}
+
+function checkVisibility(value: unknown, location: string): void {
+  if (value !== undefined && value !== 'pub' && value !== 'pub(crate)') {
+    throw new CustomizationError('InvalidArgument', `${location}: expected pub or pub(crate)`);
+  }
+}
@@ -8,6 +8,7 @@
 import { CodeGenerator } from './codegen/codeGenerator.js';
 import { CodegenError } from './codegen/errors.js';
 import { generatedCodeKeyPhrase } from './codegen/helpers.js';
+import { applyCustomizations, CustomizationError, loadCustomizations } from './customizations.js';
 import { Adapter, AdapterError, ExternalError } from './tcgcadapter/adapter.js';
 import { reportDiagnostic, RustEmitterOptions } from './lib.js';
 import { execSync } from 'child_process';
@@ -17,6 +18,9 @@ import * as path from 'path';
 import { EmitContext, NoTarget } from '@typespec/compiler';
 import 'source-map-support/register.js';
 
+/** the directory, relative to the crate, into which the stub server crate is emitted */
+const stubServerSubDir = 'stub_server';
+
 /**
  * entry point called by the tsp compiler
  * 
@@ -24,9 +28,26 @@ import 'source-map-support/register.js';
  */
 export async function $onEmit(context: EmitContext<RustEmitterOptions>) {
   let failed = false;
+  // set when the stub server crate was emitted so that it's also formatted
+  let stubServerDir: string | undefined;
   try {
     const adapter = await Adapter.create(context);
     const crate = adapter.tcgcToCrate();
+
+    const customizationFile = context.options['customization-file'];
+    if (customizationFile) {
+      const customizations = loadCustomizations(path.resolve(context.program.projectRoot, customizationFile));
+      for (const unmatched of applyCustomizations(crate, customizations)) {
+        reportDiagnostic(context.program, {
+          code: 'UnmatchedCustomization',
+          target: NoTarget,
+          format: {
+            message: `customization ${unmatched}`,
+          }
+        });
+      }
+    }
+
     const codegen = new CodeGenerator(crate);
 
     const srcDir = path.join(context.emitterOutputDir, 'src');
@@ -79,6 +100,32 @@ export async function $onEmit(context: EmitContext<RustEmitterOptions>) {
     } else {
       await writeFile(cargoTomlPath, codegen.emitCargoToml());
     }
+
+    // example tests live outside of src so they're written relative to the crate root
+    for (const file of codegen.emitExampleTests()) {
+      const fullFilePath = path.join(context.emitterOutputDir, file.name);
+      await mkdir(path.dirname(fullFilePath), { recursive: true });
+      await writeFile(fullFilePath, file.content);
+    }
+
+    // the stub server is a test-support crate that's entirely generated
+    const stubServer = codegen.emitStubServer();
+    if (stubServer) {
+      stubServerDir = path.join(context.emitterOutputDir, stubServerSubDir);
+      await writeToCrateDir(stubServerDir, 'lib.rs', stubServer.libRs);
+      // the same overwrite policy as the crate's Cargo.toml applies
+      const stubServerCargoTomlPath = path.join(stubServerDir, 'Cargo.toml');
+      if (fs.existsSync(stubServerCargoTomlPath) && context.options['overwrite-cargo-toml'] !== true) {
+        context.program.reportDiagnostic({
+          code: 'FileAlreadyExists',
+          severity: 'warning',
+          message: `skip overwriting file ${stubServerCargoTomlPath}`,
+          target: NoTarget,
+        });
+      } else {
+        await writeFile(stubServerCargoTomlPath, stubServer.cargoToml);
+      }
+    }
   } catch (error) {
     failed = true;
     if (error instanceof AdapterError) {
@@ -99,6 +146,14 @@ export async function $onEmit(context: EmitContext<RustEmitterOptions>) {
           stack: error.stack ? truncateStack(error.stack, 'tcgcToCrate') : 'Stack trace unavailable\n',
         }
       });
+    } else if (error instanceof CustomizationError) {
+      reportDiagnostic(context.program, {
+        code: error.code,
+        target: NoTarget,
+        format: {
+          message: error.message,
+        }
+      });
     } else if (error instanceof ExternalError) {
       // we don't want to throw in this case as that will
       // make it appear as if the emitter crashed. just
@@ -134,6 +189,9 @@ export async function $onEmit(context: EmitContext<RustEmitterOptions>) {
 
   try {
     execSync('cargo fmt -- --emit files', { cwd: context.emitterOutputDir, encoding: 'ascii' });
+    if (stubServerDir) {
+      execSync('cargo fmt -- --emit files', { cwd: stubServerDir, encoding: 'ascii' });
+    }
   } catch (err) {
     context.program.reportDiagnostic({
       code: 'CargoFmt',
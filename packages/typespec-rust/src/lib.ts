@@ -21,6 +21,28 @@ export interface RustEmitterOptions {
   'temp-omit-doc-links': boolean;
   /** Whether to emit TryFrom for error types */
   'emit-error-traits': boolean;
+  /** Whether to emit methods that build an operation's request without sending it. Defaults to false */
+  'emit-request-builders': boolean;
+  /** Whether to emit a send_request method on each client that sends a caller-provided request through the client's pipeline. Defaults to true */
+  'emit-send-request': boolean;
+  /** Whether extensible string enums are parsed with case-sensitive matching. Defaults to false */
+  'strict-extensible-enums': boolean;
+  /** Whether to emit handle and collection types for ARM resources. Defaults to false */
+  'emit-arm-resource-handles': boolean;
+  /** ARM endpoints that replace the defaults, keyed by the name of their CloudConfiguration variant */
+  'arm-cloud-endpoints'?: Record<string, string>;
+  /** Whether ARM sub-clients store the scope parameters shared by their operations. Defaults to false */
+  'arm-client-scope-params': boolean;
+  /** Whether to emit a trait with each client's methods and a mock implementation. Defaults to false */
+  'emit-client-traits': boolean;
+  /** Whether to emit a companion crate with an in-process stub server for the crate's clients. Defaults to false */
+  'emit-stub-server': boolean;
+  /** Whether to emit tests that replay the operation examples against a mock transport. Defaults to false */
+  'emit-example-tests': boolean;
+  /** Whether async methods add the idempotency of their operation to the request's context for retry policies. Defaults to false */
+  'emit-idempotency-context': boolean;
+  /** The path to a YAML or JSON file with customizations to apply to the generated code. Relative paths are resolved against the project root */
+  'customization-file'?: string;
 }
 
 const EmitterOptionsSchema: JSONSchemaType<RustEmitterOptions> = {
@@ -48,7 +70,7 @@ const EmitterOptionsSchema: JSONSchemaType<RustEmitterOptions> = {
       type: 'boolean', 
       nullable: false, 
       default: false,
-      description: 'Whether to overwrite an existing Cargo.toml file. Defaults to false'
+      description: 'Whether to overwrite an existing Cargo.toml file. This includes the Cargo.toml of the stub server crate. Defaults to false'
     },
     'overwrite-lib-rs': { 
       type: 'boolean', 
@@ -68,6 +90,72 @@ const EmitterOptionsSchema: JSONSchemaType<RustEmitterOptions> = {
       default: false,
       description: 'Whether to emit traits for error types. Defaults to false'
     },
+    'emit-request-builders': {
+      type: 'boolean',
+      nullable: false,
+      default: false,
+      description: 'Whether to emit methods that build an operation\'s request without sending it, along with types for batching such requests. Defaults to false'
+    },
+    'emit-send-request': {
+      type: 'boolean',
+      nullable: false,
+      default: true,
+      description: 'Whether to emit a send_request method on each client that sends a caller-provided request through the client\'s pipeline, e.g. a request from a request builder that was modified to add a parameter the client doesn\'t support yet. Any 2xx status code is treated as success. Defaults to true'
+    },
+    'strict-extensible-enums': {
+      type: 'boolean',
+      nullable: false,
+      default: false,
+      description: 'Whether extensible string enums are parsed with case-sensitive matching. By default, known values are matched case-insensitively. Defaults to false'
+    },
+    'emit-arm-resource-handles': {
+      type: 'boolean',
+      nullable: false,
+      default: false,
+      description: 'Whether to emit handle and collection types for ARM resources. They wrap the operation group clients so that nested resources can be reached from their parents. Only applies to ARM crates. Defaults to false'
+    },
+    'arm-cloud-endpoints': {
+      type: 'object',
+      nullable: true,
+      required: [],
+      additionalProperties: { type: 'string' },
+      description: 'ARM endpoints keyed by the name of their azure_core::cloud::CloudConfiguration variant (AzurePublic, AzureGovernment, or AzureChina). Entries replace the default endpoint for the cloud. Other clouds are configured at runtime with CloudConfiguration::Custom. Only applies to ARM crates'
+    },
+    'arm-client-scope-params': {
+      type: 'boolean',
+      nullable: false,
+      default: false,
+      description: 'Whether ARM sub-clients store the path parameters shared by all of their operations (e.g. the resource group and parent resource names). The sub-client accessors take their values so the operations no longer take them per call, as if they were moved to the client with @clientLocation. Only applies to ARM crates. Defaults to false'
+    },
+    'emit-client-traits': {
+      type: 'boolean',
+      nullable: false,
+      default: false,
+      description: 'Whether to emit a trait with the methods of each client so that consumers can substitute a fake. An in-memory implementation that returns canned responses is emitted behind the mock feature. Defaults to false'
+    },
+    'emit-stub-server': {
+      type: 'boolean',
+      nullable: false,
+      default: false,
+      description: 'Whether to emit a companion test-support crate named {crate-name}_stub_server into the stub_server directory. It contains a handler trait per client and an in-process HTTP server that routes requests to the handlers so generated clients can be tested end-to-end without external services. Defaults to false'
+    },
+    'emit-example-tests': {
+      type: 'boolean',
+      nullable: false,
+      default: false,
+      description: 'Whether to emit a tests/{client}_examples.rs file per client with tests that replay the examples of its operations against a mock transport and check the deserialized responses. Only clients that can be constructed without a credential are supported. Defaults to false'
+    },
+    'emit-idempotency-context': {
+      type: 'boolean',
+      nullable: false,
+      default: false,
+      description: 'Whether client methods add an Idempotency value to the request\'s context and clients add an IdempotencyPolicy to their pipeline so that requests that aren\'t safe to retry aren\'t retried after a server or I/O error. POST and PATCH operations are not idempotent unless they have a Repeatability-Request-ID header or the idempotent clientOption decorator is set. Non-idempotent methods, including long-running and pageable methods, get an allow_retry field in their options to opt into retries. send_request adds the value based on the request\'s HTTP method if the caller didn\'t. Defaults to false'
+    },
+    'customization-file': {
+      type: 'string',
+      nullable: true,
+      description: 'The path to a YAML or JSON file with customizations to apply to the generated code. It can rename models, enums, fields, and client methods, change their visibility, add derives and attributes to models and enums, replace field types with a Rust type path, and set serde helpers for fields. Relative paths are resolved against the project root'
+    },
   },
   required: [
     'crate-name',
@@ -95,11 +183,29 @@ const libDef = {
         default: paramMessage`The emitter automatically renamed one or more items which resulted in a name collision. Please update the client.tsp to rename the type(s) to avoid the collision.\n${'message'}`
       }
     },
+    'SendRequestCollision': {
+      severity: 'warning',
+      messages: {
+        default: paramMessage`The send_request method isn't emitted on client ${'client'} as it has an operation with the same name.`
+      }
+    },
+    'UnmatchedCustomization': {
+      severity: 'warning',
+      messages: {
+        default: paramMessage`A customization didn't match any item in the generated code and wasn't applied.\n${'message'}`
+      }
+    },
     'UnsupportedTsp': {
       severity: 'error',
       messages: {
         default: paramMessage`The emitter encountered a TypeSpec definition that is currently not supported.\n${'stack'}`
       }
+    },
+    'UnsupportedXmlNamespaces': {
+      severity: 'warning',
+      messages: {
+        default: paramMessage`The XML namespace declarations of model ${'model'} aren't emitted as it borrows its fields.`
+      }
     }
   },
   emitter: {
@@ -9,7 +9,7 @@ import * as tsp from '@typespec/compiler';
 import * as http from '@typespec/http';
 import * as helpers from './helpers.js';
 import * as naming from './naming.js';
-import {RustEmitterOptions} from '../lib.js';
+import {reportDiagnostic, RustEmitterOptions} from '../lib.js';
 import * as utils from '../utils/utils.js';
 import * as tcgc from '@azure-tools/typespec-client-generator-core';
 import * as rust from '../codemodel/index.js';
@@ -67,7 +67,8 @@ export class Adapter {
     // same as TypeSpec.Xml.@name. however, it's filtered out by default
     // so we need to add it to the allow list of decorators
     const ctx = await tcgc.createSdkContext(context, '@azure-tools/typespec-rust', {
-      additionalDecorators: ['TypeSpec\\.@encodedName', '@clientName', 'Azure.ClientGenerator.Core.@deserializeEmptyStringAsNull'],
+      // the ARM resource operation decorators are used to build resource handles
+      additionalDecorators: ['TypeSpec\\.@encodedName', '@clientName', 'Azure.ClientGenerator.Core.@deserializeEmptyStringAsNull', 'Azure\\.ResourceManager\\.@armResource(CreateOrUpdate|Delete|List|Read|Update)'],
       disableUsageAccessPropagationToBase: true,
     });
 
@@ -99,10 +100,17 @@ export class Adapter {
   // maps a tcgc model field to the adapted struct field
   private readonly fieldsMap: Map<tcgc.SdkModelPropertyType | tcgc.SdkPathParameter, rust.ModelField>;
 
+  // the resource ID methods of ARM resource operations, used to build resource handles
+  private readonly armResourceOperations: Array<{ client: rust.Client, kind: rust.ArmResourceOperationKind, method: rust.ResourceIdMethod }>;
+
+  // the default value of the ARM endpoint from the @server decorator
+  private armServerEndpoint?: string;
+
   private constructor(ctx: tcgc.SdkContext, options: RustEmitterOptions) {
     this.types = new Map<string, rust.Type>();
     this.clientMethodParams = new Map<string, rust.MethodParameter>();
     this.fieldsMap = new Map<tcgc.SdkModelPropertyType | tcgc.SdkPathParameter, rust.ModelField>();
+    this.armResourceOperations = new Array<{ client: rust.Client, kind: rust.ArmResourceOperationKind, method: rust.ResourceIdMethod }>();
     this.ctx = ctx;
     this.options = options;
 
@@ -132,12 +140,32 @@ export class Adapter {
     }
 
     this.crate = new rust.Crate(this.options['crate-name'], this.options['crate-version'] ?? '0.1.0', serviceType);
+    this.crate.idempotencyContext = this.options['emit-idempotency-context'] === true;
+    if (this.crate.idempotencyContext) {
+      // the emitted IdempotencyPolicy implements the async Policy trait
+      this.crate.addDependency(new rust.CrateDependency('async-trait'));
+    }
   }
 
   /** performs all the steps to convert tcgc to a crate */
   tcgcToCrate(): rust.Crate {
     this.adaptTypes();
     this.adaptClients();
+    if (this.crate.type === 'azure-arm') {
+      this.adaptCloudEndpoints();
+    }
+    if (this.crate.type === 'azure-arm' && this.options['emit-arm-resource-handles']) {
+      this.adaptArmResources();
+    }
+    if (this.options['emit-example-tests']) {
+      this.crate.exampleTests = true;
+      for (const dependency of ['async-trait', 'serde_json', 'tokio']) {
+        this.crate.devDependencies.push(new rust.CrateDependency(dependency));
+      }
+    }
+    if (this.options['emit-stub-server']) {
+      this.crate.stubServer = new rust.StubServer(`${this.crate.name}_stub_server`);
+    }
 
     return this.crate;
   }
@@ -366,6 +394,8 @@ export class Adapter {
 
     rustEnum = new rust.Enum(enumName, adaptAccessFlags(sdkEnum.access), !sdkEnum.isFixed, enumType, this.adaptNamespace(sdkEnum.namespace));
     rustEnum.docs = this.adaptDocs(sdkEnum.summary, sdkEnum.doc);
+    // per the Azure guidelines, extensible enum values are compared case-insensitively
+    rustEnum.caseInsensitive = rustEnum.extensible && enumType === 'String' && !this.options['strict-extensible-enums'];
     this.types.set(enumName, rustEnum);
 
     // the first pass is to detect any enum values that coalesce into duplicate entries
@@ -489,6 +519,7 @@ export class Adapter {
     rustModel = new rust.Model(modelName, model.access === 'internal' ? 'pubCrate' : 'pub', modelFlags, this.adaptNamespace(model.namespace));
     rustModel.docs = this.adaptDocs(model.summary, model.doc);
     rustModel.xmlName = getXMLName(model.decorators);
+    rustModel.xmlNamespace = getXMLNamespace(model.decorators);
     this.types.set(modelName, rustModel);
     stack.push(rustModel);
 
@@ -533,6 +564,9 @@ export class Adapter {
       parent = parent.baseModel;
     }
 
+    // indicates if HTTP response metadata was omitted from the model's fields
+    let omitsResponseMetadata = false;
+
     for (const property of allProps) {
       if (property.kind !== 'property') {
         if (property.type.kind === 'constant') {
@@ -543,15 +577,36 @@ export class Adapter {
           // a property of kind path is the model key and
           // will be exposed as a discrete method parameter.
           // we just adapt it here as a regular model field.
+        } else if (property.kind === 'cookie' || property.kind === 'header' || property.kind === 'query') {
+          // HTTP metadata isn't part of the payload so we don't emit it as a field.
+          // in responses, header values are read through the response headers trait
+          // that's implemented for the Response<T> returning the model. this is the
+          // typed replacement for the field as it reads the value from the response
+          // on demand, so models stay serde types and are usable as request bodies.
+          // in requests, they're exposed as discrete method parameters.
+          if (property.kind === 'header') {
+            omitsResponseMetadata = true;
+          }
+          continue;
         } else {
           throw new AdapterError('UnsupportedTsp', `model property kind ${property.__raw?.kind} NYI`, property.__raw?.node);
         }
+      } else if (property.__raw && http.isStatusCode(this.ctx.program, property.__raw)) {
+        // the status code is available via Response::status()
+        omitsResponseMetadata = true;
+        continue;
       }
 
       const structField = this.getModelField(model.usage, property, rustModel.visibility, stack);
       rustModel.fields.push(structField);
     }
 
+    if (omitsResponseMetadata && (modelFlags & rust.ModelFlags.Output) !== 0) {
+      // let callers know where the omitted values are
+      const metadataDocs = `The HTTP status code and headers aren't fields of this type. Read them from the ${this.asDocLink('Response', 'azure_core::http::Response')} that returns it, using \`status()\` and the response's headers trait.`;
+      rustModel.docs.description = rustModel.docs.description ? `${rustModel.docs.description}\n\n${metadataDocs}` : metadataDocs;
+    }
+
     if (addlProps) {
       const addlPropsType = this.getHashMap(this.typeToWireType(this.getType(addlProps)));
       const addlPropsField = new rust.ModelAdditionalProperties('additional_properties', 'pub', this.getOptionType(addlPropsType));
@@ -620,6 +675,17 @@ export class Adapter {
             baseModel.visibility = 'pubCrate';
             const lifetime = new rust.Lifetime('a');
             baseModel.lifetime = lifetime;
+            if (baseModel.xmlNamespace || baseModel.fields.some((field) => field.kind === 'modelField' && field.xmlNamespace)) {
+              // the namespace declarations are written by converting into a type
+              // that owns the fields which can't be done for borrowed fields.
+              reportDiagnostic(this.ctx.program, {
+                code: 'UnsupportedXmlNamespaces',
+                target: src.__raw?.node ?? tsp.NoTarget,
+                format: {
+                  model: baseModel.name,
+                }
+              });
+            }
             for (const field of baseModel.fields) {
               if (field.kind === 'modelField') {
                 // strip off the discriminator flag
@@ -999,6 +1065,7 @@ export class Adapter {
       modelField.serde = xmlName;
     }
     modelField.xmlKind = getXMLKind(property.decorators, modelField);
+    modelField.xmlNamespace = getXMLNamespace(property.decorators);
 
     // it's possible for different models to reference the same property definition
     if (!this.fieldsMap.get(property)) {
@@ -1448,6 +1515,13 @@ export class Adapter {
   /** converts all tcgc clients and their methods into Rust clients/methods */
   private adaptClients(): void {
     let needsCore = false;
+    if (this.crate.type === 'azure-arm' && this.options['arm-client-scope-params']) {
+      for (const client of this.ctx.sdkPackage.clients) {
+        for (const child of client.children ?? []) {
+          this.recursiveMoveArmScopeParamsToClient(child);
+        }
+      }
+    }
     for (const client of this.ctx.sdkPackage.clients) {
       // start with instantiable clients and recursively work down
       this.recursiveAdaptClient(client);
@@ -1589,6 +1663,8 @@ export class Adapter {
       };
 
       const ctorParams = new Array<rust.ClientParameter>();
+      // set when the server also accepts a complete endpoint
+      let rawEndpointParam: rust.ClientEndpointParameter | undefined;
       for (const param of client.clientInitialization.parameters) {
         switch (param.kind) {
           case 'credential':
@@ -1616,10 +1692,13 @@ export class Adapter {
               case 'union':
                 // this is a union of endpoints. the first is the endpoint plus
                 // the supplemental path. the second is a "raw" endpoint which
-                // requires the caller to provide the complete endpoint. we only
-                // expose the former at present. languages that support overloads
-                // MAY support both but it's not a requirement.
+                // requires the caller to provide the complete endpoint. the former
+                // is used for the primary constructors. the latter is exposed via
+                // additional constructors that accept the complete endpoint URL.
                 endpointType = param.type.variantTypes[0];
+                if (param.type.variantTypes.length > 1 && this.crate.type !== 'azure-arm') {
+                  rawEndpointParam = new rust.ClientEndpointParameter('endpoint');
+                }
             }
 
             for (let i = 0; i < endpointType.templateArguments.length; ++i) {
@@ -1632,9 +1711,16 @@ export class Adapter {
                 const endpointName = hasClientNameDecorator(templateArg.decorators) ? utils.snakeCaseName(templateArg.name) : 'endpoint';
                 const adaptedParam = new rust.ClientEndpointParameter(endpointName);
                 adaptedParam.docs = this.adaptDocs(param.summary, param.doc);
+                if (rawEndpointParam) {
+                  rawEndpointParam.name = endpointName;
+                  rawEndpointParam.docs.summary = 'The complete service endpoint URL. It\'s used as-is without any host template substitution.';
+                }
                 // for ARM, the endpoint is derived from cloud config at runtime; don't expose it as a ctor param
                 if (this.crate.type !== 'azure-arm') {
                   ctorParams.push(adaptedParam);
+                } else if (typeof templateArg.clientDefaultValue === 'string') {
+                  // the @server default is the endpoint for the public cloud
+                  this.armServerEndpoint = templateArg.clientDefaultValue;
                 }
                 const endpointField = new rust.StructField(endpointName, 'pubCrate', new rust.Url(this.crate));
                 rustClient.endpoint = endpointField;
@@ -1656,6 +1742,13 @@ export class Adapter {
                 continue;
               }
 
+              if (templateArg.type.kind === 'constant' && rustClient.constructable.endpoint) {
+                // constant template args aren't configurable so we bake them into the path
+                const supplementalEndpoint = rustClient.constructable.endpoint;
+                supplementalEndpoint.path = supplementalEndpoint.path.replace(`{${templateArg.serializedName}}`, encodeURIComponent(String(templateArg.type.value)));
+                continue;
+              }
+
               const clientParam = this.adaptClientParameter(templateArg, rustClient.constructable);
               if (clientParam.kind !== 'clientSupplementalEndpoint') {
                 throw new AdapterError('InternalError', `unexpected client parameter kind ${clientParam.kind}`, templateArg.__raw?.node);
@@ -1697,6 +1790,30 @@ export class Adapter {
         // ensure param order of endpoint, credential, other
         helpers.sortClientParameters(constructor.params);
       }
+
+      if (rawEndpointParam && rustClient.constructable.endpoint) {
+        // for each ctor, add a variant that takes the complete endpoint. the
+        // supplemental endpoint params aren't applicable so they're omitted.
+        const rawEndpointCtors = new Array<rust.Constructor>();
+        for (const constructor of rustClient.constructable.constructors) {
+          const rawEndpointCtor = new rust.Constructor(constructor.name === 'new' ? 'with_endpoint_url' : `${constructor.name}_and_endpoint_url`);
+          rawEndpointCtor.rawEndpoint = true;
+          for (const ctorParam of constructor.params) {
+            switch (ctorParam.kind) {
+              case 'clientEndpoint':
+                rawEndpointCtor.params.push(rawEndpointParam);
+                break;
+              case 'clientSupplementalEndpoint':
+                continue;
+              default:
+                rawEndpointCtor.params.push(ctorParam);
+            }
+          }
+          rawEndpointCtor.docs.summary = constructor.docs.summary?.replace(/^Creates a new (\w+)/, 'Creates a new $1 from a complete endpoint URL');
+          rawEndpointCtors.push(rawEndpointCtor);
+        }
+        rustClient.constructable.constructors.push(...rawEndpointCtors);
+      }
     } else if (parent) {
       // this is a sub-client. it will share some/all the fields of the parent.
       // NOTE: we must propagate parent params before a potential recursive call
@@ -1756,6 +1873,25 @@ export class Adapter {
       this.adaptClientAccessor(client, child, rustClient, subClient);
     }
 
+    // send_request defaults to on so only an explicit false disables it.
+    // it's set before adapting the methods as the request builders link to it.
+    rustClient.emitSendRequest = this.options['emit-send-request'] !== false;
+    // paging and polling methods are renamed so only basic methods or
+    // methods with @clientName applied can collide with send_request.
+    const sendRequestMethod = client.methods.find((method) => {
+      return (method.kind === 'basic' || hasClientNameDecorator(method.decorators)) && utils.snakeCaseName(method.name) === 'send_request';
+    });
+    if (rustClient.emitSendRequest && sendRequestMethod) {
+      rustClient.emitSendRequest = false;
+      reportDiagnostic(this.ctx.program, {
+        code: 'SendRequestCollision',
+        target: sendRequestMethod.__raw?.node ?? tsp.NoTarget,
+        format: {
+          client: rustClient.name,
+        }
+      });
+    }
+
     for (const method of client.methods) {
       if (method.kind === 'lropaging') {
         // skip Paging LROs for now so that codegen is unblocked
@@ -1771,6 +1907,14 @@ export class Adapter {
       this.adaptMethod(method, rustClient);
     }
 
+    if (this.options['emit-client-traits'] && rustClient.methods.some((method) => utils.isClientTraitMethod(method))) {
+      rustClient.methodsTrait = new rust.ClientMethodsTrait(`${rustClient.name}Methods`, `Mock${rustClient.name}`);
+      this.crate.addDependency(new rust.CrateDependency('async-trait'));
+      if (!this.crate.features.includes('mock')) {
+        this.crate.features.push('mock');
+      }
+    }
+
     // Set the tracing namespace for tracing based on the client's namespace
     rustClient.languageIndependentName = client.crossLanguageDefinitionId;
 
@@ -1829,7 +1973,14 @@ export class Adapter {
       const paramField = new rust.StructField(paramName, 'pub', paramType);
       paramField.docs = this.adaptDocs(param.summary, param.doc);
       constructable.options.type.fields.push(paramField);
-      if (param.clientDefaultValue) {
+      if (param.clientDefaultValue && paramType.kind === 'enum') {
+        // enum-typed params (e.g. server template params) default to the matching enum value
+        const enumValue = paramType.values.find((value) => value.value === param.clientDefaultValue);
+        if (!enumValue) {
+          throw new AdapterError('InternalError', `didn't find value ${<string>param.clientDefaultValue} in enum ${paramType.name}`, param.__raw?.node);
+        }
+        paramField.defaultValue = `${paramType.name}::${enumValue.name}`;
+      } else if (param.clientDefaultValue) {
         const constName = `DEFAULT_${paramName.toUpperCase()}`;
         paramField.defaultValue = `String::from(${constName})`;
         paramField.defaultValueConstant = { name: constName, value: <string>param.clientDefaultValue };
@@ -1883,6 +2034,41 @@ export class Adapter {
     rustClient.methods.push(clientAccessor);
   }
 
+  /**
+   * moves the path params shared by all methods of an ARM sub-client (e.g. the
+   * resource group and parent resource names) to the sub-client the same way
+   * @clientLocation does. the sub-client stores their values so that its methods
+   * don't take them per call. they're either propagated from the parent or
+   * provided to the client accessor.
+   *
+   * @param client the tcgc sub-client
+   */
+  private recursiveMoveArmScopeParamsToClient(client: tcgc.SdkClientType<tcgc.SdkHttpOperation>): void {
+    const { scopeParams, scopeOpParams } = helpers.getArmScopeParams(client.methods);
+    const scopeMethodParams = new Set<tcgc.SdkModelPropertyType>();
+    for (const opParam of scopeOpParams) {
+      opParam.onClient = true;
+      scopeMethodParams.add(opParam.methodParameterSegments[0][0]);
+    }
+
+    // client params aren't included in method.parameters
+    for (const method of client.methods) {
+      method.parameters = method.parameters.filter((param) => !scopeMethodParams.has(param));
+    }
+
+    for (const scopeParam of scopeParams) {
+      const methodParam = scopeParam.methodParameterSegments[0][0];
+      if (methodParam.kind === 'method' && !client.clientInitialization.parameters.some((param) => param.name === methodParam.name)) {
+        client.clientInitialization.parameters.push(methodParam);
+      }
+    }
+
+    for (const child of client.children ?? []) {
+      this.recursiveMoveArmScopeParamsToClient(child);
+    }
+  }
+
+
   /**
    * converts a tcgc method to a Rust method for the specified client
    *
@@ -2067,6 +2253,12 @@ export class Adapter {
       }
     };
 
+    // tracks the operation params that have been adapted from method params
+    const adaptedOpParams = new Set<tcgc.SdkHttpParameter>();
+
+    // maps operation params to their Rust method params. used for examples
+    const exampleParams = new Map<tcgc.SdkHttpParameter, Array<rust.MethodParameter>>();
+
     for (const param of method.parameters) {
       // we need to translate from the method param to its underlying operation param.
       // most params have a one-to-one mapping. however, for spread params, there will
@@ -2094,6 +2286,7 @@ export class Adapter {
       if (opParams.length === 1) {
         // case 1 or 2
         const opParam = opParams[0];
+        adaptedOpParams.add(opParam);
 
         if (skipOpParam(opParam)) {
           continue;
@@ -2113,6 +2306,7 @@ export class Adapter {
         }
 
         processAdaptedParam(adaptedParam, param);
+        exampleParams.set(opParam, [...(exampleParams.get(opParam) ?? []), adaptedParam]);
       } else if (opParams.length > 1) {
         // case 3 (param group)
         // Parameter group handling:
@@ -2124,6 +2318,7 @@ export class Adapter {
         const lifetime = new rust.Lifetime('a');
         let hasRefs = false;
         for (const opParam of opParams) {
+          adaptedOpParams.add(opParam);
           if (skipOpParam(opParam)) {
             continue;
           }
@@ -2192,6 +2387,28 @@ export class Adapter {
       }
     }
 
+    // HTTP metadata properties within a model (e.g. a @header field in the body
+    // model) don't have a corresponding method param. their values come from
+    // the model's property so we expose them as discrete method params.
+    for (const opParam of allOpParams) {
+      if (opParam.onClient || adaptedOpParams.has(opParam) || skipOpParam(opParam)) {
+        continue;
+      } else if (opParam.kind !== 'cookie' && opParam.kind !== 'header' && opParam.kind !== 'query') {
+        continue;
+      }
+
+      const fieldProp = opParam.methodParameterSegments
+        .flatMap((segment) => segment.length > 1 ? [segment[segment.length - 1]] : [])
+        .find((prop) => prop.name === opParam.name);
+      if (!fieldProp) {
+        continue;
+      }
+
+      // cast is safe as we only need .type, .optional, .summary, .doc from it.
+      const adaptedParam = this.adaptMethodParameter(opParam, fieldProp as tcgc.SdkMethodParameter);
+      processAdaptedParam(adaptedParam, fieldProp as tcgc.SdkMethodParameter);
+    }
+
     // client params aren't included in method.parameters so
     // look for them in the remaining operation parameters.
     for (const opParam of allOpParams) {
@@ -2271,9 +2488,35 @@ export class Adapter {
       }
     }
 
-    const responseFormat = getResponseFormat();
+    // when the responses declare different content types, the caller
+    // selects one via the accept header and gets a response per content type.
+    const getNegotiatedContentTypes = (): Array<NegotiatedContentType> | undefined => {
+      const contentTypes = new Array<NegotiatedContentType>();
+      for (const httpResp of method.operation.responses) {
+        if (!httpResp.defaultContentType) {
+          continue;
+        }
+        for (const contentType of httpResp.contentTypes ?? [httpResp.defaultContentType]) {
+          if (!contentTypes.find((each) => each.contentType.toLowerCase() === contentType.toLowerCase())) {
+            contentTypes.push({ contentType: contentType, type: httpResp.type });
+          }
+        }
+      }
+      return contentTypes.length > 1 ? contentTypes : undefined;
+    };
+
+    const negotiatedContentTypes = getNegotiatedContentTypes();
+    const responseFormat = negotiatedContentTypes ? 'NoFormat' : getResponseFormat();
+    if (responseFormat === 'FormFormat') {
+      throw new AdapterError('UnsupportedTsp', `form-urlencoded response bodies for method ${method.name} aren't supported`, method.__raw?.node);
+    }
 
-    if (method.kind === 'paging') {
+    if (negotiatedContentTypes) {
+      if (rustMethod.kind !== 'async') {
+        throw new AdapterError('UnsupportedTsp', `content negotiation for ${method.kind} method ${method.name} isn't supported`, method.__raw?.node);
+      }
+      rustMethod.returns = new rust.Result(this.crate, this.adaptNegotiatedResponse(rustClient, rustMethod, method.name, negotiatedContentTypes));
+    } else if (method.kind === 'paging') {
       if (responseFormat !== 'JsonFormat' && responseFormat !== 'XmlFormat') {
         throw new AdapterError('InternalError', `paged method ${method.name} unexpected response format ${responseFormat}`, method.__raw?.node);
       }
@@ -2455,7 +2698,10 @@ export class Adapter {
     rustMethod.statusCodes.sort((a, b) => a - b);
 
     const responseHeadersMap = this.adaptResponseHeaders(responseHeaders);
-    rustMethod.responseHeaders = this.adaptResponseHeadersTrait(rustClient, rustMethod, Array.from(responseHeadersMap.values()));
+    if (!negotiatedContentTypes) {
+      // headers for negotiated responses are available from each variant's response
+      rustMethod.responseHeaders = this.adaptResponseHeadersTrait(rustClient, rustMethod, Array.from(responseHeadersMap.values()));
+    }
 
     if (method.kind === 'paging') {
       // can't do this until the method has been completely adapted
@@ -2473,6 +2719,470 @@ export class Adapter {
         }
       }
     }
+
+    rustMethod.idempotent = this.isIdempotentMethod(method, rustMethod);
+    if (this.crate.idempotencyContext && !rustMethod.idempotent) {
+      // non-idempotent requests are only retried when the caller opts in
+      if (methodOptionsStruct.fields.find((field) => field.name === 'allow_retry')) {
+        throw new AdapterError('NameCollision', `allow_retry field collides with an existing field in ${methodOptionsStruct.name}`, method.__raw?.node);
+      }
+      const allowRetryField = new rust.StructField('allow_retry', pub, new rust.Scalar('bool', false));
+      allowRetryField.docs.summary = 'Allows retry policies to retry the request even though the operation isn\'t idempotent.';
+      methodOptionsStruct.fields.push(allowRetryField);
+    }
+
+    for (const example of method.operation.examples ?? []) {
+      rustMethod.examples.push(this.adaptMethodExample(example, exampleParams));
+    }
+
+    if (rustMethod.kind === 'async') {
+      // can't do these until the method has been completely adapted
+      this.adaptStreamingUploadMethod(rustClient, rustMethod);
+      this.adaptResumableDownloadMethod(rustClient, rustMethod);
+      if (this.options['emit-request-builders']) {
+        this.adaptRequestBuilderMethod(rustClient, rustMethod);
+      }
+    }
+
+    if (this.crate.type === 'azure-arm') {
+      this.adaptResourceIdMethod(rustClient, rustMethod, method.decorators);
+    }
+  }
+
+  /**
+   * adapts a tcgc operation example. only the first example response is kept.
+   *
+   * @param example the example to adapt
+   * @param exampleParams maps operation params to their Rust method params
+   * @returns the adapted example
+   */
+  private adaptMethodExample(example: tcgc.SdkHttpOperationExample, exampleParams: Map<tcgc.SdkHttpParameter, Array<rust.MethodParameter>>): rust.MethodExample {
+    const rustExample = new rust.MethodExample(example.name);
+    for (const param of example.parameters) {
+      const value = this.adaptExampleValue(param.value);
+      if (param.parameter.kind === 'body') {
+        rustExample.requestBody = value;
+      }
+
+      for (const rustParam of exampleParams.get(param.parameter) ?? []) {
+        if (rustParam.kind !== 'partialBody') {
+          rustExample.params.set(rustParam, value);
+        } else if (value !== null && typeof value === 'object' && !Array.isArray(value) && value[rustParam.serde] !== undefined) {
+          // spread params are fields within the body
+          rustExample.params.set(rustParam, value[rustParam.serde]);
+        }
+      }
+    }
+
+    if (example.responses.length > 0) {
+      const response = example.responses[0];
+      rustExample.response = new rust.ExampleResponse(response.statusCode);
+      for (const header of response.headers) {
+        rustExample.response.headers.set(header.header.serializedName, String(this.adaptExampleValue(header.value)));
+      }
+      if (response.bodyValue) {
+        rustExample.response.body = this.adaptExampleValue(response.bodyValue);
+      }
+    }
+
+    return rustExample;
+  }
+
+  /**
+   * converts a tcgc example value to its JSON form
+   *
+   * @param example the example value to convert
+   * @returns the JSON form of the value
+   */
+  private adaptExampleValue(example: tcgc.SdkExampleValue): rust.ExampleValue {
+    switch (example.kind) {
+      case 'array':
+        return example.value.map((item) => this.adaptExampleValue(item));
+      case 'dict': {
+        const dict: Record<string, rust.ExampleValue> = {};
+        for (const [key, value] of Object.entries(example.value)) {
+          dict[key] = this.adaptExampleValue(value);
+        }
+        return dict;
+      }
+      case 'model': {
+        // the values are keyed by property so map them to their names over the wire
+        const properties = new Array<tcgc.SdkModelPropertyType>();
+        for (let model: tcgc.SdkModelType | undefined = example.type; model; model = model.baseModel) {
+          properties.push(...model.properties);
+        }
+        const obj: Record<string, rust.ExampleValue> = {};
+        for (const [key, value] of Object.entries(example.value)) {
+          const property = properties.find((each) => each.name === key);
+          const serde = property ? this.getSerializedPropertyName(property) : undefined;
+          obj[serde ?? key] = this.adaptExampleValue(value);
+        }
+        for (const [key, value] of Object.entries(example.additionalPropertiesValue ?? {})) {
+          obj[key] = this.adaptExampleValue(value);
+        }
+        return obj;
+      }
+      case 'union':
+      case 'unknown':
+        return <rust.ExampleValue>example.value;
+      default:
+        return example.value;
+    }
+  }
+
+  /**
+   * creates a companion method for an ARM operation that takes its path
+   * params from a ResourceId. the companion validates the resource ID
+   * against the operation's path template and calls the operation.
+   *
+   * @param rustClient the client to which the method belongs
+   * @param rustMethod the fully adapted method for which to create the companion
+   * @param decorators the decorators of the tcgc method
+   */
+  private adaptResourceIdMethod(rustClient: rust.Client, rustMethod: MethodType, decorators: Array<tcgc.DecoratorInfo>): void {
+    const pathParams = new Array<rust.PathScalarParameter>();
+    for (const param of rustMethod.params) {
+      if (param.kind === 'pathCollection' || param.kind === 'pathHashMap') {
+        return;
+      } else if (param.kind !== 'pathScalar') {
+        continue;
+      }
+
+      // reserved expansion (e.g. a resource URI) can span multiple segments
+      const paramType = param.type.kind === 'ref' ? param.type.type : param.type;
+      if (param.optional || param.group || param.encoded || param.style !== 'simple' || (paramType.kind !== 'str' && paramType.kind !== 'String')) {
+        return;
+      }
+      pathParams.push(param);
+    }
+
+    if (!pathParams.some((param) => param.location === 'method')) {
+      // the operation doesn't address a resource
+      return;
+    }
+
+    const methodName = `${rustMethod.name}_by_id`;
+    if (rustClient.methods.find((existingMethod) => existingMethod.name === methodName)) {
+      throw new AdapterError('NameCollision', `resource ID method ${methodName} collides with an existing method`);
+    } else if (rustMethod.params.find((param) => param.name === 'resource_id')) {
+      throw new AdapterError('NameCollision', `resource ID method ${methodName} has a parameter named resource_id`);
+    }
+
+    const resourceId = new rust.Parameter('resource_id', new rust.Ref(new rust.ExternalType(this.crate, 'ResourceId', 'crate::generated::models')));
+    resourceId.docs.summary = `The resource ID from which the path parameters are taken. It must match the start of the path \`${rustMethod.httpPath}\`.`;
+
+    // the options and returned type are shared with the called method
+    const resourceIdMethod = new rust.ResourceIdMethod(methodName, rustClient, resourceId, rustMethod);
+    resourceIdMethod.docs = {
+      summary: `Calls ${this.asDocLink(`${rustClient.name}::${rustMethod.name}()`, `${utils.buildImportPath(rustClient.module, rustClient.module)}::clients::${rustClient.name}::${rustMethod.name}()`)} with its path parameters taken from a resource ID.`,
+    };
+    for (const param of rustMethod.params) {
+      if (!pathParams.includes(<rust.PathScalarParameter>param)) {
+        resourceIdMethod.params.push(param);
+      }
+    }
+    resourceIdMethod.statusCodes = rustMethod.statusCodes;
+    rustClient.methods.push(resourceIdMethod);
+
+    const armResourceOperationKind = this.options['emit-arm-resource-handles'] ? getArmResourceOperationKind(decorators) : undefined;
+    if (armResourceOperationKind) {
+      this.armResourceOperations.push({ client: rustClient, kind: armResourceOperationKind, method: resourceIdMethod });
+    }
+  }
+
+  /**
+   * populates the ARM endpoints of the known clouds. the endpoint
+   * for the public cloud comes from the @server decorator when it
+   * has a default value. the arm-cloud-endpoints option can replace
+   * the defaults. other clouds are reached through CloudConfiguration::Custom
+   * so keys must name one of the known variants.
+   */
+  private adaptCloudEndpoints(): void {
+    const endpoints = new Map<string, string>([
+      ['AzurePublic', this.armServerEndpoint ?? 'https://management.azure.com'],
+      ['AzureGovernment', 'https://management.usgovcloudapi.net'],
+      ['AzureChina', 'https://management.chinacloudapi.cn'],
+    ]);
+
+    for (const [cloud, endpoint] of Object.entries(this.options['arm-cloud-endpoints'] ?? {})) {
+      if (!endpoints.has(cloud)) {
+        throw new AdapterError('InvalidArgument', `arm-cloud-endpoints key ${cloud} isn't one of the CloudConfiguration variants ${Array.from(endpoints.keys()).join(', ')}. Other clouds use CloudConfiguration::Custom`);
+      } else if (!endpoint.match(/^https?:\/\/[^/]+/)) {
+        throw new AdapterError('InvalidArgument', `arm-cloud-endpoints value ${endpoint} for ${cloud} isn't an http(s) URL`);
+      }
+      endpoints.set(cloud, endpoint);
+    }
+
+    for (const [cloud, endpoint] of endpoints) {
+      this.crate.cloudEndpoints.push(new rust.CloudEndpoint(cloud, endpoint.replace(/\/+$/, '')));
+    }
+  }
+
+  /**
+   * builds the handle and collection types for ARM resources from
+   * the resource ID methods of the resource lifecycle operations.
+   * resources are keyed by the path template of a resource instance.
+   */
+  private adaptArmResources(): void {
+    const operationsByPath = new Map<string, Array<rust.ArmResourceOperation>>();
+    const listOperations = new Array<rust.ArmResourceOperation>();
+    const rootClients = new Map<rust.ArmResourceOperation, rust.Client>();
+
+    for (const armResourceOperation of this.armResourceOperations) {
+      // the handles hold the client that creates the operation group clients
+      const rootClient = armResourceOperation.client.parent ?? armResourceOperation.client;
+      let accessor: rust.ClientAccessor | undefined;
+      if (rootClient.parent) {
+        continue;
+      } else if (rootClient !== armResourceOperation.client) {
+        accessor = <rust.ClientAccessor | undefined>rootClient.methods.find((method) => method.kind === 'clientaccessor' && method.returns === armResourceOperation.client);
+        if (!accessor || accessor.params.length > 0) {
+          // the handle has no values for the accessor's params
+          continue;
+        }
+      }
+
+      const operation = new rust.ArmResourceOperation(armResourceOperation.kind, armResourceOperation.method, accessor);
+      rootClients.set(operation, rootClient);
+      if (operation.kind === 'list') {
+        listOperations.push(operation);
+        continue;
+      }
+
+      let operations = operationsByPath.get(operation.method.httpPath);
+      if (!operations) {
+        operations = new Array<rust.ArmResourceOperation>();
+        operationsByPath.set(operation.method.httpPath, operations);
+      }
+      operations.push(operation);
+    }
+
+    const armResources = new Map<string, rust.ArmResource>();
+    const parentPaths = new Map<rust.ArmResource, string>();
+    for (const [path, operations] of operationsByPath) {
+      // the read operation returns the resource's model
+      const read = operations.find((operation) => operation.kind === 'read');
+      if (!read || read.method.returns.type.kind !== 'response' || read.method.returns.type.content.kind !== 'model') {
+        continue;
+      }
+
+      const segments = path.split('/').filter((segment) => segment !== '');
+      const isParam = (segment: string): boolean => segment.startsWith('{');
+      let end = segments.length - 1;
+      const singletonName = isParam(segments[end]) ? undefined : segments[end];
+      const resourceType = segments[--end];
+      if (end < 1 || isParam(resourceType)) {
+        continue;
+      }
+
+      // the literals between the parent's name and the resource's type
+      let start = end;
+      while (start > 0 && !isParam(segments[start - 1])) {
+        --start;
+      }
+      const scope = segments.slice(start, end);
+      if (start === 0 || scope.length % 2 !== 0) {
+        // tenant-level resources have no parent ID for their collection
+        continue;
+      }
+
+      const model = read.method.returns.type.content;
+      const rootClient = rootClients.get(read)!;
+      const handleName = `${model.name}Handle`;
+      if (rootClient.module.armResources.find((armResource) => armResource.name === handleName)) {
+        this.ctx.program.reportDiagnostic({
+          code: 'DuplicateArmResource',
+          severity: 'warning',
+          message: `skip emitting handle for resource path ${path} as ${handleName} already exists`,
+          target: tsp.NoTarget,
+        });
+        continue;
+      }
+
+      const armResource = new rust.ArmResource(handleName, model, path, rootClient, scope, resourceType);
+      armResource.docs.summary = `A handle to a ${this.asDocLink(model.name, `${utils.buildImportPath(rootClient.module, model.module)}::models::${model.name}`)} resource.`;
+      armResource.docs.description = 'Creating a handle doesn\'t send any requests. The resource ID is validated when an operation is called.';
+      armResource.singletonName = singletonName;
+      armResource.operations = operations.filter((operation) => rootClients.get(operation) === rootClient);
+      if (!singletonName) {
+        armResource.collection = new rust.ArmResourceCollection(`${model.name}Collection`);
+        armResource.collection.docs.summary = `The ${this.asDocLink(model.name, `${utils.buildImportPath(rootClient.module, model.module)}::models::${model.name}`)} resources contained in a parent resource or scope.`;
+        const listPath = `/${segments.slice(0, segments.length - 1).join('/')}`;
+        armResource.collection.list = listOperations.find((operation) => operation.method.httpPath === listPath && rootClients.get(operation) === rootClient);
+      }
+
+      for (const typeName of [armResource.name, armResource.collection?.name]) {
+        if (typeName && (rootClient.module.models.find((each) => each.name === typeName) || rootClient.module.clients.find((each) => each.name === typeName))) {
+          throw new AdapterError('NameCollision', `ARM resource type ${typeName} collides with an existing type`);
+        }
+      }
+
+      armResources.set(path, armResource);
+      parentPaths.set(armResource, `/${segments.slice(0, start).join('/')}`);
+      rootClient.module.armResources.push(armResource);
+    }
+
+    // link nested resources to their parents
+    for (const [armResource, parentPath] of parentPaths) {
+      const parent = armResources.get(parentPath);
+      if (!parent || parent.client !== armResource.client) {
+        continue;
+      }
+      const childName = utils.snakeCaseName(armResource.singletonName ? armResource.model.name : armResource.type);
+      parent.children.push(new rust.ArmResourceChild(childName, armResource));
+    }
+
+    for (const armResource of armResources.values()) {
+      // the handle and collection methods must be distinct
+      const handleMethodNames = ['id', 'new', ...armResource.operations.map((operation) => operation.method.method.name), ...armResource.children.map((child) => child.name)];
+      const duplicateHandleMethodName = handleMethodNames.find((name, i) => handleMethodNames.indexOf(name) !== i);
+      if (duplicateHandleMethodName) {
+        throw new AdapterError('NameCollision', `method ${duplicateHandleMethodName} on ${armResource.name} collides with another method`);
+      }
+
+      const createOrUpdate = armResource.operations.find((operation) => operation.kind === 'createOrUpdate');
+      if (armResource.collection && createOrUpdate && ['get', 'handle', 'list', 'new', 'parent'].includes(createOrUpdate.method.method.name)) {
+        throw new AdapterError('NameCollision', `method ${createOrUpdate.method.method.name} on ${armResource.collection.name} collides with another method`);
+      }
+    }
+  }
+
+  /**
+   * determines if sending the method's request more than once has the same
+   * effect as sending it once. the idempotent clientOption decorator takes
+   * precedence, followed by the repeatability headers, and then the verb.
+   *
+   * @param method the tcgc method
+   * @param rustMethod the Rust method with its params adapted
+   * @returns true if the method is idempotent
+   */
+  private isIdempotentMethod(method: tcgc.SdkServiceMethod<tcgc.SdkHttpOperation>, rustMethod: MethodType): boolean {
+    const idempotentOption = method.decorators.find(
+      d => d.name === 'Azure.ClientGenerator.Core.@clientOption'
+        && d.arguments['name'] === 'idempotent'
+    )?.arguments['value'];
+    if (typeof idempotentOption === 'boolean') {
+      return idempotentOption;
+    }
+
+    // the service de-duplicates requests with a repeatability request ID
+    if (rustMethod.params.some((param) => param.kind === 'headerScalar' && param.header.toLowerCase() === 'repeatability-request-id')) {
+      return true;
+    }
+
+    // the default is based on the verb
+    return rustMethod.idempotent;
+  }
+
+  /**
+   * creates a companion method for an async method that builds its request
+   * without sending it. this allows callers to collect the requests of many
+   * operations, e.g. when sending them as a multipart/mixed batch.
+   *
+   * @param rustClient the client to which the method belongs
+   * @param rustMethod the fully adapted method for which to create the companion
+   */
+  private adaptRequestBuilderMethod(rustClient: rust.Client, rustMethod: rust.AsyncMethod): void {
+    const methodName = `${rustMethod.name}_request`;
+    if (rustClient.methods.find((existingMethod) => existingMethod.name === methodName)) {
+      throw new AdapterError('NameCollision', `request builder method ${methodName} collides with an existing method`);
+    }
+
+    // the options and params are shared with the original method
+    const requestMethod = new rust.RequestMethod(methodName, rustClient, rustMethod);
+    requestMethod.docs = {
+      summary: `Builds the request for ${this.asDocLink(`${rustClient.name}::${rustMethod.name}()`, `${utils.buildImportPath(rustClient.module, rustClient.module)}::clients::${rustClient.name}::${rustMethod.name}()`)} without sending it.`,
+      description: `The request can be ${rustClient.emitSendRequest ? `sent with ${this.asDocLink(`${rustClient.name}::send_request()`, `${utils.buildImportPath(rustClient.module, rustClient.module)}::clients::${rustClient.name}::send_request()`)} or ` : ''}added to a ${this.asDocLink('BatchRequest', `${utils.buildImportPath(rustClient.module, rustClient.module)}::models::BatchRequest`)}. Its sub-response is converted to the method's response type with ${this.asDocLink('BatchPart::into_response()', `${utils.buildImportPath(rustClient.module, rustClient.module)}::models::BatchPart::into_response()`)}.`,
+    };
+    requestMethod.returns = new rust.Result(this.crate, new rust.ExternalType(this.crate, 'Request', 'azure_core::http'));
+    requestMethod.statusCodes = rustMethod.statusCodes;
+    rustClient.methods.push(requestMethod);
+  }
+
+  /**
+   * creates a companion method for an async method with a required binary request body.
+   * the companion method streams the body from a seekable stream so the pipeline can
+   * rewind the body on retries without buffering it in memory.
+   *
+   * @param rustClient the client to which the method belongs
+   * @param rustMethod the fully adapted method for which to create the companion
+   */
+  private adaptStreamingUploadMethod(rustClient: rust.Client, rustMethod: rust.AsyncMethod): void {
+    const bodyParam = rustMethod.params.find((param) => param.kind === 'body');
+    if (!bodyParam || bodyParam.optional || bodyParam.type.content.kind !== 'bytes') {
+      return;
+    }
+
+    const methodName = `${rustMethod.name}_from_stream`;
+    if (rustClient.methods.find((existingMethod) => existingMethod.name === methodName)) {
+      throw new AdapterError('NameCollision', `streaming method ${methodName} collides with an existing method`);
+    }
+
+    // the options are shared with the buffered method
+    const streamMethod = new rust.AsyncMethod(methodName, rustMethod.languageIndependentName, rustClient, rustMethod.visibility, rustMethod.options, rustMethod.httpMethod, rustMethod.httpPath);
+    streamMethod.docs = {
+      summary: `Streaming variant of ${this.asDocLink(`${rustClient.name}::${rustMethod.name}()`, `${utils.buildImportPath(rustClient.module, rustClient.module)}::clients::${rustClient.name}::${rustMethod.name}()`)}.`,
+      description: 'The request body is read from a seekable stream and its length is sent as the content length. The stream is rewound when the request is retried.',
+    };
+
+    for (const param of rustMethod.params) {
+      if (param.kind === 'headerScalar' && param.header.toLowerCase() === 'content-length') {
+        // the content length is always the stream's length so the param is redundant
+        if (param.optional) {
+          streamMethod.docs.description += ' The content length in the options is ignored.';
+        }
+        continue;
+      } else if (param !== bodyParam) {
+        streamMethod.params.push(param);
+        continue;
+      }
+      const streamParam = new rust.StreamBodyParameter(bodyParam.name, bodyParam.location, false, new rust.SeekableStream(this.crate));
+      streamParam.docs = bodyParam.docs;
+      streamMethod.params.push(streamParam);
+    }
+
+    streamMethod.returns = rustMethod.returns;
+    streamMethod.statusCodes = rustMethod.statusCodes;
+    streamMethod.idempotent = rustMethod.idempotent;
+    streamMethod.responseHeaders = rustMethod.responseHeaders;
+    rustClient.methods.push(streamMethod);
+  }
+
+  /**
+   * creates a companion method for an async method that streams a binary response
+   * and accepts a range header. the companion method returns a byte stream that
+   * resumes the download from the last received byte after transient failures.
+   *
+   * @param rustClient the client to which the method belongs
+   * @param rustMethod the fully adapted method for which to create the companion
+   */
+  private adaptResumableDownloadMethod(rustClient: rust.Client, rustMethod: rust.AsyncMethod): void {
+    if (rustMethod.returns.type.kind !== 'asyncResponse') {
+      return;
+    }
+
+    const rangeParam = rustMethod.params.find((param) => param.kind === 'headerScalar' && param.header.match(/^(x-ms-)?range$/i));
+    if (!rangeParam || rangeParam.kind !== 'headerScalar') {
+      return;
+    }
+
+    const methodName = `${rustMethod.name}_resumable`;
+    if (rustClient.methods.find((existingMethod) => existingMethod.name === methodName)) {
+      throw new AdapterError('NameCollision', `resumable method ${methodName} collides with an existing method`);
+    }
+
+    // the options and params are shared with the original method
+    const resumableMethod = new rust.AsyncMethod(methodName, rustMethod.languageIndependentName, rustClient, rustMethod.visibility, rustMethod.options, rustMethod.httpMethod, rustMethod.httpPath);
+    resumableMethod.docs = {
+      summary: `Resumable variant of ${this.asDocLink(`${rustClient.name}::${rustMethod.name}()`, `${utils.buildImportPath(rustClient.module, rustClient.module)}::clients::${rustClient.name}::${rustMethod.name}()`)}.`,
+      description: `If reading the response body fails with a transient error, the request is reissued for the remaining bytes using the \`${rangeParam.header.toLowerCase()}\` and \`if-match\` headers.`,
+    };
+    resumableMethod.params.push(...rustMethod.params);
+    resumableMethod.returns = new rust.Result(this.crate, new rust.ResumableDownload(rustClient.module, rangeParam.header.toLowerCase()));
+    resumableMethod.statusCodes = rustMethod.statusCodes;
+    resumableMethod.idempotent = rustMethod.idempotent;
+    this.crate.addDependency(new rust.CrateDependency('futures'));
+    rustClient.methods.push(resumableMethod);
   }
 
   /**
@@ -2494,6 +3204,29 @@ export class Adapter {
           throw new AdapterError('InternalError', `unexpected response header collection ${header.serializedName}`, header.__raw.node);
         }
         responseHeader = new rust.ResponseHeaderHashMap(utils.snakeCaseName(header.name), lowerCasedHeader);
+      } else if (lowerCasedHeader === 'set-cookie') {
+        // a response can set multiple cookies so we always expose them
+        // as a Vec<String> regardless of how the header was declared.
+        responseHeader = new rust.ResponseHeaderScalar(utils.snakeCaseName(header.name), lowerCasedHeader, this.getVec(this.getStringType()));
+      } else if (header.type.kind === 'array') {
+        const elementType = this.typeToWireType(this.getType(header.type.valueType));
+        switch (elementType.kind) {
+          case 'enum':
+          case 'scalar':
+          case 'String':
+            break;
+          case 'offsetDateTime':
+            // rfc7231 dates contain commas so they can't be split from a comma-delimited list
+            if (elementType.encoding === 'rfc3339') {
+              break;
+            }
+            throw new AdapterError('UnsupportedTsp', `unsupported date-time encoding ${elementType.encoding} for response header collection ${header.serializedName}`, header.__raw.node);
+          default:
+            throw new AdapterError('UnsupportedTsp', `unsupported element kind ${elementType.kind} for response header collection ${header.serializedName}`, header.__raw.node);
+        }
+        // response headers don't have a collection format so we use
+        // the comma-delimited list format defined in RFC 9110
+        responseHeader = new rust.ResponseHeaderCollection(utils.snakeCaseName(header.name), lowerCasedHeader, this.getVec(elementType), 'csv');
       } else {
         const headerType = lowerCasedHeader.match(/^etag$/) ? this.getEtag() : this.typeToWireType(this.getType(header.type));
         responseHeader = new rust.ResponseHeaderScalar(utils.snakeCaseName(header.name), utils.fixETagName(lowerCasedHeader), headerType);
@@ -2505,6 +3238,64 @@ export class Adapter {
     return responseHeadersMap;
   }
 
+  /**
+   * adapts the responses for a method that returns different content types.
+   * the method's accept header param is replaced with a required selector
+   * enum and the method returns an enum with a variant per content type.
+   *
+   * @param rustClient the client that contains the method
+   * @param rustMethod the method whose responses are negotiated
+   * @param methodName the name of the tcgc method
+   * @param contentTypes the content types and their response body types
+   * @returns the negotiated response type
+   */
+  private adaptNegotiatedResponse(rustClient: rust.Client, rustMethod: rust.AsyncMethod, methodName: string, contentTypes: Array<NegotiatedContentType>): rust.NegotiatedResponse {
+    const typeNamePrefix = `${rustClient.name}${utils.pascalCase(methodName, false)}`;
+    const methodDocLink = this.asDocLink(`${rustClient.name}::${rustMethod.name}()`, `${utils.buildImportPath(rustClient.module, rustClient.module)}::clients::${rustClient.name}::${rustMethod.name}()`);
+
+    const acceptEnum = new rust.Enum(`${typeNamePrefix}Accept`, rustMethod.visibility, false, 'String', rustClient.module);
+    acceptEnum.docs.summary = `The media types that can be requested from ${methodDocLink}`;
+
+    const negotiatedResponse = new rust.NegotiatedResponse(`${typeNamePrefix}Response`, rustMethod.visibility, rustClient.module);
+    negotiatedResponse.docs.summary = `Contains the response from ${methodDocLink} for the requested media type`;
+
+    for (const contentType of contentTypes) {
+      const variantName = naming.fixUpEnumValueNameWorker(contentType.contentType.replace(/[^a-zA-Z0-9]+/g, '_'));
+      const acceptValue = new rust.EnumValue(variantName, acceptEnum, contentType.contentType);
+      acceptValue.docs.summary = `Requests \`${contentType.contentType}\` content.`;
+      acceptEnum.values.push(acceptValue);
+
+      let variantType: rust.RawResponse | rust.Response;
+      const format = contentType.type ? this.getPayloadFormatType(contentType.type, contentType.contentType) : 'NoFormat';
+      switch (format) {
+        case 'BinaryFormat':
+        case 'NoFormat':
+          // the caller is responsible for interpreting the raw body
+          variantType = new rust.RawResponse(this.crate);
+          break;
+        case 'FormFormat':
+          throw new AdapterError('UnsupportedTsp', `form-urlencoded response bodies for method ${rustMethod.name} aren't supported`);
+        default:
+          variantType = new rust.Response(this.crate, this.typeToWireType(this.getType(contentType.type!)), format);
+      }
+      negotiatedResponse.variants.push(new rust.NegotiatedResponseVariant(variantName, contentType.contentType, variantType));
+    }
+
+    rustClient.module.enums.push(acceptEnum);
+
+    // replace the constant accept header with the selector
+    const isAcceptParam = (param: rust.MethodParameter): boolean => param.kind === 'headerScalar' && param.header.toLowerCase() === 'accept';
+    const acceptParamNames = new Set(rustMethod.params.filter(isAcceptParam).map((param) => param.name));
+    rustMethod.params = rustMethod.params.filter((param) => !isAcceptParam(param));
+    rustMethod.options.type.type.fields = rustMethod.options.type.type.fields.filter((field) => !acceptParamNames.has(field.name));
+
+    const acceptParam = new rust.HeaderScalarParameter('accept', 'accept', 'method', false, acceptEnum);
+    acceptParam.docs.summary = 'The media type of the response content.';
+    rustMethod.params.push(acceptParam);
+
+    return negotiatedResponse;
+  }
+
   /**
    * creates a Rust ResponseHeadersTrait for the specified response headers.
    * if there are no response headers, undefined is returned.
@@ -2576,6 +3367,12 @@ export class Adapter {
             throw new AdapterError('InternalError', `unexpected trait impl type kind ${method.returns.type.type.kind}`);
         }
         break;
+      case 'negotiatedResponse':
+        // headers are available from each variant's response
+        throw new AdapterError('InternalError', `unexpected trait impl for negotiated response ${method.returns.type.name}`);
+      case 'resumableDownload':
+        // headers are available from the download's initial response
+        throw new AdapterError('InternalError', `unexpected trait impl for resumable download ${method.returns.type.name}`);
     }
 
     const traitName = `${recursiveTypeName(implFor.kind === 'asyncResponse' ? implFor.type : implFor.content)}Headers`;
@@ -2766,7 +3563,22 @@ export class Adapter {
       case 'body': {
         let requestType: rust.WireType;
         const requestFormatType = this.getPayloadFormatType(opParam.type, opParam.defaultContentType);
-        if (requestFormatType === 'BinaryFormat') {
+        if (requestFormatType === 'FormFormat') {
+          if (opParam.type.kind !== 'model' || paramType.kind !== 'model') {
+            throw new AdapterError('InternalError', `unexpected kind ${paramType.kind} for form-urlencoded body param ${opParam.name}`, opParam.__raw?.node);
+          }
+          const formParam = new rust.FormBodyParameter(paramName, paramLoc, paramOptional, paramType, new rust.RequestContent(this.crate, paramType, requestFormatType));
+          formParam.collectionFormats = this.getFormCollectionFormats(opParam.type, paramType);
+          adaptedParam = formParam;
+          break;
+        } else if (requestFormatType === 'Base64Format' || requestFormatType === 'Base64UrlFormat') {
+          if (paramType.kind !== 'encodedBytes') {
+            throw new AdapterError('InternalError', `unexpected kind ${paramType.kind} for encoded bytes body param ${opParam.name}`, opParam.__raw?.node);
+          }
+          // the bytes are encoded into the request body by the client method
+          adaptedParam = new rust.EncodedBytesBodyParameter(paramName, paramLoc, paramOptional, paramOptional ? paramType : <rust.Ref<rust.EncodedBytes>>this.getRefType(this.getEncodedBytes(paramType.encoding, true), lifetime));
+          break;
+        } else if (requestFormatType === 'BinaryFormat') {
           // binary format indicates a streaming binary request
           requestType = new rust.Bytes(this.crate);
         } else {
@@ -2778,8 +3590,18 @@ export class Adapter {
         break;
       }
       case 'cookie':
-        // TODO: https://github.com/Azure/typespec-rust/issues/192
-        throw new AdapterError('UnsupportedTsp', 'cookie parameters are not supported', opParam.__raw?.node);
+        paramType = this.typeToWireType(paramType);
+        switch (paramType.kind) {
+          case 'hashmap':
+          case 'jsonValue':
+          case 'model':
+          case 'slice':
+          case 'str':
+          case 'Vec':
+            throw new AdapterError('InternalError', `unexpected kind ${paramType.kind} for cookie ${opParam.serializedName}`, opParam.__raw?.node);
+        }
+        adaptedParam = new rust.CookieScalarParameter(paramName, opParam.serializedName, paramLoc, paramOptional, paramType);
+        break;
       case 'header':
         if (opParam.collectionFormat) {
           if (paramType.kind !== 'Vec' && !isRefSlice(paramType)) {
@@ -3029,9 +3851,77 @@ export class Adapter {
     const paramName = naming.getEscapedReservedName(utils.snakeCaseName(param.name), 'param');
     const paramLoc: rust.ParameterLocation = 'method';
     const adaptedParam = new rust.PartialBodyParameter(paramName, paramLoc, param.optional, serializedName, this.getType(param.type), new rust.RequestContent(this.crate, payloadType, format));
+    if (format === 'FormFormat') {
+      adaptedParam.collectionFormats = this.getFormCollectionFormats(opParamType, payloadType);
+    }
     return adaptedParam;
   }
 
+  /**
+   * validates that a model can be sent as an application/x-www-form-urlencoded
+   * body and returns the collection formats for any of its array fields.
+   *
+   * @param type the tcgc model that's sent in the request body
+   * @param model the Rust model for type
+   * @returns the collection formats keyed by the field's serde name
+   */
+  private getFormCollectionFormats(type: tcgc.SdkModelType, model: rust.Model): Map<string, rust.ExtendedCollectionFormat> {
+    const isFormScalar = (fieldType: rust.Type): boolean => {
+      switch (fieldType.kind) {
+        case 'decimal':
+        case 'enum':
+        case 'literal':
+        case 'offsetDateTime':
+        case 'safeint':
+        case 'scalar':
+        case 'String':
+          return true;
+        default:
+          return false;
+      }
+    };
+
+    const collectionFormats = new Map<string, rust.ExtendedCollectionFormat>();
+    for (const field of model.fields) {
+      if (field.kind === 'additionalProperties') {
+        throw new AdapterError('UnsupportedTsp', `additional properties in form-urlencoded body type ${model.name} aren't supported`, type.__raw?.node);
+      }
+
+      const fieldType = field.type.kind === 'option' ? field.type.type : field.type;
+      if (isFormScalar(fieldType)) {
+        continue;
+      } else if (fieldType.kind !== 'Vec' || !isFormScalar(fieldType.type)) {
+        throw new AdapterError('UnsupportedTsp', `field ${field.name} of kind ${fieldType.kind} in form-urlencoded body type ${model.name} isn't supported`, type.__raw?.node);
+      }
+
+      const property = type.properties.find((property) => property.kind === 'property' && (this.getSerializedPropertyName(property) ?? property.name) === field.serde);
+      if (property?.kind !== 'property') {
+        throw new AdapterError('InternalError', `didn't find property for field ${field.name} in type ${type.name}`, type.__raw?.node);
+      }
+
+      // arrays without an explicit encoding repeat the key for each item
+      let format: rust.ExtendedCollectionFormat;
+      switch (property.encode) {
+        case undefined:
+          format = 'multi';
+          break;
+        case 'commaDelimited':
+          format = 'csv';
+          break;
+        case 'pipeDelimited':
+          format = 'pipes';
+          break;
+        case 'spaceDelimited':
+          format = 'ssv';
+          break;
+        default:
+          throw new AdapterError('UnsupportedTsp', `array encoding ${property.encode} for field ${field.name} in form-urlencoded body type ${model.name} isn't supported`, property.__raw?.node);
+      }
+      collectionFormats.set(field.serde, format);
+    }
+    return collectionFormats;
+  }
+
   /**
    * determines the payload format type from serializationOptions on the type, falling
    * back to inspecting the defaultContentType when serializationOptions isn't available.
@@ -3041,7 +3931,11 @@ export class Adapter {
    * @returns a payload format type
    */
   private getPayloadFormatType(type: tcgc.SdkType | undefined, defaultContentType: string): rust.PayloadFormatType {
-    if (type?.kind === 'model') {
+    if (type?.kind === 'model' && defaultContentType.match(/x-www-form-urlencoded/i)) {
+      // form bodies are encoded with the url crate
+      this.crate.addDependency(new rust.CrateDependency('url'));
+      return 'FormFormat';
+    } else if (type?.kind === 'model') {
       const opts = type.serializationOptions;
       if (opts.json) {
         return 'JsonFormat';
@@ -3054,6 +3948,9 @@ export class Adapter {
     } else if (type?.kind === 'bytes' && type.encode === 'bytes') {
       // fallback: check if it's a bytes type with bytes encoding (binary stream)
       return 'BinaryFormat';
+    } else if (type?.kind === 'bytes' && defaultContentType.match(/json/i)) {
+      // whole-body bytes that are sent as a JSON string containing the encoded bytes
+      return type.encode === 'base64url' ? 'Base64UrlFormat' : 'Base64Format';
     }
 
     // tcgc doesn't yet have serializationOptions on types other
@@ -3064,6 +3961,9 @@ export class Adapter {
       // XML support is disabled by default
       this.crate.addDependency(new rust.CrateDependency('azure_core', ['xml']));
       return 'XmlFormat';
+    } else if (type && this.getType(type).kind === 'String' && defaultContentType.match(/^text\/|yaml|csv/i)) {
+      // textual payloads are exchanged as UTF-8 strings
+      return 'TextFormat';
     } else {
       return 'NoFormat';
     }
@@ -3083,6 +3983,15 @@ function isRefSlice(type: rust.Type): type is rust.Ref<rust.Slice> {
 /** method types that send/receive data */
 type MethodType = rust.AsyncMethod | rust.PageableMethod | rust.LroMethod;
 
+/** a content type and its body type for a method with negotiated responses */
+interface NegotiatedContentType {
+  /** the content type (e.g. application/json) */
+  contentType: string;
+
+  /** the type of the response body. undefined when there's no body */
+  type?: tcgc.SdkType;
+}
+
 /** supported kinds of tcgc scalars */
 type tcgcScalarKind = 'boolean' | 'float' | 'float32' | 'float64' | 'int16' | 'int32' | 'int64' | 'int8' | 'uint16' | 'uint32' | 'uint64' | 'uint8';
 
@@ -3166,6 +4075,34 @@ function getXMLName(decorators: Array<tcgc.DecoratorInfo>): string | undefined {
   return undefined;
 }
 
+/**
+ * returns the XML namespace based on the provided decorators
+ *
+ * @param decorators the decorators to enumerate
+ * @returns the XML namespace or undefined if there isn't one
+ */
+function getXMLNamespace(decorators: Array<tcgc.DecoratorInfo>): rust.XMLNamespace | undefined {
+  const decorator = decorators.find((each) => each.name === 'TypeSpec.Xml.@ns');
+  if (!decorator) {
+    return undefined;
+  }
+
+  const ns = decorator.arguments['ns'];
+  if (typeof ns === 'string') {
+    const prefix = decorator.arguments['prefix'];
+    return new rust.XMLNamespace(ns, typeof prefix === 'string' ? prefix : undefined);
+  }
+
+  // a member of an @nsDeclarations enum where the
+  // member's name is the prefix and its value is the URI
+  const member = <{ name?: unknown, value?: unknown } | undefined>ns;
+  if (typeof member?.name === 'string' && typeof member.value === 'string') {
+    return new rust.XMLNamespace(member.value, member.name);
+  }
+
+  throw new AdapterError('UnsupportedTsp', 'unexpected argument for @Xml.ns decorator');
+}
+
 /**
  * returns the XML-specific kind for field based on the provided decorators
  * 
@@ -3208,6 +4145,30 @@ function hasClientNameDecorator(decorators: Array<tcgc.DecoratorInfo>): boolean
   return decorators.find((decorator) => decorator.name === 'Azure.ClientGenerator.Core.@clientName') !== undefined;
 }
 
+/**
+ * returns the kind of ARM resource operation from the decorators of a tcgc method.
+ *
+ * @param decorators the decorators of the tcgc method
+ * @returns the kind of ARM resource operation or undefined if the method isn't one
+ */
+function getArmResourceOperationKind(decorators: Array<tcgc.DecoratorInfo>): rust.ArmResourceOperationKind | undefined {
+  for (const decorator of decorators) {
+    switch (decorator.name) {
+      case 'Azure.ResourceManager.@armResourceCreateOrUpdate':
+        return 'createOrUpdate';
+      case 'Azure.ResourceManager.@armResourceDelete':
+        return 'delete';
+      case 'Azure.ResourceManager.@armResourceList':
+        return 'list';
+      case 'Azure.ResourceManager.@armResourceRead':
+        return 'read';
+      case 'Azure.ResourceManager.@armResourceUpdate':
+        return 'update';
+    }
+  }
+  return undefined;
+}
+
 /**
  * narrows statusCode to a HttpStatusCodeRange within the conditional block
  * 
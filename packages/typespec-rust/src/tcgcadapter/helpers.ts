@@ -3,6 +3,9 @@
 *  Licensed under the MIT License. See License.txt in the project root for license information.
 *--------------------------------------------------------------------------------------------*/
 
+// cspell: ignore lropaging
+
+import * as tcgc from '@azure-tools/typespec-client-generator-core';
 import * as linkifyjs from 'linkifyjs';
 import turndownService from 'turndown';
 import * as rust from '../codemodel/index.js';
@@ -61,3 +64,43 @@ export function formatDocs(docs: string): string {
 
   return docs;
 }
+
+/**
+ * returns the path params shared by all methods of an ARM sub-client
+ * (e.g. the resource group and parent resource names) and the operation
+ * params whose values come from them.
+ *
+ * paging LROs aren't adapted so they don't narrow the scope params.
+ * their operation params are still classified so they use the client's
+ * values once they're supported.
+ *
+ * @param methods the methods of the tcgc sub-client
+ * @returns the scope params and the operation params they populate
+ */
+export function getArmScopeParams(methods: Array<tcgc.SdkServiceMethod<tcgc.SdkHttpOperation>>): { scopeParams: Array<tcgc.SdkPathParameter>, scopeOpParams: Array<tcgc.SdkPathParameter> } {
+  const isScopeParam = (opParam: tcgc.SdkHttpParameter): opParam is tcgc.SdkPathParameter => {
+    return opParam.kind === 'path' && !opParam.onClient && !opParam.optional && !opParam.allowReserved && opParam.type.kind === 'string'
+      && opParam.methodParameterSegments.every((segment) => segment.length === 1);
+  };
+
+  const adaptedMethods = methods.filter((method) => method.kind !== 'lropaging');
+  if (adaptedMethods.length === 0) {
+    return { scopeParams: [], scopeOpParams: [] };
+  }
+
+  let scopeParams = adaptedMethods[0].operation.parameters.filter(isScopeParam);
+  for (const method of adaptedMethods.slice(1)) {
+    scopeParams = scopeParams.filter((scopeParam) => method.operation.parameters.some((opParam) => isScopeParam(opParam) && opParam.name === scopeParam.name));
+  }
+
+  const scopeOpParams = new Array<tcgc.SdkPathParameter>();
+  for (const method of methods) {
+    for (const opParam of method.operation.parameters) {
+      if (isScopeParam(opParam) && scopeParams.some((scopeParam) => scopeParam.name === opParam.name)) {
+        scopeOpParams.push(opParam);
+      }
+    }
+  }
+
+  return { scopeParams, scopeOpParams };
+}
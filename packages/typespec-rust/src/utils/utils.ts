@@ -360,3 +360,14 @@ export function deduplicateClientFieldName(client: rust.Client, fieldName: strin
   }
   return `${snakeCaseName(clientName)}_${fieldName}`;
 }
+
+/**
+ * returns true if the method is part of its client's methods trait.
+ * only public methods that send a request are included.
+ * 
+ * @param method the method to check
+ * @returns true if the method belongs in the trait
+ */
+export function isClientTraitMethod(method: rust.MethodType): method is rust.AsyncMethod | rust.PageableMethod | rust.LroMethod {
+  return (method.kind === 'async' || method.kind === 'pageable' || method.kind === 'lro') && method.visibility === 'pub';
+}
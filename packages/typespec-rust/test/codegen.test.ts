@@ -8,7 +8,8 @@
 import * as rust from '../src/codemodel/index.js';
 import { CodeGenerator } from '../src/codegen/codeGenerator.js';
 import * as helpers from '../src/codegen/helpers.js';
-import { strictEqual } from 'assert';
+import { applyCustomizations, validateCustomizations } from '../src/customizations.js';
+import { deepStrictEqual, strictEqual, throws } from 'assert';
 import { describe, it } from 'vitest';
 
 function createClient(crate: rust.Crate, name: string): rust.Client {
@@ -320,4 +321,984 @@ describe('typespec-rust: codegen', () => {
     strictEqual(clientContent.includes('let res: DeleteStatus = json::from_json(&body)?;'), true);
     strictEqual(clientContent.includes('Ok(match res.status() {'), true);
   });
+
+  it('combines cookie params into a single Cookie header', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+    const client = createClient(crate, 'WidgetClient');
+    const lifetime = new rust.Lifetime('a');
+    const options = createMethodOptionsStruct(
+      crate,
+      'WidgetClientGetWidgetOptions',
+      new rust.ClientMethodOptions(crate, lifetime)
+    );
+    options.type.type.fields.push(new rust.StructField('theme', 'pub', new rust.Option(new rust.StringType())));
+
+    const method = new rust.AsyncMethod('get_widget', 'WidgetClient.getWidget', client, 'pub', options, 'get', '/widget');
+    method.params.push(new rust.CookieScalarParameter('session', 'session', 'method', false, new rust.StringType()));
+    method.params.push(new rust.CookieScalarParameter('theme', 'theme', 'method', true, new rust.StringType()));
+    method.returns = new rust.Result(crate, new rust.Response(crate, new rust.Unit(), 'NoFormat'));
+    method.statusCodes = [];
+    client.methods.push(method);
+
+    const clientContent = getClientContent(crate, 'generated/clients/widget_client.rs');
+
+    strictEqual(clientContent.includes('utf8_percent_encode'), true);
+    strictEqual(clientContent.includes('let mut cookies = vec![format!("session={}", utf8_percent_encode(&session, COOKIE_VALUE_ENCODE_SET))];'), true);
+    strictEqual(clientContent.includes('cookies.push(format!("theme={}", utf8_percent_encode(theme, COOKIE_VALUE_ENCODE_SET)));'), true);
+    strictEqual(clientContent.includes('const COOKIE_VALUE_ENCODE_SET: &AsciiSet = &CONTROLS'), true);
+    strictEqual(clientContent.includes(".add(b'\\\\');"), true);
+    strictEqual(crate.dependencies.some((dependency) => dependency.name === 'percent-encoding'), true);
+    strictEqual(clientContent.includes('request.insert_header("cookie", cookies.join("; "));'), true);
+  });
+
+  it('emits a client methods trait and mock', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+    const client = createClient(crate, 'WidgetClient');
+    client.methodsTrait = new rust.ClientMethodsTrait('WidgetClientMethods', 'MockWidgetClient');
+    crate.features.push('mock');
+    const lifetime = new rust.Lifetime('a');
+    const options = createMethodOptionsStruct(
+      crate,
+      'WidgetClientGetWidgetOptions',
+      new rust.ClientMethodOptions(crate, lifetime)
+    );
+
+    const method = new rust.AsyncMethod('get_widget', 'WidgetClient.getWidget', client, 'pub', options, 'get', '/widget');
+    method.returns = new rust.Result(crate, new rust.Response(crate, new rust.Unit(), 'NoFormat'));
+    method.statusCodes = [];
+    client.methods.push(method);
+
+    const codegen = new CodeGenerator(crate);
+    const files = codegen.emitContent();
+    const clientContent = files.find((file) => file.name === 'generated/clients/widget_client.rs')?.content ?? '';
+    const cargoToml = codegen.emitCargoToml();
+
+    strictEqual(clientContent.includes('use async_trait::async_trait;'), true);
+    strictEqual(clientContent.includes('pub trait WidgetClientMethods: Send + Sync {'), true);
+    strictEqual(clientContent.includes('impl WidgetClientMethods for WidgetClient {'), true);
+    strictEqual(clientContent.includes('#[cfg(feature = "mock")]'), true);
+    strictEqual(clientContent.includes('pub struct MockWidgetClient {'), true);
+    strictEqual(clientContent.includes('impl WidgetClientMethods for MockWidgetClient {'), true);
+    strictEqual(clientContent.includes('pub fn push_get_widget('), true);
+    strictEqual(cargoToml.includes('mock = []'), true);
+  });
+
+  it('emits a stub server for the client operations', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+    crate.stubServer = new rust.StubServer('test_crate_stub_server');
+    const client = createClient(crate, 'WidgetClient');
+    const lifetime = new rust.Lifetime('a');
+    const widget = new rust.Model('Widget', 'pub', rust.ModelFlags.Input | rust.ModelFlags.Output, crate);
+    crate.models.push(widget);
+
+    const getOptions = createMethodOptionsStruct(crate, 'WidgetClientGetWidgetOptions', new rust.ClientMethodOptions(crate, lifetime));
+    const getMethod = new rust.AsyncMethod('get_widget', 'WidgetClient.getWidget', client, 'pub', getOptions, 'get', '/widgets/{name}');
+    getMethod.returns = new rust.Result(crate, new rust.Response(crate, widget, 'JsonFormat'));
+    getMethod.statusCodes = [];
+    client.methods.push(getMethod);
+
+    const putOptions = createMethodOptionsStruct(crate, 'WidgetClientPutWidgetOptions', new rust.ClientMethodOptions(crate, lifetime));
+    const putMethod = new rust.AsyncMethod('put_widget', 'WidgetClient.putWidget', client, 'pub', putOptions, 'put', '/widgets/{name}');
+    putMethod.params.push(new rust.BodyParameter('widget', 'method', false, new rust.RequestContent(crate, widget, 'JsonFormat')));
+    putMethod.returns = new rust.Result(crate, new rust.Response(crate, new rust.Unit(), 'NoFormat'));
+    putMethod.statusCodes = [];
+    client.methods.push(putMethod);
+
+    const codegen = new CodeGenerator(crate);
+    const stubServer = codegen.emitStubServer();
+
+    strictEqual(stubServer?.cargoToml.includes('name = "test_crate_stub_server"'), true);
+    strictEqual(stubServer?.cargoToml.includes('test_crate = { path = ".." }'), true);
+    strictEqual(stubServer?.libRs.includes('use test_crate::models::Widget;'), true);
+    strictEqual(stubServer?.libRs.includes('pub trait WidgetClientHandler: Send + Sync {'), true);
+    strictEqual(stubServer?.libRs.includes('async fn get_widget(&self, _request: StubRequest) -> StubResponse<Widget> {'), true);
+    strictEqual(stubServer?.libRs.includes('async fn put_widget(&self, _request: StubRequest, _widget: Widget) -> StubResponse<()> {'), true);
+    strictEqual(stubServer?.libRs.includes('.route("/widgets/{name}", get(widget_client_get_widget::<H>).put(widget_client_put_widget::<H>))'), true);
+    strictEqual(stubServer?.libRs.includes('Path(path): Path<HashMap<String, String>>,'), true);
+    strictEqual(stubServer?.libRs.includes('fn raw_response('), false);
+  });
+
+  it('doesn\'t emit a stub server by default', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+    createClient(crate, 'WidgetClient');
+    strictEqual(new CodeGenerator(crate).emitStubServer(), undefined);
+  });
+
+  it('emits doc examples and example tests from method examples', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+    crate.exampleTests = true;
+    const client = createClient(crate, 'WidgetClient');
+    const clientOptions = new rust.Struct('WidgetClientOptions', 'pub');
+    clientOptions.fields.push(new rust.StructField('client_options', 'pub', new rust.ExternalType(crate, 'ClientOptions', 'azure_core::http')));
+    client.constructable = new rust.ClientConstruction(new rust.ClientOptions(clientOptions));
+    const ctor = new rust.Constructor('with_no_credential');
+    ctor.params.push(new rust.ClientEndpointParameter('endpoint'));
+    client.constructable.constructors.push(ctor);
+
+    const lifetime = new rust.Lifetime('a');
+    const widget = new rust.Model('Widget', 'pub', rust.ModelFlags.Input | rust.ModelFlags.Output, crate);
+    widget.fields.push(new rust.ModelField('color', 'color', 'pub', new rust.Option(new rust.StringType()), true));
+    widget.fields.push(new rust.ModelField('weight', 'weight', 'pub', new rust.Option(new rust.Scalar('f32', false)), true));
+    crate.models.push(widget);
+
+    const options = createMethodOptionsStruct(crate, 'WidgetClientPutWidgetOptions', new rust.ClientMethodOptions(crate, lifetime));
+    const method = new rust.AsyncMethod('put_widget', 'WidgetClient.putWidget', client, 'pub', options, 'put', '/widget');
+    method.params.push(new rust.HeaderScalarParameter('content_type', 'Content-Type', 'method', false, new rust.Literal(new rust.StringType(), 'application/json')));
+    const bodyParam = new rust.BodyParameter('widget', 'method', false, new rust.RequestContent(crate, widget, 'JsonFormat'));
+    method.params.push(bodyParam);
+    method.returns = new rust.Result(crate, new rust.Response(crate, widget, 'JsonFormat'));
+    method.statusCodes = [200];
+    const example = new rust.MethodExample('Put a red widget');
+    example.params.set(bodyParam, { color: 'red', weight: 2 });
+    example.requestBody = { color: 'red', weight: 2 };
+    example.response = new rust.ExampleResponse(200);
+    example.response.body = { color: 'red' };
+    method.examples.push(example);
+    // the unknown property means the example can't be expressed in Rust
+    const badExample = new rust.MethodExample('Put a widget with an unknown property');
+    badExample.params.set(bodyParam, { shape: 'square' });
+    method.examples.push(badExample);
+    client.methods.push(method);
+
+    const codegen = new CodeGenerator(crate);
+    const clientContent = codegen.emitContent().find((file) => file.name === 'generated/clients/widget_client.rs')?.content ?? '';
+    const exampleTests = codegen.emitExampleTests();
+
+    strictEqual(clientContent.includes('/// # Examples'), true);
+    strictEqual(clientContent.includes('/// use test_crate::models::Widget;'), true);
+    strictEqual(clientContent.includes('///     let response = client.put_widget(Widget { color: Some("red".to_string()), weight: Some(2.0) }.try_into()?, None).await?;'), true);
+    strictEqual(clientContent.includes('unknown property'), false);
+    strictEqual(exampleTests.length, 1);
+    strictEqual(exampleTests[0].name, 'tests/widget_client_examples.rs');
+    strictEqual(exampleTests[0].content.includes('async fn put_widget_put_a_red_widget() -> Result<()> {'), true);
+    strictEqual(exampleTests[0].content.includes('request_body: Some(r#"{"color":"red","weight":2}"#),'), true);
+    strictEqual(exampleTests[0].content.includes('method: Method::Put,'), true);
+    strictEqual(exampleTests[0].content.includes('path: Some("/widget"),'), true);
+    strictEqual(exampleTests[0].content.includes('query: &[],'), true);
+    strictEqual(exampleTests[0].content.includes('request_headers: &[("content-type", "application/json")],'), true);
+    strictEqual(exampleTests[0].content.includes('WidgetClient::with_no_credential("https://example.com", Some(options))'), true);
+  });
+
+  it('binds model HTTP metadata to the request and reads it from the response', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+    const client = createClient(crate, 'WidgetClient');
+    const lifetime = new rust.Lifetime('a');
+
+    // the adapter omits the @statusCode and @header properties from the response model's fields
+    const widget = new rust.Model('Widget', 'pub', rust.ModelFlags.Output, crate);
+    widget.fields.push(new rust.ModelField('name', 'name', 'pub', new rust.Option(new rust.StringType()), true));
+    crate.models.push(widget);
+    const getOptions = createMethodOptionsStruct(crate, 'WidgetClientGetWidgetOptions', new rust.ClientMethodOptions(crate, lifetime));
+    const getMethod = new rust.AsyncMethod('get_widget', 'WidgetClient.getWidget', client, 'pub', getOptions, 'get', '/widget');
+    const response = new rust.Response(crate, widget, 'JsonFormat');
+    getMethod.returns = new rust.Result(crate, response);
+    getMethod.statusCodes = [200];
+    getMethod.responseHeaders = new rust.ResponseHeadersTrait('WidgetHeaders', response, 'WidgetClient::get_widget()', 'pub', crate);
+    getMethod.responseHeaders.headers.push(new rust.ResponseHeaderScalar('etag', 'etag', new rust.StringType()));
+    getMethod.responseHeaders.headers.push(new rust.ResponseHeaderScalar('revision', 'x-ms-revision', new rust.Scalar('i32', false)));
+    client.methods.push(getMethod);
+
+    // and the @header and @query properties of the request model become method params
+    const updateOptions = createMethodOptionsStruct(crate, 'WidgetClientUpdateWidgetOptions', new rust.ClientMethodOptions(crate, lifetime));
+    updateOptions.type.type.fields.push(new rust.StructField('dry_run', 'pub', new rust.Option(new rust.Scalar('bool', false))));
+    updateOptions.type.type.fields.push(new rust.StructField('if_match', 'pub', new rust.Option(new rust.StringType())));
+    const updateMethod = new rust.AsyncMethod('update_widget', 'WidgetClient.updateWidget', client, 'pub', updateOptions, 'put', '/widget');
+    updateMethod.params.push(new rust.HeaderScalarParameter('if_match', 'if-match', 'method', true, new rust.StringType()));
+    updateMethod.params.push(new rust.QueryScalarParameter('dry_run', 'dry-run', 'method', true, new rust.Scalar('bool', false), true));
+    updateMethod.returns = new rust.Result(crate, new rust.Response(crate, new rust.Unit(), 'NoFormat'));
+    updateMethod.statusCodes = [204];
+    client.methods.push(updateMethod);
+
+    const files = new CodeGenerator(crate).emitContent();
+    const clientContent = files.find((file) => file.name === 'generated/clients/widget_client.rs')?.content ?? '';
+    const headerTraits = files.find((file) => file.name === 'generated/models/header_traits.rs')?.content ?? '';
+    const models = files.find((file) => file.name === 'generated/models/models.rs')?.content ?? '';
+
+    strictEqual(clientContent.includes('if let Some(dry_run) = options.dry_run {'), true);
+    strictEqual(clientContent.includes('query_builder.set_pair("dry-run", dry_run.to_string());'), true);
+    strictEqual(clientContent.includes('if let Some(if_match) = options.if_match.as_ref() {'), true);
+    strictEqual(clientContent.includes('request.insert_header("if-match", if_match);'), true);
+    strictEqual(headerTraits.includes('impl WidgetHeaders for Response<Widget> {'), true);
+    strictEqual(headerTraits.includes('fn revision(&self) -> Result<Option<i32>>'), true);
+    strictEqual(models.includes('pub etag'), false);
+  });
+
+  it('splits response header collections into a Vec', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+    const client = createClient(crate, 'WidgetClient');
+    const lifetime = new rust.Lifetime('a');
+    const options = createMethodOptionsStruct(
+      crate,
+      'WidgetClientGetWidgetOptions',
+      new rust.ClientMethodOptions(crate, lifetime)
+    );
+
+    const method = new rust.AsyncMethod('get_widget', 'WidgetClient.getWidget', client, 'pub', options, 'get', '/widget');
+    const markerType = new rust.MarkerType('WidgetClientGetWidgetResult', 'pub');
+    const response = new rust.Response(crate, markerType, 'NoFormat');
+    method.returns = new rust.Result(crate, response);
+    method.statusCodes = [];
+    method.responseHeaders = new rust.ResponseHeadersTrait('WidgetClientGetWidgetResultHeaders', response, 'WidgetClientGetWidgetResult', 'pub', crate);
+    method.responseHeaders.headers.push(new rust.ResponseHeaderCollection('allowed_methods', 'x-ms-allowed-methods', new rust.Vector(new rust.StringType()), 'csv'));
+    client.methods.push(method);
+
+    const codegen = new CodeGenerator(crate);
+    const headerTraits = codegen.emitContent().find((file) => file.name === 'generated/models/header_traits.rs');
+
+    strictEqual(headerTraits?.content.includes('fn allowed_methods(&self) -> Result<Option<Vec<String>>>'), true);
+    strictEqual(headerTraits?.content.includes('h.as_str().split(",").map(|v| v.trim()).map(str::parse).collect::<std::result::Result<Vec<_>, _>>()'), true);
+  });
+
+  it('raw endpoint constructors skip the supplemental endpoint', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+    const client = createClient(crate, 'WidgetClient');
+    const clientOptions = new rust.Struct('WidgetClientOptions', 'pub');
+    clientOptions.fields.push(new rust.StructField('client_options', 'pub', new rust.ExternalType(crate, 'ClientOptions', 'azure_core::http')));
+    client.constructable = new rust.ClientConstruction(new rust.ClientOptions(clientOptions));
+    client.constructable.endpoint = new rust.SupplementalEndpoint('{region}/widgets');
+    const regionParam = new rust.ClientSupplementalEndpointParameter('region', new rust.StringType(), false, 'region');
+    client.constructable.endpoint.parameters.push(regionParam);
+
+    const ctor = new rust.Constructor('with_no_credential');
+    ctor.params.push(new rust.ClientEndpointParameter('endpoint'), regionParam);
+    const rawEndpointCtor = new rust.Constructor('with_no_credential_and_endpoint_url');
+    rawEndpointCtor.rawEndpoint = true;
+    rawEndpointCtor.params.push(new rust.ClientEndpointParameter('endpoint'));
+    client.constructable.constructors.push(ctor, rawEndpointCtor);
+
+    const clientContent = getClientContent(crate, 'generated/clients/widget_client.rs');
+    const rawCtorContent = clientContent.substring(clientContent.indexOf('pub fn with_no_credential_and_endpoint_url('));
+
+    strictEqual(clientContent.includes('host = host.replace("{region}", &utf8_percent_encode(&region, ENDPOINT_HOST_ENCODE_SET).to_string());'), true);
+    strictEqual(rawCtorContent.includes('let endpoint = Url::parse(endpoint)?;'), true);
+    strictEqual(rawCtorContent.includes('host.replace'), false);
+  });
+
+  it('validates and encodes supplemental endpoint params', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+    const client = createClient(crate, 'WidgetClient');
+    const clientOptions = new rust.Struct('WidgetClientOptions', 'pub');
+    clientOptions.fields.push(new rust.StructField('client_options', 'pub', new rust.ExternalType(crate, 'ClientOptions', 'azure_core::http')));
+    client.constructable = new rust.ClientConstruction(new rust.ClientOptions(clientOptions));
+    client.constructable.endpoint = new rust.SupplementalEndpoint('{tenant}/widgets');
+    const tenantParam = new rust.ClientSupplementalEndpointParameter('tenant', new rust.StringType(), false, 'tenant');
+    client.constructable.endpoint.parameters.push(tenantParam);
+
+    const ctor = new rust.Constructor('with_no_credential');
+    ctor.params.push(new rust.ClientEndpointParameter('endpoint'), tenantParam);
+    client.constructable.constructors.push(ctor);
+
+    const clientContent = getClientContent(crate, 'generated/clients/widget_client.rs');
+
+    strictEqual(clientContent.includes('const ENDPOINT_HOST_ENCODE_SET: &AsciiSet = &CONTROLS'), true);
+    strictEqual(clientContent.includes(".add(b':')"), true);
+    strictEqual(clientContent.includes('ENDPOINT_PATH_ENCODE_SET'), false);
+    strictEqual(clientContent.includes('if matches!(tenant.as_str(), "" | "." | "..") {'), true);
+    strictEqual(clientContent.includes('"tenant must not be empty or a dot segment"'), true);
+    strictEqual(crate.dependencies.some((dependency) => dependency.name === 'percent-encoding'), true);
+  });
+
+  it('encodes supplemental endpoint path segments with the path encode set', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+    const client = createClient(crate, 'WidgetClient');
+    const clientOptions = new rust.Struct('WidgetClientOptions', 'pub');
+    clientOptions.fields.push(new rust.StructField('client_options', 'pub', new rust.ExternalType(crate, 'ClientOptions', 'azure_core::http')));
+    client.constructable = new rust.ClientConstruction(new rust.ClientOptions(clientOptions));
+    client.constructable.endpoint = new rust.SupplementalEndpoint('widgets/{kind}/{shape}');
+    const kindParam = new rust.ClientSupplementalEndpointParameter('kind', new rust.Enum('WidgetKind', 'pub', true, 'String', crate), false, 'kind');
+    const shapeParam = new rust.ClientSupplementalEndpointParameter('shape', new rust.Enum('WidgetShape', 'pub', false, 'String', crate), false, 'shape');
+    client.constructable.endpoint.parameters.push(kindParam, shapeParam);
+
+    const ctor = new rust.Constructor('with_no_credential');
+    ctor.params.push(new rust.ClientEndpointParameter('endpoint'), kindParam, shapeParam);
+    client.constructable.constructors.push(ctor);
+
+    const clientContent = getClientContent(crate, 'generated/clients/widget_client.rs');
+
+    strictEqual(clientContent.includes('const ENDPOINT_PATH_ENCODE_SET: &AsciiSet = &CONTROLS'), true);
+    strictEqual(clientContent.includes('ENDPOINT_HOST_ENCODE_SET'), false);
+    strictEqual(clientContent.includes('host = host.replace("{kind}", &utf8_percent_encode(kind.as_ref(), ENDPOINT_PATH_ENCODE_SET).to_string());'), true);
+    // extensible enums can hold arbitrary values so they're validated, fixed enums can't
+    strictEqual(clientContent.includes('if matches!(kind.as_ref(), "" | "." | "..") {'), true);
+    strictEqual(clientContent.includes('shape.as_ref(), ""'), false);
+  });
+
+  it('emits TextFormat for textual bodies', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+    const client = createClient(crate, 'WidgetClient');
+    const lifetime = new rust.Lifetime('a');
+    const options = createMethodOptionsStruct(
+      crate,
+      'WidgetClientGetTextOptions',
+      new rust.ClientMethodOptions(crate, lifetime)
+    );
+
+    const method = new rust.AsyncMethod('get_text', 'WidgetClient.getText', client, 'pub', options, 'get', '/text');
+    method.returns = new rust.Result(crate, new rust.Response(crate, new rust.StringType(), 'TextFormat'));
+    method.statusCodes = [200];
+    client.methods.push(method);
+
+    const codegen = new CodeGenerator(crate);
+    const files = codegen.emitContent();
+    const clientContent = files.find((file) => file.name === 'generated/clients/widget_client.rs')?.content ?? '';
+    const formats = files.find((file) => file.name === 'generated/models/formats.rs');
+
+    strictEqual(clientContent.includes('-> Result<Response<String, TextFormat>>'), true);
+    strictEqual(clientContent.includes('check_text_charset(rsp.headers())?;'), true);
+    strictEqual(formats?.content.includes('impl DeserializeWith<TextFormat> for String {'), true);
+  });
+
+  it('encodes form-urlencoded bodies in the client method', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+    const client = createClient(crate, 'WidgetClient');
+    const lifetime = new rust.Lifetime('a');
+    const options = createMethodOptionsStruct(
+      crate,
+      'WidgetClientSubmitOptions',
+      new rust.ClientMethodOptions(crate, lifetime)
+    );
+
+    const model = new rust.Model('SubmitRequest', 'pub', rust.ModelFlags.Input, crate);
+    model.fields.push(new rust.ModelField('name', 'name', 'pub', new rust.Option(new rust.StringType()), true));
+    model.fields.push(new rust.ModelField('scopes', 'scope', 'pub', new rust.Option(new rust.Vector(new rust.StringType())), true));
+    model.fields.push(new rust.ModelField('tags', 'tag', 'pub', new rust.Option(new rust.Vector(new rust.StringType())), true));
+    // field names that match locals in the method body must not shadow them
+    model.fields.push(new rust.ModelField('form_serializer', 'form_serializer', 'pub', new rust.Option(new rust.StringType()), true));
+    crate.models.push(model);
+
+    const method = new rust.AsyncMethod('submit', 'WidgetClient.submit', client, 'pub', options, 'post', '/submit');
+    const bodyParam = new rust.FormBodyParameter('body', 'method', false, model, new rust.RequestContent(crate, model, 'FormFormat'));
+    bodyParam.collectionFormats.set('scope', 'ssv');
+    method.params.push(bodyParam);
+    method.returns = new rust.Result(crate, new rust.Response(crate, new rust.Unit(), 'NoFormat'));
+    method.statusCodes = [204];
+    client.methods.push(method);
+
+    const clientContent = getClientContent(crate, 'generated/clients/widget_client.rs');
+
+    strictEqual(clientContent.includes('body: SubmitRequest,'), true);
+    strictEqual(clientContent.includes('if let Some(value) = &body.name {'), true);
+    strictEqual(clientContent.includes('form_serializer.append_pair("name", value);'), true);
+    strictEqual(clientContent.includes('form_serializer.append_pair("scope", &value.join(" "));'), true);
+    strictEqual(clientContent.includes('form_serializer.append_pair("tag", item);'), true);
+    strictEqual(clientContent.includes('if let Some(value) = &body.form_serializer {'), true);
+    strictEqual(clientContent.includes('form_serializer.append_pair("form_serializer", value);'), true);
+    strictEqual(clientContent.includes('let body: RequestContent<SubmitRequest, FormFormat> = form_serializer.finish().into_bytes().into();'), true);
+  });
+
+  it('maps negotiated responses to the variant for the content type', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+    const client = createClient(crate, 'WidgetClient');
+    const lifetime = new rust.Lifetime('a');
+    const options = createMethodOptionsStruct(
+      crate,
+      'WidgetClientGetAvatarOptions',
+      new rust.ClientMethodOptions(crate, lifetime)
+    );
+
+    const acceptEnum = new rust.Enum('WidgetClientGetAvatarAccept', 'pub', false, 'String', crate);
+    acceptEnum.values.push(new rust.EnumValue('ApplicationJson', acceptEnum, 'application/json'));
+    acceptEnum.values.push(new rust.EnumValue('ImagePng', acceptEnum, 'image/png'));
+    crate.enums.push(acceptEnum);
+
+    const model = new rust.Model('Avatar', 'pub', rust.ModelFlags.Output, crate);
+    model.fields.push(new rust.ModelField('url', 'url', 'pub', new rust.Option(new rust.StringType()), true));
+    crate.models.push(model);
+
+    const negotiated = new rust.NegotiatedResponse('WidgetClientGetAvatarResponse', 'pub', crate);
+    negotiated.variants.push(new rust.NegotiatedResponseVariant('ApplicationJson', 'application/json', new rust.Response(crate, model, 'JsonFormat')));
+    negotiated.variants.push(new rust.NegotiatedResponseVariant('ImagePng', 'image/png', new rust.RawResponse(crate)));
+
+    const method = new rust.AsyncMethod('get_avatar', 'WidgetClient.getAvatar', client, 'pub', options, 'get', '/avatar');
+    method.params.push(new rust.HeaderScalarParameter('accept', 'accept', 'method', false, acceptEnum));
+    method.returns = new rust.Result(crate, negotiated);
+    method.statusCodes = [200];
+    client.methods.push(method);
+
+    const codegen = new CodeGenerator(crate);
+    const files = codegen.emitContent();
+    const clientContent = files.find((file) => file.name === 'generated/clients/widget_client.rs')?.content ?? '';
+    const responses = files.find((file) => file.name === 'generated/models/responses.rs');
+
+    strictEqual(clientContent.includes('-> Result<WidgetClientGetAvatarResponse>'), true);
+    strictEqual(clientContent.includes('"application/json" => {'), true);
+    strictEqual(clientContent.includes('Ok(WidgetClientGetAvatarResponse::ApplicationJson(rsp.into()))'), true);
+    strictEqual(clientContent.includes('Ok(WidgetClientGetAvatarResponse::ImagePng(rsp))'), true);
+    strictEqual(responses?.content.includes('pub enum WidgetClientGetAvatarResponse {'), true);
+    strictEqual(responses?.content.includes('ApplicationJson(Response<Avatar>),'), true);
+    strictEqual(responses?.content.includes('ImagePng(RawResponse),'), true);
+  });
+
+  it('emits XML namespace declarations and prefixed names', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+    const client = createClient(crate, 'WidgetClient');
+    const lifetime = new rust.Lifetime('a');
+    const options = createMethodOptionsStruct(
+      crate,
+      'WidgetClientPutEntryOptions',
+      new rust.ClientMethodOptions(crate, lifetime)
+    );
+
+    const model = new rust.Model('Entry', 'pub', rust.ModelFlags.Input, crate);
+    model.xmlName = 'entry';
+    model.xmlNamespace = new rust.XMLNamespace('http://www.w3.org/2005/Atom');
+    const title = new rust.ModelField('title', 'title', 'pub', new rust.Option(new rust.StringType()), true);
+    title.xmlNamespace = new rust.XMLNamespace('http://purl.org/dc/elements/1.1/', 'dc');
+    model.fields.push(title);
+    const lang = new rust.ModelField('lang', 'lang', 'pub', new rust.Option(new rust.StringType()), true);
+    lang.xmlKind = 'attribute';
+    lang.xmlNamespace = new rust.XMLNamespace('http://www.w3.org/XML/1998/namespace', 'xml');
+    model.fields.push(lang);
+    crate.models.push(model);
+
+    const method = new rust.AsyncMethod('put_entry', 'WidgetClient.putEntry', client, 'pub', options, 'put', '/entry');
+    method.params.push(new rust.BodyParameter('entry', 'method', false, new rust.RequestContent(crate, model, 'XmlFormat')));
+    method.returns = new rust.Result(crate, new rust.Response(crate, new rust.Unit(), 'NoFormat'));
+    method.statusCodes = [204];
+    client.methods.push(method);
+
+    const codegen = new CodeGenerator(crate);
+    const files = codegen.emitContent();
+    const models = files.find((file) => file.name === 'generated/models/models.rs')?.content ?? '';
+
+    strictEqual(models.includes('#[serde(into = "EntryWithXmlNamespaces")]'), true);
+    strictEqual(models.includes('#[serde(alias = "dc:title", rename(deserialize = "title", serialize = "dc:title"), skip_serializing_if = "Option::is_none")]'), true);
+    strictEqual(models.includes('#[serde(alias = "@xml:lang", rename(deserialize = "@lang", serialize = "@xml:lang"), skip_serializing_if = "Option::is_none")]'), true);
+    strictEqual(models.includes('#[serde(rename = "@xmlns")]\n    xmlns: &\'static str,'), true);
+    strictEqual(models.includes('#[serde(rename = "@xmlns:dc")]\n    xmlns_dc: &\'static str,'), true);
+    strictEqual(models.includes('xmlns_dc: "http://purl.org/dc/elements/1.1/",'), true);
+    strictEqual(models.includes('title: value.title,'), true);
+  });
+
+  it('encodes whole-body bytes as a JSON string', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+    const client = createClient(crate, 'WidgetClient');
+    const lifetime = new rust.Lifetime('a');
+    const uploadOptions = createMethodOptionsStruct(
+      crate,
+      'WidgetClientUploadOptions',
+      new rust.ClientMethodOptions(crate, lifetime)
+    );
+    const downloadOptions = createMethodOptionsStruct(
+      crate,
+      'WidgetClientDownloadOptions',
+      new rust.ClientMethodOptions(crate, lifetime)
+    );
+
+    const upload = new rust.AsyncMethod('upload', 'WidgetClient.upload', client, 'pub', uploadOptions, 'post', '/upload');
+    upload.params.push(new rust.EncodedBytesBodyParameter('value', 'method', false, new rust.Ref(new rust.EncodedBytes('url', true))));
+    upload.returns = new rust.Result(crate, new rust.Response(crate, new rust.Unit(), 'NoFormat'));
+    upload.statusCodes = [204];
+    client.methods.push(upload);
+
+    const download = new rust.AsyncMethod('download', 'WidgetClient.download', client, 'pub', downloadOptions, 'get', '/download');
+    download.returns = new rust.Result(crate, new rust.Response(crate, new rust.EncodedBytes('url', false), 'Base64UrlFormat'));
+    download.statusCodes = [200];
+    client.methods.push(download);
+
+    const codegen = new CodeGenerator(crate);
+    const files = codegen.emitContent();
+    const clientContent = files.find((file) => file.name === 'generated/clients/widget_client.rs')?.content ?? '';
+    const formats = files.find((file) => file.name === 'generated/models/formats.rs');
+
+    strictEqual(clientContent.includes('value: &[u8],'), true);
+    strictEqual(clientContent.includes('request.set_body(to_json(&base64::encode_url_safe(value))?);'), true);
+    strictEqual(clientContent.includes('-> Result<Response<Vec<u8>, Base64UrlFormat>>'), true);
+    strictEqual(formats?.content.includes('impl DeserializeWith<Base64UrlFormat> for Vec<u8> {'), true);
+    strictEqual(formats?.content.includes('base64::decode_url_safe(encoded)'), true);
+  });
+
+  it('streams binary request bodies from seekable streams', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+    const client = createClient(crate, 'WidgetClient');
+    const lifetime = new rust.Lifetime('a');
+    const uploadOptions = createMethodOptionsStruct(
+      crate,
+      'WidgetClientUploadOptions',
+      new rust.ClientMethodOptions(crate, lifetime)
+    );
+
+    const upload = new rust.AsyncMethod('upload', 'WidgetClient.upload', client, 'pub', uploadOptions, 'put', '/upload');
+    upload.params.push(new rust.BodyParameter('value', 'method', false, new rust.RequestContent(crate, new rust.Bytes(crate), 'NoFormat')));
+    upload.returns = new rust.Result(crate, new rust.Response(crate, new rust.Unit(), 'NoFormat'));
+    upload.statusCodes = [204];
+    client.methods.push(upload);
+
+    // the streaming companion shares the options with the buffered method
+    const uploadFromStream = new rust.AsyncMethod('upload_from_stream', 'WidgetClient.upload', client, 'pub', uploadOptions, 'put', '/upload');
+    uploadFromStream.params.push(new rust.StreamBodyParameter('value', 'method', false, new rust.SeekableStream(crate)));
+    uploadFromStream.returns = upload.returns;
+    uploadFromStream.statusCodes = upload.statusCodes;
+    client.methods.push(uploadFromStream);
+
+    const codegen = new CodeGenerator(crate);
+    const files = codegen.emitContent();
+    const clientContent = files.find((file) => file.name === 'generated/clients/widget_client.rs')?.content ?? '';
+    const optionsContent = files.find((file) => file.name === 'generated/models/method_options.rs')?.content ?? '';
+
+    strictEqual(clientContent.includes('value: Box<dyn SeekableStream>,'), true);
+    strictEqual(clientContent.includes('stream::SeekableStream'), true);
+    strictEqual(clientContent.includes('request.insert_header("content-length", value.len().to_string());'), true);
+    strictEqual(clientContent.includes('request.set_body(value);'), true);
+    strictEqual(optionsContent.split('pub struct WidgetClientUploadOptions').length, 2);
+  });
+
+  it('emits resumable downloads for ranged binary responses', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+    const client = createClient(crate, 'WidgetClient');
+    const lifetime = new rust.Lifetime('a');
+    const downloadOptions = createMethodOptionsStruct(
+      crate,
+      'WidgetClientDownloadOptions',
+      new rust.ClientMethodOptions(crate, lifetime)
+    );
+    const rangeParam = new rust.HeaderScalarParameter('range', 'range', 'method', true, new rust.StringType());
+    downloadOptions.type.type.fields.push(new rust.StructField('range', 'pub', new rust.Option(rangeParam.type)));
+
+    const download = new rust.AsyncMethod('download', 'WidgetClient.download', client, 'pub', downloadOptions, 'get', '/download');
+    download.params.push(rangeParam);
+    download.returns = new rust.Result(crate, new rust.AsyncResponse(crate, new rust.Unit()));
+    download.statusCodes = [200, 206];
+    client.methods.push(download);
+
+    const downloadResumable = new rust.AsyncMethod('download_resumable', 'WidgetClient.download', client, 'pub', downloadOptions, 'get', '/download');
+    downloadResumable.params.push(rangeParam);
+    downloadResumable.returns = new rust.Result(crate, new rust.ResumableDownload(crate, 'range'));
+    downloadResumable.statusCodes = download.statusCodes;
+    client.methods.push(downloadResumable);
+
+    const codegen = new CodeGenerator(crate);
+    const files = codegen.emitContent();
+    const clientContent = files.find((file) => file.name === 'generated/clients/widget_client.rs')?.content ?? '';
+    const resumableDownload = files.find((file) => file.name === 'generated/models/resumable_download.rs')?.content ?? '';
+
+    strictEqual(clientContent.includes('-> Result<ResumableDownload>'), true);
+    strictEqual(clientContent.includes('Ok(ResumableDownload::new(self.pipeline.clone(), ctx.into_owned(), request, rsp, "range"))'), true);
+    strictEqual(resumableDownload.includes('pub struct ResumableDownload {'), true);
+    strictEqual(resumableDownload.includes('request.insert_header(IF_MATCH, etag.clone());'), true);
+    strictEqual(resumableDownload.includes('impl Stream for ResumableDownload {'), true);
+  });
+
+  it('emits request builders and batch types', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+    const client = createClient(crate, 'WidgetClient');
+    const lifetime = new rust.Lifetime('a');
+    const getWidgetOptions = createMethodOptionsStruct(
+      crate,
+      'WidgetClientGetWidgetOptions',
+      new rust.ClientMethodOptions(crate, lifetime)
+    );
+
+    const getWidget = new rust.AsyncMethod('get_widget', 'WidgetClient.getWidget', client, 'pub', getWidgetOptions, 'get', '/widget');
+    getWidget.returns = new rust.Result(crate, new rust.Response(crate, new rust.Unit(), 'NoFormat'));
+    getWidget.statusCodes = [200];
+    client.methods.push(getWidget);
+
+    const getWidgetRequest = new rust.RequestMethod('get_widget_request', client, getWidget);
+    getWidgetRequest.returns = new rust.Result(crate, new rust.ExternalType(crate, 'Request', 'azure_core::http'));
+    getWidgetRequest.statusCodes = getWidget.statusCodes;
+    client.methods.push(getWidgetRequest);
+
+    const codegen = new CodeGenerator(crate);
+    const files = codegen.emitContent();
+    const clientContent = files.find((file) => file.name === 'generated/clients/widget_client.rs')?.content ?? '';
+    const batchContent = files.find((file) => file.name === 'generated/models/batch.rs')?.content ?? '';
+
+    // the request builder doesn't take the options as the method has no optional params
+    strictEqual(clientContent.includes('pub fn get_widget_request(&self) -> Result<Request> {'), true);
+    strictEqual(clientContent.includes('let _ = options;'), false);
+    strictEqual(clientContent.includes('Ok(request)'), true);
+    // request builders don't send anything so they aren't traced
+    strictEqual(clientContent.split('#[tracing::function("WidgetClient.getWidget")]').length, 2);
+    strictEqual(batchContent.includes('pub struct BatchRequest {'), true);
+    strictEqual(batchContent.includes('pub fn parse(content_type: &str, body: &[u8]) -> Result<Self> {'), true);
+    strictEqual(batchContent.includes('pub fn into_response<T, F>(self) -> Result<Response<T, F>> {'), true);
+  });
+
+  it('emits send_request on clients', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+    const client = createClient(crate, 'WidgetClient');
+    client.emitSendRequest = true;
+    const otherClient = createClient(crate, 'OtherClient');
+
+    const codegen = new CodeGenerator(crate);
+    const files = codegen.emitContent();
+    const clientContent = files.find((file) => file.name === 'generated/clients/widget_client.rs')?.content ?? '';
+    const otherClientContent = files.find((file) => file.name === 'generated/clients/other_client.rs')?.content ?? '';
+
+    strictEqual(clientContent.includes('pub async fn send_request(&self, mut request: Request, options: Option<ClientMethodOptions<\'_>>) -> Result<RawResponse> {'), true);
+    strictEqual(clientContent.includes('/// Any 2xx status code is treated as success.'), true);
+    strictEqual(clientContent.includes('let ctx = options.context.to_borrowed();'), true);
+    strictEqual(clientContent.includes('self.pipeline.send(&ctx, &mut request, None).await'), true);
+    strictEqual(otherClient.emitSendRequest, false);
+    strictEqual(otherClientContent.includes('send_request'), false);
+  });
+
+  it('adds the idempotency to the request context', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+    crate.idempotencyContext = true;
+    const client = createClient(crate, 'WidgetClient');
+    const lifetime = new rust.Lifetime('a');
+
+    const getOptions = createMethodOptionsStruct(crate, 'WidgetClientGetWidgetOptions', new rust.ClientMethodOptions(crate, lifetime));
+    const getMethod = new rust.AsyncMethod('get_widget', 'WidgetClient.getWidget', client, 'pub', getOptions, 'get', '/widget');
+    getMethod.returns = new rust.Result(crate, new rust.Response(crate, new rust.Unit(), 'NoFormat'));
+    getMethod.statusCodes = [];
+    client.methods.push(getMethod);
+
+    const createOptions = createMethodOptionsStruct(crate, 'WidgetClientCreateWidgetOptions', new rust.ClientMethodOptions(crate, lifetime));
+    createOptions.type.type.fields.push(new rust.StructField('allow_retry', 'pub', new rust.Scalar('bool', false)));
+    const createMethod = new rust.AsyncMethod('create_widget', 'WidgetClient.createWidget', client, 'pub', createOptions, 'post', '/widget');
+    createMethod.returns = new rust.Result(crate, new rust.Response(crate, new rust.Unit(), 'NoFormat'));
+    createMethod.statusCodes = [];
+    client.methods.push(createMethod);
+
+    const files = new CodeGenerator(crate).emitContent();
+    const clientContent = files.find((file) => file.name === 'generated/clients/widget_client.rs')?.content ?? '';
+    const idempotency = files.find((file) => file.name === 'generated/models/idempotency.rs')?.content ?? '';
+
+    strictEqual(getMethod.idempotent, true);
+    strictEqual(createMethod.idempotent, false);
+    strictEqual(clientContent.includes('let ctx = options.method_options.context.to_borrowed().with_value(Idempotency { idempotent: true, retry_allowed: true });'), true);
+    strictEqual(clientContent.includes('let ctx = options.method_options.context.to_borrowed().with_value(Idempotency { idempotent: false, retry_allowed: options.allow_retry });'), true);
+    strictEqual(idempotency.includes('pub struct Idempotency {'), true);
+  });
+
+  it('adds the idempotency policy to the client pipeline', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+    crate.idempotencyContext = true;
+    const client = createClient(crate, 'WidgetClient');
+    const clientOptions = new rust.Struct('WidgetClientOptions', 'pub');
+    clientOptions.fields.push(new rust.StructField('client_options', 'pub', new rust.ExternalType(crate, 'ClientOptions', 'azure_core::http')));
+    client.constructable = new rust.ClientConstruction(new rust.ClientOptions(clientOptions));
+    const ctor = new rust.Constructor('with_no_credential');
+    ctor.params.push(new rust.ClientEndpointParameter('endpoint'));
+    client.constructable.constructors.push(ctor);
+
+    const files = new CodeGenerator(crate).emitContent();
+    const clientContent = files.find((file) => file.name === 'generated/clients/widget_client.rs')?.content ?? '';
+    const idempotency = files.find((file) => file.name === 'generated/models/idempotency.rs')?.content ?? '';
+
+    strictEqual(clientContent.includes('let idempotency_policy: Arc<dyn Policy> = Arc::new(IdempotencyPolicy);'), true);
+    strictEqual(clientContent.includes('vec![idempotency_policy], None,'), true);
+    strictEqual(idempotency.includes('impl Policy for IdempotencyPolicy {'), true);
+    strictEqual(idempotency.includes('Ok(response) if response.status().is_server_error() => {'), true);
+    strictEqual(idempotency.includes('Err(err) if matches!(err.kind(), ErrorKind::Io) => Err(Error::new(ErrorKind::Other, err)),'), true);
+  });
+
+  it('adds the idempotency to the request context of long-running, pageable, and send_request methods', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+    crate.idempotencyContext = true;
+    const client = createClient(crate, 'WidgetClient');
+    client.emitSendRequest = true;
+    const lifetime = new rust.Lifetime('a');
+
+    const statusModel = new rust.Model('ExportStatus', 'pub', rust.ModelFlags.Output, crate);
+    statusModel.fields.push(new rust.ModelField('status', 'status', 'pub', new rust.Option(new rust.StringType()), true));
+    crate.models.push(statusModel);
+    const exportOptions = createMethodOptionsStruct(crate, 'WidgetClientBeginExportOptions', new rust.PollerOptions(crate, lifetime));
+    exportOptions.type.type.fields.push(new rust.StructField('allow_retry', 'pub', new rust.Scalar('bool', false)));
+    const exportMethod = new rust.LroMethod('begin_export', 'WidgetClient.beginExport', client, 'pub', exportOptions, 'post', '/widgets:export', new rust.LroFinalResultStrategyOriginalUri());
+    exportMethod.returns = new rust.Result(crate, new rust.Poller(crate, new rust.Response(crate, statusModel, 'JsonFormat')));
+    exportMethod.statusCodes = [];
+    client.methods.push(exportMethod);
+
+    const pageModel = new rust.Model('WidgetPage', 'pub', rust.ModelFlags.Output, crate);
+    const itemsField = new rust.ModelField('items', 'items', 'pub', new rust.Vector(new rust.StringType()), false);
+    itemsField.flags = rust.ModelFieldFlags.PageItems;
+    const nextLinkField = new rust.ModelField('next_link', 'nextLink', 'pub', new rust.Option(new rust.StringType()), true);
+    pageModel.fields.push(itemsField, nextLinkField);
+    crate.models.push(pageModel);
+    const listOptions = createMethodOptionsStruct(crate, 'WidgetClientListWidgetsOptions', new rust.PagerOptions(crate, lifetime, 'nextLink'));
+    const listMethod = new rust.PageableMethod('list_widgets', 'WidgetClient.listWidgets', client, 'pub', listOptions, 'get', '/widgets');
+    listMethod.returns = new rust.Result(crate, new rust.Pager(crate, new rust.Response(crate, pageModel, 'JsonFormat'), 'nextLink'));
+    listMethod.strategy = new rust.PageableStrategyNextLink([nextLinkField]);
+    listMethod.statusCodes = [];
+    client.methods.push(listMethod);
+
+    const files = new CodeGenerator(crate).emitContent();
+    const clientContent = files.find((file) => file.name === 'generated/clients/widget_client.rs')?.content ?? '';
+    const idempotency = files.find((file) => file.name === 'generated/models/idempotency.rs')?.content ?? '';
+
+    strictEqual(exportMethod.idempotent, false);
+    strictEqual(clientContent.includes('let idempotent = !matches!(poller_state, PollerState::Initial);'), true);
+    strictEqual(clientContent.includes('let ctx = poller_options.context.clone().with_value(Idempotency { idempotent, retry_allowed: idempotent || options.allow_retry });'), true);
+    strictEqual(clientContent.includes('let ctx = pager_options.context.with_value(Idempotency { idempotent: true, retry_allowed: true });'), true);
+    strictEqual(clientContent.includes('let rsp = pipeline.send(&ctx, &mut request,'), true);
+    strictEqual(clientContent.includes('if ctx.value::<Idempotency>().is_none() {'), true);
+    strictEqual(clientContent.includes('let idempotent = !matches!(request.method(), Method::Post | Method::Patch);'), true);
+    strictEqual(idempotency.includes('pub fn new(idempotent: bool, retry_allowed: bool) -> Self {'), true);
+    strictEqual(idempotency.includes('pub struct IdempotencyPolicy;'), true);
+  });
+
+  it('emits accessors, From impls, and Deserialize for untagged unions', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+    const rustUnion = new rust.UntaggedUnion('IntOrString', 'pub', crate);
+    rustUnion.variants.push(new rust.UntaggedUnionVariant('Int32', new rust.Scalar('i32', false)));
+    rustUnion.variants.push(new rust.UntaggedUnionVariant('String', new rust.StringType()));
+    crate.unions.push(rustUnion);
+
+    const codegen = new CodeGenerator(crate);
+    const files = codegen.emitContent();
+    const unionsContent = files.find((file) => file.name === 'generated/models/unions.rs')?.content ?? '';
+    const implsContent = files.find((file) => file.name === 'generated/models/unions_impl.rs')?.content ?? '';
+    const serdeContent = files.find((file) => file.name === 'generated/models/unions_serde.rs')?.content ?? '';
+
+    strictEqual(unionsContent.includes('#[derive(Clone, SafeDebug, Serialize)]'), true);
+    strictEqual(implsContent.includes('pub fn is_int32(&self) -> bool {'), true);
+    strictEqual(implsContent.includes('pub fn as_string(&self) -> Option<&String> {'), true);
+    strictEqual(implsContent.includes('pub fn into_string(self) -> Option<String> {'), true);
+    strictEqual(implsContent.includes('impl From<i32> for IntOrString {'), true);
+    // variants are attempted in the order they're declared
+    strictEqual(serdeContent.indexOf('.map(Self::Int32)') < serdeContent.indexOf('.map(Self::String)'), true);
+    strictEqual(serdeContent.includes('errors.push(format!("Int32: {err}"))'), true);
+  });
+
+  it('matches extensible string enums case-insensitively', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+    const insensitive = new rust.Enum('Color', 'pub', true, 'String', crate);
+    insensitive.caseInsensitive = true;
+    insensitive.values.push(new rust.EnumValue('Red', insensitive, 'Red'));
+    crate.enums.push(insensitive);
+    const strict = new rust.Enum('Shape', 'pub', true, 'String', crate);
+    strict.values.push(new rust.EnumValue('Circle', strict, 'Circle'));
+    crate.enums.push(strict);
+
+    const implsContent = getClientContent(crate, 'generated/models/enums_impl.rs');
+    strictEqual(implsContent.includes('_ if s.eq_ignore_ascii_case("Red") => Color::Red,'), true);
+    strictEqual(implsContent.includes('_ => Color::UnknownValue(s.to_string()),'), true);
+    strictEqual(implsContent.includes('impl PartialEq<str> for Color {'), true);
+    strictEqual(implsContent.includes('"Circle" => Shape::Circle,'), true);
+    strictEqual(implsContent.includes('impl PartialEq<str> for Shape {'), false);
+  });
+
+  it('emits resource ID methods and the ResourceId type for ARM crates', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'azure-arm');
+    const client = createClient(crate, 'WidgetClient');
+    const subscriptionId = new rust.PathScalarParameter('subscription_id', 'subscriptionId', 'client', false, new rust.StringType(), false, 'simple');
+    client.fields.push(new rust.StructField('subscription_id', 'pubCrate', new rust.StringType()));
+    const lifetime = new rust.Lifetime('a');
+    const getWidgetOptions = createMethodOptionsStruct(
+      crate,
+      'WidgetClientGetWidgetOptions',
+      new rust.ClientMethodOptions(crate, lifetime)
+    );
+
+    const getWidget = new rust.AsyncMethod('get_widget', 'WidgetClient.getWidget', client, 'pub', getWidgetOptions, 'get', '/subscriptions/{subscriptionId}/providers/Test.Widgets/widgets/{widgetName}');
+    const widgetName = new rust.PathScalarParameter('widget_name', 'widgetName', 'method', false, new rust.StringType(), false, 'simple');
+    getWidget.params.push(subscriptionId, widgetName);
+    getWidget.returns = new rust.Result(crate, new rust.Response(crate, new rust.Unit(), 'NoFormat'));
+    getWidget.statusCodes = [200];
+    client.methods.push(getWidget);
+
+    const resourceId = new rust.Parameter('resource_id', new rust.Ref(new rust.ExternalType(crate, 'ResourceId', 'crate::generated::models')));
+    const getWidgetById = new rust.ResourceIdMethod('get_widget_by_id', client, resourceId, getWidget);
+    getWidgetById.statusCodes = getWidget.statusCodes;
+    client.methods.push(getWidgetById);
+
+    const codegen = new CodeGenerator(crate);
+    const files = codegen.emitContent();
+    const clientContent = files.find((file) => file.name === 'generated/clients/widget_client.rs')?.content ?? '';
+    const resourceIdContent = files.find((file) => file.name === 'generated/models/resource_id.rs')?.content ?? '';
+
+    strictEqual(clientContent.includes('pub async fn get_widget_by_id(&self, resource_id: &ResourceId, options: Option<WidgetClientGetWidgetOptions<\'_>>) -> Result<Response<(), NoFormat>> {'), true);
+    strictEqual(clientContent.includes('let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/providers/Test.Widgets/widgets/{widgetName}")?;'), true);
+    strictEqual(clientContent.includes('if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {'), true);
+    strictEqual(clientContent.includes('self.get_widget(path_params["widgetName"], options).await'), true);
+    strictEqual(resourceIdContent.includes('pub struct ResourceId {'), true);
+    strictEqual(resourceIdContent.includes('impl FromStr for ResourceId {'), true);
+  });
+
+  it('emits accessors and resource ID methods for sub-clients that store scope params', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'azure-arm');
+    const subscriptionIdField = new rust.StructField('subscription_id', 'pubCrate', new rust.StringType());
+    const resourceGroupNameField = new rust.StructField('resource_group_name', 'pubCrate', new rust.StringType());
+    const widgetNameField = new rust.StructField('widget_name', 'pubCrate', new rust.StringType());
+
+    // the resource group name is provided to the first accessor and propagated to the nested sub-client
+    const client = createClient(crate, 'WidgetClient');
+    client.fields.push(subscriptionIdField);
+    const widgetsClient = createClient(crate, 'WidgetWidgetsClient');
+    widgetsClient.parent = client;
+    widgetsClient.fields.push(subscriptionIdField, resourceGroupNameField);
+    const partsClient = createClient(crate, 'WidgetWidgetsPartsClient');
+    partsClient.parent = widgetsClient;
+    partsClient.fields.push(subscriptionIdField, resourceGroupNameField, widgetNameField);
+
+    const widgetsAccessor = new rust.ClientAccessor('get_widget_widgets_client', client, widgetsClient);
+    widgetsAccessor.params.push(new rust.Parameter('resource_group_name', new rust.StringType()));
+    client.methods.push(widgetsAccessor);
+    const partsAccessor = new rust.ClientAccessor('get_widget_widgets_parts_client', widgetsClient, partsClient);
+    partsAccessor.params.push(new rust.Parameter('widget_name', new rust.StringType()));
+    widgetsClient.methods.push(partsAccessor);
+
+    const lifetime = new rust.Lifetime('a');
+    const getPartOptions = createMethodOptionsStruct(
+      crate,
+      'WidgetWidgetsPartsClientGetOptions',
+      new rust.ClientMethodOptions(crate, lifetime)
+    );
+    const getPart = new rust.AsyncMethod('get', 'WidgetClient.Widgets.Parts.get', partsClient, 'pub', getPartOptions, 'get', '/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Test.Widgets/widgets/{widgetName}/parts/{partName}');
+    getPart.params.push(
+      new rust.PathScalarParameter('part_name', 'partName', 'method', false, new rust.StringType(), false, 'simple'),
+      new rust.PathScalarParameter('subscription_id', 'subscriptionId', 'client', false, new rust.StringType(), false, 'simple'),
+      new rust.PathScalarParameter('resource_group_name', 'resourceGroupName', 'client', false, new rust.StringType(), false, 'simple'),
+      new rust.PathScalarParameter('widget_name', 'widgetName', 'client', false, new rust.StringType(), false, 'simple'),
+    );
+    getPart.returns = new rust.Result(crate, new rust.Response(crate, new rust.Unit(), 'NoFormat'));
+    getPart.statusCodes = [200];
+    partsClient.methods.push(getPart);
+
+    const resourceId = new rust.Parameter('resource_id', new rust.Ref(new rust.ExternalType(crate, 'ResourceId', 'crate::generated::models')));
+    const getPartById = new rust.ResourceIdMethod('get_by_id', partsClient, resourceId, getPart);
+    getPartById.statusCodes = getPart.statusCodes;
+    partsClient.methods.push(getPartById);
+
+    const codegen = new CodeGenerator(crate);
+    const files = codegen.emitContent();
+    const clientContent = files.find((file) => file.name === 'generated/clients/widget_client.rs')?.content ?? '';
+    const widgetsClientContent = files.find((file) => file.name === 'generated/clients/widget_widgets_client.rs')?.content ?? '';
+    const partsClientContent = files.find((file) => file.name === 'generated/clients/widget_widgets_parts_client.rs')?.content ?? '';
+
+    strictEqual(clientContent.includes('pub fn get_widget_widgets_client(&self, resource_group_name: String) -> WidgetWidgetsClient {'), true);
+    strictEqual(clientContent.includes('resource_group_name,\n'), true);
+    strictEqual(widgetsClientContent.includes('pub fn get_widget_widgets_parts_client(&self, widget_name: String) -> WidgetWidgetsPartsClient {'), true);
+    strictEqual(widgetsClientContent.includes('resource_group_name: self.resource_group_name.clone(),'), true);
+    strictEqual(widgetsClientContent.includes('widget_name,\n'), true);
+
+    // the stored scope params are read from the client and the resource ID must match them
+    strictEqual(partsClientContent.includes('pub async fn get(&self, part_name: &str, options: Option<WidgetWidgetsPartsClientGetOptions<\'_>>) -> Result<Response<(), NoFormat>> {'), true);
+    strictEqual(partsClientContent.includes('path = path.replace("{resourceGroupName}", &self.resource_group_name);'), true);
+    strictEqual(partsClientContent.includes('path = path.replace("{widgetName}", &self.widget_name);'), true);
+    strictEqual(partsClientContent.includes('pub async fn get_by_id(&self, resource_id: &ResourceId, options: Option<WidgetWidgetsPartsClientGetOptions<\'_>>) -> Result<Response<(), NoFormat>> {'), true);
+    strictEqual(partsClientContent.includes('if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {'), true);
+    strictEqual(partsClientContent.includes('if !path_params["resourceGroupName"].eq_ignore_ascii_case(&self.resource_group_name) {'), true);
+    strictEqual(partsClientContent.includes('if !path_params["widgetName"].eq_ignore_ascii_case(&self.widget_name) {'), true);
+    strictEqual(partsClientContent.includes('self.get(path_params["partName"], options).await'), true);
+  });
+
+  it('emits handles and collections for ARM resources', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'azure-arm');
+    const client = createClient(crate, 'WidgetClient');
+    const widget = new rust.Model('Widget', 'pub', rust.ModelFlags.Output, crate);
+    crate.models.push(widget);
+    const lifetime = new rust.Lifetime('a');
+    const getWidgetOptions = createMethodOptionsStruct(
+      crate,
+      'WidgetClientGetOptions',
+      new rust.ClientMethodOptions(crate, lifetime)
+    );
+
+    const path = '/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Test.Widgets/widgets/{widgetName}';
+    const getWidget = new rust.AsyncMethod('get', 'WidgetClient.get', client, 'pub', getWidgetOptions, 'get', path);
+    getWidget.params.push(new rust.PathScalarParameter('resource_group_name', 'resourceGroupName', 'method', false, new rust.StringType(), false, 'simple'));
+    getWidget.params.push(new rust.PathScalarParameter('widget_name', 'widgetName', 'method', false, new rust.StringType(), false, 'simple'));
+    getWidget.returns = new rust.Result(crate, new rust.Response(crate, widget, 'JsonFormat'));
+    client.methods.push(getWidget);
+
+    const resourceId = new rust.Parameter('resource_id', new rust.Ref(new rust.ExternalType(crate, 'ResourceId', 'crate::generated::models')));
+    const getWidgetById = new rust.ResourceIdMethod('get_by_id', client, resourceId, getWidget);
+    client.methods.push(getWidgetById);
+
+    const armResource = new rust.ArmResource('WidgetHandle', widget, path, client, ['providers', 'Test.Widgets'], 'widgets');
+    armResource.operations.push(new rust.ArmResourceOperation('read', getWidgetById));
+    armResource.collection = new rust.ArmResourceCollection('WidgetCollection');
+    crate.armResources.push(armResource);
+
+    const codegen = new CodeGenerator(crate);
+    const files = codegen.emitContent();
+    const armResourcesContent = files.find((file) => file.name === 'generated/clients/arm_resources.rs')?.content ?? '';
+    const clientsModContent = files.find((file) => file.name === 'generated/clients/mod.rs')?.content ?? '';
+
+    strictEqual(armResourcesContent.includes('pub struct WidgetHandle<\'a> {'), true);
+    strictEqual(armResourcesContent.includes('pub async fn get(&self, options: Option<WidgetClientGetOptions<\'_>>) -> Result<Response<Widget>> {'), true);
+    strictEqual(armResourcesContent.includes('self.client.get_by_id(&self.id, options).await'), true);
+    strictEqual(armResourcesContent.includes('Ok(WidgetHandle::new(self.client, self.parent.child("providers", "Test.Widgets")?.child("widgets", name)?))'), true);
+    strictEqual(armResourcesContent.includes('self.handle(name)?.get(options).await'), true);
+    strictEqual(clientsModContent.includes('mod arm_resources;'), true);
+  });
+
+  it('emits a cloud endpoint helper for ARM crates', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'azure-arm');
+    createClient(crate, 'WidgetClient');
+    crate.cloudEndpoints.push(new rust.CloudEndpoint('AzurePublic', 'https://management.azure.com'));
+    crate.cloudEndpoints.push(new rust.CloudEndpoint('AzureGovernment', 'https://management.usgovcloudapi.net'));
+
+    const codegen = new CodeGenerator(crate);
+    const files = codegen.emitContent();
+    const cloudContent = files.find((file) => file.name === 'generated/models/cloud.rs')?.content ?? '';
+    const modelsModContent = files.find((file) => file.name === 'generated/models/mod.rs')?.content ?? '';
+
+    strictEqual(cloudContent.includes('pub(crate) fn arm_cloud_endpoint(cloud: Option<&CloudConfiguration>) -> Result<(Url, String)> {'), true);
+    strictEqual(cloudContent.includes('Some(CloudConfiguration::AzurePublic) => "https://management.azure.com",'), true);
+    strictEqual(cloudContent.includes('Some(CloudConfiguration::AzureGovernment) => "https://management.usgovcloudapi.net",'), true);
+    strictEqual(cloudContent.includes('None => {'), true);
+    strictEqual(cloudContent.includes('unreachable!()'), false);
+    strictEqual(modelsModContent.includes('pub(crate) mod cloud;'), true);
+  });
+
+  it('applies customizations to the code model', () => {
+    const crate = new rust.Crate('test_crate', '1.2.3', 'data-plane');
+    const model = new rust.Model('Widget', 'pub', rust.ModelFlags.Input | rust.ModelFlags.Output, crate);
+    model.fields.push(new rust.ModelField('created_on', 'createdOn', 'pub', new rust.Option(new rust.StringType()), true));
+    model.fields.push(new rust.ModelField('name', 'name', 'pub', new rust.Option(new rust.StringType()), true));
+    crate.models.push(model);
+    const rustEnum = new rust.Enum('Color', 'pub', false, 'String', crate);
+    rustEnum.values.push(new rust.EnumValue('Red', rustEnum, 'Red'));
+    crate.enums.push(rustEnum);
+    const client = createClient(crate, 'WidgetClient');
+    const getOptions = createMethodOptionsStruct(crate, 'WidgetClientGetWidgetOptions', new rust.ClientMethodOptions(crate, new rust.Lifetime('a')));
+    getOptions.type.type.docs.summary = 'Options to be passed to [`WidgetClient::get_widget()`](crate::generated::clients::WidgetClient::get_widget())';
+    const getMethod = new rust.AsyncMethod('get_widget', 'WidgetClient.getWidget', client, 'pub', getOptions, 'get', '/widget');
+    getMethod.returns = new rust.Result(crate, new rust.Response(crate, new rust.Unit(), 'NoFormat'));
+    getMethod.statusCodes = [];
+    client.methods.push(getMethod);
+
+    const customizations = validateCustomizations({
+      models: {
+        Widget: {
+          name: 'Gadget',
+          derives: ['PartialEq'],
+          attributes: ['#[serde(deny_unknown_fields)]'],
+          fields: {
+            created_on: { type: 'time::OffsetDateTime', deserializeWith: 'crate::serde::deserialize_timestamp' },
+            name: { name: 'display_name', visibility: 'pub(crate)', attributes: ['doc(hidden)'] },
+            missing: { name: 'other' },
+          },
+        },
+        Missing: { derives: ['Hash'] },
+      },
+      enums: {
+        Color: { derives: ['Hash'] },
+      },
+      clients: {
+        WidgetClient: { methods: { get_widget: { name: 'fetch_widget' } } },
+      },
+    });
+    const unmatched = applyCustomizations(crate, customizations);
+    deepStrictEqual(unmatched, [
+      'models.Widget.fields.missing didn\'t match any field in model Widget',
+      'models.Missing didn\'t match any model',
+    ]);
+    strictEqual(getMethod.name, 'fetch_widget');
+    strictEqual(getOptions.type.type.docs.summary, 'Options to be passed to [`WidgetClient::fetch_widget()`](crate::generated::clients::WidgetClient::fetch_widget())');
+
+    const files = new CodeGenerator(crate).emitContent();
+    const modelsContent = files.find((file) => file.name === 'generated/models/models.rs')?.content ?? '';
+    const enumsContent = files.find((file) => file.name === 'generated/models/enums.rs')?.content ?? '';
+    const clientContent = files.find((file) => file.name === 'generated/clients/widget_client.rs')?.content ?? '';
+
+    strictEqual(modelsContent.includes('#[derive(Clone, Default, Deserialize, PartialEq, SafeDebug, Serialize)]\n#[serde(deny_unknown_fields)]\n'), true);
+    strictEqual(modelsContent.includes('#[serde(rename = "Widget")]\npub struct Gadget {'), true);
+    strictEqual(modelsContent.includes('deserialize_with = "crate::serde::deserialize_timestamp"'), true);
+    strictEqual(modelsContent.includes('pub created_on: Option<OffsetDateTime>,'), true);
+    strictEqual(modelsContent.includes('#[doc(hidden)]\n    pub(crate) display_name: Option<String>,'), true);
+    strictEqual(modelsContent.includes('use time::OffsetDateTime;'), true);
+    strictEqual(enumsContent.includes('#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]'), true);
+    strictEqual(clientContent.includes('pub async fn fetch_widget('), true);
+
+    throws(() => validateCustomizations({ models: { Widget: { nmae: 'Gadget' } } }), /models\.Widget\.nmae: unknown customization/);
+    throws(() => validateCustomizations({ models: { Widget: { fields: { name: { type: 'String' } } } } }), /must be a path to a Rust type/);
+    throws(() => applyCustomizations(crate, { enums: { Color: { name: 'Gadget' } } }), /a type named Gadget already exists/);
+  });
 });
@@ -0,0 +1,11 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+mod scope_params_client;
+mod scope_params_widgets_client;
+mod scope_params_widgets_parts_client;
+pub use scope_params_client::*;
+pub use scope_params_widgets_client::*;
+pub use scope_params_widgets_parts_client::*;
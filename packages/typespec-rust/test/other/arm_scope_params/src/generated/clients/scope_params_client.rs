@@ -0,0 +1,129 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::generated::{clients::ScopeParamsWidgetsClient, models::arm_cloud_endpoint};
+use azure_core::{
+    credentials::TokenCredential,
+    fmt::SafeDebug,
+    http::{
+        policies::{auth::BearerTokenAuthorizationPolicy, Policy},
+        ClientMethodOptions, ClientOptions, Pipeline, RawResponse, Request, Url,
+    },
+    tracing, Result,
+};
+use std::sync::Arc;
+
+/// Manages widgets and their parts.
+#[tracing::client]
+pub struct ScopeParamsClient {
+    pub(crate) api_version: String,
+    pub(crate) endpoint: Url,
+    pub(crate) pipeline: Pipeline,
+    pub(crate) subscription_id: String,
+}
+
+/// Options used when creating a [`ScopeParamsClient`](ScopeParamsClient)
+#[derive(Clone, SafeDebug)]
+pub struct ScopeParamsClientOptions {
+    /// The API version to use for this operation.
+    pub api_version: String,
+    /// Allows customization of the client.
+    pub client_options: ClientOptions,
+}
+
+impl ScopeParamsClient {
+    /// Creates a new ScopeParamsClient, using Entra ID authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `subscription_id` - The ID of the target subscription. The value must be an UUID.
+    /// * `credential` - An implementation of [`TokenCredential`](azure_core::credentials::TokenCredential) that can provide an
+    ///   Entra ID token to use when authenticating.
+    /// * `options` - Optional configuration for the client.
+    #[tracing::new("Microsoft.ScopeParams")]
+    pub fn new(
+        subscription_id: String,
+        credential: Arc<dyn TokenCredential>,
+        options: Option<ScopeParamsClientOptions>,
+    ) -> Result<Self> {
+        let options = options.unwrap_or_default();
+        let (endpoint, scope) = arm_cloud_endpoint(options.client_options.cloud.as_deref())?;
+        let auth_policy: Arc<dyn Policy> =
+            Arc::new(BearerTokenAuthorizationPolicy::new(credential, vec![scope]));
+        Ok(Self {
+            endpoint,
+            subscription_id,
+            api_version: options.api_version,
+            pipeline: Pipeline::new(
+                option_env!("CARGO_PKG_NAME"),
+                option_env!("CARGO_PKG_VERSION"),
+                options.client_options,
+                Vec::default(),
+                vec![auth_policy],
+                None,
+            ),
+        })
+    }
+
+    /// Returns the Url associated with this client.
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
+    /// Returns a new instance of ScopeParamsWidgetsClient.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_group_name` - The name of the resource group. The name is case insensitive.
+    #[tracing::subclient]
+    pub fn get_scope_params_widgets_client(
+        &self,
+        resource_group_name: String,
+    ) -> ScopeParamsWidgetsClient {
+        ScopeParamsWidgetsClient {
+            api_version: self.api_version.clone(),
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+            resource_group_name,
+            subscription_id: self.subscription_id.clone(),
+        }
+    }
+}
+
+/// Default value for [`ScopeParamsClientOptions::api_version`].
+pub(crate) const DEFAULT_API_VERSION: &str = "2025-01-01";
+
+impl Default for ScopeParamsClientOptions {
+    fn default() -> Self {
+        Self {
+            api_version: String::from(DEFAULT_API_VERSION),
+            client_options: ClientOptions::default(),
+        }
+    }
+}
@@ -0,0 +1,233 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::generated::{
+    clients::ScopeParamsWidgetsPartsClient,
+    models::{
+        ResourceId, ScopeParamsWidgetsClientGetOptions,
+        ScopeParamsWidgetsClientListByResourceGroupOptions, Widget, WidgetListResult,
+    },
+};
+use azure_core::{
+    error::CheckSuccessOptions,
+    http::{
+        pager::{PagerContinuation, PagerResult, PagerState},
+        ClientMethodOptions, Method, Pager, Pipeline, PipelineSendOptions, RawResponse, Request,
+        Response, Url, UrlExt,
+    },
+    json, tracing, Result,
+};
+
+#[tracing::client]
+pub struct ScopeParamsWidgetsClient {
+    pub(crate) api_version: String,
+    pub(crate) endpoint: Url,
+    pub(crate) pipeline: Pipeline,
+    pub(crate) resource_group_name: String,
+    pub(crate) subscription_id: String,
+}
+
+impl ScopeParamsWidgetsClient {
+    /// Returns the Url associated with this client.
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
+    /// Returns a new instance of ScopeParamsWidgetsPartsClient.
+    ///
+    /// # Arguments
+    ///
+    /// * `widget_name` - The name of the Widget
+    #[tracing::subclient]
+    pub fn get_scope_params_widgets_parts_client(
+        &self,
+        widget_name: String,
+    ) -> ScopeParamsWidgetsPartsClient {
+        ScopeParamsWidgetsPartsClient {
+            api_version: self.api_version.clone(),
+            endpoint: self.endpoint.clone(),
+            pipeline: self.pipeline.clone(),
+            resource_group_name: self.resource_group_name.clone(),
+            subscription_id: self.subscription_id.clone(),
+            widget_name,
+        }
+    }
+
+    /// Get a Widget
+    ///
+    /// # Arguments
+    ///
+    /// * `widget_name` - The name of the Widget
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Microsoft.ScopeParams.Widgets.get")]
+    pub async fn get(
+        &self,
+        widget_name: &str,
+        options: Option<ScopeParamsWidgetsClientGetOptions<'_>>,
+    ) -> Result<Response<Widget>> {
+        if widget_name.is_empty() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "parameter widget_name cannot be empty",
+            ));
+        }
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        let mut path = String::from("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.ScopeParams/widgets/{widgetName}");
+        path = path.replace("{resourceGroupName}", &self.resource_group_name);
+        path = path.replace("{subscriptionId}", &self.subscription_id);
+        path = path.replace("{widgetName}", widget_name);
+        url.append_path(&path);
+        let mut query_builder = url.query_builder();
+        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.build();
+        let mut request = Request::new(url, Method::Get);
+        request.insert_header("accept", "application/json");
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[200],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    /// Calls [`ScopeParamsWidgetsClient::get()`](crate::generated::clients::ScopeParamsWidgetsClient::get()) with its path
+    /// parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.ScopeParams/widgets/{widgetName}`.
+    /// * `options` - Optional parameters for the request.
+    pub async fn get_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<ScopeParamsWidgetsClientGetOptions<'_>>,
+    ) -> Result<Response<Widget>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.ScopeParams/widgets/{widgetName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        if !path_params["resourceGroupName"].eq_ignore_ascii_case(&self.resource_group_name) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's resource_group_name"),
+            ));
+        }
+        self.get(path_params["widgetName"], options).await
+    }
+
+    /// List Widget resources by resource group
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Microsoft.ScopeParams.Widgets.listByResourceGroup")]
+    pub fn list_by_resource_group(
+        &self,
+        options: Option<ScopeParamsWidgetsClientListByResourceGroupOptions<'_>>,
+    ) -> Result<Pager<WidgetListResult>> {
+        let options = options.unwrap_or_default().into_owned();
+        let pipeline = self.pipeline.clone();
+        let mut first_url = self.endpoint.clone();
+        let mut path = String::from("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.ScopeParams/widgets");
+        path = path.replace("{resourceGroupName}", &self.resource_group_name);
+        path = path.replace("{subscriptionId}", &self.subscription_id);
+        first_url.append_path(&path);
+        let mut query_builder = first_url.query_builder();
+        query_builder.set_pair("api-version", &self.api_version);
+        query_builder.build();
+        #[derive(serde::Deserialize)]
+        struct ScopeParamsWidgetsClientListByResourceGroupPage {
+            #[serde(rename = "nextLink")]
+            next_link: Option<String>,
+        }
+
+        let api_version = self.api_version.clone();
+        Ok(Pager::new(
+            move |next_link: PagerState, pager_options| {
+                let url = match next_link {
+                    PagerState::More(next_link) => {
+                        let mut next_link: Url = next_link.try_into().expect("expected Url");
+                        let mut query_builder = next_link.query_builder();
+                        query_builder.set_pair("api-version", &api_version);
+                        query_builder.build();
+                        next_link
+                    }
+                    PagerState::Initial => first_url.clone(),
+                };
+                let mut request = Request::new(url, Method::Get);
+                request.insert_header("accept", "application/json");
+                let pipeline = pipeline.clone();
+                Box::pin({
+                    let first_url = first_url.clone();
+                    async move {
+                        let rsp = pipeline
+                            .send(
+                                &pager_options.context,
+                                &mut request,
+                                Some(PipelineSendOptions {
+                                    check_success: CheckSuccessOptions {
+                                        success_codes: &[200],
+                                    },
+                                    ..Default::default()
+                                }),
+                            )
+                            .await?;
+                        let (status, headers, body) = rsp.deconstruct();
+                        let res: ScopeParamsWidgetsClientListByResourceGroupPage =
+                            json::from_json(&body)?;
+                        let rsp = RawResponse::from_bytes(status, headers, body).into();
+                        Ok(match res.next_link {
+                            Some(next_link) if !next_link.is_empty() => PagerResult::More {
+                                response: rsp,
+                                continuation: PagerContinuation::Link(
+                                    first_url.join(next_link.as_ref())?,
+                                ),
+                            },
+                            _ => PagerResult::Done { response: rsp },
+                        })
+                    }
+                })
+            },
+            Some(options.method_options),
+        ))
+    }
+}
@@ -0,0 +1,39 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+/// The kind of entity that created the resource.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CreatedByType {
+    /// The entity was created by an application.
+    Application,
+
+    /// The entity was created by a key.
+    Key,
+
+    /// The entity was created by a managed identity.
+    ManagedIdentity,
+
+    /// The entity was created by a user.
+    User,
+
+    /// Any other value not defined in `CreatedByType`.
+    UnknownValue(String),
+}
+
+/// The provisioning state of a resource type.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ResourceProvisioningState {
+    /// Resource creation was canceled.
+    Canceled,
+
+    /// Resource creation failed.
+    Failed,
+
+    /// Resource has been created.
+    Succeeded,
+
+    /// Any other value not defined in `ResourceProvisioningState`.
+    UnknownValue(String),
+}
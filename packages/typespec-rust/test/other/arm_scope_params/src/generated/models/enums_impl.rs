@@ -0,0 +1,117 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::{CreatedByType, ResourceProvisioningState};
+use std::{
+    convert::{AsRef, From, Infallible},
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+impl<'a> From<&'a CreatedByType> for &'a str {
+    fn from(e: &'a CreatedByType) -> Self {
+        match e {
+            CreatedByType::Application => "Application",
+            CreatedByType::Key => "Key",
+            CreatedByType::ManagedIdentity => "ManagedIdentity",
+            CreatedByType::User => "User",
+            CreatedByType::UnknownValue(s) => s.as_ref(),
+        }
+    }
+}
+
+impl FromStr for CreatedByType {
+    type Err = Infallible;
+    fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
+        Ok(match s {
+            _ if s.eq_ignore_ascii_case("Application") => CreatedByType::Application,
+            _ if s.eq_ignore_ascii_case("Key") => CreatedByType::Key,
+            _ if s.eq_ignore_ascii_case("ManagedIdentity") => CreatedByType::ManagedIdentity,
+            _ if s.eq_ignore_ascii_case("User") => CreatedByType::User,
+            _ => CreatedByType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl AsRef<str> for CreatedByType {
+    fn as_ref(&self) -> &str {
+        match self {
+            CreatedByType::Application => "Application",
+            CreatedByType::Key => "Key",
+            CreatedByType::ManagedIdentity => "ManagedIdentity",
+            CreatedByType::User => "User",
+            CreatedByType::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+
+impl PartialEq<str> for CreatedByType {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
+impl Display for CreatedByType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            CreatedByType::Application => f.write_str("Application"),
+            CreatedByType::Key => f.write_str("Key"),
+            CreatedByType::ManagedIdentity => f.write_str("ManagedIdentity"),
+            CreatedByType::User => f.write_str("User"),
+            CreatedByType::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
+}
+
+impl<'a> From<&'a ResourceProvisioningState> for &'a str {
+    fn from(e: &'a ResourceProvisioningState) -> Self {
+        match e {
+            ResourceProvisioningState::Canceled => "Canceled",
+            ResourceProvisioningState::Failed => "Failed",
+            ResourceProvisioningState::Succeeded => "Succeeded",
+            ResourceProvisioningState::UnknownValue(s) => s.as_ref(),
+        }
+    }
+}
+
+impl FromStr for ResourceProvisioningState {
+    type Err = Infallible;
+    fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
+        Ok(match s {
+            _ if s.eq_ignore_ascii_case("Canceled") => ResourceProvisioningState::Canceled,
+            _ if s.eq_ignore_ascii_case("Failed") => ResourceProvisioningState::Failed,
+            _ if s.eq_ignore_ascii_case("Succeeded") => ResourceProvisioningState::Succeeded,
+            _ => ResourceProvisioningState::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl AsRef<str> for ResourceProvisioningState {
+    fn as_ref(&self) -> &str {
+        match self {
+            ResourceProvisioningState::Canceled => "Canceled",
+            ResourceProvisioningState::Failed => "Failed",
+            ResourceProvisioningState::Succeeded => "Succeeded",
+            ResourceProvisioningState::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+
+impl PartialEq<str> for ResourceProvisioningState {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
+impl Display for ResourceProvisioningState {
+    fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            ResourceProvisioningState::Canceled => f.write_str("Canceled"),
+            ResourceProvisioningState::Failed => f.write_str("Failed"),
+            ResourceProvisioningState::Succeeded => f.write_str("Succeeded"),
+            ResourceProvisioningState::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
+}
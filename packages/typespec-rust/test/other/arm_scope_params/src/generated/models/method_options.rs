@@ -0,0 +1,75 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{
+    fmt::SafeDebug,
+    http::{pager::PagerOptions, ClientMethodOptions},
+};
+
+/// Options to be passed to [`ScopeParamsWidgetsClient::get()`](crate::generated::clients::ScopeParamsWidgetsClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct ScopeParamsWidgetsClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`ScopeParamsWidgetsClient::list_by_resource_group()`](crate::generated::clients::ScopeParamsWidgetsClient::list_by_resource_group())
+#[derive(Clone, Default, SafeDebug)]
+pub struct ScopeParamsWidgetsClientListByResourceGroupOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: PagerOptions<'a>,
+}
+
+impl ScopeParamsWidgetsClientListByResourceGroupOptions<'_> {
+    /// Transforms this [`ScopeParamsWidgetsClientListByResourceGroupOptions`] into a new `ScopeParamsWidgetsClientListByResourceGroupOptions` that owns the underlying data, cloning it if necessary.
+    pub fn into_owned(self) -> ScopeParamsWidgetsClientListByResourceGroupOptions<'static> {
+        ScopeParamsWidgetsClientListByResourceGroupOptions {
+            method_options: PagerOptions {
+                context: self.method_options.context.into_owned(),
+                ..self.method_options
+            },
+        }
+    }
+}
+
+/// Options to be passed to [`ScopeParamsWidgetsPartsClient::create_or_replace()`](crate::generated::clients::ScopeParamsWidgetsPartsClient::create_or_replace())
+#[derive(Clone, Default, SafeDebug)]
+pub struct ScopeParamsWidgetsPartsClientCreateOrReplaceOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`ScopeParamsWidgetsPartsClient::delete()`](crate::generated::clients::ScopeParamsWidgetsPartsClient::delete())
+#[derive(Clone, Default, SafeDebug)]
+pub struct ScopeParamsWidgetsPartsClientDeleteOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`ScopeParamsWidgetsPartsClient::get()`](crate::generated::clients::ScopeParamsWidgetsPartsClient::get())
+#[derive(Clone, Default, SafeDebug)]
+pub struct ScopeParamsWidgetsPartsClientGetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`ScopeParamsWidgetsPartsClient::list_by_widget()`](crate::generated::clients::ScopeParamsWidgetsPartsClient::list_by_widget())
+#[derive(Clone, Default, SafeDebug)]
+pub struct ScopeParamsWidgetsPartsClientListByWidgetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: PagerOptions<'a>,
+}
+
+impl ScopeParamsWidgetsPartsClientListByWidgetOptions<'_> {
+    /// Transforms this [`ScopeParamsWidgetsPartsClientListByWidgetOptions`] into a new `ScopeParamsWidgetsPartsClientListByWidgetOptions` that owns the underlying data, cloning it if necessary.
+    pub fn into_owned(self) -> ScopeParamsWidgetsPartsClientListByWidgetOptions<'static> {
+        ScopeParamsWidgetsPartsClientListByWidgetOptions {
+            method_options: PagerOptions {
+                context: self.method_options.context.into_owned(),
+                ..self.method_options
+            },
+        }
+    }
+}
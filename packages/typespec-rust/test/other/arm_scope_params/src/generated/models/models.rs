@@ -0,0 +1,240 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::{CreatedByType, ResourceProvisioningState};
+use azure_core::{fmt::SafeDebug, time::OffsetDateTime, Value};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The resource management error additional info.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+#[non_exhaustive]
+pub struct ErrorAdditionalInfo {
+    /// The additional info.
+    ///
+    /// Operational visibility: Read
+    #[serde(skip_serializing)]
+    pub info: Option<Value>,
+
+    /// The additional info type.
+    ///
+    /// Operational visibility: Read
+    #[serde(rename = "type", skip_serializing)]
+    pub type_prop: Option<String>,
+}
+
+/// The error detail.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+#[non_exhaustive]
+pub struct ErrorDetail {
+    /// The error additional info.
+    ///
+    /// Operational visibility: Read
+    #[serde(rename = "additionalInfo", skip_serializing)]
+    pub additional_info: Option<Vec<ErrorAdditionalInfo>>,
+
+    /// The error code.
+    ///
+    /// Operational visibility: Read
+    #[serde(skip_serializing)]
+    pub code: Option<String>,
+
+    /// The error details.
+    ///
+    /// Operational visibility: Read
+    #[serde(skip_serializing)]
+    pub details: Option<Vec<ErrorDetail>>,
+
+    /// The error message.
+    ///
+    /// Operational visibility: Read
+    #[serde(skip_serializing)]
+    pub message: Option<String>,
+
+    /// The error target.
+    ///
+    /// Operational visibility: Read
+    #[serde(skip_serializing)]
+    pub target: Option<String>,
+}
+
+/// Error response
+///
+/// Common error response for all Azure Resource Manager APIs to return error details for failed operations.
+#[derive(Clone, Deserialize, SafeDebug, Serialize)]
+pub struct ErrorResponse {
+    /// The error object.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ErrorDetail>,
+}
+
+/// A part of a widget.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct Part {
+    /// Fully qualified resource ID for the resource. Ex - /subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/{resourceProviderNamespace}/{resourceType}/{resourceName}
+    ///
+    /// Operational visibility: Read
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+
+    /// The name of the Part
+    ///
+    /// Operational visibility: Read
+    #[serde(skip_serializing)]
+    pub name: Option<String>,
+
+    /// The resource-specific properties for this resource.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<PartProperties>,
+
+    /// Azure Resource Manager metadata containing createdBy and modifiedBy information.
+    ///
+    /// Operational visibility: Read
+    #[serde(rename = "systemData", skip_serializing)]
+    pub system_data: Option<SystemData>,
+
+    /// The type of the resource. E.g. "Microsoft.Compute/virtualMachines" or "Microsoft.Storage/storageAccounts"
+    ///
+    /// Operational visibility: Read
+    #[serde(rename = "type", skip_serializing)]
+    pub type_prop: Option<String>,
+}
+
+/// The response of a Part list operation.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+#[non_exhaustive]
+pub struct PartListResult {
+    /// The link to the next page of items
+    #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
+    pub next_link: Option<String>,
+
+    /// The Part items on this page
+    #[serde(default)]
+    pub value: Vec<Part>,
+}
+
+/// The part properties.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct PartProperties {
+    /// The status of the last operation.
+    ///
+    /// Operational visibility: Read
+    #[serde(rename = "provisioningState", skip_serializing)]
+    pub provisioning_state: Option<ResourceProvisioningState>,
+
+    /// The size of the part.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<i32>,
+}
+
+/// Metadata pertaining to creation and last modification of the resource.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+#[non_exhaustive]
+pub struct SystemData {
+    /// The timestamp of resource creation (UTC).
+    #[serde(
+        default,
+        rename = "createdAt",
+        skip_serializing_if = "Option::is_none",
+        with = "azure_core::time::rfc3339::option"
+    )]
+    pub created_at: Option<OffsetDateTime>,
+
+    /// The identity that created the resource.
+    #[serde(rename = "createdBy", skip_serializing_if = "Option::is_none")]
+    pub created_by: Option<String>,
+
+    /// The type of identity that created the resource.
+    #[serde(rename = "createdByType", skip_serializing_if = "Option::is_none")]
+    pub created_by_type: Option<CreatedByType>,
+
+    /// The timestamp of resource last modification (UTC)
+    #[serde(
+        default,
+        rename = "lastModifiedAt",
+        skip_serializing_if = "Option::is_none",
+        with = "azure_core::time::rfc3339::option"
+    )]
+    pub last_modified_at: Option<OffsetDateTime>,
+
+    /// The identity that last modified the resource.
+    #[serde(rename = "lastModifiedBy", skip_serializing_if = "Option::is_none")]
+    pub last_modified_by: Option<String>,
+
+    /// The type of identity that last modified the resource.
+    #[serde(rename = "lastModifiedByType", skip_serializing_if = "Option::is_none")]
+    pub last_modified_by_type: Option<CreatedByType>,
+}
+
+/// A widget.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+#[non_exhaustive]
+pub struct Widget {
+    /// Fully qualified resource ID for the resource. Ex - /subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/{resourceProviderNamespace}/{resourceType}/{resourceName}
+    ///
+    /// Operational visibility: Read
+    #[serde(skip_serializing)]
+    pub id: Option<String>,
+
+    /// The geo-location where the resource lives
+    ///
+    /// Operational visibility: Create, Read
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+
+    /// The name of the Widget
+    ///
+    /// Operational visibility: Read
+    #[serde(skip_serializing)]
+    pub name: Option<String>,
+
+    /// The resource-specific properties for this resource.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<WidgetProperties>,
+
+    /// Azure Resource Manager metadata containing createdBy and modifiedBy information.
+    ///
+    /// Operational visibility: Read
+    #[serde(rename = "systemData", skip_serializing)]
+    pub system_data: Option<SystemData>,
+
+    /// Resource tags.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<HashMap<String, String>>,
+
+    /// The type of the resource. E.g. "Microsoft.Compute/virtualMachines" or "Microsoft.Storage/storageAccounts"
+    ///
+    /// Operational visibility: Read
+    #[serde(rename = "type", skip_serializing)]
+    pub type_prop: Option<String>,
+}
+
+/// The response of a Widget list operation.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+#[non_exhaustive]
+pub struct WidgetListResult {
+    /// The link to the next page of items
+    #[serde(rename = "nextLink", skip_serializing_if = "Option::is_none")]
+    pub next_link: Option<String>,
+
+    /// The Widget items on this page
+    #[serde(default)]
+    pub value: Vec<Widget>,
+}
+
+/// The widget properties.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+#[non_exhaustive]
+pub struct WidgetProperties {
+    /// The description of the widget.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The status of the last operation.
+    ///
+    /// Operational visibility: Read
+    #[serde(rename = "provisioningState", skip_serializing)]
+    pub provisioning_state: Option<ResourceProvisioningState>,
+}
@@ -0,0 +1,37 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::{Part, PartListResult, Widget, WidgetListResult};
+use async_trait::async_trait;
+use azure_core::{
+    http::{pager::Page, RequestContent},
+    json::to_json,
+    Result,
+};
+
+#[async_trait]
+impl Page for PartListResult {
+    type Item = Part;
+    type IntoIter = <Vec<Part> as IntoIterator>::IntoIter;
+    async fn into_items(self) -> Result<Self::IntoIter> {
+        Ok(self.value.into_iter())
+    }
+}
+
+#[async_trait]
+impl Page for WidgetListResult {
+    type Item = Widget;
+    type IntoIter = <Vec<Widget> as IntoIterator>::IntoIter;
+    async fn into_items(self) -> Result<Self::IntoIter> {
+        Ok(self.value.into_iter())
+    }
+}
+
+impl TryFrom<Part> for RequestContent<Part> {
+    type Error = azure_core::Error;
+    fn try_from(value: Part) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
@@ -0,0 +1,130 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+use arm_scope_params::{models::ResourceId, ScopeParamsClient, ScopeParamsClientOptions};
+use async_trait::async_trait;
+use azure_core::{
+    cloud::CloudConfiguration,
+    credentials::{AccessToken, TokenCredential, TokenRequestOptions},
+    error::ErrorKind,
+    http::{
+        headers::Headers,
+        policies::{Policy, PolicyResult},
+        AsyncRawResponse, ClientOptions, Context, Method, Request, StatusCode,
+    },
+    time::OffsetDateTime,
+    Result,
+};
+use std::sync::Arc;
+
+const PART_ID: &str = "/subscriptions/00000000-0000-0000-0000-000000000000/resourceGroups/test-rg/providers/Microsoft.ScopeParams/widgets/widget/parts/part";
+
+#[derive(Debug)]
+struct FakeTokenCredential;
+
+#[async_trait]
+impl TokenCredential for FakeTokenCredential {
+    async fn get_token(
+        &self,
+        _scopes: &[&str],
+        _options: Option<TokenRequestOptions<'_>>,
+    ) -> Result<AccessToken> {
+        Ok(AccessToken::new(
+            "fake_token".to_string(),
+            OffsetDateTime::now_utc(),
+        ))
+    }
+}
+
+/// Verifies the request URL in place of the transport and returns an empty part.
+#[derive(Debug)]
+struct PartTransport;
+
+#[async_trait]
+impl Policy for PartTransport {
+    async fn send(
+        &self,
+        _ctx: &Context,
+        request: &mut Request,
+        _next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        assert_eq!(request.method(), Method::Get);
+        assert_eq!(
+            request.url().as_str(),
+            format!("https://management.azure.com{PART_ID}?api-version=2025-01-01")
+        );
+        Ok(AsyncRawResponse::from_bytes(
+            StatusCode::Ok,
+            Headers::new(),
+            br#"{"name":"part"}"#,
+        ))
+    }
+}
+
+fn new_client() -> ScopeParamsClient {
+    let options = ScopeParamsClientOptions {
+        client_options: ClientOptions {
+            cloud: Some(Arc::new(CloudConfiguration::AzurePublic)),
+            per_call_policies: vec![Arc::new(PartTransport)],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    ScopeParamsClient::new(
+        "00000000-0000-0000-0000-000000000000".to_string(),
+        Arc::new(FakeTokenCredential),
+        Some(options),
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn get_part() {
+    let part = new_client()
+        .get_scope_params_widgets_client("test-rg".to_string())
+        .get_scope_params_widgets_parts_client("widget".to_string())
+        .get("part", None)
+        .await
+        .unwrap()
+        .into_model()
+        .unwrap();
+    assert_eq!(part.name.as_deref(), Some("part"));
+}
+
+#[tokio::test]
+async fn get_part_by_id() {
+    let resource_id: ResourceId = PART_ID.parse().unwrap();
+    let part = new_client()
+        .get_scope_params_widgets_client("TEST-RG".to_string())
+        .get_scope_params_widgets_parts_client("widget".to_string())
+        .get_by_id(&resource_id, None)
+        .await
+        .unwrap()
+        .into_model()
+        .unwrap();
+    assert_eq!(part.name.as_deref(), Some("part"));
+}
+
+#[tokio::test]
+async fn get_part_by_id_mismatched_resource_group() {
+    let resource_id: ResourceId = PART_ID.parse().unwrap();
+    let err = new_client()
+        .get_scope_params_widgets_client("other-rg".to_string())
+        .get_scope_params_widgets_parts_client("widget".to_string())
+        .get_by_id(&resource_id, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::DataConversion));
+}
+
+#[tokio::test]
+async fn get_part_by_id_mismatched_widget() {
+    let resource_id: ResourceId = PART_ID.parse().unwrap();
+    let err = new_client()
+        .get_scope_params_widgets_client("test-rg".to_string())
+        .get_scope_params_widgets_parts_client("other-widget".to_string())
+        .get_by_id(&resource_id, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::DataConversion));
+}
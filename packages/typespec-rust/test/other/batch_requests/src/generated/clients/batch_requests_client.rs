@@ -0,0 +1,230 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::generated::models::{
+    BatchRequestsClientDeleteWidgetOptions, BatchRequestsClientGetWidgetOptions, Widget,
+};
+use azure_core::{
+    error::CheckSuccessOptions,
+    fmt::SafeDebug,
+    http::{
+        ClientMethodOptions, ClientOptions, Method, NoFormat, Pipeline, PipelineSendOptions,
+        RawResponse, Request, Response, Url, UrlExt,
+    },
+    tracing, Result,
+};
+
+#[tracing::client]
+pub struct BatchRequestsClient {
+    pub(crate) endpoint: Url,
+    pub(crate) pipeline: Pipeline,
+}
+
+/// Options used when creating a [`BatchRequestsClient`](BatchRequestsClient)
+#[derive(Clone, Default, SafeDebug)]
+pub struct BatchRequestsClientOptions {
+    /// Allows customization of the client.
+    pub client_options: ClientOptions,
+}
+
+impl BatchRequestsClient {
+    /// Creates a new BatchRequestsClient requiring no authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Service host
+    /// * `options` - Optional configuration for the client.
+    #[tracing::new("BatchRequests")]
+    pub fn with_no_credential(
+        endpoint: &str,
+        options: Option<BatchRequestsClientOptions>,
+    ) -> Result<Self> {
+        let options = options.unwrap_or_default();
+        let endpoint = Url::parse(endpoint)?;
+        if !endpoint.scheme().starts_with("http") {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                format!("{endpoint} must use http(s)"),
+            ));
+        }
+        Ok(Self {
+            endpoint,
+            pipeline: Pipeline::new(
+                option_env!("CARGO_PKG_NAME"),
+                option_env!("CARGO_PKG_VERSION"),
+                options.client_options,
+                Vec::default(),
+                Vec::default(),
+                None,
+            ),
+        })
+    }
+
+    /// Returns the Url associated with this client.
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("BatchRequests.deleteWidget")]
+    pub async fn delete_widget(
+        &self,
+        name: &str,
+        options: Option<BatchRequestsClientDeleteWidgetOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        if name.is_empty() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "parameter name cannot be empty",
+            ));
+        }
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        let mut path = String::from("/widgets/{name}");
+        path = path.replace("{name}", name);
+        url.append_path(&path);
+        let mut request = Request::new(url, Method::Delete);
+        if let Some(if_match) = options.if_match.as_ref() {
+            request.insert_header("if-match", if_match);
+        }
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    /// Builds the request for [`BatchRequestsClient::delete_widget()`](crate::generated::clients::BatchRequestsClient::delete_widget())
+    /// without sending it.
+    ///
+    /// The request can be sent with [`BatchRequestsClient::send_request()`](crate::generated::clients::BatchRequestsClient::send_request())
+    /// or added to a [`BatchRequest`](crate::generated::models::BatchRequest). Its sub-response is converted to the method's
+    /// response type with [`BatchPart::into_response()`](crate::generated::models::BatchPart::into_response()).
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    pub fn delete_widget_request(
+        &self,
+        name: &str,
+        options: Option<BatchRequestsClientDeleteWidgetOptions<'_>>,
+    ) -> Result<Request> {
+        if name.is_empty() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "parameter name cannot be empty",
+            ));
+        }
+        let options = options.unwrap_or_default();
+        let mut url = self.endpoint.clone();
+        let mut path = String::from("/widgets/{name}");
+        path = path.replace("{name}", name);
+        url.append_path(&path);
+        let mut request = Request::new(url, Method::Delete);
+        if let Some(if_match) = options.if_match.as_ref() {
+            request.insert_header("if-match", if_match);
+        }
+        Ok(request)
+    }
+
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("BatchRequests.getWidget")]
+    pub async fn get_widget(
+        &self,
+        name: &str,
+        options: Option<BatchRequestsClientGetWidgetOptions<'_>>,
+    ) -> Result<Response<Widget>> {
+        if name.is_empty() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "parameter name cannot be empty",
+            ));
+        }
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        let mut path = String::from("/widgets/{name}");
+        path = path.replace("{name}", name);
+        url.append_path(&path);
+        let mut request = Request::new(url, Method::Get);
+        request.insert_header("accept", "application/json");
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[200],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    /// Builds the request for [`BatchRequestsClient::get_widget()`](crate::generated::clients::BatchRequestsClient::get_widget())
+    /// without sending it.
+    ///
+    /// The request can be sent with [`BatchRequestsClient::send_request()`](crate::generated::clients::BatchRequestsClient::send_request())
+    /// or added to a [`BatchRequest`](crate::generated::models::BatchRequest). Its sub-response is converted to the method's
+    /// response type with [`BatchPart::into_response()`](crate::generated::models::BatchPart::into_response()).
+    pub fn get_widget_request(&self, name: &str) -> Result<Request> {
+        if name.is_empty() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "parameter name cannot be empty",
+            ));
+        }
+        let mut url = self.endpoint.clone();
+        let mut path = String::from("/widgets/{name}");
+        path = path.replace("{name}", name);
+        url.append_path(&path);
+        let mut request = Request::new(url, Method::Get);
+        request.insert_header("accept", "application/json");
+        Ok(request)
+    }
+}
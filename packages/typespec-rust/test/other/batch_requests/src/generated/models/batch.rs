@@ -0,0 +1,306 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{
+    error::{Error, ErrorKind},
+    http::{
+        headers::{HeaderName, Headers},
+        Body, RawResponse, Request, Response, StatusCode,
+    },
+    Bytes, Result,
+};
+use std::str;
+
+const CONTENT_ID: HeaderName = HeaderName::from_static("content-id");
+const CONTENT_LENGTH: HeaderName = HeaderName::from_static("content-length");
+const ERROR_CODE: HeaderName = HeaderName::from_static("x-ms-error-code");
+
+/// A set of requests that are sent as a single `multipart/mixed` request.
+///
+/// Each request is encoded as an `application/http` part whose `content-id` is the index of the request in the
+/// batch.
+pub struct BatchRequest {
+    boundary: String,
+    requests: Vec<Request>,
+}
+
+impl BatchRequest {
+    /// Creates an empty batch whose parts are delimited by `boundary`.
+    pub fn new(boundary: impl Into<String>) -> Self {
+        Self {
+            boundary: boundary.into(),
+            requests: Vec::new(),
+        }
+    }
+
+    /// Adds a request to the batch and returns its index.
+    ///
+    /// The index is used to retrieve the sub-response for the request with [`BatchResponse::take()`].
+    pub fn add(&mut self, request: Request) -> usize {
+        self.requests.push(request);
+        self.requests.len() - 1
+    }
+
+    /// Returns the number of requests in the batch.
+    pub fn len(&self) -> usize {
+        self.requests.len()
+    }
+
+    /// Returns `true` if the batch contains no requests.
+    pub fn is_empty(&self) -> bool {
+        self.requests.is_empty()
+    }
+
+    /// Returns the `content-type` header value for the batch body.
+    pub fn content_type(&self) -> String {
+        format!("multipart/mixed; boundary={}", self.boundary)
+    }
+
+    /// Encodes the requests as a `multipart/mixed` body.
+    ///
+    /// Returns an error if a request has a streamed body.
+    pub fn into_body(self) -> Result<Bytes> {
+        let mut body = Vec::new();
+        for (index, request) in self.requests.iter().enumerate() {
+            let Body::Bytes(content) = request.body() else {
+                return Err(Error::with_message(
+                    ErrorKind::Other,
+                    "requests with streamed bodies can't be batched",
+                ));
+            };
+            body.extend_from_slice(format!("--{}\r\n", self.boundary).as_bytes());
+            body.extend_from_slice(b"content-type: application/http\r\n");
+            body.extend_from_slice(b"content-transfer-encoding: binary\r\n");
+            body.extend_from_slice(format!("content-id: {index}\r\n\r\n").as_bytes());
+            let url = request.url();
+            let target = match url.query() {
+                Some(query) => format!("{}?{query}", url.path()),
+                None => url.path().to_string(),
+            };
+            body.extend_from_slice(
+                format!("{} {target} HTTP/1.1\r\n", request.method().as_str()).as_bytes(),
+            );
+            for (name, value) in request.headers().iter() {
+                body.extend_from_slice(
+                    format!("{}: {}\r\n", name.as_str(), value.as_str()).as_bytes(),
+                );
+            }
+            if !content.is_empty()
+                && request
+                    .headers()
+                    .get_optional_str(&CONTENT_LENGTH)
+                    .is_none()
+            {
+                body.extend_from_slice(format!("content-length: {}\r\n", content.len()).as_bytes());
+            }
+            body.extend_from_slice(b"\r\n");
+            body.extend_from_slice(content);
+            body.extend_from_slice(b"\r\n");
+        }
+        body.extend_from_slice(format!("--{}--\r\n", self.boundary).as_bytes());
+        Ok(body.into())
+    }
+}
+
+/// The sub-responses of a `multipart/mixed` batch response.
+#[derive(Debug)]
+pub struct BatchResponse {
+    parts: Vec<Option<BatchPart>>,
+    has_content_ids: bool,
+}
+
+impl BatchResponse {
+    /// Parses a `multipart/mixed` batch response.
+    ///
+    /// The `content_type` is the `content-type` header value of the batch response. It contains the boundary that
+    /// delimits the parts of the `body`.
+    pub fn parse(content_type: &str, body: &[u8]) -> Result<Self> {
+        let boundary = content_type
+            .split(';')
+            .find_map(|param| param.trim().strip_prefix("boundary="))
+            .map(|boundary| boundary.trim_matches('"'))
+            .ok_or_else(|| invalid_batch("the content-type doesn't contain a boundary"))?;
+        let delimiter = format!("--{boundary}");
+        let mut parts = Vec::new();
+        // the preamble before the first delimiter is ignored
+        for section in split(body, delimiter.as_bytes()).skip(1) {
+            if section.starts_with(b"--") {
+                // the close delimiter ends the batch
+                break;
+            }
+            parts.push(Some(parse_part(section)?));
+        }
+        let has_content_ids = parts.iter().flatten().any(|part| part.content_id.is_some());
+        Ok(Self {
+            parts,
+            has_content_ids,
+        })
+    }
+
+    /// Returns the number of sub-responses in the batch.
+    pub fn len(&self) -> usize {
+        self.parts.len()
+    }
+
+    /// Returns `true` if the batch contains no sub-responses.
+    pub fn is_empty(&self) -> bool {
+        self.parts.is_empty()
+    }
+
+    /// Removes and returns the sub-response for the request at `index` in the [`BatchRequest`].
+    ///
+    /// Sub-responses are matched by their `content-id`. Only when the response contains no `content-id`s at all is
+    /// the sub-response at the same position used. Returns `None` if there's no such sub-response or it was already taken.
+    pub fn take(&mut self, index: usize) -> Option<BatchPart> {
+        let position = if self.has_content_ids {
+            let content_id = index.to_string();
+            self.parts.iter().position(|part| {
+                part.as_ref().and_then(BatchPart::content_id) == Some(content_id.as_str())
+            })?
+        } else {
+            index
+        };
+        self.parts.get_mut(position)?.take()
+    }
+}
+
+/// A sub-response of a `multipart/mixed` batch response.
+#[derive(Debug)]
+pub struct BatchPart {
+    content_id: Option<String>,
+    status: StatusCode,
+    headers: Headers,
+    body: Bytes,
+}
+
+impl BatchPart {
+    /// Returns the `content-id` of the part, if any.
+    pub fn content_id(&self) -> Option<&str> {
+        self.content_id.as_deref()
+    }
+
+    /// Returns the status code of the sub-response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Returns the headers of the sub-response.
+    pub fn headers(&self) -> &Headers {
+        &self.headers
+    }
+
+    /// Returns the body of the sub-response.
+    pub fn body(&self) -> &Bytes {
+        &self.body
+    }
+
+    /// Converts the sub-response into the response type of the method that built its request.
+    ///
+    /// Returns an [`ErrorKind::HttpResponse`] error if the status code of the sub-response doesn't indicate success.
+    pub fn into_response<T, F>(self) -> Result<Response<T, F>> {
+        let status = self.status;
+        let error_code = self
+            .headers
+            .get_optional_str(&ERROR_CODE)
+            .map(ToString::to_string);
+        let raw_response = self.into_raw_response();
+        if !status.is_success() {
+            return Err(Error::with_message(
+                ErrorKind::HttpResponse {
+                    status,
+                    error_code,
+                    raw_response: Some(Box::new(raw_response)),
+                },
+                format!("batch sub-request failed with status {status}"),
+            ));
+        }
+        Ok(raw_response.into())
+    }
+
+    /// Converts the sub-response into a [`RawResponse`].
+    pub fn into_raw_response(self) -> RawResponse {
+        RawResponse::from_bytes(self.status, self.headers, self.body)
+    }
+}
+
+/// Returns an error for a malformed batch response.
+fn invalid_batch(message: &'static str) -> Error {
+    Error::with_message(ErrorKind::DataConversion, message)
+}
+
+/// Returns the position of the first occurrence of `needle` in `bytes`.
+fn find(bytes: &[u8], needle: &[u8]) -> Option<usize> {
+    bytes
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// Splits `bytes` on each occurrence of `delimiter`.
+fn split<'a>(bytes: &'a [u8], delimiter: &'a [u8]) -> impl Iterator<Item = &'a [u8]> {
+    let mut remaining = Some(bytes);
+    std::iter::from_fn(move || {
+        let bytes = remaining?;
+        match find(bytes, delimiter) {
+            Some(start) => {
+                remaining = Some(&bytes[start + delimiter.len()..]);
+                Some(&bytes[..start])
+            }
+            None => {
+                remaining = None;
+                Some(bytes)
+            }
+        }
+    })
+}
+
+/// Splits a message into its headers and body at the first empty line.
+fn split_head(message: &[u8]) -> Result<(&str, &[u8])> {
+    let (head, body) = if let Some(body) = message.strip_prefix(b"\r\n") {
+        (&[][..], body)
+    } else {
+        let end = find(message, b"\r\n\r\n")
+            .ok_or_else(|| invalid_batch("a batch part is missing the end of its headers"))?;
+        (&message[..end], &message[end + 4..])
+    };
+    let head = str::from_utf8(head)
+        .map_err(|_| invalid_batch("the headers of a batch part aren't valid UTF-8"))?;
+    Ok((head, body))
+}
+
+/// Splits a header line into its name and value.
+fn parse_header(line: &str) -> Option<(&str, &str)> {
+    let (name, value) = line.split_once(':')?;
+    Some((name.trim(), value.trim()))
+}
+
+/// Parses an `application/http` part into a sub-response.
+fn parse_part(section: &[u8]) -> Result<BatchPart> {
+    // the line break ending the delimiter line and the one preceding the next delimiter belong to the delimiters
+    let section = section.strip_prefix(b"\r\n").unwrap_or(section);
+    let section = section.strip_suffix(b"\r\n").unwrap_or(section);
+    let (part_head, message) = split_head(section)?;
+    let content_id = part_head
+        .split("\r\n")
+        .filter_map(parse_header)
+        .find(|(name, _)| name.eq_ignore_ascii_case(CONTENT_ID.as_str()))
+        .map(|(_, value)| value.to_string());
+    let (head, body) = split_head(message)?;
+    let mut lines = head.split("\r\n");
+    let status = lines
+        .next()
+        .and_then(|status_line| status_line.split(' ').nth(1))
+        .and_then(|code| code.parse::<u16>().ok())
+        .ok_or_else(|| invalid_batch("a batch part is missing its status line"))?;
+    let mut headers = Headers::new();
+    for (name, value) in lines.filter_map(parse_header) {
+        headers.insert(name.to_ascii_lowercase(), value.to_string());
+    }
+    Ok(BatchPart {
+        content_id,
+        status: StatusCode::from(status),
+        headers,
+        body: Bytes::copy_from_slice(body),
+    })
+}
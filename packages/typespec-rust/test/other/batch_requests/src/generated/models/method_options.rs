@@ -0,0 +1,22 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{fmt::SafeDebug, http::ClientMethodOptions};
+
+/// Options to be passed to [`BatchRequestsClient::delete_widget()`](crate::generated::clients::BatchRequestsClient::delete_widget())
+#[derive(Clone, Default, SafeDebug)]
+pub struct BatchRequestsClientDeleteWidgetOptions<'a> {
+    pub if_match: Option<String>,
+
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`BatchRequestsClient::get_widget()`](crate::generated::clients::BatchRequestsClient::get_widget())
+#[derive(Clone, Default, SafeDebug)]
+pub struct BatchRequestsClientGetWidgetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
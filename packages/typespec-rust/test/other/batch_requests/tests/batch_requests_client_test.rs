@@ -0,0 +1,279 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use async_trait::async_trait;
+use azure_core::{
+    error::ErrorKind,
+    http::{
+        headers::{HeaderName, Headers},
+        policies::{Policy, PolicyResult},
+        AsyncRawResponse, ClientOptions, Context, JsonFormat, Method, NoFormat, Request, Response,
+        StatusCode,
+    },
+};
+use batch_requests::{
+    models::{BatchRequest, BatchRequestsClientDeleteWidgetOptions, BatchResponse, Widget},
+    BatchRequestsClient, BatchRequestsClientOptions,
+};
+use std::sync::Arc;
+
+const BATCH_RESPONSE: &str = concat!(
+    "--batchresponse_1\r\n",
+    "content-type: application/http\r\n",
+    "content-id: 1\r\n",
+    "\r\n",
+    "HTTP/1.1 412 Precondition Failed\r\n",
+    "x-ms-error-code: ConditionNotMet\r\n",
+    "\r\n",
+    "\r\n",
+    "--batchresponse_1\r\n",
+    "content-type: application/http\r\n",
+    "content-id: 0\r\n",
+    "\r\n",
+    "HTTP/1.1 200 OK\r\n",
+    "content-type: application/json\r\n",
+    "\r\n",
+    "{\"name\":\"a\",\"color\":\"red\"}\r\n",
+    "--batchresponse_1--\r\n",
+);
+
+fn delete_options(if_match: &str) -> Option<BatchRequestsClientDeleteWidgetOptions<'static>> {
+    Some(BatchRequestsClientDeleteWidgetOptions {
+        if_match: Some(if_match.to_string()),
+        ..Default::default()
+    })
+}
+
+#[test]
+fn build_requests() {
+    let client = BatchRequestsClient::with_no_credential("https://contoso.com", None).unwrap();
+
+    let request = client.get_widget_request("a").unwrap();
+    assert_eq!(request.method(), Method::Get);
+    assert_eq!(request.url().as_str(), "https://contoso.com/widgets/a");
+    assert_eq!(
+        request
+            .headers()
+            .get_optional_str(&HeaderName::from_static("accept")),
+        Some("application/json")
+    );
+
+    let request = client
+        .delete_widget_request("b", delete_options("\"v1\""))
+        .unwrap();
+    assert_eq!(request.method(), Method::Delete);
+    assert_eq!(request.url().as_str(), "https://contoso.com/widgets/b");
+    assert_eq!(
+        request
+            .headers()
+            .get_optional_str(&HeaderName::from_static("if-match")),
+        Some("\"v1\"")
+    );
+
+    let err = client.get_widget_request("").unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::Other));
+}
+
+#[test]
+fn encode_batch() {
+    let client = BatchRequestsClient::with_no_credential("https://contoso.com", None).unwrap();
+    let mut batch = BatchRequest::new("batch_1");
+    assert!(batch.is_empty());
+    assert_eq!(batch.add(client.get_widget_request("a").unwrap()), 0);
+    assert_eq!(
+        batch.add(
+            client
+                .delete_widget_request("b", delete_options("\"v1\""))
+                .unwrap()
+        ),
+        1
+    );
+    assert_eq!(batch.len(), 2);
+    assert_eq!(batch.content_type(), "multipart/mixed; boundary=batch_1");
+
+    let body = batch.into_body().unwrap();
+    let expected = concat!(
+        "--batch_1\r\n",
+        "content-type: application/http\r\n",
+        "content-transfer-encoding: binary\r\n",
+        "content-id: 0\r\n",
+        "\r\n",
+        "GET /widgets/a HTTP/1.1\r\n",
+        "accept: application/json\r\n",
+        "\r\n",
+        "\r\n",
+        "--batch_1\r\n",
+        "content-type: application/http\r\n",
+        "content-transfer-encoding: binary\r\n",
+        "content-id: 1\r\n",
+        "\r\n",
+        "DELETE /widgets/b HTTP/1.1\r\n",
+        "if-match: \"v1\"\r\n",
+        "\r\n",
+        "\r\n",
+        "--batch_1--\r\n",
+    );
+    assert_eq!(std::str::from_utf8(&body).unwrap(), expected);
+}
+
+#[test]
+fn parse_batch_response() {
+    let mut batch = BatchResponse::parse(
+        "multipart/mixed; boundary=batchresponse_1",
+        BATCH_RESPONSE.as_bytes(),
+    )
+    .unwrap();
+    assert_eq!(batch.len(), 2);
+
+    // sub-responses are matched to their requests by content-id
+    let part = batch.take(0).unwrap();
+    assert_eq!(part.content_id(), Some("0"));
+    assert_eq!(part.status(), StatusCode::Ok);
+    let widget: Widget = part
+        .into_response::<Widget, JsonFormat>()
+        .unwrap()
+        .into_model()
+        .unwrap();
+    assert_eq!(widget.name.as_deref(), Some("a"));
+    assert_eq!(widget.color.as_deref(), Some("red"));
+    assert!(batch.take(0).is_none());
+
+    let part = batch.take(1).unwrap();
+    assert_eq!(part.status(), StatusCode::PreconditionFailed);
+    assert!(part.body().is_empty());
+    let err = part.into_response::<(), NoFormat>().unwrap_err();
+    match err.kind() {
+        ErrorKind::HttpResponse {
+            status, error_code, ..
+        } => {
+            assert_eq!(status, &StatusCode::PreconditionFailed);
+            assert_eq!(error_code.as_deref(), Some("ConditionNotMet"));
+        }
+        kind => panic!("unexpected error kind {kind:?}"),
+    }
+}
+
+#[test]
+fn round_trip_batch() {
+    let client = BatchRequestsClient::with_no_credential("https://contoso.com", None).unwrap();
+    let mut batch = BatchRequest::new("batch_1");
+    let get = batch.add(client.get_widget_request("a").unwrap());
+    let delete = batch.add(
+        client
+            .delete_widget_request("b", delete_options("\"v1\""))
+            .unwrap(),
+    );
+    let body = batch.into_body().unwrap();
+    let body = std::str::from_utf8(&body).unwrap();
+    assert!(body.contains(&format!("content-id: {get}\r\n")));
+    assert!(body.contains(&format!("content-id: {delete}\r\n")));
+
+    // the service returned the sub-responses out of order
+    let mut batch = BatchResponse::parse(
+        "multipart/mixed; boundary=batchresponse_1",
+        BATCH_RESPONSE.as_bytes(),
+    )
+    .unwrap();
+    let part = batch.take(get).unwrap();
+    assert_eq!(part.content_id(), Some("0"));
+    assert_eq!(part.status(), StatusCode::Ok);
+
+    // a taken sub-response isn't replaced by the one at the same position
+    assert!(batch.take(get).is_none());
+
+    let part = batch.take(delete).unwrap();
+    assert_eq!(part.content_id(), Some("1"));
+    assert_eq!(part.status(), StatusCode::PreconditionFailed);
+    assert!(batch.take(delete).is_none());
+    assert!(batch.take(2).is_none());
+}
+
+#[test]
+fn parse_batch_response_without_content_ids() {
+    let body = concat!(
+        "--batchresponse_1\r\n",
+        "content-type: application/http\r\n",
+        "\r\n",
+        "HTTP/1.1 200 OK\r\n",
+        "\r\n",
+        "\r\n",
+        "--batchresponse_1\r\n",
+        "content-type: application/http\r\n",
+        "\r\n",
+        "HTTP/1.1 204 No Content\r\n",
+        "\r\n",
+        "\r\n",
+        "--batchresponse_1--\r\n",
+    );
+    let mut batch =
+        BatchResponse::parse("multipart/mixed; boundary=batchresponse_1", body.as_bytes()).unwrap();
+
+    // sub-responses are matched to their requests by position
+    let part = batch.take(1).unwrap();
+    assert_eq!(part.content_id(), None);
+    assert_eq!(part.status(), StatusCode::NoContent);
+    assert_eq!(batch.take(0).unwrap().status(), StatusCode::Ok);
+    assert!(batch.take(0).is_none());
+}
+
+#[test]
+fn parse_batch_response_without_boundary() {
+    let err = BatchResponse::parse("multipart/mixed", BATCH_RESPONSE.as_bytes()).unwrap_err();
+    assert!(matches!(err.kind(), ErrorKind::DataConversion));
+}
+
+/// Verifies the modified request and returns a widget in place of the transport.
+#[derive(Debug)]
+struct WidgetTransport;
+
+#[async_trait]
+impl Policy for WidgetTransport {
+    async fn send(
+        &self,
+        _ctx: &Context,
+        request: &mut Request,
+        _next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        assert_eq!(
+            request.url().as_str(),
+            "https://contoso.com/widgets/a?preview=true"
+        );
+        assert_eq!(
+            request
+                .headers()
+                .get_optional_str(&HeaderName::from_static("x-ms-custom")),
+            Some("value")
+        );
+        Ok(AsyncRawResponse::from_bytes(
+            StatusCode::Ok,
+            Headers::new(),
+            r#"{"name":"a","color":"red"}"#,
+        ))
+    }
+}
+
+#[tokio::test]
+async fn send_modified_request() {
+    let options = BatchRequestsClientOptions {
+        client_options: ClientOptions {
+            per_call_policies: vec![Arc::new(WidgetTransport)],
+            ..Default::default()
+        },
+    };
+    let client =
+        BatchRequestsClient::with_no_credential("https://contoso.com", Some(options)).unwrap();
+
+    // add a parameter the client doesn't model before sending the request
+    let mut request = client.get_widget_request("a").unwrap();
+    request
+        .url_mut()
+        .query_pairs_mut()
+        .append_pair("preview", "true");
+    request.insert_header("x-ms-custom", "value");
+
+    let response: Response<Widget> = client.send_request(request, None).await.unwrap().into();
+    let widget = response.into_model().unwrap();
+    assert_eq!(widget.name.as_deref(), Some("a"));
+    assert_eq!(widget.color.as_deref(), Some("red"));
+}
@@ -0,0 +1,170 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::generated::models::{CookiesClientSignInOptions, CookiesClientSignInResult};
+use azure_core::{
+    error::CheckSuccessOptions,
+    fmt::SafeDebug,
+    http::{
+        ClientMethodOptions, ClientOptions, Method, NoFormat, Pipeline, PipelineSendOptions,
+        RawResponse, Request, Response, Url, UrlExt,
+    },
+    tracing, Result,
+};
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+
+#[tracing::client]
+pub struct CookiesClient {
+    pub(crate) endpoint: Url,
+    pub(crate) pipeline: Pipeline,
+}
+
+/// Options used when creating a [`CookiesClient`](CookiesClient)
+#[derive(Clone, Default, SafeDebug)]
+pub struct CookiesClientOptions {
+    /// Allows customization of the client.
+    pub client_options: ClientOptions,
+}
+
+impl CookiesClient {
+    /// Creates a new CookiesClient requiring no authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Service host
+    /// * `options` - Optional configuration for the client.
+    #[tracing::new("Cookies")]
+    pub fn with_no_credential(
+        endpoint: &str,
+        options: Option<CookiesClientOptions>,
+    ) -> Result<Self> {
+        let options = options.unwrap_or_default();
+        let endpoint = Url::parse(endpoint)?;
+        if !endpoint.scheme().starts_with("http") {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                format!("{endpoint} must use http(s)"),
+            ));
+        }
+        Ok(Self {
+            endpoint,
+            pipeline: Pipeline::new(
+                option_env!("CARGO_PKG_NAME"),
+                option_env!("CARGO_PKG_VERSION"),
+                options.client_options,
+                Vec::default(),
+                Vec::default(),
+                None,
+            ),
+        })
+    }
+
+    /// Returns the Url associated with this client.
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
+    /// Signs in and starts a session.
+    ///
+    /// # Arguments
+    ///
+    /// * `session` - The session identifier.
+    /// * `options` - Optional parameters for the request.
+    ///
+    /// ## Response Headers
+    ///
+    /// The returned [`Response`](azure_core::http::Response) implements the [`CookiesClientSignInResultHeaders`] trait, which provides
+    /// access to response headers. For example:
+    ///
+    /// ```no_run
+    /// use azure_core::{Result, http::{Response, NoFormat}};
+    /// use cookies::models::{CookiesClientSignInResult, CookiesClientSignInResultHeaders};
+    /// async fn example() -> Result<()> {
+    ///     let response: Response<CookiesClientSignInResult, NoFormat> = unimplemented!();
+    ///     // Access response headers
+    ///     if let Some(set_cookie) = response.set_cookie()? {
+    ///         println!("set-cookie: {:?}", set_cookie);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ### Available headers
+    /// * [`set_cookie`()](crate::generated::models::CookiesClientSignInResultHeaders::set_cookie) - set-cookie
+    ///
+    /// [`CookiesClientSignInResultHeaders`]: crate::generated::models::CookiesClientSignInResultHeaders
+    #[tracing::function("Cookies.signIn")]
+    pub async fn sign_in(
+        &self,
+        session: String,
+        options: Option<CookiesClientSignInOptions<'_>>,
+    ) -> Result<Response<CookiesClientSignInResult, NoFormat>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/sign-in");
+        let mut request = Request::new(url, Method::Post);
+        let mut cookies = vec![format!(
+            "session={}",
+            utf8_percent_encode(&session, COOKIE_VALUE_ENCODE_SET)
+        )];
+        if let Some(theme) = options.theme.as_ref() {
+            cookies.push(format!(
+                "theme={}",
+                utf8_percent_encode(theme, COOKIE_VALUE_ENCODE_SET)
+            ));
+        }
+        if !cookies.is_empty() {
+            request.insert_header("cookie", cookies.join("; "));
+        }
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+}
+
+/// Characters that are percent-encoded in cookie values.
+const COOKIE_VALUE_ENCODE_SET: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'%')
+    .add(b',')
+    .add(b';')
+    .add(b'\\');
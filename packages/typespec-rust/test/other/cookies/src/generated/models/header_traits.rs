@@ -0,0 +1,77 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::CookiesClientSignInResult;
+use azure_core::{
+    http::{
+        headers::{HeaderName, Headers},
+        NoFormat, Response,
+    },
+    Result,
+};
+use std::convert::Infallible;
+
+const SET_COOKIE: HeaderName = HeaderName::from_static("set-cookie");
+
+/// Provides access to typed response headers for [`CookiesClient::sign_in()`](crate::generated::clients::CookiesClient::sign_in())
+///
+/// # Examples
+///
+/// ```no_run
+/// use azure_core::{Result, http::{Response, NoFormat}};
+/// use cookies::models::{CookiesClientSignInResult, CookiesClientSignInResultHeaders};
+/// async fn example() -> Result<()> {
+///     let response: Response<CookiesClientSignInResult, NoFormat> = unimplemented!();
+///     // Access response headers
+///     if let Some(set_cookie) = response.set_cookie()? {
+///         println!("set-cookie: {:?}", set_cookie);
+///     }
+///     Ok(())
+/// }
+/// ```
+pub trait CookiesClientSignInResultHeaders: private::Sealed {
+    fn set_cookie(&self) -> Result<Option<Vec<String>>>;
+}
+
+impl CookiesClientSignInResultHeaders for Response<CookiesClientSignInResult, NoFormat> {
+    /// The cookies set by the service.
+    fn set_cookie(&self) -> Result<Option<Vec<String>>> {
+        Headers::get_optional_with(self.headers(), &SET_COOKIE, |h| {
+            Ok::<_, Infallible>(split_set_cookie(h.as_str()))
+        })
+    }
+}
+
+/// Splits a folded Set-Cookie header value into its individual cookies.
+///
+/// Commas within an attribute value (e.g. Expires) are not treated as delimiters.
+fn split_set_cookie(value: &str) -> Vec<String> {
+    let mut cookies: Vec<String> = Vec::new();
+    for chunk in value.split(',') {
+        // a new cookie always starts with a name=value pair
+        let is_new_cookie = chunk
+            .split(';')
+            .next()
+            .is_some_and(|pair| pair.contains('='));
+        if !is_new_cookie {
+            if let Some(last) = cookies.last_mut() {
+                last.push(',');
+                last.push_str(chunk);
+                continue;
+            }
+        }
+        cookies.push(chunk.trim().to_owned());
+    }
+    cookies
+}
+
+mod private {
+    use super::CookiesClientSignInResult;
+    use azure_core::http::{NoFormat, Response};
+
+    pub trait Sealed {}
+
+    impl Sealed for Response<CookiesClientSignInResult, NoFormat> {}
+}
@@ -0,0 +1,16 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{fmt::SafeDebug, http::ClientMethodOptions};
+
+/// Options to be passed to [`CookiesClient::sign_in()`](crate::generated::clients::CookiesClient::sign_in())
+#[derive(Clone, Default, SafeDebug)]
+pub struct CookiesClientSignInOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+
+    /// The preferred theme.
+    pub theme: Option<String>,
+}
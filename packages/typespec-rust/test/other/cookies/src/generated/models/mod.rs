@@ -0,0 +1,12 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+mod header_traits;
+mod method_options;
+#[allow(clippy::module_inception)]
+mod models;
+pub use header_traits::*;
+pub use method_options::*;
+pub use models::*;
@@ -0,0 +1,10 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::fmt::SafeDebug;
+
+/// Contains results for [`CookiesClient::sign_in()`](crate::generated::clients::CookiesClient::sign_in())
+#[derive(SafeDebug)]
+pub struct CookiesClientSignInResult;
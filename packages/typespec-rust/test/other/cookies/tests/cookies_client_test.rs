@@ -0,0 +1,105 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use async_trait::async_trait;
+use azure_core::http::{
+    headers::{HeaderName, Headers},
+    policies::{Policy, PolicyResult},
+    AsyncRawResponse, ClientOptions, Context, Method, Request, StatusCode,
+};
+use cookies::{
+    models::{CookiesClientSignInOptions, CookiesClientSignInResultHeaders},
+    CookiesClient, CookiesClientOptions,
+};
+use std::sync::Arc;
+
+/// Verifies the Cookie header and returns the Set-Cookie header in place of the transport.
+#[derive(Debug)]
+struct CookieTransport {
+    cookie: &'static str,
+    set_cookie: Option<&'static str>,
+}
+
+#[async_trait]
+impl Policy for CookieTransport {
+    async fn send(
+        &self,
+        _ctx: &Context,
+        request: &mut Request,
+        _next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        assert_eq!(request.method(), Method::Post);
+        assert_eq!(request.url().as_str(), "https://contoso.com/sign-in");
+        assert_eq!(
+            request
+                .headers()
+                .get_optional_str(&HeaderName::from_static("cookie")),
+            Some(self.cookie)
+        );
+        let mut headers = Headers::new();
+        if let Some(set_cookie) = self.set_cookie {
+            headers.insert("set-cookie", set_cookie);
+        }
+        Ok(AsyncRawResponse::from_bytes(
+            StatusCode::NoContent,
+            headers,
+            "",
+        ))
+    }
+}
+
+fn new_client(transport: CookieTransport) -> CookiesClient {
+    let options = CookiesClientOptions {
+        client_options: ClientOptions {
+            per_call_policies: vec![Arc::new(transport)],
+            ..Default::default()
+        },
+    };
+    CookiesClient::with_no_credential("https://contoso.com", Some(options)).unwrap()
+}
+
+#[tokio::test]
+async fn send_required_cookie() {
+    let client = new_client(CookieTransport {
+        cookie: "session=abc123",
+        set_cookie: None,
+    });
+    let response = client.sign_in("abc123".to_string(), None).await.unwrap();
+    assert_eq!(response.status(), StatusCode::NoContent);
+    assert_eq!(response.set_cookie().unwrap(), None);
+}
+
+#[tokio::test]
+async fn send_encoded_cookies() {
+    let client = new_client(CookieTransport {
+        cookie: "session=a%3Bb%2Cc%25d; theme=dark%20blue",
+        set_cookie: None,
+    });
+    let options = CookiesClientSignInOptions {
+        theme: Some("dark blue".to_string()),
+        ..Default::default()
+    };
+    client
+        .sign_in("a;b,c%d".to_string(), Some(options))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn parse_set_cookie() {
+    let client = new_client(CookieTransport {
+        cookie: "session=abc123",
+        set_cookie: Some(
+            "session=def456; Path=/; Expires=Wed, 21 Oct 2026 07:28:00 GMT; HttpOnly, theme=dark",
+        ),
+    });
+    let response = client.sign_in("abc123".to_string(), None).await.unwrap();
+    assert_eq!(
+        response.set_cookie().unwrap(),
+        Some(vec![
+            "session=def456; Path=/; Expires=Wed, 21 Oct 2026 07:28:00 GMT; HttpOnly".to_string(),
+            "theme=dark".to_string(),
+        ])
+    );
+}
@@ -25,8 +25,8 @@ impl FromStr for ExtensibleShape {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "round" => ExtensibleShape::Round,
-            "square" => ExtensibleShape::Square,
+            _ if s.eq_ignore_ascii_case("round") => ExtensibleShape::Round,
+            _ if s.eq_ignore_ascii_case("square") => ExtensibleShape::Square,
             _ => ExtensibleShape::UnknownValue(s.to_string()),
         })
     }
@@ -42,6 +42,12 @@ impl AsRef<str> for ExtensibleShape {
     }
 }
 
+impl PartialEq<str> for ExtensibleShape {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for ExtensibleShape {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -66,8 +72,8 @@ impl FromStr for ExtensibleValues {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "one" => ExtensibleValues::One,
-            "two" => ExtensibleValues::Two,
+            _ if s.eq_ignore_ascii_case("one") => ExtensibleValues::One,
+            _ if s.eq_ignore_ascii_case("two") => ExtensibleValues::Two,
             _ => ExtensibleValues::UnknownValue(s.to_string()),
         })
     }
@@ -83,6 +89,12 @@ impl AsRef<str> for ExtensibleValues {
     }
 }
 
+impl PartialEq<str> for ExtensibleValues {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for ExtensibleValues {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -0,0 +1,113 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::generated::models::{FormBodyClientGetTokenOptions, FormFormat, TokenRequest};
+use azure_core::{
+    error::CheckSuccessOptions,
+    fmt::SafeDebug,
+    http::{
+        ClientMethodOptions, ClientOptions, Method, NoFormat, Pipeline, PipelineSendOptions,
+        RawResponse, Request, RequestContent, Response, Url, UrlExt,
+    },
+    tracing, Result,
+};
+use url::form_urlencoded;
+
+#[tracing::client]
+pub struct FormBodyClient {
+    pub(crate) bogus_url: Url,
+    pub(crate) pipeline: Pipeline,
+}
+
+/// Options used when creating a [`FormBodyClient`](FormBodyClient)
+#[derive(Clone, Default, SafeDebug)]
+pub struct FormBodyClientOptions {
+    /// Allows customization of the client.
+    pub client_options: ClientOptions,
+}
+
+impl FormBodyClient {
+    /// Returns the Url associated with this client.
+    pub fn endpoint(&self) -> &Url {
+        &self.bogus_url
+    }
+
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("FormBody.getToken")]
+    pub async fn get_token(
+        &self,
+        body: TokenRequest,
+        options: Option<FormBodyClientGetTokenOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.bogus_url.clone();
+        url.append_path("/token");
+        let mut request = Request::new(url, Method::Post);
+        request.insert_header("content-type", "application/x-www-form-urlencoded");
+        let mut form_serializer = form_urlencoded::Serializer::new(String::new());
+        if let Some(value) = &body.client_id {
+            form_serializer.append_pair("client_id", value);
+        }
+        if let Some(value) = &body.expires_in {
+            form_serializer.append_pair("expires_in", &value.to_string());
+        }
+        if let Some(value) = &body.grant_type {
+            form_serializer.append_pair("grant_type", value.as_ref());
+        }
+        if let Some(value) = &body.resource {
+            for item in value {
+                form_serializer.append_pair("resource", item);
+            }
+        }
+        if let Some(value) = &body.scope {
+            form_serializer.append_pair("scope", &value.join(" "));
+        }
+        let body: RequestContent<TokenRequest, FormFormat> =
+            form_serializer.finish().into_bytes().into();
+        request.set_body(body);
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+}
@@ -0,0 +1,14 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GrantType {
+    ClientCredentials,
+
+    RefreshToken,
+
+    /// Any other value not defined in `GrantType`.
+    UnknownValue(String),
+}
@@ -0,0 +1,58 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::GrantType;
+use std::{
+    convert::{AsRef, From, Infallible},
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+impl<'a> From<&'a GrantType> for &'a str {
+    fn from(e: &'a GrantType) -> Self {
+        match e {
+            GrantType::ClientCredentials => "client_credentials",
+            GrantType::RefreshToken => "refresh_token",
+            GrantType::UnknownValue(s) => s.as_ref(),
+        }
+    }
+}
+
+impl FromStr for GrantType {
+    type Err = Infallible;
+    fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
+        Ok(match s {
+            _ if s.eq_ignore_ascii_case("client_credentials") => GrantType::ClientCredentials,
+            _ if s.eq_ignore_ascii_case("refresh_token") => GrantType::RefreshToken,
+            _ => GrantType::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl AsRef<str> for GrantType {
+    fn as_ref(&self) -> &str {
+        match self {
+            GrantType::ClientCredentials => "client_credentials",
+            GrantType::RefreshToken => "refresh_token",
+            GrantType::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+
+impl PartialEq<str> for GrantType {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
+impl Display for GrantType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            GrantType::ClientCredentials => f.write_str("client_credentials"),
+            GrantType::RefreshToken => f.write_str("refresh_token"),
+            GrantType::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
+}
@@ -0,0 +1,12 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::http::Format;
+
+/// The format for `application/x-www-form-urlencoded` request bodies.
+#[derive(Clone, Debug, Default)]
+pub struct FormFormat;
+
+impl Format for FormFormat {}
@@ -0,0 +1,16 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+mod enums;
+mod enums_impl;
+mod enums_serde;
+mod formats;
+mod method_options;
+#[allow(clippy::module_inception)]
+mod models;
+pub use enums::*;
+pub use formats::*;
+pub use method_options::*;
+pub use models::*;
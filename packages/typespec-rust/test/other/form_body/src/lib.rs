@@ -0,0 +1,38 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator.
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod generated;
+pub use generated::*;
+
+use azure_core::http::{Pipeline, Url};
+
+// The client is initialized by customized code so its constructor is hand-authored.
+impl FormBodyClient {
+    /// Creates a new FormBodyClient requiring no authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `bogus_url` - The service endpoint.
+    /// * `options` - Optional configuration for the client.
+    pub fn with_no_credential(
+        bogus_url: &str,
+        options: Option<FormBodyClientOptions>,
+    ) -> azure_core::Result<Self> {
+        let options = options.unwrap_or_default();
+        Ok(Self {
+            bogus_url: Url::parse(bogus_url)?,
+            pipeline: Pipeline::new(
+                option_env!("CARGO_PKG_NAME"),
+                option_env!("CARGO_PKG_VERSION"),
+                options.client_options,
+                Vec::default(),
+                Vec::default(),
+                None,
+            ),
+        })
+    }
+}
@@ -0,0 +1,82 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+use async_trait::async_trait;
+use azure_core::http::{
+    headers::{HeaderName, Headers},
+    policies::{Policy, PolicyResult},
+    AsyncRawResponse, Body, ClientOptions, Context, Request, StatusCode,
+};
+use form_body::{
+    models::{GrantType, TokenRequest},
+    FormBodyClient, FormBodyClientOptions,
+};
+use std::sync::Arc;
+
+/// Verifies the form-urlencoded request body in place of the transport.
+#[derive(Debug)]
+struct FormTransport {
+    expected: &'static str,
+}
+
+#[async_trait]
+impl Policy for FormTransport {
+    async fn send(
+        &self,
+        _ctx: &Context,
+        request: &mut Request,
+        _next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        assert_eq!(request.url().as_str(), "https://contoso.com/token");
+        assert_eq!(
+            request
+                .headers()
+                .get_optional_str(&HeaderName::from_static("content-type")),
+            Some("application/x-www-form-urlencoded")
+        );
+        let Body::Bytes(body) = request.body() else {
+            panic!("expected a buffered request body");
+        };
+        assert_eq!(std::str::from_utf8(body).unwrap(), self.expected);
+        Ok(AsyncRawResponse::from_bytes(
+            StatusCode::NoContent,
+            Headers::new(),
+            "",
+        ))
+    }
+}
+
+fn new_client(expected: &'static str) -> FormBodyClient {
+    let options = FormBodyClientOptions {
+        client_options: ClientOptions {
+            per_call_policies: vec![Arc::new(FormTransport { expected })],
+            ..Default::default()
+        },
+    };
+    FormBodyClient::with_no_credential("https://contoso.com", Some(options)).unwrap()
+}
+
+#[tokio::test]
+async fn get_token_all_fields() {
+    let body = TokenRequest {
+        client_id: Some("my app".to_string()),
+        expires_in: Some(3600),
+        grant_type: Some(GrantType::ClientCredentials),
+        resource: Some(vec!["r1".to_string(), "r2&r3".to_string()]),
+        scope: Some(vec!["read".to_string(), "write".to_string()]),
+    };
+    let client = new_client(
+        "client_id=my+app&expires_in=3600&grant_type=client_credentials&resource=r1&resource=r2%26r3&scope=read+write",
+    );
+    client.get_token(body, None).await.unwrap();
+}
+
+#[tokio::test]
+async fn get_token_omits_unset_fields() {
+    let body = TokenRequest {
+        grant_type: Some(GrantType::RefreshToken),
+        ..Default::default()
+    };
+    let client = new_client("grant_type=refresh_token");
+    client.get_token(body, None).await.unwrap();
+}
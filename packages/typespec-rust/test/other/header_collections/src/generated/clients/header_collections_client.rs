@@ -0,0 +1,155 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::generated::models::{
+    HeaderCollectionsClientGetWidgetMetadataOptions, HeaderCollectionsClientGetWidgetMetadataResult,
+};
+use azure_core::{
+    error::CheckSuccessOptions,
+    fmt::SafeDebug,
+    http::{
+        ClientMethodOptions, ClientOptions, Method, NoFormat, Pipeline, PipelineSendOptions,
+        RawResponse, Request, Response, Url, UrlExt,
+    },
+    tracing, Result,
+};
+
+#[tracing::client]
+pub struct HeaderCollectionsClient {
+    pub(crate) endpoint: Url,
+    pub(crate) pipeline: Pipeline,
+}
+
+/// Options used when creating a [`HeaderCollectionsClient`](HeaderCollectionsClient)
+#[derive(Clone, Default, SafeDebug)]
+pub struct HeaderCollectionsClientOptions {
+    /// Allows customization of the client.
+    pub client_options: ClientOptions,
+}
+
+impl HeaderCollectionsClient {
+    /// Creates a new HeaderCollectionsClient requiring no authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Service host
+    /// * `options` - Optional configuration for the client.
+    #[tracing::new("HeaderCollections")]
+    pub fn with_no_credential(
+        endpoint: &str,
+        options: Option<HeaderCollectionsClientOptions>,
+    ) -> Result<Self> {
+        let options = options.unwrap_or_default();
+        let endpoint = Url::parse(endpoint)?;
+        if !endpoint.scheme().starts_with("http") {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                format!("{endpoint} must use http(s)"),
+            ));
+        }
+        Ok(Self {
+            endpoint,
+            pipeline: Pipeline::new(
+                option_env!("CARGO_PKG_NAME"),
+                option_env!("CARGO_PKG_VERSION"),
+                options.client_options,
+                Vec::default(),
+                Vec::default(),
+                None,
+            ),
+        })
+    }
+
+    /// Returns the Url associated with this client.
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
+    /// Gets the metadata of the widget.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    ///
+    /// ## Response Headers
+    ///
+    /// The returned [`Response`](azure_core::http::Response) implements the [`HeaderCollectionsClientGetWidgetMetadataResultHeaders`] trait, which provides
+    /// access to response headers. For example:
+    ///
+    /// ```no_run
+    /// use azure_core::{Result, http::{Response, NoFormat}};
+    /// use header_collections::models::{HeaderCollectionsClientGetWidgetMetadataResult, HeaderCollectionsClientGetWidgetMetadataResultHeaders};
+    /// async fn example() -> Result<()> {
+    ///     let response: Response<HeaderCollectionsClientGetWidgetMetadataResult, NoFormat> = unimplemented!();
+    ///     // Access response headers
+    ///     if let Some(allowed_methods) = response.allowed_methods()? {
+    ///         println!("x-ms-allowed-methods: {:?}", allowed_methods);
+    ///     }
+    ///     if let Some(colors) = response.colors()? {
+    ///         println!("x-ms-colors: {:?}", colors);
+    ///     }
+    ///     if let Some(part_sizes) = response.part_sizes()? {
+    ///         println!("x-ms-part-sizes: {:?}", part_sizes);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ### Available headers
+    /// * [`allowed_methods`()](crate::generated::models::HeaderCollectionsClientGetWidgetMetadataResultHeaders::allowed_methods) - x-ms-allowed-methods
+    /// * [`colors`()](crate::generated::models::HeaderCollectionsClientGetWidgetMetadataResultHeaders::colors) - x-ms-colors
+    /// * [`part_sizes`()](crate::generated::models::HeaderCollectionsClientGetWidgetMetadataResultHeaders::part_sizes) - x-ms-part-sizes
+    ///
+    /// [`HeaderCollectionsClientGetWidgetMetadataResultHeaders`]: crate::generated::models::HeaderCollectionsClientGetWidgetMetadataResultHeaders
+    #[tracing::function("HeaderCollections.getWidgetMetadata")]
+    pub async fn get_widget_metadata(
+        &self,
+        options: Option<HeaderCollectionsClientGetWidgetMetadataOptions<'_>>,
+    ) -> Result<Response<HeaderCollectionsClientGetWidgetMetadataResult, NoFormat>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/widget");
+        let mut request = Request::new(url, Method::Head);
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+}
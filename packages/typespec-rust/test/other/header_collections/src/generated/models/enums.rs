@@ -0,0 +1,17 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+/// The colors a widget can have.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Color {
+    /// Blue.
+    Blue,
+
+    /// Red.
+    Red,
+
+    /// Any other value not defined in `Color`.
+    UnknownValue(String),
+}
@@ -0,0 +1,58 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::Color;
+use std::{
+    convert::{AsRef, From, Infallible},
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+impl<'a> From<&'a Color> for &'a str {
+    fn from(e: &'a Color) -> Self {
+        match e {
+            Color::Blue => "Blue",
+            Color::Red => "Red",
+            Color::UnknownValue(s) => s.as_ref(),
+        }
+    }
+}
+
+impl FromStr for Color {
+    type Err = Infallible;
+    fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
+        Ok(match s {
+            _ if s.eq_ignore_ascii_case("Blue") => Color::Blue,
+            _ if s.eq_ignore_ascii_case("Red") => Color::Red,
+            _ => Color::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl AsRef<str> for Color {
+    fn as_ref(&self) -> &str {
+        match self {
+            Color::Blue => "Blue",
+            Color::Red => "Red",
+            Color::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+
+impl PartialEq<str> for Color {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
+impl Display for Color {
+    fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            Color::Blue => f.write_str("Blue"),
+            Color::Red => f.write_str("Red"),
+            Color::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
+}
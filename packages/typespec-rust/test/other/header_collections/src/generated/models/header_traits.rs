@@ -0,0 +1,91 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::{Color, HeaderCollectionsClientGetWidgetMetadataResult};
+use azure_core::{
+    http::{
+        headers::{HeaderName, Headers},
+        NoFormat, Response,
+    },
+    Result,
+};
+
+const ALLOWED_METHODS: HeaderName = HeaderName::from_static("x-ms-allowed-methods");
+const COLORS: HeaderName = HeaderName::from_static("x-ms-colors");
+const PART_SIZES: HeaderName = HeaderName::from_static("x-ms-part-sizes");
+
+/// Provides access to typed response headers for [`HeaderCollectionsClient::get_widget_metadata()`](crate::generated::clients::HeaderCollectionsClient::get_widget_metadata())
+///
+/// # Examples
+///
+/// ```no_run
+/// use azure_core::{Result, http::{Response, NoFormat}};
+/// use header_collections::models::{HeaderCollectionsClientGetWidgetMetadataResult, HeaderCollectionsClientGetWidgetMetadataResultHeaders};
+/// async fn example() -> Result<()> {
+///     let response: Response<HeaderCollectionsClientGetWidgetMetadataResult, NoFormat> = unimplemented!();
+///     // Access response headers
+///     if let Some(allowed_methods) = response.allowed_methods()? {
+///         println!("x-ms-allowed-methods: {:?}", allowed_methods);
+///     }
+///     if let Some(colors) = response.colors()? {
+///         println!("x-ms-colors: {:?}", colors);
+///     }
+///     if let Some(part_sizes) = response.part_sizes()? {
+///         println!("x-ms-part-sizes: {:?}", part_sizes);
+///     }
+///     Ok(())
+/// }
+/// ```
+pub trait HeaderCollectionsClientGetWidgetMetadataResultHeaders: private::Sealed {
+    fn allowed_methods(&self) -> Result<Option<Vec<String>>>;
+    fn colors(&self) -> Result<Option<Vec<Color>>>;
+    fn part_sizes(&self) -> Result<Option<Vec<i32>>>;
+}
+
+impl HeaderCollectionsClientGetWidgetMetadataResultHeaders
+    for Response<HeaderCollectionsClientGetWidgetMetadataResult, NoFormat>
+{
+    /// The methods allowed on the widget.
+    fn allowed_methods(&self) -> Result<Option<Vec<String>>> {
+        Headers::get_optional_with(self.headers(), &ALLOWED_METHODS, |h| {
+            h.as_str()
+                .split(",")
+                .map(|v| v.trim())
+                .map(str::parse)
+                .collect::<std::result::Result<Vec<_>, _>>()
+        })
+    }
+
+    /// The colors of the widget.
+    fn colors(&self) -> Result<Option<Vec<Color>>> {
+        Headers::get_optional_with(self.headers(), &COLORS, |h| {
+            h.as_str()
+                .split(",")
+                .map(|v| v.trim())
+                .map(str::parse)
+                .collect::<std::result::Result<Vec<_>, _>>()
+        })
+    }
+
+    /// The sizes of the widget's parts.
+    fn part_sizes(&self) -> Result<Option<Vec<i32>>> {
+        Headers::get_optional_with(self.headers(), &PART_SIZES, |h| {
+            h.as_str()
+                .split(",")
+                .map(|v| v.trim())
+                .map(str::parse)
+                .collect::<std::result::Result<Vec<_>, _>>()
+        })
+    }
+}
+
+mod private {
+    use super::HeaderCollectionsClientGetWidgetMetadataResult;
+    use azure_core::http::{NoFormat, Response};
+
+    pub trait Sealed {}
+
+    impl Sealed for Response<HeaderCollectionsClientGetWidgetMetadataResult, NoFormat> {}
+}
@@ -0,0 +1,13 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{fmt::SafeDebug, http::ClientMethodOptions};
+
+/// Options to be passed to [`HeaderCollectionsClient::get_widget_metadata()`](crate::generated::clients::HeaderCollectionsClient::get_widget_metadata())
+#[derive(Clone, Default, SafeDebug)]
+pub struct HeaderCollectionsClientGetWidgetMetadataOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
@@ -0,0 +1,10 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::fmt::SafeDebug;
+
+/// Contains results for [`HeaderCollectionsClient::get_widget_metadata()`](crate::generated::clients::HeaderCollectionsClient::get_widget_metadata())
+#[derive(SafeDebug)]
+pub struct HeaderCollectionsClientGetWidgetMetadataResult;
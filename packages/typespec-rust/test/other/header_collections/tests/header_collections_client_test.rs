@@ -0,0 +1,104 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use async_trait::async_trait;
+use azure_core::http::{
+    headers::Headers,
+    policies::{Policy, PolicyResult},
+    AsyncRawResponse, ClientOptions, Context, Method, Request, StatusCode,
+};
+use header_collections::{
+    models::{Color, HeaderCollectionsClientGetWidgetMetadataResultHeaders},
+    HeaderCollectionsClient, HeaderCollectionsClientOptions,
+};
+use std::sync::Arc;
+
+/// Returns the response headers in place of the transport.
+#[derive(Debug)]
+struct HeadersTransport {
+    headers: &'static [(&'static str, &'static str)],
+}
+
+#[async_trait]
+impl Policy for HeadersTransport {
+    async fn send(
+        &self,
+        _ctx: &Context,
+        request: &mut Request,
+        _next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        assert_eq!(request.method(), Method::Head);
+        assert_eq!(request.url().as_str(), "https://contoso.com/widget");
+        let mut headers = Headers::new();
+        for (name, value) in self.headers {
+            headers.insert(*name, *value);
+        }
+        Ok(AsyncRawResponse::from_bytes(
+            StatusCode::NoContent,
+            headers,
+            "",
+        ))
+    }
+}
+
+fn new_client(headers: &'static [(&'static str, &'static str)]) -> HeaderCollectionsClient {
+    let options = HeaderCollectionsClientOptions {
+        client_options: ClientOptions {
+            per_call_policies: vec![Arc::new(HeadersTransport { headers })],
+            ..Default::default()
+        },
+    };
+    HeaderCollectionsClient::with_no_credential("https://contoso.com", Some(options)).unwrap()
+}
+
+#[tokio::test]
+async fn parse_header_collections() {
+    let client = new_client(&[
+        ("x-ms-allowed-methods", "GET, PUT,DELETE"),
+        ("x-ms-colors", "Red, blue, Green"),
+        ("x-ms-part-sizes", "1, 20,300"),
+    ]);
+    let response = client.get_widget_metadata(None).await.unwrap();
+    assert_eq!(
+        response.allowed_methods().unwrap(),
+        Some(vec![
+            "GET".to_string(),
+            "PUT".to_string(),
+            "DELETE".to_string()
+        ])
+    );
+    // extensible enum values are matched case-insensitively and unknown values are preserved
+    assert_eq!(
+        response.colors().unwrap(),
+        Some(vec![
+            Color::Red,
+            Color::Blue,
+            Color::UnknownValue("Green".to_string())
+        ])
+    );
+    assert_eq!(response.part_sizes().unwrap(), Some(vec![1, 20, 300]));
+}
+
+#[tokio::test]
+async fn parse_single_element() {
+    let client = new_client(&[("x-ms-part-sizes", "42")]);
+    let response = client.get_widget_metadata(None).await.unwrap();
+    assert_eq!(response.part_sizes().unwrap(), Some(vec![42]));
+}
+
+#[tokio::test]
+async fn missing_header_collections() {
+    let client = new_client(&[]);
+    let response = client.get_widget_metadata(None).await.unwrap();
+    assert_eq!(response.allowed_methods().unwrap(), None);
+    assert_eq!(response.colors().unwrap(), None);
+    assert_eq!(response.part_sizes().unwrap(), None);
+}
+
+#[tokio::test]
+async fn invalid_header_collection_element() {
+    let client = new_client(&[("x-ms-part-sizes", "1,two,3")]);
+    let response = client.get_widget_metadata(None).await.unwrap();
+    assert!(response.part_sizes().is_err());
+}
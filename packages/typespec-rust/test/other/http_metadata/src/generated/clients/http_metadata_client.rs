@@ -0,0 +1,205 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::generated::models::{
+    HttpMetadataClientGetWidgetOptions, HttpMetadataClientUpdateWidgetOptions, Widget, WidgetUpdate,
+};
+use azure_core::{
+    error::CheckSuccessOptions,
+    fmt::SafeDebug,
+    http::{
+        ClientMethodOptions, ClientOptions, Method, NoFormat, Pipeline, PipelineSendOptions,
+        RawResponse, Request, RequestContent, Response, Url, UrlExt,
+    },
+    tracing, Result,
+};
+
+#[tracing::client]
+pub struct HttpMetadataClient {
+    pub(crate) endpoint: Url,
+    pub(crate) pipeline: Pipeline,
+}
+
+/// Options used when creating a [`HttpMetadataClient`](HttpMetadataClient)
+#[derive(Clone, Default, SafeDebug)]
+pub struct HttpMetadataClientOptions {
+    /// Allows customization of the client.
+    pub client_options: ClientOptions,
+}
+
+impl HttpMetadataClient {
+    /// Creates a new HttpMetadataClient requiring no authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Service host
+    /// * `options` - Optional configuration for the client.
+    #[tracing::new("HttpMetadata")]
+    pub fn with_no_credential(
+        endpoint: &str,
+        options: Option<HttpMetadataClientOptions>,
+    ) -> Result<Self> {
+        let options = options.unwrap_or_default();
+        let endpoint = Url::parse(endpoint)?;
+        if !endpoint.scheme().starts_with("http") {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                format!("{endpoint} must use http(s)"),
+            ));
+        }
+        Ok(Self {
+            endpoint,
+            pipeline: Pipeline::new(
+                option_env!("CARGO_PKG_NAME"),
+                option_env!("CARGO_PKG_VERSION"),
+                options.client_options,
+                Vec::default(),
+                Vec::default(),
+                None,
+            ),
+        })
+    }
+
+    /// Returns the Url associated with this client.
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
+    /// Gets a widget.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the widget.
+    /// * `options` - Optional parameters for the request.
+    ///
+    /// ## Response Headers
+    ///
+    /// The returned [`Response`](azure_core::http::Response) implements the [`WidgetHeaders`] trait, which provides
+    /// access to response headers. For example:
+    ///
+    /// ```no_run
+    /// use azure_core::{Result, http::Response};
+    /// use http_metadata::models::{Widget, WidgetHeaders};
+    /// async fn example() -> Result<()> {
+    ///     let response: Response<Widget> = unimplemented!();
+    ///     // Access response headers
+    ///     if let Some(etag) = response.etag()? {
+    ///         println!("etag: {:?}", etag);
+    ///     }
+    ///     if let Some(revision) = response.revision()? {
+    ///         println!("x-ms-revision: {:?}", revision);
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    ///
+    /// ### Available headers
+    /// * [`etag`()](crate::generated::models::WidgetHeaders::etag) - etag
+    /// * [`revision`()](crate::generated::models::WidgetHeaders::revision) - x-ms-revision
+    ///
+    /// [`WidgetHeaders`]: crate::generated::models::WidgetHeaders
+    #[tracing::function("HttpMetadata.getWidget")]
+    pub async fn get_widget(
+        &self,
+        name: &str,
+        options: Option<HttpMetadataClientGetWidgetOptions<'_>>,
+    ) -> Result<Response<Widget>> {
+        if name.is_empty() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "parameter name cannot be empty",
+            ));
+        }
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        let mut path = String::from("/widgets/{name}");
+        path = path.replace("{name}", name);
+        url.append_path(&path);
+        let mut request = Request::new(url, Method::Get);
+        request.insert_header("accept", "application/json");
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[200],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    /// Updates a widget.
+    ///
+    /// # Arguments
+    ///
+    /// * `widget` - The widget to update.
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("HttpMetadata.updateWidget")]
+    pub async fn update_widget(
+        &self,
+        widget: RequestContent<WidgetUpdate>,
+        options: Option<HttpMetadataClientUpdateWidgetOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/widgets");
+        let mut query_builder = url.query_builder();
+        if let Some(dry_run) = options.dry_run {
+            query_builder.set_pair("dry-run", dry_run.to_string());
+        }
+        query_builder.build();
+        let mut request = Request::new(url, Method::Put);
+        request.insert_header("content-type", "application/json");
+        if let Some(if_match) = options.if_match.as_ref() {
+            request.insert_header("if-match", if_match);
+        }
+        request.set_body(widget);
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+}
@@ -0,0 +1,61 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::Widget;
+use azure_core::{
+    http::{
+        headers::{HeaderName, Headers},
+        Response,
+    },
+    Result,
+};
+
+const ETAG: HeaderName = HeaderName::from_static("etag");
+const REVISION: HeaderName = HeaderName::from_static("x-ms-revision");
+
+/// Provides access to typed response headers for [`HttpMetadataClient::get_widget()`](crate::generated::clients::HttpMetadataClient::get_widget())
+///
+/// # Examples
+///
+/// ```no_run
+/// use azure_core::{Result, http::Response};
+/// use http_metadata::models::{Widget, WidgetHeaders};
+/// async fn example() -> Result<()> {
+///     let response: Response<Widget> = unimplemented!();
+///     // Access response headers
+///     if let Some(etag) = response.etag()? {
+///         println!("etag: {:?}", etag);
+///     }
+///     if let Some(revision) = response.revision()? {
+///         println!("x-ms-revision: {:?}", revision);
+///     }
+///     Ok(())
+/// }
+/// ```
+pub trait WidgetHeaders: private::Sealed {
+    fn etag(&self) -> Result<Option<String>>;
+    fn revision(&self) -> Result<Option<i32>>;
+}
+
+impl WidgetHeaders for Response<Widget> {
+    /// The entity tag of the widget.
+    fn etag(&self) -> Result<Option<String>> {
+        Headers::get_optional_as(self.headers(), &ETAG)
+    }
+
+    /// The revision of the widget.
+    fn revision(&self) -> Result<Option<i32>> {
+        Headers::get_optional_as(self.headers(), &REVISION)
+    }
+}
+
+mod private {
+    use super::Widget;
+    use azure_core::http::Response;
+
+    pub trait Sealed {}
+
+    impl Sealed for Response<Widget> {}
+}
@@ -0,0 +1,26 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{fmt::SafeDebug, http::ClientMethodOptions};
+
+/// Options to be passed to [`HttpMetadataClient::get_widget()`](crate::generated::clients::HttpMetadataClient::get_widget())
+#[derive(Clone, Default, SafeDebug)]
+pub struct HttpMetadataClientGetWidgetOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`HttpMetadataClient::update_widget()`](crate::generated::clients::HttpMetadataClient::update_widget())
+#[derive(Clone, Default, SafeDebug)]
+pub struct HttpMetadataClientUpdateWidgetOptions<'a> {
+    /// Validate the update without applying it.
+    pub dry_run: Option<bool>,
+
+    /// Only update the widget if its entity tag matches.
+    pub if_match: Option<String>,
+
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
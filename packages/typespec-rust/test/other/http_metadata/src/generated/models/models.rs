@@ -0,0 +1,35 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::fmt::SafeDebug;
+use serde::{Deserialize, Serialize};
+
+/// A widget.
+///
+/// The HTTP status code and headers aren't fields of this type. Read them from the [`Response`](azure_core::http::Response)
+/// that returns it, using `status()` and the response's headers trait.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+#[non_exhaustive]
+pub struct Widget {
+    /// The color of the widget.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+
+    /// The name of the widget.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// The parameters for updating a widget.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+pub struct WidgetUpdate {
+    /// The color of the widget.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+
+    /// The name of the widget.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
@@ -0,0 +1,14 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::WidgetUpdate;
+use azure_core::{http::RequestContent, json::to_json, Result};
+
+impl TryFrom<WidgetUpdate> for RequestContent<WidgetUpdate> {
+    type Error = azure_core::Error;
+    fn try_from(value: WidgetUpdate) -> Result<Self> {
+        Ok(to_json(&value)?.into())
+    }
+}
@@ -0,0 +1,111 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+use async_trait::async_trait;
+use azure_core::http::{
+    headers::{HeaderName, Headers},
+    policies::{Policy, PolicyResult},
+    AsyncRawResponse, Body, ClientOptions, Context, Method, Request, StatusCode,
+};
+use http_metadata::{
+    models::{HttpMetadataClientUpdateWidgetOptions, WidgetHeaders, WidgetUpdate},
+    HttpMetadataClient, HttpMetadataClientOptions,
+};
+use serde_json::Value;
+use std::sync::Arc;
+
+/// Verifies the request and returns a canned response in place of the transport.
+#[derive(Debug)]
+struct WidgetTransport;
+
+#[async_trait]
+impl Policy for WidgetTransport {
+    async fn send(
+        &self,
+        _ctx: &Context,
+        request: &mut Request,
+        _next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        match request.method() {
+            Method::Get => {
+                assert_eq!(request.url().as_str(), "https://contoso.com/widgets/a");
+                let mut headers = Headers::new();
+                headers.insert("etag", "\"0x1\"");
+                headers.insert("x-ms-revision", "3");
+                Ok(AsyncRawResponse::from_bytes(
+                    StatusCode::Ok,
+                    headers,
+                    r#"{"name":"a","color":"red"}"#,
+                ))
+            }
+            Method::Put => {
+                // the metadata values are sent as a header and query param, not in the body
+                assert_eq!(
+                    request.url().as_str(),
+                    "https://contoso.com/widgets?dry-run=true"
+                );
+                assert_eq!(
+                    request
+                        .headers()
+                        .get_optional_str(&HeaderName::from_static("if-match")),
+                    Some("\"0x1\"")
+                );
+                let Body::Bytes(body) = request.body() else {
+                    panic!("expected a buffered request body");
+                };
+                assert_eq!(
+                    serde_json::from_slice::<Value>(body).unwrap(),
+                    serde_json::json!({"name": "a", "color": "blue"})
+                );
+                Ok(AsyncRawResponse::from_bytes(
+                    StatusCode::NoContent,
+                    Headers::new(),
+                    "",
+                ))
+            }
+            method => panic!("unexpected method {method:?}"),
+        }
+    }
+}
+
+fn new_client() -> HttpMetadataClient {
+    let options = HttpMetadataClientOptions {
+        client_options: ClientOptions {
+            per_call_policies: vec![Arc::new(WidgetTransport)],
+            ..Default::default()
+        },
+    };
+    HttpMetadataClient::with_no_credential("https://contoso.com", Some(options)).unwrap()
+}
+
+#[tokio::test]
+async fn get_widget_metadata() {
+    let response = new_client().get_widget("a", None).await.unwrap();
+    assert_eq!(response.status(), StatusCode::Ok);
+    assert_eq!(response.etag().unwrap().as_deref(), Some("\"0x1\""));
+    assert_eq!(response.revision().unwrap(), Some(3));
+
+    let widget = response.into_model().unwrap();
+    assert_eq!(widget.name.as_deref(), Some("a"));
+    assert_eq!(widget.color.as_deref(), Some("red"));
+}
+
+#[tokio::test]
+async fn update_widget_metadata() {
+    let widget = WidgetUpdate {
+        name: Some("a".to_string()),
+        color: Some("blue".to_string()),
+    };
+    let response = new_client()
+        .update_widget(
+            widget.try_into().unwrap(),
+            Some(HttpMetadataClientUpdateWidgetOptions {
+                dry_run: Some(true),
+                if_match: Some("\"0x1\"".to_string()),
+                ..Default::default()
+            }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NoContent);
+}
@@ -18,8 +18,8 @@ use azure_core::{
             get_retry_after, PollerContinuation, PollerResult, PollerState, PollerStatus,
             StatusMonitor,
         },
-        Method, Pipeline, PipelineSendOptions, Poller, RawResponse, Request, RequestContent,
-        Response, Url, UrlExt,
+        ClientMethodOptions, Method, Pipeline, PipelineSendOptions, Poller, RawResponse, Request,
+        RequestContent, Response, Url, UrlExt,
     },
     json, tracing, Result,
 };
@@ -36,6 +36,30 @@ impl NIClient {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     /// The most basic operation.
     ///
     /// # Arguments
@@ -26,9 +26,9 @@ impl FromStr for Colors {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "blue" => Colors::Blue,
-            "green" => Colors::Green,
-            "yellow" => Colors::Yellow,
+            _ if s.eq_ignore_ascii_case("blue") => Colors::Blue,
+            _ if s.eq_ignore_ascii_case("green") => Colors::Green,
+            _ if s.eq_ignore_ascii_case("yellow") => Colors::Yellow,
             _ => Colors::UnknownValue(s.to_string()),
         })
     }
@@ -45,6 +45,12 @@ impl AsRef<str> for Colors {
     }
 }
 
+impl PartialEq<str> for Colors {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for Colors {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -13,6 +13,7 @@ mod models;
 mod models_impl;
 mod models_serde;
 mod unions;
+mod unions_impl;
 mod unions_serde;
 mod xml_helpers;
 pub use enums::*;
@@ -27,7 +27,7 @@ pub enum ChatRequestMessage {
     UnknownRole { role: Option<String> },
 }
 
-#[derive(Clone, Deserialize, SafeDebug, Serialize)]
+#[derive(Clone, SafeDebug, Serialize)]
 #[serde(untagged)]
 pub enum ChatRequestUserMessageContent {
     String(String),
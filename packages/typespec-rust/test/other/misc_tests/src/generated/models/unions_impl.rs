@@ -0,0 +1,62 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::{ChatMessageContentItem, ChatRequestUserMessageContent};
+
+impl ChatRequestUserMessageContent {
+    /// Returns `true` if this is the [`ChatRequestUserMessageContent::String`] variant.
+    pub fn is_string(&self) -> bool {
+        matches!(self, Self::String(_))
+    }
+
+    /// Returns a reference to the value if this is the [`ChatRequestUserMessageContent::String`] variant.
+    pub fn as_string(&self) -> Option<&String> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`ChatRequestUserMessageContent::String`] variant.
+    pub fn into_string(self) -> Option<String> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this is the [`ChatRequestUserMessageContent::ChatMessageContentItemArray`] variant.
+    pub fn is_chat_message_content_item_array(&self) -> bool {
+        matches!(self, Self::ChatMessageContentItemArray(_))
+    }
+
+    /// Returns a reference to the value if this is the [`ChatRequestUserMessageContent::ChatMessageContentItemArray`] variant.
+    pub fn as_chat_message_content_item_array(&self) -> Option<&Vec<ChatMessageContentItem>> {
+        match self {
+            Self::ChatMessageContentItemArray(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`ChatRequestUserMessageContent::ChatMessageContentItemArray`] variant.
+    pub fn into_chat_message_content_item_array(self) -> Option<Vec<ChatMessageContentItem>> {
+        match self {
+            Self::ChatMessageContentItemArray(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl From<String> for ChatRequestUserMessageContent {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<Vec<ChatMessageContentItem>> for ChatRequestUserMessageContent {
+    fn from(value: Vec<ChatMessageContentItem>) -> Self {
+        Self::ChatMessageContentItemArray(value)
+    }
+}
@@ -5,10 +5,11 @@
 
 use super::{
     ChatMessageContentItem, ChatMessageTextContentItem, ChatRequestMessage, ChatRequestUserMessage,
-    DiscriminatedBaseNoSubTypes, UnknownChatMessageContentItemType, UnknownChatRequestMessageRole,
-    UnknownDiscriminatedBaseNoSubTypesKind,
+    ChatRequestUserMessageContent, DiscriminatedBaseNoSubTypes, UnknownChatMessageContentItemType,
+    UnknownChatRequestMessageRole, UnknownDiscriminatedBaseNoSubTypesKind,
 };
-use serde::{Serialize, Serializer};
+use azure_core::Value;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 impl Serialize for ChatMessageContentItem {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -46,6 +47,28 @@ impl Serialize for ChatRequestMessage {
     }
 }
 
+impl<'de> Deserialize<'de> for ChatRequestUserMessageContent {
+    fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let mut errors = Vec::new();
+        match Deserialize::deserialize(&value).map(Self::String) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("String: {err}")),
+        }
+        match Deserialize::deserialize(&value).map(Self::ChatMessageContentItemArray) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("ChatMessageContentItemArray: {err}")),
+        }
+        Err(serde::de::Error::custom(format!(
+            "data did not match any variant of untagged enum ChatRequestUserMessageContent ({})",
+            errors.join("; ")
+        )))
+    }
+}
+
 impl Serialize for DiscriminatedBaseNoSubTypes {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -0,0 +1,154 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::generated::models::{
+    NegotiatedResponseClientGetAvatarAccept, NegotiatedResponseClientGetAvatarOptions,
+    NegotiatedResponseClientGetAvatarResponse,
+};
+use azure_core::{
+    error::{CheckSuccessOptions, Error, ErrorKind},
+    fmt::SafeDebug,
+    http::{
+        headers::CONTENT_TYPE, ClientMethodOptions, ClientOptions, Method, Pipeline,
+        PipelineSendOptions, RawResponse, Request, Url, UrlExt,
+    },
+    tracing, Result,
+};
+
+#[tracing::client]
+pub struct NegotiatedResponseClient {
+    pub(crate) endpoint: Url,
+    pub(crate) pipeline: Pipeline,
+}
+
+/// Options used when creating a [`NegotiatedResponseClient`](NegotiatedResponseClient)
+#[derive(Clone, Default, SafeDebug)]
+pub struct NegotiatedResponseClientOptions {
+    /// Allows customization of the client.
+    pub client_options: ClientOptions,
+}
+
+impl NegotiatedResponseClient {
+    /// Creates a new NegotiatedResponseClient requiring no authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Service host
+    /// * `options` - Optional configuration for the client.
+    #[tracing::new("NegotiatedResponse")]
+    pub fn with_no_credential(
+        endpoint: &str,
+        options: Option<NegotiatedResponseClientOptions>,
+    ) -> Result<Self> {
+        let options = options.unwrap_or_default();
+        let endpoint = Url::parse(endpoint)?;
+        if !endpoint.scheme().starts_with("http") {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                format!("{endpoint} must use http(s)"),
+            ));
+        }
+        Ok(Self {
+            endpoint,
+            pipeline: Pipeline::new(
+                option_env!("CARGO_PKG_NAME"),
+                option_env!("CARGO_PKG_VERSION"),
+                options.client_options,
+                Vec::default(),
+                Vec::default(),
+                None,
+            ),
+        })
+    }
+
+    /// Returns the Url associated with this client.
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
+    /// Gets an avatar in the requested media type.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the avatar.
+    /// * `accept` - The media type of the response content.
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("NegotiatedResponse.getAvatar")]
+    pub async fn get_avatar(
+        &self,
+        name: &str,
+        accept: NegotiatedResponseClientGetAvatarAccept,
+        options: Option<NegotiatedResponseClientGetAvatarOptions<'_>>,
+    ) -> Result<NegotiatedResponseClientGetAvatarResponse> {
+        if name.is_empty() {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "parameter name cannot be empty",
+            ));
+        }
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        let mut path = String::from("/avatars/{name}");
+        path = path.replace("{name}", name);
+        url.append_path(&path);
+        let mut request = Request::new(url, Method::Get);
+        request.insert_header("accept", accept.to_string());
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[200],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        let content_type = rsp
+            .headers()
+            .get_optional_str(&CONTENT_TYPE)
+            .and_then(|v| v.split(';').next())
+            .unwrap_or_default()
+            .trim()
+            .to_ascii_lowercase();
+        match content_type.as_str() {
+            "application/json" => Ok(NegotiatedResponseClientGetAvatarResponse::ApplicationJson(
+                rsp.into(),
+            )),
+            "image/png" => Ok(NegotiatedResponseClientGetAvatarResponse::ImagePng(rsp)),
+            _ => Err(Error::with_message(
+                ErrorKind::DataConversion,
+                format!("unexpected content type '{content_type}'"),
+            )),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+/// The media types that can be requested from [`NegotiatedResponseClient::get_avatar()`](crate::generated::clients::NegotiatedResponseClient::get_avatar())
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NegotiatedResponseClientGetAvatarAccept {
+    /// Requests `application/json` content.
+    ApplicationJson,
+
+    /// Requests `image/png` content.
+    ImagePng,
+}
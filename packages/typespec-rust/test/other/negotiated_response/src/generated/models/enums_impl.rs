@@ -0,0 +1,49 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::NegotiatedResponseClientGetAvatarAccept;
+use azure_core::error::{Error, ErrorKind};
+use std::{
+    convert::AsRef,
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+impl FromStr for NegotiatedResponseClientGetAvatarAccept {
+    type Err = Error;
+    fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
+        Ok(match s {
+            "application/json" => NegotiatedResponseClientGetAvatarAccept::ApplicationJson,
+            "image/png" => NegotiatedResponseClientGetAvatarAccept::ImagePng,
+            _ => {
+                return Err(Error::with_message_fn(ErrorKind::DataConversion, || {
+                    format!(
+                        "unknown variant of NegotiatedResponseClientGetAvatarAccept found: \"{s}\""
+                    )
+                }))
+            }
+        })
+    }
+}
+
+impl AsRef<str> for NegotiatedResponseClientGetAvatarAccept {
+    fn as_ref(&self) -> &str {
+        match self {
+            NegotiatedResponseClientGetAvatarAccept::ApplicationJson => "application/json",
+            NegotiatedResponseClientGetAvatarAccept::ImagePng => "image/png",
+        }
+    }
+}
+
+impl Display for NegotiatedResponseClientGetAvatarAccept {
+    fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            NegotiatedResponseClientGetAvatarAccept::ApplicationJson => {
+                Display::fmt("application/json", f)
+            }
+            NegotiatedResponseClientGetAvatarAccept::ImagePng => Display::fmt("image/png", f),
+        }
+    }
+}
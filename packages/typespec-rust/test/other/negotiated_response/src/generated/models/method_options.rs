@@ -0,0 +1,13 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{fmt::SafeDebug, http::ClientMethodOptions};
+
+/// Options to be passed to [`NegotiatedResponseClient::get_avatar()`](crate::generated::clients::NegotiatedResponseClient::get_avatar())
+#[derive(Clone, Default, SafeDebug)]
+pub struct NegotiatedResponseClientGetAvatarOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
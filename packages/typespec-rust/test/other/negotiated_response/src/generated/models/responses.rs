@@ -0,0 +1,16 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::Avatar;
+use azure_core::http::{RawResponse, Response};
+
+/// Contains the response from [`NegotiatedResponseClient::get_avatar()`](crate::generated::clients::NegotiatedResponseClient::get_avatar())
+/// for the requested media type
+pub enum NegotiatedResponseClientGetAvatarResponse {
+    /// The response when the service returns `application/json` content.
+    ApplicationJson(Response<Avatar>),
+    /// The response when the service returns `image/png` content.
+    ImagePng(RawResponse),
+}
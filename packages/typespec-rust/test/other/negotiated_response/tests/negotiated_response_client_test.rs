@@ -0,0 +1,110 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+use async_trait::async_trait;
+use azure_core::http::{
+    headers::{HeaderName, Headers, CONTENT_TYPE},
+    policies::{Policy, PolicyResult},
+    AsyncRawResponse, ClientOptions, Context, Request, StatusCode,
+};
+use negotiated_response::{
+    models::{NegotiatedResponseClientGetAvatarAccept, NegotiatedResponseClientGetAvatarResponse},
+    NegotiatedResponseClient, NegotiatedResponseClientOptions,
+};
+use std::sync::Arc;
+
+/// Returns the avatar in the requested media type in place of the transport.
+#[derive(Debug)]
+struct AvatarTransport {
+    /// When set, the response uses this content type instead of the requested one.
+    content_type: Option<&'static str>,
+}
+
+#[async_trait]
+impl Policy for AvatarTransport {
+    async fn send(
+        &self,
+        _ctx: &Context,
+        request: &mut Request,
+        _next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        assert_eq!(request.url().as_str(), "https://contoso.com/avatars/a");
+        let accept = request
+            .headers()
+            .get_optional_str(&HeaderName::from_static("accept"))
+            .unwrap()
+            .to_string();
+        let mut headers = Headers::new();
+        let body: &'static [u8] = match accept.as_str() {
+            "application/json" => {
+                headers.insert(
+                    CONTENT_TYPE,
+                    self.content_type
+                        .unwrap_or("Application/JSON; charset=utf-8"),
+                );
+                br#"{"url":"https://contoso.com/a.png"}"#
+            }
+            "image/png" => {
+                headers.insert(CONTENT_TYPE, self.content_type.unwrap_or("image/png"));
+                b"\x89PNG"
+            }
+            accept => panic!("unexpected accept {accept}"),
+        };
+        Ok(AsyncRawResponse::from_bytes(StatusCode::Ok, headers, body))
+    }
+}
+
+fn new_client(content_type: Option<&'static str>) -> NegotiatedResponseClient {
+    let options = NegotiatedResponseClientOptions {
+        client_options: ClientOptions {
+            per_call_policies: vec![Arc::new(AvatarTransport { content_type })],
+            ..Default::default()
+        },
+    };
+    NegotiatedResponseClient::with_no_credential("https://contoso.com", Some(options)).unwrap()
+}
+
+#[tokio::test]
+async fn get_avatar_json() {
+    let response = new_client(None)
+        .get_avatar(
+            "a",
+            NegotiatedResponseClientGetAvatarAccept::ApplicationJson,
+            None,
+        )
+        .await
+        .unwrap();
+    let NegotiatedResponseClientGetAvatarResponse::ApplicationJson(response) = response else {
+        panic!("expected a JSON response");
+    };
+    let avatar = response.into_model().unwrap();
+    assert_eq!(avatar.url.as_deref(), Some("https://contoso.com/a.png"));
+}
+
+#[tokio::test]
+async fn get_avatar_png() {
+    let response = new_client(None)
+        .get_avatar("a", NegotiatedResponseClientGetAvatarAccept::ImagePng, None)
+        .await
+        .unwrap();
+    let NegotiatedResponseClientGetAvatarResponse::ImagePng(response) = response else {
+        panic!("expected a PNG response");
+    };
+    assert_eq!(response.status(), StatusCode::Ok);
+    assert_eq!(
+        response.headers().get_optional_str(&CONTENT_TYPE),
+        Some("image/png")
+    );
+}
+
+#[tokio::test]
+async fn get_avatar_unexpected_content_type() {
+    let err = new_client(Some("text/plain"))
+        .get_avatar("a", NegotiatedResponseClientGetAvatarAccept::ImagePng, None)
+        .await
+        .err()
+        .unwrap();
+    assert!(err
+        .to_string()
+        .contains("unexpected content type 'text/plain'"));
+}
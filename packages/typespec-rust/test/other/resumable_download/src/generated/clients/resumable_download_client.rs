@@ -0,0 +1,169 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::generated::models::{ResumableDownload, ResumableDownloadClientDownloadOptions};
+use azure_core::{
+    error::CheckSuccessOptions,
+    fmt::SafeDebug,
+    http::{
+        AsyncResponse, ClientMethodOptions, ClientOptions, Method, Pipeline, PipelineStreamOptions,
+        RawResponse, Request, Url, UrlExt,
+    },
+    tracing, Result,
+};
+
+#[tracing::client]
+pub struct ResumableDownloadClient {
+    pub(crate) endpoint: Url,
+    pub(crate) pipeline: Pipeline,
+}
+
+/// Options used when creating a [`ResumableDownloadClient`](ResumableDownloadClient)
+#[derive(Clone, Default, SafeDebug)]
+pub struct ResumableDownloadClientOptions {
+    /// Allows customization of the client.
+    pub client_options: ClientOptions,
+}
+
+impl ResumableDownloadClient {
+    /// Creates a new ResumableDownloadClient requiring no authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - Service host
+    /// * `options` - Optional configuration for the client.
+    #[tracing::new("ResumableDownload")]
+    pub fn with_no_credential(
+        endpoint: &str,
+        options: Option<ResumableDownloadClientOptions>,
+    ) -> Result<Self> {
+        let options = options.unwrap_or_default();
+        let endpoint = Url::parse(endpoint)?;
+        if !endpoint.scheme().starts_with("http") {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                format!("{endpoint} must use http(s)"),
+            ));
+        }
+        Ok(Self {
+            endpoint,
+            pipeline: Pipeline::new(
+                option_env!("CARGO_PKG_NAME"),
+                option_env!("CARGO_PKG_VERSION"),
+                options.client_options,
+                Vec::default(),
+                Vec::default(),
+                None,
+            ),
+        })
+    }
+
+    /// Returns the Url associated with this client.
+    pub fn endpoint(&self) -> &Url {
+        &self.endpoint
+    }
+
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("ResumableDownload.download")]
+    pub async fn download(
+        &self,
+        options: Option<ResumableDownloadClientDownloadOptions<'_>>,
+    ) -> Result<AsyncResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/download");
+        let mut request = Request::new(url, Method::Get);
+        request.insert_header("accept", "application/octet-stream");
+        if let Some(range) = options.range.as_ref() {
+            request.insert_header("range", range);
+        }
+        let rsp = self
+            .pipeline
+            .stream(
+                &ctx,
+                &mut request,
+                Some(PipelineStreamOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[200, 206],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
+    /// Resumable variant of [`ResumableDownloadClient::download()`](crate::generated::clients::ResumableDownloadClient::download()).
+    ///
+    /// If reading the response body fails with a transient error, the request is reissued for the remaining bytes using the
+    /// `range` and `if-match` headers.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("ResumableDownload.download")]
+    pub async fn download_resumable(
+        &self,
+        options: Option<ResumableDownloadClientDownloadOptions<'_>>,
+    ) -> Result<ResumableDownload> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/download");
+        let mut request = Request::new(url, Method::Get);
+        request.insert_header("accept", "application/octet-stream");
+        if let Some(range) = options.range.as_ref() {
+            request.insert_header("range", range);
+        }
+        let rsp = self
+            .pipeline
+            .stream(
+                &ctx,
+                &mut request,
+                Some(PipelineStreamOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[200, 206],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(ResumableDownload::new(
+            self.pipeline.clone(),
+            ctx.into_owned(),
+            request,
+            rsp,
+            "range",
+        ))
+    }
+}
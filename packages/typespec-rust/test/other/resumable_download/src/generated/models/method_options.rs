@@ -0,0 +1,15 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{fmt::SafeDebug, http::ClientMethodOptions};
+
+/// Options to be passed to [`ResumableDownloadClient::download()`](crate::generated::clients::ResumableDownloadClient::download())
+#[derive(Clone, Default, SafeDebug)]
+pub struct ResumableDownloadClientDownloadOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+
+    pub range: Option<String>,
+}
@@ -0,0 +1,123 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use azure_core::http::StatusCode;
+use resumable_download::{models::ResumableDownloadClientDownloadOptions, ResumableDownloadClient};
+use std::sync::{Arc, Mutex};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+
+const CONTENT: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// A stand-in for the service that drops the connection halfway through the first response body.
+/// Requests with a range header are served the requested bytes. Each request head is recorded in requests.
+async fn serve(listener: TcpListener, requests: Arc<Mutex<Vec<String>>>) {
+    let mut drop_connection = true;
+    loop {
+        let Ok((mut socket, _)) = listener.accept().await else {
+            return;
+        };
+
+        let mut head = Vec::new();
+        let mut buf = [0u8; 1024];
+        while !head.ends_with(b"\r\n\r\n") {
+            let read = socket.read(&mut buf).await.unwrap();
+            if read == 0 {
+                break;
+            }
+            head.extend_from_slice(&buf[..read]);
+        }
+        let head = String::from_utf8(head).unwrap().to_lowercase();
+
+        let range = head
+            .lines()
+            .find_map(|line| line.strip_prefix("range: bytes="))
+            .map(|range| {
+                let (first, last) = range.split_once('-').unwrap();
+                let end = match last {
+                    "" => CONTENT.len(),
+                    last => last.parse::<usize>().unwrap() + 1,
+                };
+                (first.parse::<usize>().unwrap(), end)
+            });
+        requests.lock().unwrap().push(head);
+
+        let (status, body) = match range {
+            Some((first, end)) => ("206 Partial Content", &CONTENT[first..end]),
+            None => ("200 OK", CONTENT),
+        };
+        let response = format!(
+            "HTTP/1.1 {status}\r\ncontent-type: application/octet-stream\r\ncontent-length: {}\r\netag: \"v1\"\r\n\r\n",
+            body.len()
+        );
+        socket.write_all(response.as_bytes()).await.unwrap();
+        if drop_connection {
+            // send half of the body then drop the connection
+            drop_connection = false;
+            socket.write_all(&body[..body.len() / 2]).await.unwrap();
+        } else {
+            socket.write_all(body).await.unwrap();
+        }
+    }
+}
+
+async fn start_server() -> (String, Arc<Mutex<Vec<String>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let endpoint = format!("http://{}", listener.local_addr().unwrap());
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    tokio::spawn(serve(listener, requests.clone()));
+    (endpoint, requests)
+}
+
+#[tokio::test]
+async fn download_resumable() {
+    let (endpoint, requests) = start_server().await;
+    let client = ResumableDownloadClient::with_no_credential(&endpoint, None).unwrap();
+
+    let progress = Arc::new(Mutex::new(Vec::new()));
+    let progress_clone = progress.clone();
+    let download = client
+        .download_resumable(None)
+        .await
+        .unwrap()
+        .on_progress(move |received| progress_clone.lock().unwrap().push(received));
+    assert_eq!(download.status(), StatusCode::Ok);
+    let body = download.collect().await.unwrap();
+    assert_eq!(body.as_ref(), CONTENT);
+    assert_eq!(
+        progress.lock().unwrap().last(),
+        Some(&(CONTENT.len() as u64))
+    );
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    assert!(!requests[0].contains("if-match:"));
+    assert!(requests[1].contains(&format!("range: bytes={}-\r\n", CONTENT.len() / 2)));
+    assert!(requests[1].contains("if-match: \"v1\"\r\n"));
+}
+
+#[tokio::test]
+async fn download_resumable_with_range() {
+    let (endpoint, requests) = start_server().await;
+    let client = ResumableDownloadClient::with_no_credential(&endpoint, None).unwrap();
+
+    let download = client
+        .download_resumable(Some(ResumableDownloadClientDownloadOptions {
+            range: Some("bytes=4-23".to_string()),
+            ..Default::default()
+        }))
+        .await
+        .unwrap();
+    assert_eq!(download.status(), StatusCode::PartialContent);
+    let body = download.collect().await.unwrap();
+    assert_eq!(body.as_ref(), &CONTENT[4..24]);
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 2);
+    assert!(requests[0].contains("range: bytes=4-23\r\n"));
+    assert!(requests[1].contains("range: bytes=14-23\r\n"));
+    assert!(requests[1].contains("if-match: \"v1\"\r\n"));
+}
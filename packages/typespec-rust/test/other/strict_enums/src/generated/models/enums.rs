@@ -0,0 +1,17 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+/// Days of the week.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DayOfWeek {
+    /// Monday.
+    Monday,
+
+    /// Tuesday.
+    Tuesday,
+
+    /// Any other value not defined in `DayOfWeek`.
+    UnknownValue(String),
+}
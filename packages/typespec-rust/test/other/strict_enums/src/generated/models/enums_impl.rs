@@ -0,0 +1,52 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::DayOfWeek;
+use std::{
+    convert::{AsRef, From, Infallible},
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+impl<'a> From<&'a DayOfWeek> for &'a str {
+    fn from(e: &'a DayOfWeek) -> Self {
+        match e {
+            DayOfWeek::Monday => "Monday",
+            DayOfWeek::Tuesday => "Tuesday",
+            DayOfWeek::UnknownValue(s) => s.as_ref(),
+        }
+    }
+}
+
+impl FromStr for DayOfWeek {
+    type Err = Infallible;
+    fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
+        Ok(match s {
+            "Monday" => DayOfWeek::Monday,
+            "Tuesday" => DayOfWeek::Tuesday,
+            _ => DayOfWeek::UnknownValue(s.to_string()),
+        })
+    }
+}
+
+impl AsRef<str> for DayOfWeek {
+    fn as_ref(&self) -> &str {
+        match self {
+            DayOfWeek::Monday => "Monday",
+            DayOfWeek::Tuesday => "Tuesday",
+            DayOfWeek::UnknownValue(s) => s.as_str(),
+        }
+    }
+}
+
+impl Display for DayOfWeek {
+    fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            DayOfWeek::Monday => f.write_str("Monday"),
+            DayOfWeek::Tuesday => f.write_str("Tuesday"),
+            DayOfWeek::UnknownValue(s) => f.write_str(s.as_str()),
+        }
+    }
+}
@@ -0,0 +1,69 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use async_trait::async_trait;
+use azure_core::http::{
+    headers::Headers,
+    policies::{Policy, PolicyResult},
+    AsyncRawResponse, ClientOptions, Context, Request, StatusCode,
+};
+use std::sync::Arc;
+use strict_enums::{models::DayOfWeek, StrictEnumsClient, StrictEnumsClientOptions};
+
+/// Returns the response body in place of the transport.
+#[derive(Debug)]
+struct BodyTransport {
+    body: &'static str,
+}
+
+#[async_trait]
+impl Policy for BodyTransport {
+    async fn send(
+        &self,
+        _ctx: &Context,
+        _request: &mut Request,
+        _next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        Ok(AsyncRawResponse::from_bytes(
+            StatusCode::Ok,
+            Headers::new(),
+            self.body,
+        ))
+    }
+}
+
+fn new_client(body: &'static str) -> StrictEnumsClient {
+    let options = StrictEnumsClientOptions {
+        client_options: ClientOptions {
+            per_call_policies: vec![Arc::new(BodyTransport { body })],
+            ..Default::default()
+        },
+    };
+    StrictEnumsClient::with_no_credential("https://contoso.com", Some(options)).unwrap()
+}
+
+#[tokio::test]
+async fn get_known_value() {
+    let client = new_client(r#""Monday""#);
+    let value = client.get_day(None).await.unwrap().into_model().unwrap();
+    assert_eq!(value, DayOfWeek::Monday);
+}
+
+#[tokio::test]
+async fn get_value_with_different_case() {
+    // known values must match exactly so this is an unknown value
+    let client = new_client(r#""monday""#);
+    let value = client.get_day(None).await.unwrap().into_model().unwrap();
+    assert_eq!(value, DayOfWeek::UnknownValue("monday".to_string()));
+}
+
+#[test]
+fn parse_is_case_sensitive() {
+    let value: DayOfWeek = "Tuesday".parse().unwrap();
+    assert_eq!(value, DayOfWeek::Tuesday);
+
+    let value: DayOfWeek = "TUESDAY".parse().unwrap();
+    assert_eq!(value, DayOfWeek::UnknownValue("TUESDAY".to_string()));
+    assert_eq!(value.as_ref(), "TUESDAY");
+}
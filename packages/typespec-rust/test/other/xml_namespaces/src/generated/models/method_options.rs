@@ -0,0 +1,20 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{fmt::SafeDebug, http::ClientMethodOptions};
+
+/// Options to be passed to [`XmlNamespacesClient::get_entry()`](crate::generated::clients::XmlNamespacesClient::get_entry())
+#[derive(Clone, Default, SafeDebug)]
+pub struct XmlNamespacesClientGetEntryOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
+
+/// Options to be passed to [`XmlNamespacesClient::put_entry()`](crate::generated::clients::XmlNamespacesClient::put_entry())
+#[derive(Clone, Default, SafeDebug)]
+pub struct XmlNamespacesClientPutEntryOptions<'a> {
+    /// Allows customization of the method call.
+    pub method_options: ClientMethodOptions<'a>,
+}
@@ -0,0 +1,76 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::fmt::SafeDebug;
+use serde::{Deserialize, Serialize};
+
+/// An Atom entry.
+#[derive(Clone, Default, Deserialize, SafeDebug, Serialize)]
+#[serde(into = "EntryWithXmlNamespaces")]
+#[serde(rename = "entry")]
+pub struct Entry {
+    /// The identifier of the entry.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// The language of the entry.
+    #[serde(
+        alias = "@dc:language",
+        rename(deserialize = "@language", serialize = "@dc:language"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub language: Option<String>,
+
+    /// The title of the entry.
+    #[serde(
+        alias = "dc:title",
+        rename(deserialize = "title", serialize = "dc:title"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub title: Option<String>,
+}
+
+/// Serializes [`Entry`] along with its XML namespace declarations.
+#[derive(Serialize)]
+#[serde(rename = "atom:entry")]
+struct EntryWithXmlNamespaces {
+    #[serde(rename = "@xmlns:atom")]
+    xmlns_atom: &'static str,
+
+    #[serde(rename = "@xmlns:dc")]
+    xmlns_dc: &'static str,
+
+    /// The identifier of the entry.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// The language of the entry.
+    #[serde(
+        alias = "@dc:language",
+        rename(deserialize = "@language", serialize = "@dc:language"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub language: Option<String>,
+
+    /// The title of the entry.
+    #[serde(
+        alias = "dc:title",
+        rename(deserialize = "title", serialize = "dc:title"),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub title: Option<String>,
+}
+
+impl From<Entry> for EntryWithXmlNamespaces {
+    fn from(value: Entry) -> Self {
+        Self {
+            xmlns_atom: "http://www.w3.org/2005/Atom",
+            xmlns_dc: "http://purl.org/dc/elements/1.1/",
+            id: value.id,
+            language: value.language,
+            title: value.title,
+        }
+    }
+}
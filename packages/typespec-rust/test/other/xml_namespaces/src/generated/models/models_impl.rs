@@ -0,0 +1,18 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::Entry;
+use azure_core::{
+    http::{RequestContent, XmlFormat},
+    xml::to_xml,
+    Result,
+};
+
+impl TryFrom<Entry> for RequestContent<Entry, XmlFormat> {
+    type Error = azure_core::Error;
+    fn try_from(value: Entry) -> Result<Self> {
+        Ok(to_xml(&value)?.into())
+    }
+}
@@ -0,0 +1,115 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+
+use async_trait::async_trait;
+use azure_core::http::{
+    headers::Headers,
+    policies::{Policy, PolicyResult},
+    AsyncRawResponse, Body, ClientOptions, Context, Method, Request, StatusCode,
+};
+use std::sync::{Arc, Mutex};
+use xml_namespaces::{models::Entry, XmlNamespacesClient, XmlNamespacesClientOptions};
+
+/// Stores the entry sent by a PUT and returns it from a GET in place of the transport.
+#[derive(Debug, Default)]
+struct EntryTransport {
+    entry: Mutex<Option<Vec<u8>>>,
+}
+
+#[async_trait]
+impl Policy for EntryTransport {
+    async fn send(
+        &self,
+        _ctx: &Context,
+        request: &mut Request,
+        _next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        assert_eq!(request.url().as_str(), "https://contoso.com/entry");
+        if request.method() == Method::Put {
+            let Body::Bytes(body) = request.body() else {
+                panic!("expected a buffered request body");
+            };
+            *self.entry.lock().unwrap() = Some(body.to_vec());
+            return Ok(AsyncRawResponse::from_bytes(
+                StatusCode::NoContent,
+                Headers::new(),
+                "",
+            ));
+        }
+        let entry = self
+            .entry
+            .lock()
+            .unwrap()
+            .clone()
+            .expect("no entry was put");
+        Ok(AsyncRawResponse::from_bytes(
+            StatusCode::Ok,
+            Headers::new(),
+            entry,
+        ))
+    }
+}
+
+fn new_client(transport: Arc<EntryTransport>) -> XmlNamespacesClient {
+    let options = XmlNamespacesClientOptions {
+        client_options: ClientOptions {
+            per_call_policies: vec![transport as Arc<dyn Policy>],
+            ..Default::default()
+        },
+    };
+    XmlNamespacesClient::with_no_credential("https://contoso.com", Some(options)).unwrap()
+}
+
+#[tokio::test]
+async fn round_trip_entry() {
+    let transport = Arc::new(EntryTransport::default());
+    let client = new_client(transport.clone());
+
+    let entry = Entry {
+        id: Some("urn:uuid:1225c695".to_string()),
+        language: Some("en".to_string()),
+        title: Some("Atom-Powered Robots Run Amok".to_string()),
+    };
+    client
+        .put_entry(entry.try_into().unwrap(), None)
+        .await
+        .unwrap();
+
+    // the namespaces are declared on the root element and the qualified names use their prefixes
+    let body = transport.entry.lock().unwrap().clone().unwrap();
+    let body = String::from_utf8(body).unwrap();
+    assert!(body.contains("<atom:entry "), "{body}");
+    assert!(
+        body.contains(r#"xmlns:atom="http://www.w3.org/2005/Atom""#),
+        "{body}"
+    );
+    assert!(
+        body.contains(r#"xmlns:dc="http://purl.org/dc/elements/1.1/""#),
+        "{body}"
+    );
+    assert!(body.contains(r#"dc:language="en""#), "{body}");
+    assert!(
+        body.contains("<dc:title>Atom-Powered Robots Run Amok</dc:title>"),
+        "{body}"
+    );
+    assert!(body.contains("<id>urn:uuid:1225c695</id>"), "{body}");
+
+    let entry: Entry = client.get_entry(None).await.unwrap().into_model().unwrap();
+    assert_eq!(entry.id.as_deref(), Some("urn:uuid:1225c695"));
+    assert_eq!(entry.language.as_deref(), Some("en"));
+    assert_eq!(entry.title.as_deref(), Some("Atom-Powered Robots Run Amok"));
+}
+
+#[tokio::test]
+async fn get_entry_without_prefixes() {
+    let transport = Arc::new(EntryTransport::default());
+    *transport.entry.lock().unwrap() = Some(
+        br#"<entry xmlns="http://www.w3.org/2005/Atom" language="fr"><id>urn:uuid:1</id><title>Bonjour</title></entry>"#.to_vec(),
+    );
+    let client = new_client(transport);
+
+    let entry: Entry = client.get_entry(None).await.unwrap().into_model().unwrap();
+    assert_eq!(entry.id.as_deref(), Some("urn:uuid:1"));
+    assert_eq!(entry.language.as_deref(), Some("fr"));
+    assert_eq!(entry.title.as_deref(), Some("Bonjour"));
+}
@@ -31,8 +31,8 @@ impl FromStr for CompositionType {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "key" => CompositionType::Key,
-            "key_label" => CompositionType::KeyLabel,
+            _ if s.eq_ignore_ascii_case("key") => CompositionType::Key,
+            _ if s.eq_ignore_ascii_case("key_label") => CompositionType::KeyLabel,
             _ => CompositionType::UnknownValue(s.to_string()),
         })
     }
@@ -48,6 +48,12 @@ impl AsRef<str> for CompositionType {
     }
 }
 
+impl PartialEq<str> for CompositionType {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for CompositionType {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -365,14 +371,14 @@ impl FromStr for KeyValueFields {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "content_type" => KeyValueFields::ContentType,
-            "etag" => KeyValueFields::Etag,
-            "key" => KeyValueFields::Key,
-            "label" => KeyValueFields::Label,
-            "last_modified" => KeyValueFields::LastModified,
-            "locked" => KeyValueFields::Locked,
-            "tags" => KeyValueFields::Tags,
-            "value" => KeyValueFields::Value,
+            _ if s.eq_ignore_ascii_case("content_type") => KeyValueFields::ContentType,
+            _ if s.eq_ignore_ascii_case("etag") => KeyValueFields::Etag,
+            _ if s.eq_ignore_ascii_case("key") => KeyValueFields::Key,
+            _ if s.eq_ignore_ascii_case("label") => KeyValueFields::Label,
+            _ if s.eq_ignore_ascii_case("last_modified") => KeyValueFields::LastModified,
+            _ if s.eq_ignore_ascii_case("locked") => KeyValueFields::Locked,
+            _ if s.eq_ignore_ascii_case("tags") => KeyValueFields::Tags,
+            _ if s.eq_ignore_ascii_case("value") => KeyValueFields::Value,
             _ => KeyValueFields::UnknownValue(s.to_string()),
         })
     }
@@ -394,6 +400,12 @@ impl AsRef<str> for KeyValueFields {
     }
 }
 
+impl PartialEq<str> for KeyValueFields {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for KeyValueFields {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -423,7 +435,7 @@ impl FromStr for LabelFields {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "name" => LabelFields::Name,
+            _ if s.eq_ignore_ascii_case("name") => LabelFields::Name,
             _ => LabelFields::UnknownValue(s.to_string()),
         })
     }
@@ -438,6 +450,12 @@ impl AsRef<str> for LabelFields {
     }
 }
 
+impl PartialEq<str> for LabelFields {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for LabelFields {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -464,11 +482,11 @@ impl FromStr for OperationState {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "Canceled" => OperationState::Canceled,
-            "Failed" => OperationState::Failed,
-            "NotStarted" => OperationState::NotStarted,
-            "Running" => OperationState::Running,
-            "Succeeded" => OperationState::Succeeded,
+            _ if s.eq_ignore_ascii_case("Canceled") => OperationState::Canceled,
+            _ if s.eq_ignore_ascii_case("Failed") => OperationState::Failed,
+            _ if s.eq_ignore_ascii_case("NotStarted") => OperationState::NotStarted,
+            _ if s.eq_ignore_ascii_case("Running") => OperationState::Running,
+            _ if s.eq_ignore_ascii_case("Succeeded") => OperationState::Succeeded,
             _ => OperationState::UnknownValue(s.to_string()),
         })
     }
@@ -487,6 +505,12 @@ impl AsRef<str> for OperationState {
     }
 }
 
+impl PartialEq<str> for OperationState {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for OperationState {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -588,17 +612,17 @@ impl FromStr for SnapshotFields {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "composition_type" => SnapshotFields::CompositionType,
-            "created" => SnapshotFields::Created,
-            "etag" => SnapshotFields::Etag,
-            "expires" => SnapshotFields::Expires,
-            "filters" => SnapshotFields::Filters,
-            "items_count" => SnapshotFields::ItemsCount,
-            "name" => SnapshotFields::Name,
-            "retention_period" => SnapshotFields::RetentionPeriod,
-            "size" => SnapshotFields::Size,
-            "status" => SnapshotFields::Status,
-            "tags" => SnapshotFields::Tags,
+            _ if s.eq_ignore_ascii_case("composition_type") => SnapshotFields::CompositionType,
+            _ if s.eq_ignore_ascii_case("created") => SnapshotFields::Created,
+            _ if s.eq_ignore_ascii_case("etag") => SnapshotFields::Etag,
+            _ if s.eq_ignore_ascii_case("expires") => SnapshotFields::Expires,
+            _ if s.eq_ignore_ascii_case("filters") => SnapshotFields::Filters,
+            _ if s.eq_ignore_ascii_case("items_count") => SnapshotFields::ItemsCount,
+            _ if s.eq_ignore_ascii_case("name") => SnapshotFields::Name,
+            _ if s.eq_ignore_ascii_case("retention_period") => SnapshotFields::RetentionPeriod,
+            _ if s.eq_ignore_ascii_case("size") => SnapshotFields::Size,
+            _ if s.eq_ignore_ascii_case("status") => SnapshotFields::Status,
+            _ if s.eq_ignore_ascii_case("tags") => SnapshotFields::Tags,
             _ => SnapshotFields::UnknownValue(s.to_string()),
         })
     }
@@ -623,6 +647,12 @@ impl AsRef<str> for SnapshotFields {
     }
 }
 
+impl PartialEq<str> for SnapshotFields {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for SnapshotFields {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -658,10 +688,10 @@ impl FromStr for SnapshotStatus {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "archived" => SnapshotStatus::Archived,
-            "failed" => SnapshotStatus::Failed,
-            "provisioning" => SnapshotStatus::Provisioning,
-            "ready" => SnapshotStatus::Ready,
+            _ if s.eq_ignore_ascii_case("archived") => SnapshotStatus::Archived,
+            _ if s.eq_ignore_ascii_case("failed") => SnapshotStatus::Failed,
+            _ if s.eq_ignore_ascii_case("provisioning") => SnapshotStatus::Provisioning,
+            _ if s.eq_ignore_ascii_case("ready") => SnapshotStatus::Ready,
             _ => SnapshotStatus::UnknownValue(s.to_string()),
         })
     }
@@ -679,6 +709,12 @@ impl AsRef<str> for SnapshotStatus {
     }
 }
 
+impl PartialEq<str> for SnapshotStatus {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for SnapshotStatus {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
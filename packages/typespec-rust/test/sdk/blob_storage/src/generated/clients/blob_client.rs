@@ -15,14 +15,16 @@ use crate::generated::models::{
     BlobClientRenewLeaseResult, BlobClientSetImmutabilityPolicyOptions,
     BlobClientSetLegalHoldOptions, BlobClientSetMetadataOptions, BlobClientSetPropertiesOptions,
     BlobClientSetTagsOptions, BlobClientSetTierOptions, BlobClientUndeleteOptions, BlobTags,
+    ResumableDownload,
 };
 use azure_core::{
     base64,
     error::CheckSuccessOptions,
     fmt::SafeDebug,
     http::{
-        AsyncResponse, ClientOptions, Method, NoFormat, Pipeline, PipelineSendOptions,
-        PipelineStreamOptions, Request, RequestContent, Response, Url, UrlExt, XmlFormat,
+        AsyncResponse, ClientMethodOptions, ClientOptions, Method, NoFormat, Pipeline,
+        PipelineSendOptions, PipelineStreamOptions, RawResponse, Request, RequestContent, Response,
+        Url, UrlExt, XmlFormat,
     },
     time::{to_rfc7231, OffsetDateTime},
     tracing, Result,
@@ -51,6 +53,30 @@ impl BlobClient {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     /// The Acquire Lease operation requests a new lease on a blob. The lease lock duration can be 15 to 60 seconds, or can be
     /// infinite.
     ///
@@ -717,6 +743,106 @@ impl BlobClient {
         Ok(rsp.into())
     }
 
+    /// Resumable variant of `BlobClient::download_internal()`.
+    ///
+    /// If reading the response body fails with a transient error, the request is reissued for the remaining bytes using the
+    /// `range` and `if-match` headers.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Storage.Blob.BlobClient.download")]
+    pub async fn download_internal_resumable(
+        &self,
+        options: Option<BlobClientDownloadInternalOptions<'_>>,
+    ) -> Result<ResumableDownload> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        let mut query_builder = url.query_builder();
+        if let Some(snapshot) = options.snapshot.as_ref() {
+            query_builder.set_pair("snapshot", snapshot);
+        }
+        if let Some(timeout) = options.timeout {
+            query_builder.set_pair("timeout", timeout.to_string());
+        }
+        if let Some(version_id) = options.version_id.as_ref() {
+            query_builder.set_pair("versionid", version_id);
+        }
+        query_builder.build();
+        let mut request = Request::new(url, Method::Get);
+        request.insert_header("accept", "application/octet-stream");
+        if let Some(if_match) = options.if_match.as_ref() {
+            request.insert_header("if-match", if_match.to_string());
+        }
+        if let Some(if_modified_since) = options.if_modified_since {
+            request.insert_header("if-modified-since", to_rfc7231(&if_modified_since));
+        }
+        if let Some(if_none_match) = options.if_none_match.as_ref() {
+            request.insert_header("if-none-match", if_none_match.to_string());
+        }
+        if let Some(if_unmodified_since) = options.if_unmodified_since {
+            request.insert_header("if-unmodified-since", to_rfc7231(&if_unmodified_since));
+        }
+        if let Some(range) = options.range.as_ref() {
+            request.insert_header("range", range);
+        }
+        if let Some(encryption_algorithm) = options.encryption_algorithm.as_ref() {
+            request.insert_header(
+                "x-ms-encryption-algorithm",
+                encryption_algorithm.to_string(),
+            );
+        }
+        if let Some(encryption_key) = options.encryption_key.as_ref() {
+            request.insert_header("x-ms-encryption-key", encryption_key);
+        }
+        if let Some(encryption_key_sha256) = options.encryption_key_sha256.as_ref() {
+            request.insert_header("x-ms-encryption-key-sha256", encryption_key_sha256);
+        }
+        if let Some(if_tags) = options.if_tags.as_ref() {
+            request.insert_header("x-ms-if-tags", if_tags);
+        }
+        if let Some(lease_id) = options.lease_id.as_ref() {
+            request.insert_header("x-ms-lease-id", lease_id);
+        }
+        if let Some(range_get_content_crc64) = options.range_get_content_crc64 {
+            request.insert_header(
+                "x-ms-range-get-content-crc64",
+                range_get_content_crc64.to_string(),
+            );
+        }
+        if let Some(range_get_content_md5) = options.range_get_content_md5 {
+            request.insert_header(
+                "x-ms-range-get-content-md5",
+                range_get_content_md5.to_string(),
+            );
+        }
+        if let Some(structured_body_type) = options.structured_body_type.as_ref() {
+            request.insert_header("x-ms-structured-body", structured_body_type);
+        }
+        request.insert_header("x-ms-version", &self.version);
+        let rsp = self
+            .pipeline
+            .stream(
+                &ctx,
+                &mut request,
+                Some(PipelineStreamOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[200, 206],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(ResumableDownload::new(
+            self.pipeline.clone(),
+            ctx.into_owned(),
+            request,
+            rsp,
+            "range",
+        ))
+    }
+
     /// Returns the sku name and account kind
     ///
     /// # Arguments
@@ -47,23 +47,23 @@ impl FromStr for AccessTier {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "Archive" => AccessTier::Archive,
-            "Cold" => AccessTier::Cold,
-            "Cool" => AccessTier::Cool,
-            "Hot" => AccessTier::Hot,
-            "P10" => AccessTier::P10,
-            "P15" => AccessTier::P15,
-            "P20" => AccessTier::P20,
-            "P30" => AccessTier::P30,
-            "P4" => AccessTier::P4,
-            "P40" => AccessTier::P40,
-            "P50" => AccessTier::P50,
-            "P6" => AccessTier::P6,
-            "P60" => AccessTier::P60,
-            "P70" => AccessTier::P70,
-            "P80" => AccessTier::P80,
-            "Premium" => AccessTier::Premium,
-            "Smart" => AccessTier::Smart,
+            _ if s.eq_ignore_ascii_case("Archive") => AccessTier::Archive,
+            _ if s.eq_ignore_ascii_case("Cold") => AccessTier::Cold,
+            _ if s.eq_ignore_ascii_case("Cool") => AccessTier::Cool,
+            _ if s.eq_ignore_ascii_case("Hot") => AccessTier::Hot,
+            _ if s.eq_ignore_ascii_case("P10") => AccessTier::P10,
+            _ if s.eq_ignore_ascii_case("P15") => AccessTier::P15,
+            _ if s.eq_ignore_ascii_case("P20") => AccessTier::P20,
+            _ if s.eq_ignore_ascii_case("P30") => AccessTier::P30,
+            _ if s.eq_ignore_ascii_case("P4") => AccessTier::P4,
+            _ if s.eq_ignore_ascii_case("P40") => AccessTier::P40,
+            _ if s.eq_ignore_ascii_case("P50") => AccessTier::P50,
+            _ if s.eq_ignore_ascii_case("P6") => AccessTier::P6,
+            _ if s.eq_ignore_ascii_case("P60") => AccessTier::P60,
+            _ if s.eq_ignore_ascii_case("P70") => AccessTier::P70,
+            _ if s.eq_ignore_ascii_case("P80") => AccessTier::P80,
+            _ if s.eq_ignore_ascii_case("Premium") => AccessTier::Premium,
+            _ if s.eq_ignore_ascii_case("Smart") => AccessTier::Smart,
             _ => AccessTier::UnknownValue(s.to_string()),
         })
     }
@@ -94,6 +94,12 @@ impl AsRef<str> for AccessTier {
     }
 }
 
+impl PartialEq<str> for AccessTier {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for AccessTier {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -177,10 +183,18 @@ impl FromStr for ArchiveStatus {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "rehydrate-pending-to-cold" => ArchiveStatus::RehydratePendingToCold,
-            "rehydrate-pending-to-cool" => ArchiveStatus::RehydratePendingToCool,
-            "rehydrate-pending-to-hot" => ArchiveStatus::RehydratePendingToHot,
-            "rehydrate-pending-to-smart" => ArchiveStatus::RehydratePendingToSmart,
+            _ if s.eq_ignore_ascii_case("rehydrate-pending-to-cold") => {
+                ArchiveStatus::RehydratePendingToCold
+            }
+            _ if s.eq_ignore_ascii_case("rehydrate-pending-to-cool") => {
+                ArchiveStatus::RehydratePendingToCool
+            }
+            _ if s.eq_ignore_ascii_case("rehydrate-pending-to-hot") => {
+                ArchiveStatus::RehydratePendingToHot
+            }
+            _ if s.eq_ignore_ascii_case("rehydrate-pending-to-smart") => {
+                ArchiveStatus::RehydratePendingToSmart
+            }
             _ => ArchiveStatus::UnknownValue(s.to_string()),
         })
     }
@@ -198,6 +212,12 @@ impl AsRef<str> for ArchiveStatus {
     }
 }
 
+impl PartialEq<str> for ArchiveStatus {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for ArchiveStatus {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -460,7 +480,7 @@ impl FromStr for FileShareTokenIntent {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "backup" => FileShareTokenIntent::Backup,
+            _ if s.eq_ignore_ascii_case("backup") => FileShareTokenIntent::Backup,
             _ => FileShareTokenIntent::UnknownValue(s.to_string()),
         })
     }
@@ -475,6 +495,12 @@ impl AsRef<str> for FileShareTokenIntent {
     }
 }
 
+impl PartialEq<str> for FileShareTokenIntent {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for FileShareTokenIntent {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -532,9 +558,9 @@ impl FromStr for GeoReplicationStatusType {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "bootstrap" => GeoReplicationStatusType::Bootstrap,
-            "live" => GeoReplicationStatusType::Live,
-            "unavailable" => GeoReplicationStatusType::Unavailable,
+            _ if s.eq_ignore_ascii_case("bootstrap") => GeoReplicationStatusType::Bootstrap,
+            _ if s.eq_ignore_ascii_case("live") => GeoReplicationStatusType::Live,
+            _ if s.eq_ignore_ascii_case("unavailable") => GeoReplicationStatusType::Unavailable,
             _ => GeoReplicationStatusType::UnknownValue(s.to_string()),
         })
     }
@@ -551,6 +577,12 @@ impl AsRef<str> for GeoReplicationStatusType {
     }
 }
 
+impl PartialEq<str> for GeoReplicationStatusType {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for GeoReplicationStatusType {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -822,17 +854,17 @@ impl FromStr for PremiumPageBlobAccessTier {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "P10" => PremiumPageBlobAccessTier::P10,
-            "P15" => PremiumPageBlobAccessTier::P15,
-            "P20" => PremiumPageBlobAccessTier::P20,
-            "P30" => PremiumPageBlobAccessTier::P30,
-            "P4" => PremiumPageBlobAccessTier::P4,
-            "P40" => PremiumPageBlobAccessTier::P40,
-            "P50" => PremiumPageBlobAccessTier::P50,
-            "P6" => PremiumPageBlobAccessTier::P6,
-            "P60" => PremiumPageBlobAccessTier::P60,
-            "P70" => PremiumPageBlobAccessTier::P70,
-            "P80" => PremiumPageBlobAccessTier::P80,
+            _ if s.eq_ignore_ascii_case("P10") => PremiumPageBlobAccessTier::P10,
+            _ if s.eq_ignore_ascii_case("P15") => PremiumPageBlobAccessTier::P15,
+            _ if s.eq_ignore_ascii_case("P20") => PremiumPageBlobAccessTier::P20,
+            _ if s.eq_ignore_ascii_case("P30") => PremiumPageBlobAccessTier::P30,
+            _ if s.eq_ignore_ascii_case("P4") => PremiumPageBlobAccessTier::P4,
+            _ if s.eq_ignore_ascii_case("P40") => PremiumPageBlobAccessTier::P40,
+            _ if s.eq_ignore_ascii_case("P50") => PremiumPageBlobAccessTier::P50,
+            _ if s.eq_ignore_ascii_case("P6") => PremiumPageBlobAccessTier::P6,
+            _ if s.eq_ignore_ascii_case("P60") => PremiumPageBlobAccessTier::P60,
+            _ if s.eq_ignore_ascii_case("P70") => PremiumPageBlobAccessTier::P70,
+            _ if s.eq_ignore_ascii_case("P80") => PremiumPageBlobAccessTier::P80,
             _ => PremiumPageBlobAccessTier::UnknownValue(s.to_string()),
         })
     }
@@ -857,6 +889,12 @@ impl AsRef<str> for PremiumPageBlobAccessTier {
     }
 }
 
+impl PartialEq<str> for PremiumPageBlobAccessTier {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for PremiumPageBlobAccessTier {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -890,8 +928,8 @@ impl FromStr for PublicAccessType {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "blob" => PublicAccessType::Blob,
-            "container" => PublicAccessType::Container,
+            _ if s.eq_ignore_ascii_case("blob") => PublicAccessType::Blob,
+            _ if s.eq_ignore_ascii_case("container") => PublicAccessType::Container,
             _ => PublicAccessType::UnknownValue(s.to_string()),
         })
     }
@@ -907,6 +945,12 @@ impl AsRef<str> for PublicAccessType {
     }
 }
 
+impl PartialEq<str> for PublicAccessType {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for PublicAccessType {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -931,8 +975,8 @@ impl FromStr for RehydratePriority {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "High" => RehydratePriority::High,
-            "Standard" => RehydratePriority::Standard,
+            _ if s.eq_ignore_ascii_case("High") => RehydratePriority::High,
+            _ if s.eq_ignore_ascii_case("Standard") => RehydratePriority::Standard,
             _ => RehydratePriority::UnknownValue(s.to_string()),
         })
     }
@@ -948,6 +992,12 @@ impl AsRef<str> for RehydratePriority {
     }
 }
 
+impl PartialEq<str> for RehydratePriority {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for RehydratePriority {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -1213,162 +1263,280 @@ impl FromStr for StorageErrorCode {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "AccountAlreadyExists" => StorageErrorCode::AccountAlreadyExists,
-            "AccountBeingCreated" => StorageErrorCode::AccountBeingCreated,
-            "AccountIsDisabled" => StorageErrorCode::AccountIsDisabled,
-            "AppendPositionConditionNotMet" => StorageErrorCode::AppendPositionConditionNotMet,
-            "AuthenticationFailed" => StorageErrorCode::AuthenticationFailed,
-            "AuthorizationFailure" => StorageErrorCode::AuthorizationFailure,
-            "AuthorizationPermissionMismatch" => StorageErrorCode::AuthorizationPermissionMismatch,
-            "AuthorizationProtocolMismatch" => StorageErrorCode::AuthorizationProtocolMismatch,
-            "AuthorizationResourceTypeMismatch" => {
+            _ if s.eq_ignore_ascii_case("AccountAlreadyExists") => {
+                StorageErrorCode::AccountAlreadyExists
+            }
+            _ if s.eq_ignore_ascii_case("AccountBeingCreated") => {
+                StorageErrorCode::AccountBeingCreated
+            }
+            _ if s.eq_ignore_ascii_case("AccountIsDisabled") => StorageErrorCode::AccountIsDisabled,
+            _ if s.eq_ignore_ascii_case("AppendPositionConditionNotMet") => {
+                StorageErrorCode::AppendPositionConditionNotMet
+            }
+            _ if s.eq_ignore_ascii_case("AuthenticationFailed") => {
+                StorageErrorCode::AuthenticationFailed
+            }
+            _ if s.eq_ignore_ascii_case("AuthorizationFailure") => {
+                StorageErrorCode::AuthorizationFailure
+            }
+            _ if s.eq_ignore_ascii_case("AuthorizationPermissionMismatch") => {
+                StorageErrorCode::AuthorizationPermissionMismatch
+            }
+            _ if s.eq_ignore_ascii_case("AuthorizationProtocolMismatch") => {
+                StorageErrorCode::AuthorizationProtocolMismatch
+            }
+            _ if s.eq_ignore_ascii_case("AuthorizationResourceTypeMismatch") => {
                 StorageErrorCode::AuthorizationResourceTypeMismatch
             }
-            "AuthorizationServiceMismatch" => StorageErrorCode::AuthorizationServiceMismatch,
-            "AuthorizationSourceIPMismatch" => StorageErrorCode::AuthorizationSourceIPMismatch,
-            "BlobAccessTierNotSupportedForAccountType" => {
+            _ if s.eq_ignore_ascii_case("AuthorizationServiceMismatch") => {
+                StorageErrorCode::AuthorizationServiceMismatch
+            }
+            _ if s.eq_ignore_ascii_case("AuthorizationSourceIPMismatch") => {
+                StorageErrorCode::AuthorizationSourceIPMismatch
+            }
+            _ if s.eq_ignore_ascii_case("BlobAccessTierNotSupportedForAccountType") => {
                 StorageErrorCode::BlobAccessTierNotSupportedForAccountType
             }
-            "BlobAlreadyExists" => StorageErrorCode::BlobAlreadyExists,
-            "BlobArchived" => StorageErrorCode::BlobArchived,
-            "BlobBeingRehydrated" => StorageErrorCode::BlobBeingRehydrated,
-            "BlobImmutableDueToPolicy" => StorageErrorCode::BlobImmutableDueToPolicy,
-            "BlobNotArchived" => StorageErrorCode::BlobNotArchived,
-            "BlobNotFound" => StorageErrorCode::BlobNotFound,
-            "BlobOverwritten" => StorageErrorCode::BlobOverwritten,
-            "BlobTierInadequateForContentLength" => {
+            _ if s.eq_ignore_ascii_case("BlobAlreadyExists") => StorageErrorCode::BlobAlreadyExists,
+            _ if s.eq_ignore_ascii_case("BlobArchived") => StorageErrorCode::BlobArchived,
+            _ if s.eq_ignore_ascii_case("BlobBeingRehydrated") => {
+                StorageErrorCode::BlobBeingRehydrated
+            }
+            _ if s.eq_ignore_ascii_case("BlobImmutableDueToPolicy") => {
+                StorageErrorCode::BlobImmutableDueToPolicy
+            }
+            _ if s.eq_ignore_ascii_case("BlobNotArchived") => StorageErrorCode::BlobNotArchived,
+            _ if s.eq_ignore_ascii_case("BlobNotFound") => StorageErrorCode::BlobNotFound,
+            _ if s.eq_ignore_ascii_case("BlobOverwritten") => StorageErrorCode::BlobOverwritten,
+            _ if s.eq_ignore_ascii_case("BlobTierInadequateForContentLength") => {
                 StorageErrorCode::BlobTierInadequateForContentLength
             }
-            "BlobUsesCustomerSpecifiedEncryption" => {
+            _ if s.eq_ignore_ascii_case("BlobUsesCustomerSpecifiedEncryption") => {
                 StorageErrorCode::BlobUsesCustomerSpecifiedEncryption
             }
-            "BlockCountExceedsLimit" => StorageErrorCode::BlockCountExceedsLimit,
-            "BlockListTooLong" => StorageErrorCode::BlockListTooLong,
-            "CannotChangeToLowerTier" => StorageErrorCode::CannotChangeToLowerTier,
-            "CannotVerifyCopySource" => StorageErrorCode::CannotVerifyCopySource,
-            "ConditionHeadersNotSupported" => StorageErrorCode::ConditionHeadersNotSupported,
-            "ConditionNotMet" => StorageErrorCode::ConditionNotMet,
-            "ContainerAlreadyExists" => StorageErrorCode::ContainerAlreadyExists,
-            "ContainerBeingDeleted" => StorageErrorCode::ContainerBeingDeleted,
-            "ContainerDisabled" => StorageErrorCode::ContainerDisabled,
-            "ContainerNotFound" => StorageErrorCode::ContainerNotFound,
-            "ContentLengthLargerThanTierLimit" => {
+            _ if s.eq_ignore_ascii_case("BlockCountExceedsLimit") => {
+                StorageErrorCode::BlockCountExceedsLimit
+            }
+            _ if s.eq_ignore_ascii_case("BlockListTooLong") => StorageErrorCode::BlockListTooLong,
+            _ if s.eq_ignore_ascii_case("CannotChangeToLowerTier") => {
+                StorageErrorCode::CannotChangeToLowerTier
+            }
+            _ if s.eq_ignore_ascii_case("CannotVerifyCopySource") => {
+                StorageErrorCode::CannotVerifyCopySource
+            }
+            _ if s.eq_ignore_ascii_case("ConditionHeadersNotSupported") => {
+                StorageErrorCode::ConditionHeadersNotSupported
+            }
+            _ if s.eq_ignore_ascii_case("ConditionNotMet") => StorageErrorCode::ConditionNotMet,
+            _ if s.eq_ignore_ascii_case("ContainerAlreadyExists") => {
+                StorageErrorCode::ContainerAlreadyExists
+            }
+            _ if s.eq_ignore_ascii_case("ContainerBeingDeleted") => {
+                StorageErrorCode::ContainerBeingDeleted
+            }
+            _ if s.eq_ignore_ascii_case("ContainerDisabled") => StorageErrorCode::ContainerDisabled,
+            _ if s.eq_ignore_ascii_case("ContainerNotFound") => StorageErrorCode::ContainerNotFound,
+            _ if s.eq_ignore_ascii_case("ContentLengthLargerThanTierLimit") => {
                 StorageErrorCode::ContentLengthLargerThanTierLimit
             }
-            "CopyAcrossAccountsNotSupported" => StorageErrorCode::CopyAcrossAccountsNotSupported,
-            "CopyIdMismatch" => StorageErrorCode::CopyIdMismatch,
-            "EmptyMetadataKey" => StorageErrorCode::EmptyMetadataKey,
-            "FeatureVersionMismatch" => StorageErrorCode::FeatureVersionMismatch,
-            "IncrementalCopyBlobMismatch" => StorageErrorCode::IncrementalCopyBlobMismatch,
-            "IncrementalCopyOfEarlierSnapshotNotAllowed" => {
+            _ if s.eq_ignore_ascii_case("CopyAcrossAccountsNotSupported") => {
+                StorageErrorCode::CopyAcrossAccountsNotSupported
+            }
+            _ if s.eq_ignore_ascii_case("CopyIdMismatch") => StorageErrorCode::CopyIdMismatch,
+            _ if s.eq_ignore_ascii_case("EmptyMetadataKey") => StorageErrorCode::EmptyMetadataKey,
+            _ if s.eq_ignore_ascii_case("FeatureVersionMismatch") => {
+                StorageErrorCode::FeatureVersionMismatch
+            }
+            _ if s.eq_ignore_ascii_case("IncrementalCopyBlobMismatch") => {
+                StorageErrorCode::IncrementalCopyBlobMismatch
+            }
+            _ if s.eq_ignore_ascii_case("IncrementalCopyOfEarlierSnapshotNotAllowed") => {
                 StorageErrorCode::IncrementalCopyOfEarlierSnapshotNotAllowed
             }
-            "IncrementalCopyOfEarlierVersionSnapshotNotAllowed" => {
+            _ if s.eq_ignore_ascii_case("IncrementalCopyOfEarlierVersionSnapshotNotAllowed") => {
                 StorageErrorCode::IncrementalCopyOfEarlierVersionSnapshotNotAllowed
             }
-            "IncrementalCopySourceMustBeSnapshot" => {
+            _ if s.eq_ignore_ascii_case("IncrementalCopySourceMustBeSnapshot") => {
                 StorageErrorCode::IncrementalCopySourceMustBeSnapshot
             }
-            "InfiniteLeaseDurationRequired" => StorageErrorCode::InfiniteLeaseDurationRequired,
-            "InsufficientAccountPermissions" => StorageErrorCode::InsufficientAccountPermissions,
-            "InternalError" => StorageErrorCode::InternalError,
-            "InvalidAuthenticationInfo" => StorageErrorCode::InvalidAuthenticationInfo,
-            "InvalidBlobOrBlock" => StorageErrorCode::InvalidBlobOrBlock,
-            "InvalidBlobTier" => StorageErrorCode::InvalidBlobTier,
-            "InvalidBlobType" => StorageErrorCode::InvalidBlobType,
-            "InvalidBlockId" => StorageErrorCode::InvalidBlockId,
-            "InvalidBlockList" => StorageErrorCode::InvalidBlockList,
-            "InvalidHeaderValue" => StorageErrorCode::InvalidHeaderValue,
-            "InvalidHttpVerb" => StorageErrorCode::InvalidHttpVerb,
-            "InvalidInput" => StorageErrorCode::InvalidInput,
-            "InvalidMd5" => StorageErrorCode::InvalidMd5,
-            "InvalidMetadata" => StorageErrorCode::InvalidMetadata,
-            "InvalidOperation" => StorageErrorCode::InvalidOperation,
-            "InvalidPageRange" => StorageErrorCode::InvalidPageRange,
-            "InvalidQueryParameterValue" => StorageErrorCode::InvalidQueryParameterValue,
-            "InvalidRange" => StorageErrorCode::InvalidRange,
-            "InvalidRequestUrl" => StorageErrorCode::InvalidRequestUrl,
-            "InvalidResourceName" => StorageErrorCode::InvalidResourceName,
-            "InvalidSourceBlobType" => StorageErrorCode::InvalidSourceBlobType,
-            "InvalidSourceBlobUrl" => StorageErrorCode::InvalidSourceBlobUrl,
-            "InvalidUri" => StorageErrorCode::InvalidUri,
-            "InvalidVersionForPageBlobOperation" => {
+            _ if s.eq_ignore_ascii_case("InfiniteLeaseDurationRequired") => {
+                StorageErrorCode::InfiniteLeaseDurationRequired
+            }
+            _ if s.eq_ignore_ascii_case("InsufficientAccountPermissions") => {
+                StorageErrorCode::InsufficientAccountPermissions
+            }
+            _ if s.eq_ignore_ascii_case("InternalError") => StorageErrorCode::InternalError,
+            _ if s.eq_ignore_ascii_case("InvalidAuthenticationInfo") => {
+                StorageErrorCode::InvalidAuthenticationInfo
+            }
+            _ if s.eq_ignore_ascii_case("InvalidBlobOrBlock") => {
+                StorageErrorCode::InvalidBlobOrBlock
+            }
+            _ if s.eq_ignore_ascii_case("InvalidBlobTier") => StorageErrorCode::InvalidBlobTier,
+            _ if s.eq_ignore_ascii_case("InvalidBlobType") => StorageErrorCode::InvalidBlobType,
+            _ if s.eq_ignore_ascii_case("InvalidBlockId") => StorageErrorCode::InvalidBlockId,
+            _ if s.eq_ignore_ascii_case("InvalidBlockList") => StorageErrorCode::InvalidBlockList,
+            _ if s.eq_ignore_ascii_case("InvalidHeaderValue") => {
+                StorageErrorCode::InvalidHeaderValue
+            }
+            _ if s.eq_ignore_ascii_case("InvalidHttpVerb") => StorageErrorCode::InvalidHttpVerb,
+            _ if s.eq_ignore_ascii_case("InvalidInput") => StorageErrorCode::InvalidInput,
+            _ if s.eq_ignore_ascii_case("InvalidMd5") => StorageErrorCode::InvalidMd5,
+            _ if s.eq_ignore_ascii_case("InvalidMetadata") => StorageErrorCode::InvalidMetadata,
+            _ if s.eq_ignore_ascii_case("InvalidOperation") => StorageErrorCode::InvalidOperation,
+            _ if s.eq_ignore_ascii_case("InvalidPageRange") => StorageErrorCode::InvalidPageRange,
+            _ if s.eq_ignore_ascii_case("InvalidQueryParameterValue") => {
+                StorageErrorCode::InvalidQueryParameterValue
+            }
+            _ if s.eq_ignore_ascii_case("InvalidRange") => StorageErrorCode::InvalidRange,
+            _ if s.eq_ignore_ascii_case("InvalidRequestUrl") => StorageErrorCode::InvalidRequestUrl,
+            _ if s.eq_ignore_ascii_case("InvalidResourceName") => {
+                StorageErrorCode::InvalidResourceName
+            }
+            _ if s.eq_ignore_ascii_case("InvalidSourceBlobType") => {
+                StorageErrorCode::InvalidSourceBlobType
+            }
+            _ if s.eq_ignore_ascii_case("InvalidSourceBlobUrl") => {
+                StorageErrorCode::InvalidSourceBlobUrl
+            }
+            _ if s.eq_ignore_ascii_case("InvalidUri") => StorageErrorCode::InvalidUri,
+            _ if s.eq_ignore_ascii_case("InvalidVersionForPageBlobOperation") => {
                 StorageErrorCode::InvalidVersionForPageBlobOperation
             }
-            "InvalidXmlDocument" => StorageErrorCode::InvalidXmlDocument,
-            "InvalidXmlNodeValue" => StorageErrorCode::InvalidXmlNodeValue,
-            "LeaseAlreadyBroken" => StorageErrorCode::LeaseAlreadyBroken,
-            "LeaseAlreadyPresent" => StorageErrorCode::LeaseAlreadyPresent,
-            "LeaseIdMismatchWithBlobOperation" => {
+            _ if s.eq_ignore_ascii_case("InvalidXmlDocument") => {
+                StorageErrorCode::InvalidXmlDocument
+            }
+            _ if s.eq_ignore_ascii_case("InvalidXmlNodeValue") => {
+                StorageErrorCode::InvalidXmlNodeValue
+            }
+            _ if s.eq_ignore_ascii_case("LeaseAlreadyBroken") => {
+                StorageErrorCode::LeaseAlreadyBroken
+            }
+            _ if s.eq_ignore_ascii_case("LeaseAlreadyPresent") => {
+                StorageErrorCode::LeaseAlreadyPresent
+            }
+            _ if s.eq_ignore_ascii_case("LeaseIdMismatchWithBlobOperation") => {
                 StorageErrorCode::LeaseIdMismatchWithBlobOperation
             }
-            "LeaseIdMismatchWithContainerOperation" => {
+            _ if s.eq_ignore_ascii_case("LeaseIdMismatchWithContainerOperation") => {
                 StorageErrorCode::LeaseIdMismatchWithContainerOperation
             }
-            "LeaseIdMismatchWithLeaseOperation" => {
+            _ if s.eq_ignore_ascii_case("LeaseIdMismatchWithLeaseOperation") => {
                 StorageErrorCode::LeaseIdMismatchWithLeaseOperation
             }
-            "LeaseIdMissing" => StorageErrorCode::LeaseIdMissing,
-            "LeaseIsBreakingAndCannotBeAcquired" => {
+            _ if s.eq_ignore_ascii_case("LeaseIdMissing") => StorageErrorCode::LeaseIdMissing,
+            _ if s.eq_ignore_ascii_case("LeaseIsBreakingAndCannotBeAcquired") => {
                 StorageErrorCode::LeaseIsBreakingAndCannotBeAcquired
             }
-            "LeaseIsBreakingAndCannotBeChanged" => {
+            _ if s.eq_ignore_ascii_case("LeaseIsBreakingAndCannotBeChanged") => {
                 StorageErrorCode::LeaseIsBreakingAndCannotBeChanged
             }
-            "LeaseIsBrokenAndCannotBeRenewed" => StorageErrorCode::LeaseIsBrokenAndCannotBeRenewed,
-            "LeaseLost" => StorageErrorCode::LeaseLost,
-            "LeaseNotPresentWithBlobOperation" => {
+            _ if s.eq_ignore_ascii_case("LeaseIsBrokenAndCannotBeRenewed") => {
+                StorageErrorCode::LeaseIsBrokenAndCannotBeRenewed
+            }
+            _ if s.eq_ignore_ascii_case("LeaseLost") => StorageErrorCode::LeaseLost,
+            _ if s.eq_ignore_ascii_case("LeaseNotPresentWithBlobOperation") => {
                 StorageErrorCode::LeaseNotPresentWithBlobOperation
             }
-            "LeaseNotPresentWithContainerOperation" => {
+            _ if s.eq_ignore_ascii_case("LeaseNotPresentWithContainerOperation") => {
                 StorageErrorCode::LeaseNotPresentWithContainerOperation
             }
-            "LeaseNotPresentWithLeaseOperation" => {
+            _ if s.eq_ignore_ascii_case("LeaseNotPresentWithLeaseOperation") => {
                 StorageErrorCode::LeaseNotPresentWithLeaseOperation
             }
-            "MaxBlobSizeConditionNotMet" => StorageErrorCode::MaxBlobSizeConditionNotMet,
-            "Md5Mismatch" => StorageErrorCode::Md5Mismatch,
-            "MetadataTooLarge" => StorageErrorCode::MetadataTooLarge,
-            "MissingContentLengthHeader" => StorageErrorCode::MissingContentLengthHeader,
-            "MissingRequiredHeader" => StorageErrorCode::MissingRequiredHeader,
-            "MissingRequiredQueryParameter" => StorageErrorCode::MissingRequiredQueryParameter,
-            "MissingRequiredXmlNode" => StorageErrorCode::MissingRequiredXmlNode,
-            "MultipleConditionHeadersNotSupported" => {
+            _ if s.eq_ignore_ascii_case("MaxBlobSizeConditionNotMet") => {
+                StorageErrorCode::MaxBlobSizeConditionNotMet
+            }
+            _ if s.eq_ignore_ascii_case("Md5Mismatch") => StorageErrorCode::Md5Mismatch,
+            _ if s.eq_ignore_ascii_case("MetadataTooLarge") => StorageErrorCode::MetadataTooLarge,
+            _ if s.eq_ignore_ascii_case("MissingContentLengthHeader") => {
+                StorageErrorCode::MissingContentLengthHeader
+            }
+            _ if s.eq_ignore_ascii_case("MissingRequiredHeader") => {
+                StorageErrorCode::MissingRequiredHeader
+            }
+            _ if s.eq_ignore_ascii_case("MissingRequiredQueryParameter") => {
+                StorageErrorCode::MissingRequiredQueryParameter
+            }
+            _ if s.eq_ignore_ascii_case("MissingRequiredXmlNode") => {
+                StorageErrorCode::MissingRequiredXmlNode
+            }
+            _ if s.eq_ignore_ascii_case("MultipleConditionHeadersNotSupported") => {
                 StorageErrorCode::MultipleConditionHeadersNotSupported
             }
-            "NoAuthenticationInformation" => StorageErrorCode::NoAuthenticationInformation,
-            "NoPendingCopyOperation" => StorageErrorCode::NoPendingCopyOperation,
-            "OperationNotAllowedOnIncrementalCopyBlob" => {
+            _ if s.eq_ignore_ascii_case("NoAuthenticationInformation") => {
+                StorageErrorCode::NoAuthenticationInformation
+            }
+            _ if s.eq_ignore_ascii_case("NoPendingCopyOperation") => {
+                StorageErrorCode::NoPendingCopyOperation
+            }
+            _ if s.eq_ignore_ascii_case("OperationNotAllowedOnIncrementalCopyBlob") => {
                 StorageErrorCode::OperationNotAllowedOnIncrementalCopyBlob
             }
-            "OperationTimedOut" => StorageErrorCode::OperationTimedOut,
-            "OutOfRangeInput" => StorageErrorCode::OutOfRangeInput,
-            "OutOfRangeQueryParameterValue" => StorageErrorCode::OutOfRangeQueryParameterValue,
-            "PendingCopyOperation" => StorageErrorCode::PendingCopyOperation,
-            "PreviousSnapshotCannotBeNewer" => StorageErrorCode::PreviousSnapshotCannotBeNewer,
-            "PreviousSnapshotNotFound" => StorageErrorCode::PreviousSnapshotNotFound,
-            "PreviousSnapshotOperationNotSupported" => {
+            _ if s.eq_ignore_ascii_case("OperationTimedOut") => StorageErrorCode::OperationTimedOut,
+            _ if s.eq_ignore_ascii_case("OutOfRangeInput") => StorageErrorCode::OutOfRangeInput,
+            _ if s.eq_ignore_ascii_case("OutOfRangeQueryParameterValue") => {
+                StorageErrorCode::OutOfRangeQueryParameterValue
+            }
+            _ if s.eq_ignore_ascii_case("PendingCopyOperation") => {
+                StorageErrorCode::PendingCopyOperation
+            }
+            _ if s.eq_ignore_ascii_case("PreviousSnapshotCannotBeNewer") => {
+                StorageErrorCode::PreviousSnapshotCannotBeNewer
+            }
+            _ if s.eq_ignore_ascii_case("PreviousSnapshotNotFound") => {
+                StorageErrorCode::PreviousSnapshotNotFound
+            }
+            _ if s.eq_ignore_ascii_case("PreviousSnapshotOperationNotSupported") => {
                 StorageErrorCode::PreviousSnapshotOperationNotSupported
             }
-            "RequestBodyTooLarge" => StorageErrorCode::RequestBodyTooLarge,
-            "RequestUrlFailedToParse" => StorageErrorCode::RequestUrlFailedToParse,
-            "ResourceAlreadyExists" => StorageErrorCode::ResourceAlreadyExists,
-            "ResourceNotFound" => StorageErrorCode::ResourceNotFound,
-            "ResourceTypeMismatch" => StorageErrorCode::ResourceTypeMismatch,
-            "SequenceNumberConditionNotMet" => StorageErrorCode::SequenceNumberConditionNotMet,
-            "SequenceNumberIncrementTooLarge" => StorageErrorCode::SequenceNumberIncrementTooLarge,
-            "ServerBusy" => StorageErrorCode::ServerBusy,
-            "SnapshotCountExceeded" => StorageErrorCode::SnapshotCountExceeded,
-            "SnapshotOperationRateExceeded" => StorageErrorCode::SnapshotOperationRateExceeded,
-            "SnapshotsPresent" => StorageErrorCode::SnapshotsPresent,
-            "SourceConditionNotMet" => StorageErrorCode::SourceConditionNotMet,
-            "SystemInUse" => StorageErrorCode::SystemInUse,
-            "TargetConditionNotMet" => StorageErrorCode::TargetConditionNotMet,
-            "UnauthorizedBlobOverwrite" => StorageErrorCode::UnauthorizedBlobOverwrite,
-            "UnsupportedHeader" => StorageErrorCode::UnsupportedHeader,
-            "UnsupportedHttpVerb" => StorageErrorCode::UnsupportedHttpVerb,
-            "UnsupportedQueryParameter" => StorageErrorCode::UnsupportedQueryParameter,
-            "UnsupportedXmlNode" => StorageErrorCode::UnsupportedXmlNode,
+            _ if s.eq_ignore_ascii_case("RequestBodyTooLarge") => {
+                StorageErrorCode::RequestBodyTooLarge
+            }
+            _ if s.eq_ignore_ascii_case("RequestUrlFailedToParse") => {
+                StorageErrorCode::RequestUrlFailedToParse
+            }
+            _ if s.eq_ignore_ascii_case("ResourceAlreadyExists") => {
+                StorageErrorCode::ResourceAlreadyExists
+            }
+            _ if s.eq_ignore_ascii_case("ResourceNotFound") => StorageErrorCode::ResourceNotFound,
+            _ if s.eq_ignore_ascii_case("ResourceTypeMismatch") => {
+                StorageErrorCode::ResourceTypeMismatch
+            }
+            _ if s.eq_ignore_ascii_case("SequenceNumberConditionNotMet") => {
+                StorageErrorCode::SequenceNumberConditionNotMet
+            }
+            _ if s.eq_ignore_ascii_case("SequenceNumberIncrementTooLarge") => {
+                StorageErrorCode::SequenceNumberIncrementTooLarge
+            }
+            _ if s.eq_ignore_ascii_case("ServerBusy") => StorageErrorCode::ServerBusy,
+            _ if s.eq_ignore_ascii_case("SnapshotCountExceeded") => {
+                StorageErrorCode::SnapshotCountExceeded
+            }
+            _ if s.eq_ignore_ascii_case("SnapshotOperationRateExceeded") => {
+                StorageErrorCode::SnapshotOperationRateExceeded
+            }
+            _ if s.eq_ignore_ascii_case("SnapshotsPresent") => StorageErrorCode::SnapshotsPresent,
+            _ if s.eq_ignore_ascii_case("SourceConditionNotMet") => {
+                StorageErrorCode::SourceConditionNotMet
+            }
+            _ if s.eq_ignore_ascii_case("SystemInUse") => StorageErrorCode::SystemInUse,
+            _ if s.eq_ignore_ascii_case("TargetConditionNotMet") => {
+                StorageErrorCode::TargetConditionNotMet
+            }
+            _ if s.eq_ignore_ascii_case("UnauthorizedBlobOverwrite") => {
+                StorageErrorCode::UnauthorizedBlobOverwrite
+            }
+            _ if s.eq_ignore_ascii_case("UnsupportedHeader") => StorageErrorCode::UnsupportedHeader,
+            _ if s.eq_ignore_ascii_case("UnsupportedHttpVerb") => {
+                StorageErrorCode::UnsupportedHttpVerb
+            }
+            _ if s.eq_ignore_ascii_case("UnsupportedQueryParameter") => {
+                StorageErrorCode::UnsupportedQueryParameter
+            }
+            _ if s.eq_ignore_ascii_case("UnsupportedXmlNode") => {
+                StorageErrorCode::UnsupportedXmlNode
+            }
             _ => StorageErrorCode::UnknownValue(s.to_string()),
         })
     }
@@ -1538,6 +1706,12 @@ impl AsRef<str> for StorageErrorCode {
     }
 }
 
+impl PartialEq<str> for StorageErrorCode {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for StorageErrorCode {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
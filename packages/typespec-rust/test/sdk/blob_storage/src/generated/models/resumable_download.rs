@@ -0,0 +1,207 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{
+    error::{CheckSuccessOptions, Error, ErrorKind},
+    http::{
+        headers::{HeaderName, Headers},
+        AsyncRawResponse, Context, Pipeline, PipelineStreamOptions, Request, StatusCode,
+    },
+    Bytes, Result,
+};
+use futures::{
+    stream::{self, BoxStream},
+    Stream, StreamExt,
+};
+use std::{pin::Pin, task::Poll};
+
+/// The number of times a download is resumed before the error is returned.
+const MAX_RESUMES: usize = 3;
+
+const ETAG: HeaderName = HeaderName::from_static("etag");
+const IF_MATCH: HeaderName = HeaderName::from_static("if-match");
+
+/// A byte stream over a binary response body.
+///
+/// If reading the response body fails with a transient error, the request is reissued for the bytes that
+/// haven't been received and the stream continues where it left off. The resumed request includes the
+/// `if-match` header with the original ETag so the stream fails instead of mixing different versions.
+pub struct ResumableDownload {
+    status: StatusCode,
+    headers: Headers,
+    stream: BoxStream<'static, Result<Bytes>>,
+}
+
+impl ResumableDownload {
+    pub(crate) fn new(
+        pipeline: Pipeline,
+        ctx: Context<'static>,
+        request: Request,
+        response: AsyncRawResponse,
+        range_header: &'static str,
+    ) -> Self {
+        let status = response.status();
+        let headers = response.headers().clone();
+        let range_header = HeaderName::from_static(range_header);
+        let state = DownloadState {
+            range: parse_range(request.headers().get_optional_str(&range_header)),
+            etag: headers.get_optional_str(&ETAG).map(ToString::to_string),
+            body: response.into_body().boxed(),
+            received: 0,
+            resumes: 0,
+            pipeline,
+            ctx,
+            request,
+            range_header,
+        };
+        Self {
+            status,
+            headers,
+            stream: stream::unfold(Some(state), next_chunk).boxed(),
+        }
+    }
+
+    /// Returns the status code of the initial response.
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Returns the headers of the initial response.
+    pub fn headers(&self) -> &Headers {
+        &self.headers
+    }
+
+    /// Invokes `progress` with the total number of bytes received after each chunk is received.
+    pub fn on_progress<F>(self, progress: F) -> Self
+    where
+        F: Fn(u64) + Send + 'static,
+    {
+        let mut received = 0u64;
+        let stream = self.stream.inspect(move |chunk| {
+            if let Ok(chunk) = chunk {
+                received += chunk.len() as u64;
+                progress(received);
+            }
+        });
+        Self {
+            stream: stream.boxed(),
+            ..self
+        }
+    }
+
+    /// Reads the remainder of the stream into memory.
+    pub async fn collect(mut self) -> Result<Bytes> {
+        let mut body = Vec::new();
+        while let Some(chunk) = self.stream.next().await {
+            body.extend_from_slice(&chunk?);
+        }
+        Ok(body.into())
+    }
+}
+
+impl Stream for ResumableDownload {
+    type Item = Result<Bytes>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        self.stream.poll_next_unpin(cx)
+    }
+}
+
+/// The state used to read and resume a download.
+struct DownloadState {
+    pipeline: Pipeline,
+    ctx: Context<'static>,
+    request: Request,
+    range_header: HeaderName,
+    /// the first and optional last byte requested. None if the download can't be resumed.
+    range: Option<(u64, Option<u64>)>,
+    etag: Option<String>,
+    body: BoxStream<'static, Result<Bytes>>,
+    received: u64,
+    resumes: usize,
+}
+
+impl DownloadState {
+    /// Reissues the request for the bytes that haven't been received.
+    async fn resume(&mut self) -> Result<()> {
+        let Some((first, last)) = self.range else {
+            return Err(Error::with_message(
+                ErrorKind::Other,
+                "the download can't be resumed for the requested range",
+            ));
+        };
+        let first = first + self.received;
+        let range = match last {
+            Some(last) => format!("bytes={first}-{last}"),
+            None => format!("bytes={first}-"),
+        };
+        let mut request = self.request.clone();
+        request.insert_header(self.range_header.clone(), range);
+        if let Some(etag) = self.etag.as_ref() {
+            request.insert_header(IF_MATCH, etag.clone());
+        }
+        let rsp = self
+            .pipeline
+            .stream(
+                &self.ctx,
+                &mut request,
+                Some(PipelineStreamOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[206],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        self.body = rsp.into_body().boxed();
+        Ok(())
+    }
+}
+
+/// Returns the next chunk of the download, resuming the download after transient failures.
+async fn next_chunk(
+    state: Option<DownloadState>,
+) -> Option<(Result<Bytes>, Option<DownloadState>)> {
+    let mut state = state?;
+    loop {
+        match state.body.next().await {
+            Some(Ok(chunk)) => {
+                state.received += chunk.len() as u64;
+                return Some((Ok(chunk), Some(state)));
+            }
+            Some(Err(err))
+                if state.resumes < MAX_RESUMES
+                    && state.range.is_some()
+                    && matches!(err.kind(), ErrorKind::Io) =>
+            {
+                state.resumes += 1;
+                if let Err(err) = state.resume().await {
+                    return Some((Err(err), None));
+                }
+            }
+            Some(Err(err)) => return Some((Err(err), None)),
+            None => return None,
+        }
+    }
+}
+
+/// Returns the first and optional last byte of a `bytes=first-[last]` range.
+///
+/// No range starts at the first byte. Returns None for ranges that can't be resumed (e.g. suffix ranges).
+fn parse_range(range: Option<&str>) -> Option<(u64, Option<u64>)> {
+    let Some(range) = range else {
+        return Some((0, None));
+    };
+    let (first, last) = range.trim().strip_prefix("bytes=")?.split_once('-')?;
+    let first = first.trim().parse().ok()?;
+    let last = match last.trim() {
+        "" => None,
+        last => Some(last.parse().ok()?),
+    };
+    Some((first, last))
+}
@@ -30,10 +30,10 @@ impl FromStr for CurveName {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "P-256" => CurveName::P256,
-            "P-256K" => CurveName::P256K,
-            "P-384" => CurveName::P384,
-            "P-521" => CurveName::P521,
+            _ if s.eq_ignore_ascii_case("P-256") => CurveName::P256,
+            _ if s.eq_ignore_ascii_case("P-256K") => CurveName::P256K,
+            _ if s.eq_ignore_ascii_case("P-384") => CurveName::P384,
+            _ if s.eq_ignore_ascii_case("P-521") => CurveName::P521,
             _ => CurveName::UnknownValue(s.to_string()),
         })
     }
@@ -51,6 +51,12 @@ impl AsRef<str> for CurveName {
     }
 }
 
+impl PartialEq<str> for CurveName {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for CurveName {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -88,19 +94,23 @@ impl FromStr for DeletionRecoveryLevel {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "CustomizedRecoverable" => DeletionRecoveryLevel::CustomizedRecoverable,
-            "CustomizedRecoverable+ProtectedSubscription" => {
+            _ if s.eq_ignore_ascii_case("CustomizedRecoverable") => {
+                DeletionRecoveryLevel::CustomizedRecoverable
+            }
+            _ if s.eq_ignore_ascii_case("CustomizedRecoverable+ProtectedSubscription") => {
                 DeletionRecoveryLevel::CustomizedRecoverableProtectedSubscription
             }
-            "CustomizedRecoverable+Purgeable" => {
+            _ if s.eq_ignore_ascii_case("CustomizedRecoverable+Purgeable") => {
                 DeletionRecoveryLevel::CustomizedRecoverablePurgeable
             }
-            "Purgeable" => DeletionRecoveryLevel::Purgeable,
-            "Recoverable" => DeletionRecoveryLevel::Recoverable,
-            "Recoverable+ProtectedSubscription" => {
+            _ if s.eq_ignore_ascii_case("Purgeable") => DeletionRecoveryLevel::Purgeable,
+            _ if s.eq_ignore_ascii_case("Recoverable") => DeletionRecoveryLevel::Recoverable,
+            _ if s.eq_ignore_ascii_case("Recoverable+ProtectedSubscription") => {
                 DeletionRecoveryLevel::RecoverableProtectedSubscription
             }
-            "Recoverable+Purgeable" => DeletionRecoveryLevel::RecoverablePurgeable,
+            _ if s.eq_ignore_ascii_case("Recoverable+Purgeable") => {
+                DeletionRecoveryLevel::RecoverablePurgeable
+            }
             _ => DeletionRecoveryLevel::UnknownValue(s.to_string()),
         })
     }
@@ -127,6 +137,12 @@ impl AsRef<str> for DeletionRecoveryLevel {
     }
 }
 
+impl PartialEq<str> for DeletionRecoveryLevel {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for DeletionRecoveryLevel {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -177,23 +193,25 @@ impl FromStr for EncryptionAlgorithm {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "A128CBC" => EncryptionAlgorithm::A128Cbc,
-            "A128CBCPAD" => EncryptionAlgorithm::A128Cbcpad,
-            "A128GCM" => EncryptionAlgorithm::A128Gcm,
-            "A128KW" => EncryptionAlgorithm::A128Kw,
-            "A192CBC" => EncryptionAlgorithm::A192Cbc,
-            "A192CBCPAD" => EncryptionAlgorithm::A192Cbcpad,
-            "A192GCM" => EncryptionAlgorithm::A192Gcm,
-            "A192KW" => EncryptionAlgorithm::A192Kw,
-            "A256CBC" => EncryptionAlgorithm::A256Cbc,
-            "A256CBCPAD" => EncryptionAlgorithm::A256Cbcpad,
-            "A256GCM" => EncryptionAlgorithm::A256Gcm,
-            "A256KW" => EncryptionAlgorithm::A256Kw,
-            "CKM_AES_KEY_WRAP" => EncryptionAlgorithm::CkmAesKeyWrap,
-            "CKM_AES_KEY_WRAP_PAD" => EncryptionAlgorithm::CkmAesKeyWrapPad,
-            "RSA1_5" => EncryptionAlgorithm::Rsa1_5,
-            "RSA-OAEP" => EncryptionAlgorithm::RsaOaep,
-            "RSA-OAEP-256" => EncryptionAlgorithm::RsaOaep256,
+            _ if s.eq_ignore_ascii_case("A128CBC") => EncryptionAlgorithm::A128Cbc,
+            _ if s.eq_ignore_ascii_case("A128CBCPAD") => EncryptionAlgorithm::A128Cbcpad,
+            _ if s.eq_ignore_ascii_case("A128GCM") => EncryptionAlgorithm::A128Gcm,
+            _ if s.eq_ignore_ascii_case("A128KW") => EncryptionAlgorithm::A128Kw,
+            _ if s.eq_ignore_ascii_case("A192CBC") => EncryptionAlgorithm::A192Cbc,
+            _ if s.eq_ignore_ascii_case("A192CBCPAD") => EncryptionAlgorithm::A192Cbcpad,
+            _ if s.eq_ignore_ascii_case("A192GCM") => EncryptionAlgorithm::A192Gcm,
+            _ if s.eq_ignore_ascii_case("A192KW") => EncryptionAlgorithm::A192Kw,
+            _ if s.eq_ignore_ascii_case("A256CBC") => EncryptionAlgorithm::A256Cbc,
+            _ if s.eq_ignore_ascii_case("A256CBCPAD") => EncryptionAlgorithm::A256Cbcpad,
+            _ if s.eq_ignore_ascii_case("A256GCM") => EncryptionAlgorithm::A256Gcm,
+            _ if s.eq_ignore_ascii_case("A256KW") => EncryptionAlgorithm::A256Kw,
+            _ if s.eq_ignore_ascii_case("CKM_AES_KEY_WRAP") => EncryptionAlgorithm::CkmAesKeyWrap,
+            _ if s.eq_ignore_ascii_case("CKM_AES_KEY_WRAP_PAD") => {
+                EncryptionAlgorithm::CkmAesKeyWrapPad
+            }
+            _ if s.eq_ignore_ascii_case("RSA1_5") => EncryptionAlgorithm::Rsa1_5,
+            _ if s.eq_ignore_ascii_case("RSA-OAEP") => EncryptionAlgorithm::RsaOaep,
+            _ if s.eq_ignore_ascii_case("RSA-OAEP-256") => EncryptionAlgorithm::RsaOaep256,
             _ => EncryptionAlgorithm::UnknownValue(s.to_string()),
         })
     }
@@ -224,6 +242,12 @@ impl AsRef<str> for EncryptionAlgorithm {
     }
 }
 
+impl PartialEq<str> for EncryptionAlgorithm {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for EncryptionAlgorithm {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -264,9 +288,15 @@ impl FromStr for KeyEncryptionAlgorithm {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "CKM_RSA_AES_KEY_WRAP" => KeyEncryptionAlgorithm::CkmRsaAesKeyWrap,
-            "RSA_AES_KEY_WRAP_256" => KeyEncryptionAlgorithm::RsaAesKeyWrap256,
-            "RSA_AES_KEY_WRAP_384" => KeyEncryptionAlgorithm::RsaAesKeyWrap384,
+            _ if s.eq_ignore_ascii_case("CKM_RSA_AES_KEY_WRAP") => {
+                KeyEncryptionAlgorithm::CkmRsaAesKeyWrap
+            }
+            _ if s.eq_ignore_ascii_case("RSA_AES_KEY_WRAP_256") => {
+                KeyEncryptionAlgorithm::RsaAesKeyWrap256
+            }
+            _ if s.eq_ignore_ascii_case("RSA_AES_KEY_WRAP_384") => {
+                KeyEncryptionAlgorithm::RsaAesKeyWrap384
+            }
             _ => KeyEncryptionAlgorithm::UnknownValue(s.to_string()),
         })
     }
@@ -283,6 +313,12 @@ impl AsRef<str> for KeyEncryptionAlgorithm {
     }
 }
 
+impl PartialEq<str> for KeyEncryptionAlgorithm {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for KeyEncryptionAlgorithm {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -314,14 +350,14 @@ impl FromStr for KeyOperation {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "decrypt" => KeyOperation::Decrypt,
-            "encrypt" => KeyOperation::Encrypt,
-            "export" => KeyOperation::Export,
-            "import" => KeyOperation::Import,
-            "sign" => KeyOperation::Sign,
-            "unwrapKey" => KeyOperation::UnwrapKey,
-            "verify" => KeyOperation::Verify,
-            "wrapKey" => KeyOperation::WrapKey,
+            _ if s.eq_ignore_ascii_case("decrypt") => KeyOperation::Decrypt,
+            _ if s.eq_ignore_ascii_case("encrypt") => KeyOperation::Encrypt,
+            _ if s.eq_ignore_ascii_case("export") => KeyOperation::Export,
+            _ if s.eq_ignore_ascii_case("import") => KeyOperation::Import,
+            _ if s.eq_ignore_ascii_case("sign") => KeyOperation::Sign,
+            _ if s.eq_ignore_ascii_case("unwrapKey") => KeyOperation::UnwrapKey,
+            _ if s.eq_ignore_ascii_case("verify") => KeyOperation::Verify,
+            _ if s.eq_ignore_ascii_case("wrapKey") => KeyOperation::WrapKey,
             _ => KeyOperation::UnknownValue(s.to_string()),
         })
     }
@@ -343,6 +379,12 @@ impl AsRef<str> for KeyOperation {
     }
 }
 
+impl PartialEq<str> for KeyOperation {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for KeyOperation {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -410,12 +452,12 @@ impl FromStr for KeyType {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "EC" => KeyType::Ec,
-            "EC-HSM" => KeyType::EcHsm,
-            "oct" => KeyType::Oct,
-            "oct-HSM" => KeyType::OctHsm,
-            "RSA" => KeyType::Rsa,
-            "RSA-HSM" => KeyType::RsaHsm,
+            _ if s.eq_ignore_ascii_case("EC") => KeyType::Ec,
+            _ if s.eq_ignore_ascii_case("EC-HSM") => KeyType::EcHsm,
+            _ if s.eq_ignore_ascii_case("oct") => KeyType::Oct,
+            _ if s.eq_ignore_ascii_case("oct-HSM") => KeyType::OctHsm,
+            _ if s.eq_ignore_ascii_case("RSA") => KeyType::Rsa,
+            _ if s.eq_ignore_ascii_case("RSA-HSM") => KeyType::RsaHsm,
             _ => KeyType::UnknownValue(s.to_string()),
         })
     }
@@ -435,6 +477,12 @@ impl AsRef<str> for KeyType {
     }
 }
 
+impl PartialEq<str> for KeyType {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for KeyType {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -475,20 +523,20 @@ impl FromStr for SignatureAlgorithm {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "ES256" => SignatureAlgorithm::Es256,
-            "ES256K" => SignatureAlgorithm::Es256K,
-            "ES384" => SignatureAlgorithm::Es384,
-            "ES512" => SignatureAlgorithm::Es512,
-            "HS256" => SignatureAlgorithm::Hs256,
-            "HS384" => SignatureAlgorithm::Hs384,
-            "HS512" => SignatureAlgorithm::Hs512,
-            "PS256" => SignatureAlgorithm::Ps256,
-            "PS384" => SignatureAlgorithm::Ps384,
-            "PS512" => SignatureAlgorithm::Ps512,
-            "RS256" => SignatureAlgorithm::Rs256,
-            "RS384" => SignatureAlgorithm::Rs384,
-            "RS512" => SignatureAlgorithm::Rs512,
-            "RSNULL" => SignatureAlgorithm::Rsnull,
+            _ if s.eq_ignore_ascii_case("ES256") => SignatureAlgorithm::Es256,
+            _ if s.eq_ignore_ascii_case("ES256K") => SignatureAlgorithm::Es256K,
+            _ if s.eq_ignore_ascii_case("ES384") => SignatureAlgorithm::Es384,
+            _ if s.eq_ignore_ascii_case("ES512") => SignatureAlgorithm::Es512,
+            _ if s.eq_ignore_ascii_case("HS256") => SignatureAlgorithm::Hs256,
+            _ if s.eq_ignore_ascii_case("HS384") => SignatureAlgorithm::Hs384,
+            _ if s.eq_ignore_ascii_case("HS512") => SignatureAlgorithm::Hs512,
+            _ if s.eq_ignore_ascii_case("PS256") => SignatureAlgorithm::Ps256,
+            _ if s.eq_ignore_ascii_case("PS384") => SignatureAlgorithm::Ps384,
+            _ if s.eq_ignore_ascii_case("PS512") => SignatureAlgorithm::Ps512,
+            _ if s.eq_ignore_ascii_case("RS256") => SignatureAlgorithm::Rs256,
+            _ if s.eq_ignore_ascii_case("RS384") => SignatureAlgorithm::Rs384,
+            _ if s.eq_ignore_ascii_case("RS512") => SignatureAlgorithm::Rs512,
+            _ if s.eq_ignore_ascii_case("RSNULL") => SignatureAlgorithm::Rsnull,
             _ => SignatureAlgorithm::UnknownValue(s.to_string()),
         })
     }
@@ -516,6 +564,12 @@ impl AsRef<str> for SignatureAlgorithm {
     }
 }
 
+impl PartialEq<str> for SignatureAlgorithm {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for SignatureAlgorithm {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
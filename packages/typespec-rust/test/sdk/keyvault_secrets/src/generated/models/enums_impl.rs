@@ -24,8 +24,8 @@ impl FromStr for ContentType {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "application/x-pem-file" => ContentType::Pem,
-            "application/x-pkcs12" => ContentType::Pfx,
+            _ if s.eq_ignore_ascii_case("application/x-pem-file") => ContentType::Pem,
+            _ if s.eq_ignore_ascii_case("application/x-pkcs12") => ContentType::Pfx,
             _ => ContentType::UnknownValue(s.to_string()),
         })
     }
@@ -41,6 +41,12 @@ impl AsRef<str> for ContentType {
     }
 }
 
+impl PartialEq<str> for ContentType {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for ContentType {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -76,19 +82,23 @@ impl FromStr for DeletionRecoveryLevel {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "CustomizedRecoverable" => DeletionRecoveryLevel::CustomizedRecoverable,
-            "CustomizedRecoverable+ProtectedSubscription" => {
+            _ if s.eq_ignore_ascii_case("CustomizedRecoverable") => {
+                DeletionRecoveryLevel::CustomizedRecoverable
+            }
+            _ if s.eq_ignore_ascii_case("CustomizedRecoverable+ProtectedSubscription") => {
                 DeletionRecoveryLevel::CustomizedRecoverableProtectedSubscription
             }
-            "CustomizedRecoverable+Purgeable" => {
+            _ if s.eq_ignore_ascii_case("CustomizedRecoverable+Purgeable") => {
                 DeletionRecoveryLevel::CustomizedRecoverablePurgeable
             }
-            "Purgeable" => DeletionRecoveryLevel::Purgeable,
-            "Recoverable" => DeletionRecoveryLevel::Recoverable,
-            "Recoverable+ProtectedSubscription" => {
+            _ if s.eq_ignore_ascii_case("Purgeable") => DeletionRecoveryLevel::Purgeable,
+            _ if s.eq_ignore_ascii_case("Recoverable") => DeletionRecoveryLevel::Recoverable,
+            _ if s.eq_ignore_ascii_case("Recoverable+ProtectedSubscription") => {
                 DeletionRecoveryLevel::RecoverableProtectedSubscription
             }
-            "Recoverable+Purgeable" => DeletionRecoveryLevel::RecoverablePurgeable,
+            _ if s.eq_ignore_ascii_case("Recoverable+Purgeable") => {
+                DeletionRecoveryLevel::RecoverablePurgeable
+            }
             _ => DeletionRecoveryLevel::UnknownValue(s.to_string()),
         })
     }
@@ -115,6 +125,12 @@ impl AsRef<str> for DeletionRecoveryLevel {
     }
 }
 
+impl PartialEq<str> for DeletionRecoveryLevel {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for DeletionRecoveryLevel {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
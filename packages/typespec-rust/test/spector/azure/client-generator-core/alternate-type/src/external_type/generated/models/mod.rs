@@ -8,6 +8,8 @@ mod method_options;
 mod models;
 mod models_impl;
 mod unions;
+mod unions_impl;
+mod unions_serde;
 pub use method_options::*;
 pub use models::*;
 pub use unions::*;
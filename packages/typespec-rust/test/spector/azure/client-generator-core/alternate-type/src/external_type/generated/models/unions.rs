@@ -5,9 +5,9 @@
 
 use azure_core::fmt::SafeDebug;
 use rust_decimal::Decimal;
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
 
-#[derive(Clone, Deserialize, SafeDebug, Serialize)]
+#[derive(Clone, SafeDebug, Serialize)]
 #[serde(untagged)]
 pub enum FeatureId {
     String(String),
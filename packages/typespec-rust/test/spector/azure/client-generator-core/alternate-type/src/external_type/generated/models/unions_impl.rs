@@ -0,0 +1,63 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::FeatureId;
+use rust_decimal::Decimal;
+
+impl FeatureId {
+    /// Returns `true` if this is the [`FeatureId::String`] variant.
+    pub fn is_string(&self) -> bool {
+        matches!(self, Self::String(_))
+    }
+
+    /// Returns a reference to the value if this is the [`FeatureId::String`] variant.
+    pub fn as_string(&self) -> Option<&String> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`FeatureId::String`] variant.
+    pub fn into_string(self) -> Option<String> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this is the [`FeatureId::Value`] variant.
+    pub fn is_value(&self) -> bool {
+        matches!(self, Self::Value(_))
+    }
+
+    /// Returns a reference to the value if this is the [`FeatureId::Value`] variant.
+    pub fn as_value(&self) -> Option<&Decimal> {
+        match self {
+            Self::Value(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`FeatureId::Value`] variant.
+    pub fn into_value(self) -> Option<Decimal> {
+        match self {
+            Self::Value(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl From<String> for FeatureId {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<Decimal> for FeatureId {
+    fn from(value: Decimal) -> Self {
+        Self::Value(value)
+    }
+}
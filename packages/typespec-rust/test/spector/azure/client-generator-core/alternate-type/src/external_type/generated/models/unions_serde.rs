@@ -0,0 +1,30 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::FeatureId;
+use azure_core::Value;
+use serde::{Deserialize, Deserializer};
+
+impl<'de> Deserialize<'de> for FeatureId {
+    fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let mut errors = Vec::new();
+        match Deserialize::deserialize(&value).map(Self::String) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("String: {err}")),
+        }
+        match Deserialize::deserialize(&value).map(Self::Value) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("Value: {err}")),
+        }
+        Err(serde::de::Error::custom(format!(
+            "data did not match any variant of untagged enum FeatureId ({})",
+            errors.join("; ")
+        )))
+    }
+}
@@ -23,7 +23,7 @@ impl FromStr for SecondClientEnumType {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "second" => SecondClientEnumType::Second,
+            _ if s.eq_ignore_ascii_case("second") => SecondClientEnumType::Second,
             _ => SecondClientEnumType::UnknownValue(s.to_string()),
         })
     }
@@ -38,6 +38,12 @@ impl AsRef<str> for SecondClientEnumType {
     }
 }
 
+impl PartialEq<str> for SecondClientEnumType {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for SecondClientEnumType {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -27,11 +27,11 @@ impl FromStr for OperationState {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "Canceled" => OperationState::Canceled,
-            "Failed" => OperationState::Failed,
-            "NotStarted" => OperationState::NotStarted,
-            "Running" => OperationState::Running,
-            "Succeeded" => OperationState::Succeeded,
+            _ if s.eq_ignore_ascii_case("Canceled") => OperationState::Canceled,
+            _ if s.eq_ignore_ascii_case("Failed") => OperationState::Failed,
+            _ if s.eq_ignore_ascii_case("NotStarted") => OperationState::NotStarted,
+            _ if s.eq_ignore_ascii_case("Running") => OperationState::Running,
+            _ if s.eq_ignore_ascii_case("Succeeded") => OperationState::Succeeded,
             _ => OperationState::UnknownValue(s.to_string()),
         })
     }
@@ -50,6 +50,12 @@ impl AsRef<str> for OperationState {
     }
 }
 
+impl PartialEq<str> for OperationState {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for OperationState {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
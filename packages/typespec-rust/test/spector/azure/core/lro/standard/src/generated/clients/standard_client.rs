@@ -4,24 +4,27 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    StandardClientBeginCreateOrReplaceOperationStatus, StandardClientBeginCreateOrReplaceOptions,
-    StandardClientBeginDeleteOperationStatus, StandardClientBeginDeleteOptions,
-    StandardClientBeginExportOperationStatus, StandardClientBeginExportOptions, User,
+    Idempotency, IdempotencyPolicy, StandardClientBeginCreateOrReplaceOperationStatus,
+    StandardClientBeginCreateOrReplaceOptions, StandardClientBeginDeleteOperationStatus,
+    StandardClientBeginDeleteOptions, StandardClientBeginExportOperationStatus,
+    StandardClientBeginExportOptions, User,
 };
 use azure_core::{
     error::{CheckSuccessOptions, Error, ErrorKind},
     fmt::SafeDebug,
     http::{
         headers::{HeaderName, RETRY_AFTER, RETRY_AFTER_MS, X_MS_RETRY_AFTER_MS},
+        policies::Policy,
         poller::{
             get_retry_after, PollerContinuation, PollerResult, PollerState, PollerStatus,
             StatusMonitor,
         },
-        ClientOptions, Method, Pipeline, PipelineSendOptions, Poller, RawResponse, Request,
-        RequestContent, Url, UrlExt,
+        ClientMethodOptions, ClientOptions, Method, Pipeline, PipelineSendOptions, Poller,
+        RawResponse, Request, RequestContent, Url, UrlExt,
     },
     json, tracing, Result,
 };
+use std::sync::Arc;
 
 /// Illustrates bodies templated with Azure Core with long-running operation
 #[tracing::client]
@@ -60,6 +63,7 @@ impl StandardClient {
                 format!("{endpoint} must use http(s)"),
             ));
         }
+        let idempotency_policy: Arc<dyn Policy> = Arc::new(IdempotencyPolicy);
         Ok(Self {
             endpoint,
             api_version: options.api_version,
@@ -68,7 +72,7 @@ impl StandardClient {
                 option_env!("CARGO_PKG_VERSION"),
                 options.client_options,
                 Vec::default(),
-                Vec::default(),
+                vec![idempotency_policy],
                 None,
             ),
         })
@@ -79,6 +83,37 @@ impl StandardClient {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// If the context in `options` doesn't contain an [`Idempotency`] value, one is added based on the
+    /// request's method. POST and PATCH requests are treated as non-idempotent and won't be retried.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let mut ctx = options.context.to_borrowed();
+        if ctx.value::<Idempotency>().is_none() {
+            let idempotent = !matches!(request.method(), Method::Post | Method::Patch);
+            ctx = ctx.with_value(Idempotency::new(idempotent, idempotent));
+        }
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     /// Adds a user or replaces a user's fields.
     ///
     /// Creates or replaces a User
@@ -169,7 +204,10 @@ impl StandardClient {
                         )
                     }
                 };
-                let ctx = poller_options.context.clone();
+                let ctx = poller_options.context.clone().with_value(Idempotency {
+                    idempotent: true,
+                    retry_allowed: true,
+                });
                 let pipeline = pipeline.clone();
                 let final_link = url.clone();
                 Box::pin(async move {
@@ -322,7 +360,10 @@ impl StandardClient {
                         )
                     }
                 };
-                let ctx = poller_options.context.clone();
+                let ctx = poller_options.context.clone().with_value(Idempotency {
+                    idempotent: true,
+                    retry_allowed: true,
+                });
                 let pipeline = pipeline.clone();
                 Box::pin(async move {
                     let rsp = pipeline
@@ -449,6 +490,7 @@ impl StandardClient {
         let api_version = self.api_version.clone();
         Ok(Poller::new(
             move |poller_state: PollerState, poller_options| {
+                let idempotent = !matches!(poller_state, PollerState::Initial);
                 let (mut request, continuation) = match poller_state {
                     PollerState::More(continuation) => {
                         let (mut next_link, final_link) = match continuation.clone() {
@@ -485,7 +527,10 @@ impl StandardClient {
                         )
                     }
                 };
-                let ctx = poller_options.context.clone();
+                let ctx = poller_options.context.clone().with_value(Idempotency {
+                    idempotent,
+                    retry_allowed: idempotent || options.allow_retry,
+                });
                 let pipeline = pipeline.clone();
                 Box::pin(async move {
                     let rsp = pipeline
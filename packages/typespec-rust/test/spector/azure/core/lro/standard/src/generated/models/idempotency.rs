@@ -0,0 +1,94 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use async_trait::async_trait;
+use azure_core::{
+    error::{Error, ErrorKind},
+    http::{
+        headers::HeaderName,
+        policies::{Policy, PolicyResult},
+        Context, Request,
+    },
+};
+use std::sync::Arc;
+
+const ERROR_CODE: HeaderName = HeaderName::from_static("x-ms-error-code");
+
+/// Indicates if the request for an operation can be safely retried.
+///
+/// Client methods add this to the request's [`Context`] so that the client's [`IdempotencyPolicy`]
+/// can keep the retry policy from retrying requests with side effects that a retry would duplicate.
+/// Other policies retrieve it with `ctx.value::<Idempotency>()`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct Idempotency {
+    /// Indicates if sending the request more than once has the same effect as sending it once.
+    pub idempotent: bool,
+
+    /// Indicates if the request can be retried. This is always true for idempotent requests.
+    /// For all other requests, it's set by the `allow_retry` field in the method's options.
+    pub retry_allowed: bool,
+}
+
+impl Idempotency {
+    /// Creates a new `Idempotency` to add to the context of a request sent with `send_request`.
+    pub fn new(idempotent: bool, retry_allowed: bool) -> Self {
+        Self {
+            idempotent,
+            retry_allowed,
+        }
+    }
+}
+
+/// Keeps the retry policy from retrying requests whose [`Idempotency`] doesn't allow retries.
+///
+/// The retry policy in `azure_core` doesn't read the [`Idempotency`] in a request's context, so clients
+/// add this policy after it. When a request can't be retried, server errors and I/O errors, after which
+/// the request might have been processed, are returned as errors that the retry policy doesn't retry.
+#[derive(Clone, Debug, Default)]
+pub struct IdempotencyPolicy;
+
+#[async_trait]
+impl Policy for IdempotencyPolicy {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let result = next[0].send(ctx, request, &next[1..]).await;
+        if !matches!(
+            ctx.value::<Idempotency>(),
+            Some(Idempotency {
+                retry_allowed: false,
+                ..
+            })
+        ) {
+            return result;
+        }
+        match result {
+            Ok(response) if response.status().is_server_error() => {
+                let status = response.status();
+                let error_code = response
+                    .headers()
+                    .get_optional_str(&ERROR_CODE)
+                    .map(ToString::to_string);
+                let raw_response = response.try_into_raw_response().await?;
+                Err(Error::with_message(
+                    ErrorKind::HttpResponse {
+                        status,
+                        error_code,
+                        raw_response: Some(Box::new(raw_response)),
+                    },
+                    format!("the request isn't idempotent and wasn't retried after it failed with status {status}"),
+                ))
+            }
+            Err(err) if matches!(err.kind(), ErrorKind::Io) => {
+                Err(Error::new(ErrorKind::Other, err))
+            }
+            result => result,
+        }
+    }
+}
@@ -46,6 +46,9 @@ impl StandardClientBeginDeleteOptions<'_> {
 /// Options to be passed to [`StandardClient::begin_export()`](crate::generated::clients::StandardClient::begin_export())
 #[derive(Clone, Default, SafeDebug)]
 pub struct StandardClientBeginExportOptions<'a> {
+    /// Allows retry policies to retry the request even though the operation isn't idempotent.
+    pub allow_retry: bool,
+
     /// Allows customization of the method call.
     pub method_options: PollerOptions<'a>,
 }
@@ -54,6 +57,7 @@ impl StandardClientBeginExportOptions<'_> {
     /// Transforms this [`StandardClientBeginExportOptions`] into a new `StandardClientBeginExportOptions` that owns the underlying data, cloning it if necessary.
     pub fn into_owned(self) -> StandardClientBeginExportOptions<'static> {
         StandardClientBeginExportOptions {
+            allow_retry: self.allow_retry,
             method_options: PollerOptions {
                 context: self.method_options.context.into_owned(),
                 ..self.method_options
@@ -2,18 +2,23 @@
 //
 // Licensed under the MIT License. See License.txt in the project root for license information.
 
+use async_trait::async_trait;
+use azure_core::http::headers::{HeaderName, Headers};
+use azure_core::http::policies::{Policy, PolicyResult};
 use azure_core::http::poller::{PollerOptions, PollerStatus, StatusMonitor};
-use azure_core::http::RequestContent;
-use azure_core::http::StatusCode;
+use azure_core::http::{
+    AsyncRawResponse, ClientOptions, Context, Method, Request, RequestContent, StatusCode,
+};
 use azure_core::time::Duration;
 use futures::StreamExt;
+use std::sync::Arc;
 
 use spector_lrostd::{
     models::{
-        StandardClientBeginCreateOrReplaceOptions, StandardClientBeginDeleteOptions,
+        Idempotency, StandardClientBeginCreateOrReplaceOptions, StandardClientBeginDeleteOptions,
         StandardClientBeginExportOptions, User,
     },
-    StandardClient,
+    StandardClient, StandardClientOptions,
 };
 
 #[tokio::test]
@@ -121,6 +126,7 @@ async fn export() {
             frequency: Duration::seconds(1),
             ..Default::default()
         },
+        ..Default::default()
     });
 
     let mut poller = client
@@ -159,3 +165,81 @@ async fn export() {
     assert_eq!(final_result.name, Some("madge".to_string()));
     assert_eq!(final_result.resource_uri, Some("/users/madge".to_string()));
 }
+
+/// Verifies the idempotency of the export requests in place of the transport.
+/// The initial POST isn't idempotent but the polling requests are.
+#[derive(Debug)]
+struct ExportIdempotencyTransport {
+    allow_retry: bool,
+}
+
+#[async_trait]
+impl Policy for ExportIdempotencyTransport {
+    async fn send(
+        &self,
+        ctx: &Context,
+        request: &mut Request,
+        _next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let idempotency = ctx.value::<Idempotency>().unwrap();
+        match request.method() {
+            Method::Post => {
+                assert!(!idempotency.idempotent);
+                assert_eq!(idempotency.retry_allowed, self.allow_retry);
+                let mut headers = Headers::new();
+                headers.insert(
+                    HeaderName::from_static("operation-location"),
+                    "http://localhost:3000/azure/core/lro/standard/users/madge/operations/export",
+                );
+                Ok(AsyncRawResponse::from_bytes(
+                    StatusCode::Accepted,
+                    headers,
+                    r#"{"id":"export","status":"InProgress"}"#,
+                ))
+            }
+            Method::Get => {
+                assert!(idempotency.idempotent);
+                assert!(idempotency.retry_allowed);
+                Ok(AsyncRawResponse::from_bytes(
+                    StatusCode::Ok,
+                    Headers::new(),
+                    r#"{"id":"export","status":"Succeeded","result":{"name":"madge","resourceUri":"/users/madge"}}"#,
+                ))
+            }
+            method => panic!("unexpected method {method:?}"),
+        }
+    }
+}
+
+async fn export_with_idempotency(allow_retry: bool) {
+    let client_options = StandardClientOptions {
+        client_options: ClientOptions {
+            per_call_policies: vec![Arc::new(ExportIdempotencyTransport { allow_retry })],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let client =
+        StandardClient::with_no_credential("http://localhost:3000", Some(client_options)).unwrap();
+    let options = StandardClientBeginExportOptions {
+        allow_retry,
+        method_options: PollerOptions {
+            frequency: Duration::seconds(1),
+            ..Default::default()
+        },
+    };
+
+    let poller = client.begin_export("madge", "json", Some(options)).unwrap();
+    let final_result = poller.await.unwrap().into_model().unwrap();
+    assert_eq!(final_result.name, Some("madge".to_string()));
+}
+
+#[tokio::test]
+async fn export_is_not_retried_by_default() {
+    export_with_idempotency(false).await;
+}
+
+#[tokio::test]
+async fn export_retry_is_allowed_by_options() {
+    export_with_idempotency(true).await;
+}
@@ -10,8 +10,8 @@ use azure_core::{
     error::CheckSuccessOptions,
     fmt::SafeDebug,
     http::{
-        ClientOptions, Method, Pipeline, PipelineSendOptions, Request, RequestContent, Response,
-        Url, UrlExt,
+        ClientMethodOptions, ClientOptions, Method, Pipeline, PipelineSendOptions, RawResponse,
+        Request, RequestContent, Response, Url, UrlExt,
     },
     tracing, Result,
 };
@@ -71,10 +71,51 @@ impl AzureExampleClient {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
     ///
     /// # Arguments
     ///
+    /// * `request` - The request to send.
     /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use azure_core::Result;
+    /// use spector_azurebasic::clients::AzureExampleClient;
+    /// use spector_azurebasic::models::ActionRequest;
+    /// use spector_azurebasic::models::Enum;
+    /// use spector_azurebasic::models::Model;
+    /// use std::collections::HashMap;
+    ///
+    /// async fn example(client: &AzureExampleClient) -> Result<()> {
+    ///     // Basic action
+    ///     let response = client.basic_action("query", "header".to_string(), ActionRequest { array_property: Some(vec!["item".to_string()]), model_property: Some(Model { enum_property: Some(Enum::EnumValue1), float32_property: Some(1.5), int32_property: Some(1) }), record_property: Some(HashMap::from([("record".to_string(), "value".to_string())])), string_property: Some("text".to_string()) }.try_into()?, None).await?;
+    ///     Ok(())
+    /// }
+    /// ```
     #[tracing::function("AzureExampleBasicClient.AzureExampleClient.basicAction")]
     pub async fn basic_action(
         &self,
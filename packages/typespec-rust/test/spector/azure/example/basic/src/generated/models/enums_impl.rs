@@ -23,7 +23,7 @@ impl FromStr for Enum {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "EnumValue1" => Enum::EnumValue1,
+            _ if s.eq_ignore_ascii_case("EnumValue1") => Enum::EnumValue1,
             _ => Enum::UnknownValue(s.to_string()),
         })
     }
@@ -38,6 +38,12 @@ impl AsRef<str> for Enum {
     }
 }
 
+impl PartialEq<str> for Enum {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for Enum {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
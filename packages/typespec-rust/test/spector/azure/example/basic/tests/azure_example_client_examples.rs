@@ -0,0 +1,144 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use async_trait::async_trait;
+use azure_core::{
+    http::{
+        headers::{HeaderName, Headers},
+        policies::{Policy, PolicyResult},
+        AsyncRawResponse, Body, ClientOptions, Context, Method, Request, StatusCode,
+    },
+    Result,
+};
+use serde_json::Value;
+use spector_azurebasic::{
+    clients::{AzureExampleClient, AzureExampleClientOptions},
+    models::{ActionRequest, Enum, Model},
+};
+use std::{collections::HashMap, sync::Arc};
+
+/// Verifies the request and replays the example response in place of the transport.
+#[derive(Debug)]
+struct ExampleTransport {
+    method: Method,
+    path: Option<&'static str>,
+    query: &'static [(&'static str, &'static str)],
+    request_headers: &'static [(&'static str, &'static str)],
+    request_body: Option<&'static str>,
+    status: u16,
+    headers: &'static [(&'static str, &'static str)],
+    response_body: &'static str,
+}
+
+#[async_trait]
+impl Policy for ExampleTransport {
+    async fn send(
+        &self,
+        _ctx: &Context,
+        request: &mut Request,
+        _next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        assert_eq!(request.method(), self.method);
+        if let Some(path) = self.path {
+            assert!(
+                request.url().path().ends_with(path),
+                "unexpected path {}",
+                request.url().path()
+            );
+        }
+        for (name, value) in self.query {
+            assert!(
+                request
+                    .url()
+                    .query_pairs()
+                    .any(|(k, v)| k == *name && v == *value),
+                "missing query param {name}={value}"
+            );
+        }
+        for (name, value) in self.request_headers {
+            assert_eq!(
+                request.headers().get_optional_str(&HeaderName::from(*name)),
+                Some(*value),
+                "unexpected value for header {name}"
+            );
+        }
+        if let Some(expected) = self.request_body {
+            let Body::Bytes(actual) = request.body() else {
+                panic!("expected a buffered request body");
+            };
+            assert_eq!(
+                serde_json::from_slice::<Value>(actual).unwrap(),
+                serde_json::from_str::<Value>(expected).unwrap()
+            );
+        }
+        let mut headers = Headers::new();
+        for (name, value) in self.headers {
+            headers.insert(*name, *value);
+        }
+        Ok(AsyncRawResponse::from_bytes(
+            StatusCode::from(self.status),
+            headers,
+            self.response_body,
+        ))
+    }
+}
+
+/// Creates a [`AzureExampleClient`] that sends its requests to the transport.
+fn new_client(transport: ExampleTransport) -> Result<AzureExampleClient> {
+    let options = AzureExampleClientOptions {
+        client_options: ClientOptions {
+            per_call_policies: vec![Arc::new(transport)],
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    AzureExampleClient::with_no_credential("https://example.com", Some(options))
+}
+
+/// Basic action
+#[tokio::test]
+async fn basic_action_basic_action() -> Result<()> {
+    let client = new_client(ExampleTransport {
+        method: Method::Post,
+        path: Some("/azure/example/basic/basic"),
+        query: &[("query-param", "query")],
+        request_headers: &[
+            ("accept", "application/json"),
+            ("content-type", "application/json"),
+            ("header-param", "header"),
+        ],
+        request_body: Some(
+            r#"{"stringProperty":"text","modelProperty":{"int32Property":1,"float32Property":1.5,"enumProperty":"EnumValue1"},"arrayProperty":["item"],"recordProperty":{"record":"value"}}"#,
+        ),
+        status: 200,
+        headers: &[],
+        response_body: r#"{"stringProperty":"text"}"#,
+    })?;
+    let response = client
+        .basic_action(
+            "query",
+            "header".to_string(),
+            ActionRequest {
+                array_property: Some(vec!["item".to_string()]),
+                model_property: Some(Model {
+                    enum_property: Some(Enum::EnumValue1),
+                    float32_property: Some(1.5),
+                    int32_property: Some(1),
+                }),
+                record_property: Some(HashMap::from([("record".to_string(), "value".to_string())])),
+                string_property: Some("text".to_string()),
+            }
+            .try_into()?,
+            None,
+        )
+        .await?;
+    assert_eq!(response.status(), StatusCode::from(200));
+    let actual = serde_json::to_value(response.into_model()?).unwrap();
+    assert_eq!(
+        actual,
+        serde_json::from_str::<Value>(r#"{"stringProperty":"text"}"#).unwrap()
+    );
+    Ok(())
+}
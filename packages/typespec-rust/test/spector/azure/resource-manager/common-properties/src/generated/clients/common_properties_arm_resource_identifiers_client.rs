@@ -6,11 +6,14 @@
 use crate::generated::models::{
     ArmResourceIdentifierResource,
     CommonPropertiesArmResourceIdentifiersClientCreateOrReplaceOptions,
-    CommonPropertiesArmResourceIdentifiersClientGetOptions,
+    CommonPropertiesArmResourceIdentifiersClientGetOptions, ResourceId,
 };
 use azure_core::{
     error::CheckSuccessOptions,
-    http::{Method, Pipeline, PipelineSendOptions, Request, RequestContent, Response, Url, UrlExt},
+    http::{
+        ClientMethodOptions, Method, Pipeline, PipelineSendOptions, RawResponse, Request,
+        RequestContent, Response, Url, UrlExt,
+    },
     tracing, Result,
 };
 
@@ -28,6 +31,30 @@ impl CommonPropertiesArmResourceIdentifiersClient {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     /// Create a ArmResourceIdentifierResource
     ///
     /// # Arguments
@@ -92,6 +119,34 @@ impl CommonPropertiesArmResourceIdentifiersClient {
         Ok(rsp.into())
     }
 
+    /// Calls [`CommonPropertiesArmResourceIdentifiersClient::create_or_replace()`](crate::generated::clients::CommonPropertiesArmResourceIdentifiersClient::create_or_replace())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.CommonProperties/armResourceIdentifierResources/{armResourceIdentifierResourceName}`.
+    pub async fn create_or_replace_by_id(
+        &self,
+        resource_id: &ResourceId,
+        resource: RequestContent<ArmResourceIdentifierResource>,
+        options: Option<CommonPropertiesArmResourceIdentifiersClientCreateOrReplaceOptions<'_>>,
+    ) -> Result<Response<ArmResourceIdentifierResource>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.CommonProperties/armResourceIdentifierResources/{armResourceIdentifierResourceName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.create_or_replace(
+            path_params["resourceGroupName"],
+            path_params["armResourceIdentifierResourceName"],
+            resource,
+            options,
+        )
+        .await
+    }
+
     /// Get a ArmResourceIdentifierResource
     ///
     /// # Arguments
@@ -149,4 +204,31 @@ impl CommonPropertiesArmResourceIdentifiersClient {
             .await?;
         Ok(rsp.into())
     }
+
+    /// Calls [`CommonPropertiesArmResourceIdentifiersClient::get()`](crate::generated::clients::CommonPropertiesArmResourceIdentifiersClient::get())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.CommonProperties/armResourceIdentifierResources/{armResourceIdentifierResourceName}`.
+    /// * `options` - Optional parameters for the request.
+    pub async fn get_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<CommonPropertiesArmResourceIdentifiersClientGetOptions<'_>>,
+    ) -> Result<Response<ArmResourceIdentifierResource>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.CommonProperties/armResourceIdentifierResources/{armResourceIdentifierResourceName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.get(
+            path_params["resourceGroupName"],
+            path_params["armResourceIdentifierResourceName"],
+            options,
+        )
+        .await
+    }
 }
@@ -5,11 +5,14 @@
 
 use crate::generated::models::{
     CommonPropertiesErrorClientCreateForUserDefinedErrorOptions,
-    CommonPropertiesErrorClientGetForPredefinedErrorOptions, ConfidentialResource,
+    CommonPropertiesErrorClientGetForPredefinedErrorOptions, ConfidentialResource, ResourceId,
 };
 use azure_core::{
     error::CheckSuccessOptions,
-    http::{Method, Pipeline, PipelineSendOptions, Request, RequestContent, Response, Url, UrlExt},
+    http::{
+        ClientMethodOptions, Method, Pipeline, PipelineSendOptions, RawResponse, Request,
+        RequestContent, Response, Url, UrlExt,
+    },
     tracing, Result,
 };
 
@@ -27,6 +30,30 @@ impl CommonPropertiesErrorClient {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     /// Create a ConfidentialResource
     ///
     /// # Arguments
@@ -86,6 +113,36 @@ impl CommonPropertiesErrorClient {
         Ok(rsp.into())
     }
 
+    /// Calls [`CommonPropertiesErrorClient::create_for_user_defined_error()`](crate::generated::clients::CommonPropertiesErrorClient::create_for_user_defined_error())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.CommonProperties/confidentialResources/{confidentialResourceName}`.
+    /// * `resource` - Resource create parameters.
+    /// * `options` - Optional parameters for the request.
+    pub async fn create_for_user_defined_error_by_id(
+        &self,
+        resource_id: &ResourceId,
+        resource: RequestContent<ConfidentialResource>,
+        options: Option<CommonPropertiesErrorClientCreateForUserDefinedErrorOptions<'_>>,
+    ) -> Result<Response<ConfidentialResource>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.CommonProperties/confidentialResources/{confidentialResourceName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.create_for_user_defined_error(
+            path_params["resourceGroupName"],
+            path_params["confidentialResourceName"],
+            resource,
+            options,
+        )
+        .await
+    }
+
     /// Get a ConfidentialResource
     ///
     /// # Arguments
@@ -140,4 +197,31 @@ impl CommonPropertiesErrorClient {
             .await?;
         Ok(rsp.into())
     }
+
+    /// Calls [`CommonPropertiesErrorClient::get_for_predefined_error()`](crate::generated::clients::CommonPropertiesErrorClient::get_for_predefined_error())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.CommonProperties/confidentialResources/{confidentialResourceName}`.
+    /// * `options` - Optional parameters for the request.
+    pub async fn get_for_predefined_error_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<CommonPropertiesErrorClientGetForPredefinedErrorOptions<'_>>,
+    ) -> Result<Response<ConfidentialResource>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.CommonProperties/confidentialResources/{confidentialResourceName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.get_for_predefined_error(
+            path_params["resourceGroupName"],
+            path_params["confidentialResourceName"],
+            options,
+        )
+        .await
+    }
 }
@@ -7,11 +7,14 @@ use crate::generated::models::{
     CommonPropertiesManagedIdentityClientCreateWithSystemAssignedOptions,
     CommonPropertiesManagedIdentityClientGetOptions,
     CommonPropertiesManagedIdentityClientUpdateWithUserAssignedAndSystemAssignedOptions,
-    ManagedIdentityTrackedResource,
+    ManagedIdentityTrackedResource, ResourceId,
 };
 use azure_core::{
     error::CheckSuccessOptions,
-    http::{Method, Pipeline, PipelineSendOptions, Request, RequestContent, Response, Url, UrlExt},
+    http::{
+        ClientMethodOptions, Method, Pipeline, PipelineSendOptions, RawResponse, Request,
+        RequestContent, Response, Url, UrlExt,
+    },
     tracing, Result,
 };
 
@@ -29,6 +32,30 @@ impl CommonPropertiesManagedIdentityClient {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     /// Create a ManagedIdentityTrackedResource
     ///
     /// # Arguments
@@ -93,6 +120,34 @@ impl CommonPropertiesManagedIdentityClient {
         Ok(rsp.into())
     }
 
+    /// Calls [`CommonPropertiesManagedIdentityClient::create_with_system_assigned()`](crate::generated::clients::CommonPropertiesManagedIdentityClient::create_with_system_assigned())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.CommonProperties/managedIdentityTrackedResources/{managedIdentityTrackedResourceName}`.
+    pub async fn create_with_system_assigned_by_id(
+        &self,
+        resource_id: &ResourceId,
+        resource: RequestContent<ManagedIdentityTrackedResource>,
+        options: Option<CommonPropertiesManagedIdentityClientCreateWithSystemAssignedOptions<'_>>,
+    ) -> Result<Response<ManagedIdentityTrackedResource>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.CommonProperties/managedIdentityTrackedResources/{managedIdentityTrackedResourceName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.create_with_system_assigned(
+            path_params["resourceGroupName"],
+            path_params["managedIdentityTrackedResourceName"],
+            resource,
+            options,
+        )
+        .await
+    }
+
     /// Get a ManagedIdentityTrackedResource
     ///
     /// # Arguments
@@ -151,6 +206,33 @@ impl CommonPropertiesManagedIdentityClient {
         Ok(rsp.into())
     }
 
+    /// Calls [`CommonPropertiesManagedIdentityClient::get()`](crate::generated::clients::CommonPropertiesManagedIdentityClient::get())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.CommonProperties/managedIdentityTrackedResources/{managedIdentityTrackedResourceName}`.
+    /// * `options` - Optional parameters for the request.
+    pub async fn get_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<CommonPropertiesManagedIdentityClientGetOptions<'_>>,
+    ) -> Result<Response<ManagedIdentityTrackedResource>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.CommonProperties/managedIdentityTrackedResources/{managedIdentityTrackedResourceName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.get(
+            path_params["resourceGroupName"],
+            path_params["managedIdentityTrackedResourceName"],
+            options,
+        )
+        .await
+    }
+
     /// Update a ManagedIdentityTrackedResource
     ///
     /// # Arguments
@@ -214,4 +296,36 @@ impl CommonPropertiesManagedIdentityClient {
             .await?;
         Ok(rsp.into())
     }
+
+    /// Calls [`CommonPropertiesManagedIdentityClient::update_with_user_assigned_and_system_assigned()`](crate::generated::clients::CommonPropertiesManagedIdentityClient::update_with_user_assigned_and_system_assigned())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.CommonProperties/managedIdentityTrackedResources/{managedIdentityTrackedResourceName}`.
+    /// * `properties` - The resource properties to be updated.
+    /// * `options` - Optional parameters for the request.
+    pub async fn update_with_user_assigned_and_system_assigned_by_id(
+        &self,
+        resource_id: &ResourceId,
+        properties: RequestContent<ManagedIdentityTrackedResource>,
+        options: Option<
+            CommonPropertiesManagedIdentityClientUpdateWithUserAssignedAndSystemAssignedOptions<'_>,
+        >,
+    ) -> Result<Response<ManagedIdentityTrackedResource>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.CommonProperties/managedIdentityTrackedResources/{managedIdentityTrackedResourceName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.update_with_user_assigned_and_system_assigned(
+            path_params["resourceGroupName"],
+            path_params["managedIdentityTrackedResourceName"],
+            properties,
+            options,
+        )
+        .await
+    }
 }
@@ -26,10 +26,10 @@ impl FromStr for CreatedByType {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "Application" => CreatedByType::Application,
-            "Key" => CreatedByType::Key,
-            "ManagedIdentity" => CreatedByType::ManagedIdentity,
-            "User" => CreatedByType::User,
+            _ if s.eq_ignore_ascii_case("Application") => CreatedByType::Application,
+            _ if s.eq_ignore_ascii_case("Key") => CreatedByType::Key,
+            _ if s.eq_ignore_ascii_case("ManagedIdentity") => CreatedByType::ManagedIdentity,
+            _ if s.eq_ignore_ascii_case("User") => CreatedByType::User,
             _ => CreatedByType::UnknownValue(s.to_string()),
         })
     }
@@ -47,6 +47,12 @@ impl AsRef<str> for CreatedByType {
     }
 }
 
+impl PartialEq<str> for CreatedByType {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for CreatedByType {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -75,10 +81,14 @@ impl FromStr for ManagedServiceIdentityType {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "None" => ManagedServiceIdentityType::None,
-            "SystemAssigned" => ManagedServiceIdentityType::SystemAssigned,
-            "SystemAssigned,UserAssigned" => ManagedServiceIdentityType::SystemAssignedUserAssigned,
-            "UserAssigned" => ManagedServiceIdentityType::UserAssigned,
+            _ if s.eq_ignore_ascii_case("None") => ManagedServiceIdentityType::None,
+            _ if s.eq_ignore_ascii_case("SystemAssigned") => {
+                ManagedServiceIdentityType::SystemAssigned
+            }
+            _ if s.eq_ignore_ascii_case("SystemAssigned,UserAssigned") => {
+                ManagedServiceIdentityType::SystemAssignedUserAssigned
+            }
+            _ if s.eq_ignore_ascii_case("UserAssigned") => ManagedServiceIdentityType::UserAssigned,
             _ => ManagedServiceIdentityType::UnknownValue(s.to_string()),
         })
     }
@@ -96,6 +106,12 @@ impl AsRef<str> for ManagedServiceIdentityType {
     }
 }
 
+impl PartialEq<str> for ManagedServiceIdentityType {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for ManagedServiceIdentityType {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -125,9 +141,9 @@ impl FromStr for ResourceProvisioningState {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "Canceled" => ResourceProvisioningState::Canceled,
-            "Failed" => ResourceProvisioningState::Failed,
-            "Succeeded" => ResourceProvisioningState::Succeeded,
+            _ if s.eq_ignore_ascii_case("Canceled") => ResourceProvisioningState::Canceled,
+            _ if s.eq_ignore_ascii_case("Failed") => ResourceProvisioningState::Failed,
+            _ if s.eq_ignore_ascii_case("Succeeded") => ResourceProvisioningState::Succeeded,
             _ => ResourceProvisioningState::UnknownValue(s.to_string()),
         })
     }
@@ -144,6 +160,12 @@ impl AsRef<str> for ResourceProvisioningState {
     }
 }
 
+impl PartialEq<str> for ResourceProvisioningState {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for ResourceProvisioningState {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -5,7 +5,7 @@
 
 use crate::generated::models::{
     LargeHeaderLargeHeadersClientBeginTwo6KOperationStatus,
-    LargeHeaderLargeHeadersClientBeginTwo6KOptions,
+    LargeHeaderLargeHeadersClientBeginTwo6KOptions, ResourceId,
 };
 use azure_core::{
     error::CheckSuccessOptions,
@@ -15,7 +15,8 @@ use azure_core::{
             get_retry_after, PollerContinuation, PollerResult, PollerState, PollerStatus,
             StatusMonitor,
         },
-        Method, Pipeline, PipelineSendOptions, Poller, RawResponse, Request, Url, UrlExt,
+        ClientMethodOptions, Method, Pipeline, PipelineSendOptions, Poller, RawResponse, Request,
+        Url, UrlExt,
     },
     json, tracing, Result,
 };
@@ -34,6 +35,30 @@ impl LargeHeaderLargeHeadersClient {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     ///
     /// # Arguments
     ///
@@ -223,4 +248,30 @@ impl LargeHeaderLargeHeadersClient {
             Some(options.method_options),
         ))
     }
+
+    /// Calls [`LargeHeaderLargeHeadersClient::begin_two6_k()`](crate::generated::clients::LargeHeaderLargeHeadersClient::begin_two6_k())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.LargeHeader/largeHeaders/{largeHeaderName}/two6k`.
+    /// * `options` - Optional parameters for the request.
+    pub fn begin_two6_k_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<LargeHeaderLargeHeadersClientBeginTwo6KOptions<'_>>,
+    ) -> Result<Poller<LargeHeaderLargeHeadersClientBeginTwo6KOperationStatus>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.LargeHeader/largeHeaders/{largeHeaderName}/two6k")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.begin_two6_k(
+            path_params["resourceGroupName"],
+            path_params["largeHeaderName"],
+            options,
+        )
+    }
 }
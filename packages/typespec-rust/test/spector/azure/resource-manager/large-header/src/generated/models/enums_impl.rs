@@ -25,9 +25,9 @@ impl FromStr for ResourceProvisioningState {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "Canceled" => ResourceProvisioningState::Canceled,
-            "Failed" => ResourceProvisioningState::Failed,
-            "Succeeded" => ResourceProvisioningState::Succeeded,
+            _ if s.eq_ignore_ascii_case("Canceled") => ResourceProvisioningState::Canceled,
+            _ if s.eq_ignore_ascii_case("Failed") => ResourceProvisioningState::Failed,
+            _ if s.eq_ignore_ascii_case("Succeeded") => ResourceProvisioningState::Succeeded,
             _ => ResourceProvisioningState::UnknownValue(s.to_string()),
         })
     }
@@ -44,6 +44,12 @@ impl AsRef<str> for ResourceProvisioningState {
     }
 }
 
+impl PartialEq<str> for ResourceProvisioningState {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for ResourceProvisioningState {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
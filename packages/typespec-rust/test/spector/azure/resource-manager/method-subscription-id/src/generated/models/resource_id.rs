@@ -0,0 +1,190 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{
+    error::{Error, ErrorKind},
+    Result,
+};
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter},
+    str::FromStr,
+};
+
+/// An Azure Resource Manager resource ID.
+///
+/// A resource ID is a sequence of `{type}/{name}` pairs, e.g.
+/// `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/{namespace}/{type}/{name}`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResourceId {
+    segments: Vec<String>,
+}
+
+impl ResourceId {
+    /// Returns the subscription ID or `None` if the resource isn't scoped to a subscription.
+    pub fn subscription_id(&self) -> Option<&str> {
+        self.value_of(0, "subscriptions")
+    }
+
+    /// Returns the resource group name or `None` if the resource isn't scoped to a resource group.
+    pub fn resource_group_name(&self) -> Option<&str> {
+        self.value_of(1, "resourceGroups")
+    }
+
+    /// Returns the namespace of the resource provider, e.g. `Microsoft.Compute`.
+    ///
+    /// Returns `None` for subscriptions, resource groups, and other resources that aren't owned by a resource provider.
+    pub fn provider_namespace(&self) -> Option<&str> {
+        self.providers_index()
+            .map(|index| self.segments[index + 1].as_str())
+    }
+
+    /// Returns the fully qualified resource type, e.g. `Microsoft.Compute/virtualMachines/extensions`.
+    pub fn resource_type(&self) -> String {
+        match self.providers_index() {
+            Some(index) => {
+                let mut resource_type = self.segments[index + 1].clone();
+                for pair in self.segments[index + 2..].chunks(2) {
+                    resource_type.push('/');
+                    resource_type.push_str(&pair[0]);
+                }
+                resource_type
+            }
+            None => format!(
+                "Microsoft.Resources/{}",
+                self.segments[self.segments.len() - 2]
+            ),
+        }
+    }
+
+    /// Returns the name of the resource.
+    pub fn name(&self) -> &str {
+        &self.segments[self.segments.len() - 1]
+    }
+
+    /// Returns the ID of the parent resource or `None` if the resource has no parent.
+    ///
+    /// The parent of a top-level resource is the scope that contains it, e.g. its resource group.
+    pub fn parent(&self) -> Option<ResourceId> {
+        let mut segments = self.segments.clone();
+        segments.truncate(segments.len() - 2);
+        if segments.len() >= 2 && segments[segments.len() - 2].eq_ignore_ascii_case("providers") {
+            segments.truncate(segments.len() - 2);
+        }
+        if segments.is_empty() {
+            return None;
+        }
+        Some(ResourceId { segments })
+    }
+
+    /// Returns the ID of a child resource with the specified type and name.
+    ///
+    /// The type is the last segment of the child's resource type, e.g. `extensions`.
+    pub fn child(&self, resource_type: &str, name: &str) -> Result<ResourceId> {
+        if resource_type.is_empty()
+            || resource_type.contains('/')
+            || name.is_empty()
+            || name.contains('/')
+        {
+            return Err(invalid_resource_id(format!(
+                "invalid child resource {resource_type}/{name}"
+            )));
+        }
+        let mut segments = self.segments.clone();
+        segments.push(resource_type.to_string());
+        segments.push(name.to_string());
+        Ok(ResourceId { segments })
+    }
+
+    /// Matches the resource ID against a path template and returns the values of its parameters.
+    ///
+    /// The resource ID must match the start of the template. Any remaining segments in the template
+    /// must be literals (e.g. a collection or action name). Literal segments are compared without
+    /// regard to case.
+    pub fn path_params<'a>(&'a self, template: &'a str) -> Result<HashMap<&'a str, &'a str>> {
+        let mut template_segments = template.trim_matches('/').split('/');
+        let mut params = HashMap::new();
+        for segment in &self.segments {
+            let Some(template_segment) = template_segments.next() else {
+                return Err(invalid_resource_id(format!(
+                    "resource ID {self} doesn't match {template}"
+                )));
+            };
+            match template_segment
+                .strip_prefix('{')
+                .and_then(|param| param.strip_suffix('}'))
+            {
+                Some(param) => {
+                    params.insert(param, segment.as_str());
+                }
+                None if template_segment.eq_ignore_ascii_case(segment) => {}
+                None => {
+                    return Err(invalid_resource_id(format!(
+                        "resource ID {self} doesn't match {template}"
+                    )));
+                }
+            }
+        }
+        if template_segments.any(|segment| segment.starts_with('{')) {
+            return Err(invalid_resource_id(format!(
+                "resource ID {self} doesn't match {template}"
+            )));
+        }
+        Ok(params)
+    }
+
+    /// Returns the value of the `{type}/{name}` pair at `index` if its type is `key`.
+    fn value_of(&self, index: usize, key: &str) -> Option<&str> {
+        match self.segments.get(index * 2) {
+            Some(segment) if segment.eq_ignore_ascii_case(key) => {
+                Some(self.segments[index * 2 + 1].as_str())
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the index of the last `providers` segment.
+    fn providers_index(&self) -> Option<usize> {
+        (0..self.segments.len())
+            .step_by(2)
+            .rev()
+            .find(|index| self.segments[*index].eq_ignore_ascii_case("providers"))
+    }
+}
+
+impl Display for ResourceId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
+        for segment in &self.segments {
+            write!(f, "/{segment}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ResourceId {
+    type Err = Error;
+    fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
+        let Some(path) = s.strip_prefix('/') else {
+            return Err(invalid_resource_id(format!(
+                "resource ID {s} must start with '/'"
+            )));
+        };
+        let segments: Vec<String> = path
+            .trim_end_matches('/')
+            .split('/')
+            .map(str::to_string)
+            .collect();
+        if segments.iter().any(String::is_empty) || segments.len() % 2 == 1 {
+            return Err(invalid_resource_id(format!(
+                "resource ID {s} must contain {{type}}/{{name}} pairs"
+            )));
+        }
+        Ok(ResourceId { segments })
+    }
+}
+
+fn invalid_resource_id(message: String) -> Error {
+    Error::with_message(ErrorKind::DataConversion, message)
+}
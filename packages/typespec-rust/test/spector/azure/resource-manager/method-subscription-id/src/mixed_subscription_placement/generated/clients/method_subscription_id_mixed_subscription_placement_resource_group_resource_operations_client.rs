@@ -3,17 +3,20 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use crate::mixed_subscription_placement::generated::models::{
-    MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperationsClientDeleteOptions,
-    MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperationsClientGetOptions,
-    MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperationsClientPutOptions,
-    ResourceGroupResource,
+use crate::{
+    generated::models::ResourceId,
+    mixed_subscription_placement::generated::models::{
+        MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperationsClientDeleteOptions,
+        MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperationsClientGetOptions,
+        MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperationsClientPutOptions,
+        ResourceGroupResource,
+    },
 };
 use azure_core::{
     error::CheckSuccessOptions,
     http::{
-        Method, NoFormat, Pipeline, PipelineSendOptions, Request, RequestContent, Response, Url,
-        UrlExt,
+        ClientMethodOptions, Method, NoFormat, Pipeline, PipelineSendOptions, RawResponse, Request,
+        RequestContent, Response, Url, UrlExt,
     },
     tracing, Result,
 };
@@ -32,6 +35,30 @@ impl MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperatio
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     /// Delete a ResourceGroupResource
     ///
     /// # Arguments
@@ -86,6 +113,33 @@ impl MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperatio
         Ok(rsp.into())
     }
 
+    /// Calls [`MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperationsClient::delete()`](crate::mixed_subscription_placement::generated::clients::MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperationsClient::delete())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.MethodSubscriptionId/resourceGroupResources/{resourceGroupResourceName}`.
+    /// * `options` - Optional parameters for the request.
+    pub async fn delete_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperationsClientDeleteOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.MethodSubscriptionId/resourceGroupResources/{resourceGroupResourceName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.delete(
+            path_params["resourceGroupName"],
+            path_params["resourceGroupResourceName"],
+            options,
+        )
+        .await
+    }
+
     /// Get a ResourceGroupResource
     ///
     /// # Arguments
@@ -141,6 +195,33 @@ impl MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperatio
         Ok(rsp.into())
     }
 
+    /// Calls [`MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperationsClient::get()`](crate::mixed_subscription_placement::generated::clients::MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperationsClient::get())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.MethodSubscriptionId/resourceGroupResources/{resourceGroupResourceName}`.
+    /// * `options` - Optional parameters for the request.
+    pub async fn get_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperationsClientGetOptions<'_>>,
+    ) -> Result<Response<ResourceGroupResource>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.MethodSubscriptionId/resourceGroupResources/{resourceGroupResourceName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.get(
+            path_params["resourceGroupName"],
+            path_params["resourceGroupResourceName"],
+            options,
+        )
+        .await
+    }
+
     /// Create a ResourceGroupResource
     ///
     /// # Arguments
@@ -199,4 +280,34 @@ impl MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperatio
             .await?;
         Ok(rsp.into())
     }
+
+    /// Calls [`MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperationsClient::put()`](crate::mixed_subscription_placement::generated::clients::MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperationsClient::put())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.MethodSubscriptionId/resourceGroupResources/{resourceGroupResourceName}`.
+    /// * `resource` - Resource create parameters.
+    /// * `options` - Optional parameters for the request.
+    pub async fn put_by_id(
+        &self,
+        resource_id: &ResourceId,
+        resource: RequestContent<ResourceGroupResource>,
+        options: Option<MethodSubscriptionIdMixedSubscriptionPlacementResourceGroupResourceOperationsClientPutOptions<'_>>,
+    ) -> Result<Response<ResourceGroupResource>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.MethodSubscriptionId/resourceGroupResources/{resourceGroupResourceName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.put(
+            path_params["resourceGroupName"],
+            path_params["resourceGroupResourceName"],
+            resource,
+            options,
+        )
+        .await
+    }
 }
@@ -3,17 +3,20 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use crate::mixed_subscription_placement::generated::models::{
-    MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperationsClientDeleteOptions,
-    MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperationsClientGetOptions,
-    MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperationsClientPutOptions,
-    SubscriptionResource,
+use crate::{
+    generated::models::ResourceId,
+    mixed_subscription_placement::generated::models::{
+        MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperationsClientDeleteOptions,
+        MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperationsClientGetOptions,
+        MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperationsClientPutOptions,
+        SubscriptionResource,
+    },
 };
 use azure_core::{
     error::CheckSuccessOptions,
     http::{
-        Method, NoFormat, Pipeline, PipelineSendOptions, Request, RequestContent, Response, Url,
-        UrlExt,
+        ClientMethodOptions, Method, NoFormat, Pipeline, PipelineSendOptions, RawResponse, Request,
+        RequestContent, Response, Url, UrlExt,
     },
     tracing, Result,
 };
@@ -31,6 +34,30 @@ impl MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperation
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     /// Delete a SubscriptionResource
     ///
     /// # Arguments
@@ -84,6 +111,27 @@ impl MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperation
         Ok(rsp.into())
     }
 
+    /// Calls [`MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperationsClient::delete()`](crate::mixed_subscription_placement::generated::clients::MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperationsClient::delete())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/providers/Azure.ResourceManager.MethodSubscriptionId/subscriptionResources/{subscriptionResourceName}`.
+    /// * `options` - Optional parameters for the request.
+    pub async fn delete_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperationsClientDeleteOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/providers/Azure.ResourceManager.MethodSubscriptionId/subscriptionResources/{subscriptionResourceName}")?;
+        self.delete(
+            path_params["subscriptionId"],
+            path_params["subscriptionResourceName"],
+            options,
+        )
+        .await
+    }
+
     /// Get a SubscriptionResource
     ///
     /// # Arguments
@@ -138,6 +186,27 @@ impl MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperation
         Ok(rsp.into())
     }
 
+    /// Calls [`MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperationsClient::get()`](crate::mixed_subscription_placement::generated::clients::MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperationsClient::get())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/providers/Azure.ResourceManager.MethodSubscriptionId/subscriptionResources/{subscriptionResourceName}`.
+    /// * `options` - Optional parameters for the request.
+    pub async fn get_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperationsClientGetOptions<'_>>,
+    ) -> Result<Response<SubscriptionResource>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/providers/Azure.ResourceManager.MethodSubscriptionId/subscriptionResources/{subscriptionResourceName}")?;
+        self.get(
+            path_params["subscriptionId"],
+            path_params["subscriptionResourceName"],
+            options,
+        )
+        .await
+    }
+
     /// Create a SubscriptionResource
     ///
     /// # Arguments
@@ -195,4 +264,28 @@ impl MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperation
             .await?;
         Ok(rsp.into())
     }
+
+    /// Calls [`MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperationsClient::put()`](crate::mixed_subscription_placement::generated::clients::MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperationsClient::put())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/providers/Azure.ResourceManager.MethodSubscriptionId/subscriptionResources/{subscriptionResourceName}`.
+    /// * `resource` - Resource create parameters.
+    /// * `options` - Optional parameters for the request.
+    pub async fn put_by_id(
+        &self,
+        resource_id: &ResourceId,
+        resource: RequestContent<SubscriptionResource>,
+        options: Option<MethodSubscriptionIdMixedSubscriptionPlacementSubscriptionResourceOperationsClientPutOptions<'_>>,
+    ) -> Result<Response<SubscriptionResource>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/providers/Azure.ResourceManager.MethodSubscriptionId/subscriptionResources/{subscriptionResourceName}")?;
+        self.put(
+            path_params["subscriptionId"],
+            path_params["subscriptionResourceName"],
+            resource,
+            options,
+        )
+        .await
+    }
 }
@@ -8,8 +8,8 @@ use crate::two_subscription_resources_method_level::generated::clients::{
     MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource2OperationsClient,
 };
 use azure_core::{
-    http::{Pipeline, Url},
-    tracing,
+    http::{ClientMethodOptions, Pipeline, RawResponse, Request, Url},
+    tracing, Result,
 };
 
 /// Scenario 1: Two subscription level resources with subscriptionId at method level for all operations
@@ -32,6 +32,30 @@ impl MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelClient {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     /// Returns a new instance of MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource1OperationsClient.
     #[tracing::subclient]
     pub fn get_method_subscription_id_two_subscription_resources_method_level_subscription_resource1_operations_client(
@@ -3,17 +3,20 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use crate::two_subscription_resources_method_level::generated::models::{
-    MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource2OperationsClientDeleteOptions,
-    MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource2OperationsClientGetOptions,
-    MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource2OperationsClientPutOptions,
-    SubscriptionResource2,
+use crate::{
+    generated::models::ResourceId,
+    two_subscription_resources_method_level::generated::models::{
+        MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource2OperationsClientDeleteOptions,
+        MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource2OperationsClientGetOptions,
+        MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource2OperationsClientPutOptions,
+        SubscriptionResource2,
+    },
 };
 use azure_core::{
     error::CheckSuccessOptions,
     http::{
-        Method, NoFormat, Pipeline, PipelineSendOptions, Request, RequestContent, Response, Url,
-        UrlExt,
+        ClientMethodOptions, Method, NoFormat, Pipeline, PipelineSendOptions, RawResponse, Request,
+        RequestContent, Response, Url, UrlExt,
     },
     tracing, Result,
 };
@@ -32,6 +35,30 @@ impl MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     /// Delete a SubscriptionResource2
     ///
     /// # Arguments
@@ -85,6 +112,27 @@ impl MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource
         Ok(rsp.into())
     }
 
+    /// Calls [`MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource2OperationsClient::delete()`](crate::two_subscription_resources_method_level::generated::clients::MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource2OperationsClient::delete())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/providers/Azure.ResourceManager.MethodSubscriptionId/subscriptionResource2s/{subscriptionResource2Name}`.
+    /// * `options` - Optional parameters for the request.
+    pub async fn delete_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource2OperationsClientDeleteOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/providers/Azure.ResourceManager.MethodSubscriptionId/subscriptionResource2s/{subscriptionResource2Name}")?;
+        self.delete(
+            path_params["subscriptionId"],
+            path_params["subscriptionResource2Name"],
+            options,
+        )
+        .await
+    }
+
     /// Get a SubscriptionResource2
     ///
     /// # Arguments
@@ -139,6 +187,27 @@ impl MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource
         Ok(rsp.into())
     }
 
+    /// Calls [`MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource2OperationsClient::get()`](crate::two_subscription_resources_method_level::generated::clients::MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource2OperationsClient::get())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/providers/Azure.ResourceManager.MethodSubscriptionId/subscriptionResource2s/{subscriptionResource2Name}`.
+    /// * `options` - Optional parameters for the request.
+    pub async fn get_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource2OperationsClientGetOptions<'_>>,
+    ) -> Result<Response<SubscriptionResource2>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/providers/Azure.ResourceManager.MethodSubscriptionId/subscriptionResource2s/{subscriptionResource2Name}")?;
+        self.get(
+            path_params["subscriptionId"],
+            path_params["subscriptionResource2Name"],
+            options,
+        )
+        .await
+    }
+
     /// Create a SubscriptionResource2
     ///
     /// # Arguments
@@ -196,4 +265,28 @@ impl MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource
             .await?;
         Ok(rsp.into())
     }
+
+    /// Calls [`MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource2OperationsClient::put()`](crate::two_subscription_resources_method_level::generated::clients::MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource2OperationsClient::put())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/providers/Azure.ResourceManager.MethodSubscriptionId/subscriptionResource2s/{subscriptionResource2Name}`.
+    /// * `resource` - Resource create parameters.
+    /// * `options` - Optional parameters for the request.
+    pub async fn put_by_id(
+        &self,
+        resource_id: &ResourceId,
+        resource: RequestContent<SubscriptionResource2>,
+        options: Option<MethodSubscriptionIdTwoSubscriptionResourcesMethodLevelSubscriptionResource2OperationsClientPutOptions<'_>>,
+    ) -> Result<Response<SubscriptionResource2>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/providers/Azure.ResourceManager.MethodSubscriptionId/subscriptionResource2s/{subscriptionResource2Name}")?;
+        self.put(
+            path_params["subscriptionId"],
+            path_params["subscriptionResource2Name"],
+            resource,
+            options,
+        )
+        .await
+    }
 }
@@ -4,19 +4,17 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::{
-    compute::clients::CombinedVirtualMachinesClient,
-    storage::clients::CombinedStorageAccountsClient, Audience,
+    compute::clients::CombinedVirtualMachinesClient, generated::models::arm_cloud_endpoint,
+    storage::clients::CombinedStorageAccountsClient,
 };
 use azure_core::{
-    cloud::CloudConfiguration,
     credentials::TokenCredential,
-    error::ErrorKind,
     fmt::SafeDebug,
     http::{
         policies::{auth::BearerTokenAuthorizationPolicy, Policy},
-        ClientOptions, Pipeline, Url,
+        ClientMethodOptions, ClientOptions, Pipeline, RawResponse, Request, Url,
     },
-    tracing, Error, Result,
+    tracing, Result,
 };
 use std::sync::Arc;
 
@@ -56,33 +54,7 @@ impl CombinedClient {
         options: Option<CombinedClientOptions>,
     ) -> Result<Self> {
         let options = options.unwrap_or_default();
-        let (endpoint, scope) = match options.client_options.cloud.as_deref() {
-            Some(CloudConfiguration::AzurePublic) => (
-                "https://management.azure.com".to_string(),
-                "https://management.azure.com/.default".to_string(),
-            ),
-            Some(CloudConfiguration::AzureGovernment) => (
-                "https://management.usgovcloudapi.net".to_string(),
-                "https://management.usgovcloudapi.net/.default".to_string(),
-            ),
-            Some(CloudConfiguration::AzureChina) => (
-                "https://management.chinacloudapi.cn".to_string(),
-                "https://management.chinacloudapi.cn/.default".to_string(),
-            ),
-            Some(CloudConfiguration::Custom(custom)) => {
-                let audience = custom.audiences.get::<Audience>().ok_or_else(|| {
-                    Error::new(
-                        ErrorKind::Credential,
-                        "missing custom cloud configuration audience",
-                    )
-                })?;
-                (audience.to_string(), format!("{}/.default", audience))
-            }
-            _ => {
-                unreachable!()
-            }
-        };
-        let endpoint = Url::parse(&endpoint)?;
+        let (endpoint, scope) = arm_cloud_endpoint(options.client_options.cloud.as_deref())?;
         let auth_policy: Arc<dyn Policy> =
             Arc::new(BearerTokenAuthorizationPolicy::new(credential, vec![scope]));
         Ok(Self {
@@ -106,6 +78,30 @@ impl CombinedClient {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     /// Returns a new instance of CombinedStorageAccountsClient.
     #[tracing::subclient]
     pub fn get_combined_storage_accounts_client(&self) -> CombinedStorageAccountsClient {
@@ -8,5 +8,9 @@ mod enums_impl;
 mod enums_serde;
 #[allow(clippy::module_inception)]
 mod models;
+mod resource_id;
 pub use enums::*;
 pub use models::*;
+pub use resource_id::*;
+pub(crate) mod cloud;
+pub(crate) use cloud::*;
@@ -3,10 +3,13 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use crate::storage::generated::models::{
-    CombinedStorageAccountsClientBeginCreateOrUpdateOperationStatus,
-    CombinedStorageAccountsClientBeginCreateOrUpdateOptions,
-    CombinedStorageAccountsClientGetOptions, StorageAccount,
+use crate::{
+    generated::models::ResourceId,
+    storage::generated::models::{
+        CombinedStorageAccountsClientBeginCreateOrUpdateOperationStatus,
+        CombinedStorageAccountsClientBeginCreateOrUpdateOptions,
+        CombinedStorageAccountsClientGetOptions, StorageAccount,
+    },
 };
 use azure_core::{
     error::{CheckSuccessOptions, Error, ErrorKind},
@@ -16,8 +19,8 @@ use azure_core::{
             get_retry_after, PollerContinuation, PollerResult, PollerState, PollerStatus,
             StatusMonitor,
         },
-        Method, Pipeline, PipelineSendOptions, Poller, RawResponse, Request, RequestContent,
-        Response, StatusCode, Url, UrlExt,
+        ClientMethodOptions, Method, Pipeline, PipelineSendOptions, Poller, RawResponse, Request,
+        RequestContent, Response, StatusCode, Url, UrlExt,
     },
     json, tracing, Result,
 };
@@ -36,6 +39,30 @@ impl CombinedStorageAccountsClient {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     /// Creates or updates a storage account.
     ///
     /// # Arguments
@@ -228,16 +255,33 @@ impl CombinedStorageAccountsClient {
         ))
     }
 
-    /// Gets information about a storage account.
+    /// Calls [`CombinedStorageAccountsClient::begin_create_or_update()`](crate::storage::generated::clients::CombinedStorageAccountsClient::begin_create_or_update())
+    /// with its path parameters taken from a resource ID.
     ///
     /// # Arguments
     ///
-    /// * `resource_group_name` - The name of the resource group. The name is case insensitive.
-    /// * `account_name` - The name of the StorageAccount
-    /// * `options` - Optional parameters for the request.
-    #[tracing::function(
-        "Azure.ResourceManager.MultiServiceSharedModels.Storage.StorageAccounts.get"
-    )]
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.Storage/storageAccounts/{accountName}`.
+    pub fn begin_create_or_update_by_id(
+        &self,
+        resource_id: &ResourceId,
+        resource: RequestContent<StorageAccount>,
+        options: Option<CombinedStorageAccountsClientBeginCreateOrUpdateOptions<'_>>,
+    ) -> Result<Poller<CombinedStorageAccountsClientBeginCreateOrUpdateOperationStatus>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.Storage/storageAccounts/{accountName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.begin_create_or_update(
+            path_params["resourceGroupName"],
+            path_params["accountName"],
+            resource,
+            options,
+        )
+    }
+
     pub async fn get(
         &self,
         resource_group_name: &str,
@@ -284,4 +328,30 @@ impl CombinedStorageAccountsClient {
             .await?;
         Ok(rsp.into())
     }
+
+    /// Calls [`CombinedStorageAccountsClient::get()`](crate::storage::generated::clients::CombinedStorageAccountsClient::get())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.Storage/storageAccounts/{accountName}`.
+    pub async fn get_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<CombinedStorageAccountsClientGetOptions<'_>>,
+    ) -> Result<Response<StorageAccount>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.Storage/storageAccounts/{accountName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.get(
+            path_params["resourceGroupName"],
+            path_params["accountName"],
+            options,
+        )
+        .await
+    }
 }
@@ -3,9 +3,12 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
-use crate::compute_disk::generated::models::{
-    CombinedDisksClientBeginCreateOrUpdateOperationStatus,
-    CombinedDisksClientBeginCreateOrUpdateOptions, CombinedDisksClientGetOptions, Disk,
+use crate::{
+    compute_disk::generated::models::{
+        CombinedDisksClientBeginCreateOrUpdateOperationStatus,
+        CombinedDisksClientBeginCreateOrUpdateOptions, CombinedDisksClientGetOptions, Disk,
+    },
+    generated::models::ResourceId,
 };
 use azure_core::{
     error::{CheckSuccessOptions, Error, ErrorKind},
@@ -15,8 +18,8 @@ use azure_core::{
             get_retry_after, PollerContinuation, PollerResult, PollerState, PollerStatus,
             StatusMonitor,
         },
-        Method, Pipeline, PipelineSendOptions, Poller, RawResponse, Request, RequestContent,
-        Response, StatusCode, Url, UrlExt,
+        ClientMethodOptions, Method, Pipeline, PipelineSendOptions, Poller, RawResponse, Request,
+        RequestContent, Response, StatusCode, Url, UrlExt,
     },
     json, tracing, Result,
 };
@@ -35,6 +38,30 @@ impl CombinedDisksClient {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     /// Creates or updates a disk.
     ///
     /// # Arguments
@@ -225,6 +252,35 @@ impl CombinedDisksClient {
         ))
     }
 
+    /// Calls [`CombinedDisksClient::begin_create_or_update()`](crate::compute_disk::generated::clients::CombinedDisksClient::begin_create_or_update())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.Compute/disks/{diskName}`.
+    /// * `resource` - Resource create parameters.
+    /// * `options` - Optional parameters for the request.
+    pub fn begin_create_or_update_by_id(
+        &self,
+        resource_id: &ResourceId,
+        resource: RequestContent<Disk>,
+        options: Option<CombinedDisksClientBeginCreateOrUpdateOptions<'_>>,
+    ) -> Result<Poller<CombinedDisksClientBeginCreateOrUpdateOperationStatus>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.Compute/disks/{diskName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.begin_create_or_update(
+            path_params["resourceGroupName"],
+            path_params["diskName"],
+            resource,
+            options,
+        )
+    }
+
     /// Gets information about a disk.
     ///
     /// # Arguments
@@ -279,4 +335,31 @@ impl CombinedDisksClient {
             .await?;
         Ok(rsp.into())
     }
+
+    /// Calls [`CombinedDisksClient::get()`](crate::compute_disk::generated::clients::CombinedDisksClient::get()) with its path
+    /// parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.Compute/disks/{diskName}`.
+    /// * `options` - Optional parameters for the request.
+    pub async fn get_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<CombinedDisksClientGetOptions<'_>>,
+    ) -> Result<Response<Disk>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Microsoft.Compute/disks/{diskName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.get(
+            path_params["resourceGroupName"],
+            path_params["diskName"],
+            options,
+        )
+        .await
+    }
 }
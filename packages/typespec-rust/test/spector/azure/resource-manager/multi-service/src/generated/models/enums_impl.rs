@@ -26,10 +26,10 @@ impl FromStr for CreatedByType {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "Application" => CreatedByType::Application,
-            "Key" => CreatedByType::Key,
-            "ManagedIdentity" => CreatedByType::ManagedIdentity,
-            "User" => CreatedByType::User,
+            _ if s.eq_ignore_ascii_case("Application") => CreatedByType::Application,
+            _ if s.eq_ignore_ascii_case("Key") => CreatedByType::Key,
+            _ if s.eq_ignore_ascii_case("ManagedIdentity") => CreatedByType::ManagedIdentity,
+            _ if s.eq_ignore_ascii_case("User") => CreatedByType::User,
             _ => CreatedByType::UnknownValue(s.to_string()),
         })
     }
@@ -47,6 +47,12 @@ impl AsRef<str> for CreatedByType {
     }
 }
 
+impl PartialEq<str> for CreatedByType {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for CreatedByType {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -74,9 +80,9 @@ impl FromStr for ResourceProvisioningState {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "Canceled" => ResourceProvisioningState::Canceled,
-            "Failed" => ResourceProvisioningState::Failed,
-            "Succeeded" => ResourceProvisioningState::Succeeded,
+            _ if s.eq_ignore_ascii_case("Canceled") => ResourceProvisioningState::Canceled,
+            _ if s.eq_ignore_ascii_case("Failed") => ResourceProvisioningState::Failed,
+            _ if s.eq_ignore_ascii_case("Succeeded") => ResourceProvisioningState::Succeeded,
             _ => ResourceProvisioningState::UnknownValue(s.to_string()),
         })
     }
@@ -93,6 +99,12 @@ impl AsRef<str> for ResourceProvisioningState {
     }
 }
 
+impl PartialEq<str> for ResourceProvisioningState {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for ResourceProvisioningState {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -5,11 +5,14 @@
 
 use crate::generated::models::{
     NonResource, NonResourceNonResourceOperationsClientCreateOptions,
-    NonResourceNonResourceOperationsClientGetOptions,
+    NonResourceNonResourceOperationsClientGetOptions, ResourceId,
 };
 use azure_core::{
     error::CheckSuccessOptions,
-    http::{Method, Pipeline, PipelineSendOptions, Request, RequestContent, Response, Url, UrlExt},
+    http::{
+        ClientMethodOptions, Method, Pipeline, PipelineSendOptions, RawResponse, Request,
+        RequestContent, Response, Url, UrlExt,
+    },
     tracing, Result,
 };
 
@@ -28,6 +31,30 @@ impl NonResourceNonResourceOperationsClient {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     ///
     /// # Arguments
     ///
@@ -86,6 +113,36 @@ impl NonResourceNonResourceOperationsClient {
         Ok(rsp.into())
     }
 
+    /// Calls [`NonResourceNonResourceOperationsClient::create()`](crate::generated::clients::NonResourceNonResourceOperationsClient::create())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/providers/Microsoft.NonResource/locations/{location}/otherParameters/{parameter}`.
+    /// * `body` - The request body.
+    /// * `options` - Optional parameters for the request.
+    pub async fn create_by_id(
+        &self,
+        resource_id: &ResourceId,
+        body: RequestContent<NonResource>,
+        options: Option<NonResourceNonResourceOperationsClientCreateOptions<'_>>,
+    ) -> Result<Response<NonResource>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/providers/Microsoft.NonResource/locations/{location}/otherParameters/{parameter}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.create(
+            path_params["location"],
+            path_params["parameter"],
+            body,
+            options,
+        )
+        .await
+    }
+
     ///
     /// # Arguments
     ///
@@ -139,4 +196,27 @@ impl NonResourceNonResourceOperationsClient {
             .await?;
         Ok(rsp.into())
     }
+
+    /// Calls [`NonResourceNonResourceOperationsClient::get()`](crate::generated::clients::NonResourceNonResourceOperationsClient::get())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/providers/Microsoft.NonResource/locations/{location}/otherParameters/{parameter}`.
+    /// * `options` - Optional parameters for the request.
+    pub async fn get_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<NonResourceNonResourceOperationsClientGetOptions<'_>>,
+    ) -> Result<Response<NonResource>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/providers/Microsoft.NonResource/locations/{location}/otherParameters/{parameter}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.get(path_params["location"], path_params["parameter"], options)
+            .await
+    }
 }
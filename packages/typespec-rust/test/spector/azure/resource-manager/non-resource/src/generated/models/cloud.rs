@@ -0,0 +1,44 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::Audience;
+use azure_core::{
+    cloud::CloudConfiguration,
+    error::{Error, ErrorKind},
+    http::Url,
+    Result,
+};
+
+/// Returns the Azure Resource Manager endpoint for the specified cloud and the scope for its access tokens.
+///
+/// Returns an error if no cloud is specified or the cloud isn't supported.
+pub(crate) fn arm_cloud_endpoint(cloud: Option<&CloudConfiguration>) -> Result<(Url, String)> {
+    let audience: &str = match cloud {
+        Some(CloudConfiguration::AzurePublic) => "https://management.azure.com",
+        Some(CloudConfiguration::AzureGovernment) => "https://management.usgovcloudapi.net",
+        Some(CloudConfiguration::AzureChina) => "https://management.chinacloudapi.cn",
+        Some(CloudConfiguration::Custom(custom)) => {
+            custom.audiences.get::<Audience>().ok_or_else(|| {
+                Error::new(
+                    ErrorKind::Credential,
+                    "missing custom cloud configuration audience",
+                )
+            })?
+        }
+        Some(_) => {
+            return Err(Error::with_message(
+                ErrorKind::Other,
+                "the cloud configuration isn't supported by this client",
+            ));
+        }
+        None => {
+            return Err(Error::with_message(
+                ErrorKind::Other,
+                "no cloud configuration was specified in the client options",
+            ));
+        }
+    };
+    Ok((Url::parse(audience)?, format!("{audience}/.default")))
+}
@@ -7,5 +7,9 @@ mod method_options;
 #[allow(clippy::module_inception)]
 mod models;
 mod models_impl;
+mod resource_id;
 pub use method_options::*;
 pub use models::*;
+pub use resource_id::*;
+pub(crate) mod cloud;
+pub(crate) use cloud::*;
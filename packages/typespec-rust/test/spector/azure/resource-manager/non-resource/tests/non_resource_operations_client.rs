@@ -59,6 +59,22 @@ fn create_client() -> NonResourceClient {
     .unwrap()
 }
 
+#[test]
+fn new_without_cloud() {
+    let result = NonResourceClient::new(
+        "00000000-0000-0000-0000-000000000000".to_string(),
+        Arc::new(FakeTokenCredential::new("fake_token".to_string())),
+        Some(NonResourceClientOptions {
+            client_options: ClientOptions {
+                cloud: None,
+                ..Default::default()
+            },
+            ..Default::default()
+        }),
+    );
+    assert!(result.is_err());
+}
+
 #[tokio::test]
 async fn create() {
     let client = create_client();
@@ -6,11 +6,15 @@
 use crate::generated::models::{
     CheckNameAvailabilityRequest, CheckNameAvailabilityResponse,
     OperationTemplatesCheckNameAvailabilityClientCheckGlobalOptions,
-    OperationTemplatesCheckNameAvailabilityClientCheckLocalOptions,
+    OperationTemplatesCheckNameAvailabilityClientCheckLocalOptions, ResourceId,
 };
+use async_trait::async_trait;
 use azure_core::{
     error::CheckSuccessOptions,
-    http::{Method, Pipeline, PipelineSendOptions, Request, RequestContent, Response, Url, UrlExt},
+    http::{
+        ClientMethodOptions, Method, Pipeline, PipelineSendOptions, RawResponse, Request,
+        RequestContent, Response, Url, UrlExt,
+    },
     tracing, Result,
 };
 
@@ -28,6 +32,30 @@ impl OperationTemplatesCheckNameAvailabilityClient {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     /// Implements global CheckNameAvailability operations
     ///
     /// # Arguments
@@ -71,16 +99,6 @@ impl OperationTemplatesCheckNameAvailabilityClient {
         Ok(rsp.into())
     }
 
-    /// Implements local CheckNameAvailability operations
-    ///
-    /// # Arguments
-    ///
-    /// * `location` - The name of the Azure region.
-    /// * `body` - The CheckAvailability request
-    /// * `options` - Optional parameters for the request.
-    #[tracing::function(
-        "Azure.ResourceManager.OperationTemplates.CheckNameAvailability.checkLocal"
-    )]
     pub async fn check_local(
         &self,
         location: &str,
@@ -122,4 +140,118 @@ impl OperationTemplatesCheckNameAvailabilityClient {
             .await?;
         Ok(rsp.into())
     }
+
+    /// Calls [`OperationTemplatesCheckNameAvailabilityClient::check_local()`](crate::generated::clients::OperationTemplatesCheckNameAvailabilityClient::check_local())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/providers/Azure.ResourceManager.OperationTemplates/locations/{location}/checkNameAvailability`.
+    pub async fn check_local_by_id(
+        &self,
+        resource_id: &ResourceId,
+        body: RequestContent<CheckNameAvailabilityRequest>,
+        options: Option<OperationTemplatesCheckNameAvailabilityClientCheckLocalOptions<'_>>,
+    ) -> Result<Response<CheckNameAvailabilityResponse>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/providers/Azure.ResourceManager.OperationTemplates/locations/{location}/checkNameAvailability")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.check_local(path_params["location"], body, options)
+            .await
+    }
+}
+
+/// The methods of [`OperationTemplatesCheckNameAvailabilityClient`] as a trait so that consumers can substitute a fake.
+///
+/// An in-memory implementation, `MockOperationTemplatesCheckNameAvailabilityClient`, is available with the `mock` feature.
+#[async_trait]
+pub trait OperationTemplatesCheckNameAvailabilityClientMethods: Send + Sync {
+    /// Implements global CheckNameAvailability operations
+    async fn check_global(
+        &self,
+        body: RequestContent<CheckNameAvailabilityRequest>,
+        options: Option<OperationTemplatesCheckNameAvailabilityClientCheckGlobalOptions<'_>>,
+    ) -> Result<Response<CheckNameAvailabilityResponse>>;
+
+    async fn check_local(
+        &self,
+        location: &str,
+        body: RequestContent<CheckNameAvailabilityRequest>,
+        options: Option<OperationTemplatesCheckNameAvailabilityClientCheckLocalOptions<'_>>,
+    ) -> Result<Response<CheckNameAvailabilityResponse>>;
+}
+
+#[async_trait]
+impl OperationTemplatesCheckNameAvailabilityClientMethods
+    for OperationTemplatesCheckNameAvailabilityClient
+{
+    async fn check_global(
+        &self,
+        body: RequestContent<CheckNameAvailabilityRequest>,
+        options: Option<OperationTemplatesCheckNameAvailabilityClientCheckGlobalOptions<'_>>,
+    ) -> Result<Response<CheckNameAvailabilityResponse>> {
+        OperationTemplatesCheckNameAvailabilityClient::check_global(self, body, options).await
+    }
+
+    async fn check_local(
+        &self,
+        location: &str,
+        body: RequestContent<CheckNameAvailabilityRequest>,
+        options: Option<OperationTemplatesCheckNameAvailabilityClientCheckLocalOptions<'_>>,
+    ) -> Result<Response<CheckNameAvailabilityResponse>> {
+        OperationTemplatesCheckNameAvailabilityClient::check_local(self, location, body, options)
+            .await
+    }
+}
+
+/// An in-memory implementation of [`OperationTemplatesCheckNameAvailabilityClientMethods`] that returns canned responses.
+///
+/// Each method returns the responses queued for it in order, and an error once none remain.
+#[cfg(feature = "mock")]
+#[derive(Default)]
+pub struct MockOperationTemplatesCheckNameAvailabilityClient {
+    check_global:
+        std::sync::Mutex<std::collections::VecDeque<Response<CheckNameAvailabilityResponse>>>,
+    check_local:
+        std::sync::Mutex<std::collections::VecDeque<Response<CheckNameAvailabilityResponse>>>,
+}
+
+#[cfg(feature = "mock")]
+impl MockOperationTemplatesCheckNameAvailabilityClient {
+    /// Queues a response for [`OperationTemplatesCheckNameAvailabilityClientMethods::check_global()`].
+    pub fn push_check_global(&self, response: Response<CheckNameAvailabilityResponse>) {
+        self.check_global.lock().unwrap().push_back(response);
+    }
+
+    /// Queues a response for [`OperationTemplatesCheckNameAvailabilityClientMethods::check_local()`].
+    pub fn push_check_local(&self, response: Response<CheckNameAvailabilityResponse>) {
+        self.check_local.lock().unwrap().push_back(response);
+    }
+}
+
+#[cfg(feature = "mock")]
+#[async_trait]
+impl OperationTemplatesCheckNameAvailabilityClientMethods
+    for MockOperationTemplatesCheckNameAvailabilityClient
+{
+    async fn check_global(
+        &self,
+        _body: RequestContent<CheckNameAvailabilityRequest>,
+        _options: Option<OperationTemplatesCheckNameAvailabilityClientCheckGlobalOptions<'_>>,
+    ) -> Result<Response<CheckNameAvailabilityResponse>> {
+        self.check_global.lock().unwrap().pop_front().ok_or_else(|| azure_core::Error::with_message(azure_core::error::ErrorKind::Other, "no response is queued for MockOperationTemplatesCheckNameAvailabilityClient::check_global()"))
+    }
+
+    async fn check_local(
+        &self,
+        _location: &str,
+        _body: RequestContent<CheckNameAvailabilityRequest>,
+        _options: Option<OperationTemplatesCheckNameAvailabilityClientCheckLocalOptions<'_>>,
+    ) -> Result<Response<CheckNameAvailabilityResponse>> {
+        self.check_local.lock().unwrap().pop_front().ok_or_else(|| azure_core::Error::with_message(azure_core::error::ErrorKind::Other, "no response is queued for MockOperationTemplatesCheckNameAvailabilityClient::check_local()"))
+    }
 }
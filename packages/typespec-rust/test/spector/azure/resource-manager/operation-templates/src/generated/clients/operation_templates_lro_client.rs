@@ -11,8 +11,9 @@ use crate::generated::models::{
     OperationTemplatesLroClientBeginExportArrayOperationStatus,
     OperationTemplatesLroClientBeginExportArrayOptions,
     OperationTemplatesLroClientBeginExportOperationStatus,
-    OperationTemplatesLroClientBeginExportOptions, Order,
+    OperationTemplatesLroClientBeginExportOptions, Order, ResourceId,
 };
+use async_trait::async_trait;
 use azure_core::{
     error::{CheckSuccessOptions, Error, ErrorKind},
     http::{
@@ -21,8 +22,8 @@ use azure_core::{
             get_retry_after, PollerContinuation, PollerResult, PollerState, PollerStatus,
             StatusMonitor,
         },
-        Method, Pipeline, PipelineSendOptions, Poller, RawResponse, Request, RequestContent,
-        StatusCode, Url, UrlExt,
+        ClientMethodOptions, Method, Pipeline, PipelineSendOptions, Poller, RawResponse, Request,
+        RequestContent, StatusCode, Url, UrlExt,
     },
     json, tracing, Result,
 };
@@ -41,6 +42,30 @@ impl OperationTemplatesLroClient {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     /// Create a Order
     ///
     /// # Arguments
@@ -231,6 +256,35 @@ impl OperationTemplatesLroClient {
         ))
     }
 
+    /// Calls [`OperationTemplatesLroClient::begin_create_or_replace()`](crate::generated::clients::OperationTemplatesLroClient::begin_create_or_replace())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.OperationTemplates/orders/{orderName}`.
+    /// * `resource` - Resource create parameters.
+    /// * `options` - Optional parameters for the request.
+    pub fn begin_create_or_replace_by_id(
+        &self,
+        resource_id: &ResourceId,
+        resource: RequestContent<Order>,
+        options: Option<OperationTemplatesLroClientBeginCreateOrReplaceOptions<'_>>,
+    ) -> Result<Poller<OperationTemplatesLroClientBeginCreateOrReplaceOperationStatus>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.OperationTemplates/orders/{orderName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.begin_create_or_replace(
+            path_params["resourceGroupName"],
+            path_params["orderName"],
+            resource,
+            options,
+        )
+    }
+
     /// Delete a Order
     ///
     /// # Arguments
@@ -405,6 +459,32 @@ impl OperationTemplatesLroClient {
         ))
     }
 
+    /// Calls [`OperationTemplatesLroClient::begin_delete()`](crate::generated::clients::OperationTemplatesLroClient::begin_delete())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.OperationTemplates/orders/{orderName}`.
+    /// * `options` - Optional parameters for the request.
+    pub fn begin_delete_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<OperationTemplatesLroClientBeginDeleteOptions<'_>>,
+    ) -> Result<Poller<OperationTemplatesLroClientBeginDeleteOperationStatus>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.OperationTemplates/orders/{orderName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.begin_delete(
+            path_params["resourceGroupName"],
+            path_params["orderName"],
+            options,
+        )
+    }
+
     ///
     /// # Arguments
     ///
@@ -790,4 +870,243 @@ impl OperationTemplatesLroClient {
             Some(options.method_options),
         ))
     }
+
+    /// Calls [`OperationTemplatesLroClient::begin_export()`](crate::generated::clients::OperationTemplatesLroClient::begin_export())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.OperationTemplates/orders/{orderName}/export`.
+    /// * `body` - The content of the action request
+    /// * `options` - Optional parameters for the request.
+    pub fn begin_export_by_id(
+        &self,
+        resource_id: &ResourceId,
+        body: RequestContent<ExportRequest>,
+        options: Option<OperationTemplatesLroClientBeginExportOptions<'_>>,
+    ) -> Result<Poller<OperationTemplatesLroClientBeginExportOperationStatus>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.OperationTemplates/orders/{orderName}/export")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.begin_export(
+            path_params["resourceGroupName"],
+            path_params["orderName"],
+            body,
+            options,
+        )
+    }
+}
+
+/// The methods of [`OperationTemplatesLroClient`] as a trait so that consumers can substitute a fake.
+///
+/// An in-memory implementation, `MockOperationTemplatesLroClient`, is available with the `mock` feature.
+#[async_trait]
+pub trait OperationTemplatesLroClientMethods: Send + Sync {
+    /// Create a Order
+    fn begin_create_or_replace(
+        &self,
+        resource_group_name: &str,
+        order_name: &str,
+        resource: RequestContent<Order>,
+        options: Option<OperationTemplatesLroClientBeginCreateOrReplaceOptions<'_>>,
+    ) -> Result<Poller<OperationTemplatesLroClientBeginCreateOrReplaceOperationStatus>>;
+
+    /// Delete a Order
+    fn begin_delete(
+        &self,
+        resource_group_name: &str,
+        order_name: &str,
+        options: Option<OperationTemplatesLroClientBeginDeleteOptions<'_>>,
+    ) -> Result<Poller<OperationTemplatesLroClientBeginDeleteOperationStatus>>;
+
+    fn begin_export(
+        &self,
+        resource_group_name: &str,
+        order_name: &str,
+        body: RequestContent<ExportRequest>,
+        options: Option<OperationTemplatesLroClientBeginExportOptions<'_>>,
+    ) -> Result<Poller<OperationTemplatesLroClientBeginExportOperationStatus>>;
+
+    fn begin_export_array(
+        &self,
+        body: RequestContent<ExportRequest>,
+        options: Option<OperationTemplatesLroClientBeginExportArrayOptions<'_>>,
+    ) -> Result<Poller<OperationTemplatesLroClientBeginExportArrayOperationStatus>>;
+}
+
+#[async_trait]
+impl OperationTemplatesLroClientMethods for OperationTemplatesLroClient {
+    fn begin_create_or_replace(
+        &self,
+        resource_group_name: &str,
+        order_name: &str,
+        resource: RequestContent<Order>,
+        options: Option<OperationTemplatesLroClientBeginCreateOrReplaceOptions<'_>>,
+    ) -> Result<Poller<OperationTemplatesLroClientBeginCreateOrReplaceOperationStatus>> {
+        OperationTemplatesLroClient::begin_create_or_replace(
+            self,
+            resource_group_name,
+            order_name,
+            resource,
+            options,
+        )
+    }
+
+    fn begin_delete(
+        &self,
+        resource_group_name: &str,
+        order_name: &str,
+        options: Option<OperationTemplatesLroClientBeginDeleteOptions<'_>>,
+    ) -> Result<Poller<OperationTemplatesLroClientBeginDeleteOperationStatus>> {
+        OperationTemplatesLroClient::begin_delete(self, resource_group_name, order_name, options)
+    }
+
+    fn begin_export(
+        &self,
+        resource_group_name: &str,
+        order_name: &str,
+        body: RequestContent<ExportRequest>,
+        options: Option<OperationTemplatesLroClientBeginExportOptions<'_>>,
+    ) -> Result<Poller<OperationTemplatesLroClientBeginExportOperationStatus>> {
+        OperationTemplatesLroClient::begin_export(
+            self,
+            resource_group_name,
+            order_name,
+            body,
+            options,
+        )
+    }
+
+    fn begin_export_array(
+        &self,
+        body: RequestContent<ExportRequest>,
+        options: Option<OperationTemplatesLroClientBeginExportArrayOptions<'_>>,
+    ) -> Result<Poller<OperationTemplatesLroClientBeginExportArrayOperationStatus>> {
+        OperationTemplatesLroClient::begin_export_array(self, body, options)
+    }
+}
+
+/// An in-memory implementation of [`OperationTemplatesLroClientMethods`] that returns canned responses.
+///
+/// Each method returns the responses queued for it in order, and an error once none remain.
+#[cfg(feature = "mock")]
+#[derive(Default)]
+pub struct MockOperationTemplatesLroClient {
+    begin_create_or_replace: std::sync::Mutex<
+        std::collections::VecDeque<
+            Poller<OperationTemplatesLroClientBeginCreateOrReplaceOperationStatus>,
+        >,
+    >,
+    begin_delete: std::sync::Mutex<
+        std::collections::VecDeque<Poller<OperationTemplatesLroClientBeginDeleteOperationStatus>>,
+    >,
+    begin_export: std::sync::Mutex<
+        std::collections::VecDeque<Poller<OperationTemplatesLroClientBeginExportOperationStatus>>,
+    >,
+    begin_export_array: std::sync::Mutex<
+        std::collections::VecDeque<
+            Poller<OperationTemplatesLroClientBeginExportArrayOperationStatus>,
+        >,
+    >,
+}
+
+#[cfg(feature = "mock")]
+impl MockOperationTemplatesLroClient {
+    /// Queues a response for [`OperationTemplatesLroClientMethods::begin_create_or_replace()`].
+    pub fn push_begin_create_or_replace(
+        &self,
+        response: Poller<OperationTemplatesLroClientBeginCreateOrReplaceOperationStatus>,
+    ) {
+        self.begin_create_or_replace
+            .lock()
+            .unwrap()
+            .push_back(response);
+    }
+
+    /// Queues a response for [`OperationTemplatesLroClientMethods::begin_delete()`].
+    pub fn push_begin_delete(
+        &self,
+        response: Poller<OperationTemplatesLroClientBeginDeleteOperationStatus>,
+    ) {
+        self.begin_delete.lock().unwrap().push_back(response);
+    }
+
+    /// Queues a response for [`OperationTemplatesLroClientMethods::begin_export()`].
+    pub fn push_begin_export(
+        &self,
+        response: Poller<OperationTemplatesLroClientBeginExportOperationStatus>,
+    ) {
+        self.begin_export.lock().unwrap().push_back(response);
+    }
+
+    /// Queues a response for [`OperationTemplatesLroClientMethods::begin_export_array()`].
+    pub fn push_begin_export_array(
+        &self,
+        response: Poller<OperationTemplatesLroClientBeginExportArrayOperationStatus>,
+    ) {
+        self.begin_export_array.lock().unwrap().push_back(response);
+    }
+}
+
+#[cfg(feature = "mock")]
+#[async_trait]
+impl OperationTemplatesLroClientMethods for MockOperationTemplatesLroClient {
+    fn begin_create_or_replace(
+        &self,
+        _resource_group_name: &str,
+        _order_name: &str,
+        _resource: RequestContent<Order>,
+        _options: Option<OperationTemplatesLroClientBeginCreateOrReplaceOptions<'_>>,
+    ) -> Result<Poller<OperationTemplatesLroClientBeginCreateOrReplaceOperationStatus>> {
+        self.begin_create_or_replace.lock().unwrap().pop_front().ok_or_else(|| azure_core::Error::with_message(azure_core::error::ErrorKind::Other, "no response is queued for MockOperationTemplatesLroClient::begin_create_or_replace()"))
+    }
+
+    fn begin_delete(
+        &self,
+        _resource_group_name: &str,
+        _order_name: &str,
+        _options: Option<OperationTemplatesLroClientBeginDeleteOptions<'_>>,
+    ) -> Result<Poller<OperationTemplatesLroClientBeginDeleteOperationStatus>> {
+        self.begin_delete
+            .lock()
+            .unwrap()
+            .pop_front()
+            .ok_or_else(|| {
+                azure_core::Error::with_message(
+                    azure_core::error::ErrorKind::Other,
+                    "no response is queued for MockOperationTemplatesLroClient::begin_delete()",
+                )
+            })
+    }
+
+    fn begin_export(
+        &self,
+        _resource_group_name: &str,
+        _order_name: &str,
+        _body: RequestContent<ExportRequest>,
+        _options: Option<OperationTemplatesLroClientBeginExportOptions<'_>>,
+    ) -> Result<Poller<OperationTemplatesLroClientBeginExportOperationStatus>> {
+        self.begin_export
+            .lock()
+            .unwrap()
+            .pop_front()
+            .ok_or_else(|| {
+                azure_core::Error::with_message(
+                    azure_core::error::ErrorKind::Other,
+                    "no response is queued for MockOperationTemplatesLroClient::begin_export()",
+                )
+            })
+    }
+
+    fn begin_export_array(
+        &self,
+        _body: RequestContent<ExportRequest>,
+        _options: Option<OperationTemplatesLroClientBeginExportArrayOptions<'_>>,
+    ) -> Result<Poller<OperationTemplatesLroClientBeginExportArrayOperationStatus>> {
+        self.begin_export_array.lock().unwrap().pop_front().ok_or_else(|| azure_core::Error::with_message(azure_core::error::ErrorKind::Other, "no response is queued for MockOperationTemplatesLroClient::begin_export_array()"))
+    }
 }
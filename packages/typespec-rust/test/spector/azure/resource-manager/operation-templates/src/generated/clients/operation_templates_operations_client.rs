@@ -6,11 +6,13 @@
 use crate::generated::models::{
     OperationListResult, OperationTemplatesOperationsClientListOptions,
 };
+use async_trait::async_trait;
 use azure_core::{
     error::CheckSuccessOptions,
     http::{
         pager::{PagerContinuation, PagerResult, PagerState},
-        Method, Pager, Pipeline, PipelineSendOptions, RawResponse, Request, Url, UrlExt,
+        ClientMethodOptions, Method, Pager, Pipeline, PipelineSendOptions, RawResponse, Request,
+        Url, UrlExt,
     },
     json, tracing, Result,
 };
@@ -28,6 +30,30 @@ impl OperationTemplatesOperationsClient {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     /// List the operations for the provider
     ///
     /// # Arguments
@@ -102,3 +128,58 @@ impl OperationTemplatesOperationsClient {
         ))
     }
 }
+
+/// The methods of [`OperationTemplatesOperationsClient`] as a trait so that consumers can substitute a fake.
+///
+/// An in-memory implementation, `MockOperationTemplatesOperationsClient`, is available with the `mock` feature.
+#[async_trait]
+pub trait OperationTemplatesOperationsClientMethods: Send + Sync {
+    /// List the operations for the provider
+    fn list(
+        &self,
+        options: Option<OperationTemplatesOperationsClientListOptions<'_>>,
+    ) -> Result<Pager<OperationListResult>>;
+}
+
+#[async_trait]
+impl OperationTemplatesOperationsClientMethods for OperationTemplatesOperationsClient {
+    fn list(
+        &self,
+        options: Option<OperationTemplatesOperationsClientListOptions<'_>>,
+    ) -> Result<Pager<OperationListResult>> {
+        OperationTemplatesOperationsClient::list(self, options)
+    }
+}
+
+/// An in-memory implementation of [`OperationTemplatesOperationsClientMethods`] that returns canned responses.
+///
+/// Each method returns the responses queued for it in order, and an error once none remain.
+#[cfg(feature = "mock")]
+#[derive(Default)]
+pub struct MockOperationTemplatesOperationsClient {
+    list: std::sync::Mutex<std::collections::VecDeque<Pager<OperationListResult>>>,
+}
+
+#[cfg(feature = "mock")]
+impl MockOperationTemplatesOperationsClient {
+    /// Queues a response for [`OperationTemplatesOperationsClientMethods::list()`].
+    pub fn push_list(&self, response: Pager<OperationListResult>) {
+        self.list.lock().unwrap().push_back(response);
+    }
+}
+
+#[cfg(feature = "mock")]
+#[async_trait]
+impl OperationTemplatesOperationsClientMethods for MockOperationTemplatesOperationsClient {
+    fn list(
+        &self,
+        _options: Option<OperationTemplatesOperationsClientListOptions<'_>>,
+    ) -> Result<Pager<OperationListResult>> {
+        self.list.lock().unwrap().pop_front().ok_or_else(|| {
+            azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "no response is queued for MockOperationTemplatesOperationsClient::list()",
+            )
+        })
+    }
+}
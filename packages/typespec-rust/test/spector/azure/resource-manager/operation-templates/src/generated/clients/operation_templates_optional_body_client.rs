@@ -7,11 +7,15 @@ use crate::generated::models::{
     ActionResult, ChangeAllowanceResult, OperationTemplatesOptionalBodyClientGetOptions,
     OperationTemplatesOptionalBodyClientPatchOptions,
     OperationTemplatesOptionalBodyClientPostOptions,
-    OperationTemplatesOptionalBodyClientProviderPostOptions, Widget,
+    OperationTemplatesOptionalBodyClientProviderPostOptions, ResourceId, Widget,
 };
+use async_trait::async_trait;
 use azure_core::{
     error::CheckSuccessOptions,
-    http::{Method, Pipeline, PipelineSendOptions, Request, Response, Url, UrlExt},
+    http::{
+        ClientMethodOptions, Method, Pipeline, PipelineSendOptions, RawResponse, Request, Response,
+        Url, UrlExt,
+    },
     tracing, Result,
 };
 
@@ -29,6 +33,30 @@ impl OperationTemplatesOptionalBodyClient {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     /// Get a Widget
     ///
     /// # Arguments
@@ -84,6 +112,33 @@ impl OperationTemplatesOptionalBodyClient {
         Ok(rsp.into())
     }
 
+    /// Calls [`OperationTemplatesOptionalBodyClient::get()`](crate::generated::clients::OperationTemplatesOptionalBodyClient::get())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.OperationTemplates/widgets/{widgetName}`.
+    /// * `options` - Optional parameters for the request.
+    pub async fn get_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<OperationTemplatesOptionalBodyClientGetOptions<'_>>,
+    ) -> Result<Response<Widget>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.OperationTemplates/widgets/{widgetName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.get(
+            path_params["resourceGroupName"],
+            path_params["widgetName"],
+            options,
+        )
+        .await
+    }
+
     /// Update a Widget
     ///
     /// # Arguments
@@ -143,6 +198,33 @@ impl OperationTemplatesOptionalBodyClient {
         Ok(rsp.into())
     }
 
+    /// Calls [`OperationTemplatesOptionalBodyClient::patch()`](crate::generated::clients::OperationTemplatesOptionalBodyClient::patch())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.OperationTemplates/widgets/{widgetName}`.
+    /// * `options` - Optional parameters for the request.
+    pub async fn patch_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<OperationTemplatesOptionalBodyClientPatchOptions<'_>>,
+    ) -> Result<Response<Widget>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.OperationTemplates/widgets/{widgetName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.patch(
+            path_params["resourceGroupName"],
+            path_params["widgetName"],
+            options,
+        )
+        .await
+    }
+
     /// A synchronous resource action.
     ///
     /// # Arguments
@@ -202,6 +284,33 @@ impl OperationTemplatesOptionalBodyClient {
         Ok(rsp.into())
     }
 
+    /// Calls [`OperationTemplatesOptionalBodyClient::post()`](crate::generated::clients::OperationTemplatesOptionalBodyClient::post())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.OperationTemplates/widgets/{widgetName}/post`.
+    /// * `options` - Optional parameters for the request.
+    pub async fn post_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<OperationTemplatesOptionalBodyClientPostOptions<'_>>,
+    ) -> Result<Response<ActionResult>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.OperationTemplates/widgets/{widgetName}/post")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.post(
+            path_params["resourceGroupName"],
+            path_params["widgetName"],
+            options,
+        )
+        .await
+    }
+
     ///
     /// # Arguments
     ///
@@ -242,3 +351,166 @@ impl OperationTemplatesOptionalBodyClient {
         Ok(rsp.into())
     }
 }
+
+/// The methods of [`OperationTemplatesOptionalBodyClient`] as a trait so that consumers can substitute a fake.
+///
+/// An in-memory implementation, `MockOperationTemplatesOptionalBodyClient`, is available with the `mock` feature.
+#[async_trait]
+pub trait OperationTemplatesOptionalBodyClientMethods: Send + Sync {
+    /// Get a Widget
+    async fn get(
+        &self,
+        resource_group_name: &str,
+        widget_name: &str,
+        options: Option<OperationTemplatesOptionalBodyClientGetOptions<'_>>,
+    ) -> Result<Response<Widget>>;
+
+    /// Update a Widget
+    async fn patch(
+        &self,
+        resource_group_name: &str,
+        widget_name: &str,
+        options: Option<OperationTemplatesOptionalBodyClientPatchOptions<'_>>,
+    ) -> Result<Response<Widget>>;
+
+    /// A synchronous resource action.
+    async fn post(
+        &self,
+        resource_group_name: &str,
+        widget_name: &str,
+        options: Option<OperationTemplatesOptionalBodyClientPostOptions<'_>>,
+    ) -> Result<Response<ActionResult>>;
+
+    async fn provider_post(
+        &self,
+        options: Option<OperationTemplatesOptionalBodyClientProviderPostOptions<'_>>,
+    ) -> Result<Response<ChangeAllowanceResult>>;
+}
+
+#[async_trait]
+impl OperationTemplatesOptionalBodyClientMethods for OperationTemplatesOptionalBodyClient {
+    async fn get(
+        &self,
+        resource_group_name: &str,
+        widget_name: &str,
+        options: Option<OperationTemplatesOptionalBodyClientGetOptions<'_>>,
+    ) -> Result<Response<Widget>> {
+        OperationTemplatesOptionalBodyClient::get(self, resource_group_name, widget_name, options)
+            .await
+    }
+
+    async fn patch(
+        &self,
+        resource_group_name: &str,
+        widget_name: &str,
+        options: Option<OperationTemplatesOptionalBodyClientPatchOptions<'_>>,
+    ) -> Result<Response<Widget>> {
+        OperationTemplatesOptionalBodyClient::patch(self, resource_group_name, widget_name, options)
+            .await
+    }
+
+    async fn post(
+        &self,
+        resource_group_name: &str,
+        widget_name: &str,
+        options: Option<OperationTemplatesOptionalBodyClientPostOptions<'_>>,
+    ) -> Result<Response<ActionResult>> {
+        OperationTemplatesOptionalBodyClient::post(self, resource_group_name, widget_name, options)
+            .await
+    }
+
+    async fn provider_post(
+        &self,
+        options: Option<OperationTemplatesOptionalBodyClientProviderPostOptions<'_>>,
+    ) -> Result<Response<ChangeAllowanceResult>> {
+        OperationTemplatesOptionalBodyClient::provider_post(self, options).await
+    }
+}
+
+/// An in-memory implementation of [`OperationTemplatesOptionalBodyClientMethods`] that returns canned responses.
+///
+/// Each method returns the responses queued for it in order, and an error once none remain.
+#[cfg(feature = "mock")]
+#[derive(Default)]
+pub struct MockOperationTemplatesOptionalBodyClient {
+    get: std::sync::Mutex<std::collections::VecDeque<Response<Widget>>>,
+    patch: std::sync::Mutex<std::collections::VecDeque<Response<Widget>>>,
+    post: std::sync::Mutex<std::collections::VecDeque<Response<ActionResult>>>,
+    provider_post: std::sync::Mutex<std::collections::VecDeque<Response<ChangeAllowanceResult>>>,
+}
+
+#[cfg(feature = "mock")]
+impl MockOperationTemplatesOptionalBodyClient {
+    /// Queues a response for [`OperationTemplatesOptionalBodyClientMethods::get()`].
+    pub fn push_get(&self, response: Response<Widget>) {
+        self.get.lock().unwrap().push_back(response);
+    }
+
+    /// Queues a response for [`OperationTemplatesOptionalBodyClientMethods::patch()`].
+    pub fn push_patch(&self, response: Response<Widget>) {
+        self.patch.lock().unwrap().push_back(response);
+    }
+
+    /// Queues a response for [`OperationTemplatesOptionalBodyClientMethods::post()`].
+    pub fn push_post(&self, response: Response<ActionResult>) {
+        self.post.lock().unwrap().push_back(response);
+    }
+
+    /// Queues a response for [`OperationTemplatesOptionalBodyClientMethods::provider_post()`].
+    pub fn push_provider_post(&self, response: Response<ChangeAllowanceResult>) {
+        self.provider_post.lock().unwrap().push_back(response);
+    }
+}
+
+#[cfg(feature = "mock")]
+#[async_trait]
+impl OperationTemplatesOptionalBodyClientMethods for MockOperationTemplatesOptionalBodyClient {
+    async fn get(
+        &self,
+        _resource_group_name: &str,
+        _widget_name: &str,
+        _options: Option<OperationTemplatesOptionalBodyClientGetOptions<'_>>,
+    ) -> Result<Response<Widget>> {
+        self.get.lock().unwrap().pop_front().ok_or_else(|| {
+            azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "no response is queued for MockOperationTemplatesOptionalBodyClient::get()",
+            )
+        })
+    }
+
+    async fn patch(
+        &self,
+        _resource_group_name: &str,
+        _widget_name: &str,
+        _options: Option<OperationTemplatesOptionalBodyClientPatchOptions<'_>>,
+    ) -> Result<Response<Widget>> {
+        self.patch.lock().unwrap().pop_front().ok_or_else(|| {
+            azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "no response is queued for MockOperationTemplatesOptionalBodyClient::patch()",
+            )
+        })
+    }
+
+    async fn post(
+        &self,
+        _resource_group_name: &str,
+        _widget_name: &str,
+        _options: Option<OperationTemplatesOptionalBodyClientPostOptions<'_>>,
+    ) -> Result<Response<ActionResult>> {
+        self.post.lock().unwrap().pop_front().ok_or_else(|| {
+            azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "no response is queued for MockOperationTemplatesOptionalBodyClient::post()",
+            )
+        })
+    }
+
+    async fn provider_post(
+        &self,
+        _options: Option<OperationTemplatesOptionalBodyClientProviderPostOptions<'_>>,
+    ) -> Result<Response<ChangeAllowanceResult>> {
+        self.provider_post.lock().unwrap().pop_front().ok_or_else(|| azure_core::Error::with_message(azure_core::error::ErrorKind::Other, "no response is queued for MockOperationTemplatesOptionalBodyClient::provider_post()"))
+    }
+}
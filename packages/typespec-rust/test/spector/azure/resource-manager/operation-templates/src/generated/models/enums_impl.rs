@@ -25,7 +25,7 @@ impl FromStr for ActionType {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "Internal" => ActionType::Internal,
+            _ if s.eq_ignore_ascii_case("Internal") => ActionType::Internal,
             _ => ActionType::UnknownValue(s.to_string()),
         })
     }
@@ -40,6 +40,12 @@ impl AsRef<str> for ActionType {
     }
 }
 
+impl PartialEq<str> for ActionType {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for ActionType {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -63,8 +69,10 @@ impl FromStr for CheckNameAvailabilityReason {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "AlreadyExists" => CheckNameAvailabilityReason::AlreadyExists,
-            "Invalid" => CheckNameAvailabilityReason::Invalid,
+            _ if s.eq_ignore_ascii_case("AlreadyExists") => {
+                CheckNameAvailabilityReason::AlreadyExists
+            }
+            _ if s.eq_ignore_ascii_case("Invalid") => CheckNameAvailabilityReason::Invalid,
             _ => CheckNameAvailabilityReason::UnknownValue(s.to_string()),
         })
     }
@@ -80,6 +88,12 @@ impl AsRef<str> for CheckNameAvailabilityReason {
     }
 }
 
+impl PartialEq<str> for CheckNameAvailabilityReason {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for CheckNameAvailabilityReason {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -106,10 +120,10 @@ impl FromStr for CreatedByType {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "Application" => CreatedByType::Application,
-            "Key" => CreatedByType::Key,
-            "ManagedIdentity" => CreatedByType::ManagedIdentity,
-            "User" => CreatedByType::User,
+            _ if s.eq_ignore_ascii_case("Application") => CreatedByType::Application,
+            _ if s.eq_ignore_ascii_case("Key") => CreatedByType::Key,
+            _ if s.eq_ignore_ascii_case("ManagedIdentity") => CreatedByType::ManagedIdentity,
+            _ if s.eq_ignore_ascii_case("User") => CreatedByType::User,
             _ => CreatedByType::UnknownValue(s.to_string()),
         })
     }
@@ -127,6 +141,12 @@ impl AsRef<str> for CreatedByType {
     }
 }
 
+impl PartialEq<str> for CreatedByType {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for CreatedByType {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -154,9 +174,9 @@ impl FromStr for Origin {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "system" => Origin::System,
-            "user" => Origin::User,
-            "user,system" => Origin::UserSystem,
+            _ if s.eq_ignore_ascii_case("system") => Origin::System,
+            _ if s.eq_ignore_ascii_case("user") => Origin::User,
+            _ if s.eq_ignore_ascii_case("user,system") => Origin::UserSystem,
             _ => Origin::UnknownValue(s.to_string()),
         })
     }
@@ -173,6 +193,12 @@ impl AsRef<str> for Origin {
     }
 }
 
+impl PartialEq<str> for Origin {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for Origin {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -199,9 +225,9 @@ impl FromStr for ResourceProvisioningState {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "Canceled" => ResourceProvisioningState::Canceled,
-            "Failed" => ResourceProvisioningState::Failed,
-            "Succeeded" => ResourceProvisioningState::Succeeded,
+            _ if s.eq_ignore_ascii_case("Canceled") => ResourceProvisioningState::Canceled,
+            _ if s.eq_ignore_ascii_case("Failed") => ResourceProvisioningState::Failed,
+            _ if s.eq_ignore_ascii_case("Succeeded") => ResourceProvisioningState::Succeeded,
             _ => ResourceProvisioningState::UnknownValue(s.to_string()),
         })
     }
@@ -218,6 +244,12 @@ impl AsRef<str> for ResourceProvisioningState {
     }
 }
 
+impl PartialEq<str> for ResourceProvisioningState {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for ResourceProvisioningState {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -6,8 +6,9 @@ mod common;
 
 use azure_core::{
     http::{
-        poller::{PollerOptions, PollerStatus, StatusMonitor},
-        RequestContent, StatusCode,
+        headers::Headers,
+        poller::{PollerOptions, PollerResult, PollerState, PollerStatus, StatusMonitor},
+        Poller, RawResponse, RequestContent, StatusCode,
     },
     time::{Duration, OffsetDateTime},
 };
@@ -16,9 +17,13 @@ use time::{Date, Month, Time};
 
 use futures::StreamExt;
 
+use spector_armoptemplates::clients::{
+    MockOperationTemplatesLroClient, OperationTemplatesLroClientMethods,
+};
 use spector_armoptemplates::models::{
     CreatedByType, ExportRequest, ExportResult,
     OperationTemplatesLroClientBeginCreateOrReplaceOptions,
+    OperationTemplatesLroClientBeginDeleteOperationStatus,
     OperationTemplatesLroClientBeginDeleteOptions,
     OperationTemplatesLroClientBeginExportArrayOptions,
     OperationTemplatesLroClientBeginExportOptions, Order, OrderProperties,
@@ -302,3 +307,41 @@ async fn lro_client_export_array() {
         Some("order2,product2,2".to_string())
     );
 }
+
+fn begin_delete_order(
+    client: &dyn OperationTemplatesLroClientMethods,
+) -> azure_core::Result<Poller<OperationTemplatesLroClientBeginDeleteOperationStatus>> {
+    client.begin_delete("test-rg", "order1", None)
+}
+
+#[tokio::test]
+async fn mock_begin_delete() {
+    let client = MockOperationTemplatesLroClient::default();
+    client.push_begin_delete(Poller::new(
+        |_: PollerState, _| {
+            Box::pin(async {
+                Ok(PollerResult::Done {
+                    response: RawResponse::from_bytes(
+                        StatusCode::Ok,
+                        Headers::new(),
+                        r#"{"status":"Succeeded"}"#,
+                    )
+                    .into(),
+                })
+            })
+        },
+        None,
+    ));
+
+    let mut poller = begin_delete_order(&client).unwrap();
+    let mut poll_count = 0;
+    while let Some(result) = poller.next().await {
+        poll_count += 1;
+        let status_monitor = result.unwrap().into_model().unwrap();
+        assert_eq!(status_monitor.status(), PollerStatus::Succeeded);
+    }
+    assert_eq!(poll_count, 1);
+
+    // the queued poller was consumed
+    assert!(begin_delete_order(&client).is_err());
+}
@@ -4,8 +4,22 @@
 
 mod common;
 
+use azure_core::http::{
+    headers::Headers,
+    pager::{PagerResult, PagerState},
+    Pager, RawResponse, StatusCode,
+};
 use futures::StreamExt;
-use spector_armoptemplates::models::{ActionType, Origin};
+use spector_armoptemplates::clients::{
+    MockOperationTemplatesOperationsClient, OperationTemplatesOperationsClientMethods,
+};
+use spector_armoptemplates::models::{ActionType, OperationListResult, Origin};
+
+fn list_operations(
+    client: &dyn OperationTemplatesOperationsClientMethods,
+) -> azure_core::Result<Pager<OperationListResult>> {
+    client.list(None)
+}
 
 #[tokio::test]
 async fn test_list_operations() {
@@ -94,3 +108,37 @@ async fn test_list_operations_pages() {
         }
     }
 }
+
+#[tokio::test]
+async fn mock_list_operations() {
+    let client = MockOperationTemplatesOperationsClient::default();
+    client.push_list(Pager::new(
+        |_: PagerState, _| {
+            Box::pin(async {
+                Ok(PagerResult::Done {
+                    response: RawResponse::from_bytes(
+                        StatusCode::Ok,
+                        Headers::new(),
+                        r#"{"value":[{"name":"Microsoft.Compute/virtualMachines/write"}]}"#,
+                    )
+                    .into(),
+                })
+            })
+        },
+        None,
+    ));
+
+    let mut iter = list_operations(&client).unwrap();
+    let mut item_count = 0;
+    while let Some(item) = iter.next().await {
+        item_count += 1;
+        assert_eq!(
+            item.unwrap().name,
+            Some("Microsoft.Compute/virtualMachines/write".to_string())
+        );
+    }
+    assert_eq!(item_count, 1);
+
+    // the queued pager was consumed
+    assert!(list_operations(&client).is_err());
+}
@@ -0,0 +1,486 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use crate::generated::{
+    clients::ResourcesClient,
+    models::{
+        LocationResource, LocationResourceListResult, NestedProxyResource,
+        NestedProxyResourceListResult, ResourceId,
+        ResourcesLocationResourcesClientCreateOrUpdateOptions,
+        ResourcesLocationResourcesClientDeleteOptions, ResourcesLocationResourcesClientGetOptions,
+        ResourcesLocationResourcesClientListByLocationOptions,
+        ResourcesLocationResourcesClientUpdateOptions,
+        ResourcesNestedClientBeginCreateOrReplaceOperationStatus,
+        ResourcesNestedClientBeginCreateOrReplaceOptions,
+        ResourcesNestedClientBeginDeleteOperationStatus, ResourcesNestedClientBeginDeleteOptions,
+        ResourcesNestedClientBeginUpdateOperationStatus, ResourcesNestedClientBeginUpdateOptions,
+        ResourcesNestedClientGetOptions, ResourcesNestedClientListByTopLevelTrackedResourceOptions,
+        ResourcesSingletonClientBeginCreateOrUpdateOperationStatus,
+        ResourcesSingletonClientBeginCreateOrUpdateOptions,
+        ResourcesSingletonClientGetByResourceGroupOptions, ResourcesSingletonClientUpdateOptions,
+        ResourcesTopLevelClientBeginCreateOrReplaceOperationStatus,
+        ResourcesTopLevelClientBeginCreateOrReplaceOptions,
+        ResourcesTopLevelClientBeginDeleteOperationStatus,
+        ResourcesTopLevelClientBeginDeleteOptions,
+        ResourcesTopLevelClientBeginUpdateOperationStatus,
+        ResourcesTopLevelClientBeginUpdateOptions, ResourcesTopLevelClientGetOptions,
+        ResourcesTopLevelClientListByResourceGroupOptions, SingletonTrackedResource,
+        TopLevelTrackedResource, TopLevelTrackedResourceListResult,
+    },
+};
+use azure_core::{
+    http::{NoFormat, Pager, Poller, RequestContent, Response},
+    Result,
+};
+
+/// A handle to a [`LocationResource`](crate::generated::models::LocationResource) resource.
+///
+/// Creating a handle doesn't send any requests. The resource ID is validated when an operation is called.
+#[derive(Clone)]
+pub struct LocationResourceHandle<'a> {
+    client: &'a ResourcesClient,
+    id: ResourceId,
+}
+
+impl<'a> LocationResourceHandle<'a> {
+    /// Creates a handle to the resource with the specified ID.
+    pub fn new(client: &'a ResourcesClient, id: ResourceId) -> Self {
+        Self { client, id }
+    }
+
+    /// Returns the ID of the resource.
+    pub fn id(&self) -> &ResourceId {
+        &self.id
+    }
+
+    /// Calls [`ResourcesLocationResourcesClient::create_or_update()`](crate::generated::clients::ResourcesLocationResourcesClient::create_or_update())
+    /// for this resource.
+    pub async fn create_or_update(
+        &self,
+        resource: RequestContent<LocationResource>,
+        options: Option<ResourcesLocationResourcesClientCreateOrUpdateOptions<'_>>,
+    ) -> Result<Response<LocationResource>> {
+        self.client
+            .get_resources_location_resources_client()
+            .create_or_update_by_id(&self.id, resource, options)
+            .await
+    }
+
+    /// Calls [`ResourcesLocationResourcesClient::delete()`](crate::generated::clients::ResourcesLocationResourcesClient::delete())
+    /// for this resource.
+    pub async fn delete(
+        &self,
+        options: Option<ResourcesLocationResourcesClientDeleteOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        self.client
+            .get_resources_location_resources_client()
+            .delete_by_id(&self.id, options)
+            .await
+    }
+
+    /// Calls [`ResourcesLocationResourcesClient::get()`](crate::generated::clients::ResourcesLocationResourcesClient::get())
+    /// for this resource.
+    pub async fn get(
+        &self,
+        options: Option<ResourcesLocationResourcesClientGetOptions<'_>>,
+    ) -> Result<Response<LocationResource>> {
+        self.client
+            .get_resources_location_resources_client()
+            .get_by_id(&self.id, options)
+            .await
+    }
+
+    /// Calls [`ResourcesLocationResourcesClient::update()`](crate::generated::clients::ResourcesLocationResourcesClient::update())
+    /// for this resource.
+    pub async fn update(
+        &self,
+        properties: RequestContent<LocationResource>,
+        options: Option<ResourcesLocationResourcesClientUpdateOptions<'_>>,
+    ) -> Result<Response<LocationResource>> {
+        self.client
+            .get_resources_location_resources_client()
+            .update_by_id(&self.id, properties, options)
+            .await
+    }
+}
+
+/// The [`LocationResource`](crate::generated::models::LocationResource) resources contained in a parent resource or scope.
+#[derive(Clone)]
+pub struct LocationResourceCollection<'a> {
+    client: &'a ResourcesClient,
+    parent: ResourceId,
+}
+
+impl<'a> LocationResourceCollection<'a> {
+    /// Creates the collection of resources contained in the specified parent, e.g. a resource group.
+    pub fn new(client: &'a ResourcesClient, parent: ResourceId) -> Self {
+        Self { client, parent }
+    }
+
+    /// Returns a handle to the resource with the specified name. No request is sent.
+    pub fn handle(&self, name: &str) -> Result<LocationResourceHandle<'a>> {
+        Ok(LocationResourceHandle::new(
+            self.client,
+            self.parent.child("locationResources", name)?,
+        ))
+    }
+
+    /// Calls [`ResourcesLocationResourcesClient::create_or_update()`](crate::generated::clients::ResourcesLocationResourcesClient::create_or_update())
+    /// for the resource with the specified name.
+    pub async fn create_or_update(
+        &self,
+        name: &str,
+        resource: RequestContent<LocationResource>,
+        options: Option<ResourcesLocationResourcesClientCreateOrUpdateOptions<'_>>,
+    ) -> Result<Response<LocationResource>> {
+        self.handle(name)?.create_or_update(resource, options).await
+    }
+
+    /// Calls [`ResourcesLocationResourcesClient::get()`](crate::generated::clients::ResourcesLocationResourcesClient::get())
+    /// for the resource with the specified name.
+    pub async fn get(
+        &self,
+        name: &str,
+        options: Option<ResourcesLocationResourcesClientGetOptions<'_>>,
+    ) -> Result<Response<LocationResource>> {
+        self.handle(name)?.get(options).await
+    }
+
+    /// Calls [`ResourcesLocationResourcesClient::list_by_location()`](crate::generated::clients::ResourcesLocationResourcesClient::list_by_location())
+    /// for the resources in the collection.
+    pub fn list(
+        &self,
+        options: Option<ResourcesLocationResourcesClientListByLocationOptions<'_>>,
+    ) -> Result<Pager<LocationResourceListResult>> {
+        self.client
+            .get_resources_location_resources_client()
+            .list_by_location_by_id(&self.parent, options)
+    }
+
+    /// Returns the ID of the resource or scope that contains the collection.
+    pub fn parent(&self) -> &ResourceId {
+        &self.parent
+    }
+}
+
+/// A handle to a [`NestedProxyResource`](crate::generated::models::NestedProxyResource) resource.
+///
+/// Creating a handle doesn't send any requests. The resource ID is validated when an operation is called.
+#[derive(Clone)]
+pub struct NestedProxyResourceHandle<'a> {
+    client: &'a ResourcesClient,
+    id: ResourceId,
+}
+
+impl<'a> NestedProxyResourceHandle<'a> {
+    /// Creates a handle to the resource with the specified ID.
+    pub fn new(client: &'a ResourcesClient, id: ResourceId) -> Self {
+        Self { client, id }
+    }
+
+    /// Returns the ID of the resource.
+    pub fn id(&self) -> &ResourceId {
+        &self.id
+    }
+
+    /// Calls [`ResourcesNestedClient::begin_create_or_replace()`](crate::generated::clients::ResourcesNestedClient::begin_create_or_replace())
+    /// for this resource.
+    pub fn begin_create_or_replace(
+        &self,
+        resource: RequestContent<NestedProxyResource>,
+        options: Option<ResourcesNestedClientBeginCreateOrReplaceOptions<'_>>,
+    ) -> Result<Poller<ResourcesNestedClientBeginCreateOrReplaceOperationStatus>> {
+        self.client
+            .get_resources_nested_client()
+            .begin_create_or_replace_by_id(&self.id, resource, options)
+    }
+
+    /// Calls [`ResourcesNestedClient::begin_delete()`](crate::generated::clients::ResourcesNestedClient::begin_delete()) for
+    /// this resource.
+    pub fn begin_delete(
+        &self,
+        options: Option<ResourcesNestedClientBeginDeleteOptions<'_>>,
+    ) -> Result<Poller<ResourcesNestedClientBeginDeleteOperationStatus>> {
+        self.client
+            .get_resources_nested_client()
+            .begin_delete_by_id(&self.id, options)
+    }
+
+    /// Calls [`ResourcesNestedClient::begin_update()`](crate::generated::clients::ResourcesNestedClient::begin_update()) for
+    /// this resource.
+    pub fn begin_update(
+        &self,
+        properties: RequestContent<NestedProxyResource>,
+        options: Option<ResourcesNestedClientBeginUpdateOptions<'_>>,
+    ) -> Result<Poller<ResourcesNestedClientBeginUpdateOperationStatus>> {
+        self.client
+            .get_resources_nested_client()
+            .begin_update_by_id(&self.id, properties, options)
+    }
+
+    /// Calls [`ResourcesNestedClient::get()`](crate::generated::clients::ResourcesNestedClient::get()) for this resource.
+    pub async fn get(
+        &self,
+        options: Option<ResourcesNestedClientGetOptions<'_>>,
+    ) -> Result<Response<NestedProxyResource>> {
+        self.client
+            .get_resources_nested_client()
+            .get_by_id(&self.id, options)
+            .await
+    }
+}
+
+/// The [`NestedProxyResource`](crate::generated::models::NestedProxyResource) resources contained in a parent resource or
+/// scope.
+#[derive(Clone)]
+pub struct NestedProxyResourceCollection<'a> {
+    client: &'a ResourcesClient,
+    parent: ResourceId,
+}
+
+impl<'a> NestedProxyResourceCollection<'a> {
+    /// Creates the collection of resources contained in the specified parent, e.g. a resource group.
+    pub fn new(client: &'a ResourcesClient, parent: ResourceId) -> Self {
+        Self { client, parent }
+    }
+
+    /// Returns a handle to the resource with the specified name. No request is sent.
+    pub fn handle(&self, name: &str) -> Result<NestedProxyResourceHandle<'a>> {
+        Ok(NestedProxyResourceHandle::new(
+            self.client,
+            self.parent.child("nestedProxyResources", name)?,
+        ))
+    }
+
+    /// Calls [`ResourcesNestedClient::begin_create_or_replace()`](crate::generated::clients::ResourcesNestedClient::begin_create_or_replace())
+    /// for the resource with the specified name.
+    pub fn begin_create_or_replace(
+        &self,
+        name: &str,
+        resource: RequestContent<NestedProxyResource>,
+        options: Option<ResourcesNestedClientBeginCreateOrReplaceOptions<'_>>,
+    ) -> Result<Poller<ResourcesNestedClientBeginCreateOrReplaceOperationStatus>> {
+        self.handle(name)?
+            .begin_create_or_replace(resource, options)
+    }
+
+    /// Calls [`ResourcesNestedClient::get()`](crate::generated::clients::ResourcesNestedClient::get()) for the resource with
+    /// the specified name.
+    pub async fn get(
+        &self,
+        name: &str,
+        options: Option<ResourcesNestedClientGetOptions<'_>>,
+    ) -> Result<Response<NestedProxyResource>> {
+        self.handle(name)?.get(options).await
+    }
+
+    /// Calls [`ResourcesNestedClient::list_by_top_level_tracked_resource()`](crate::generated::clients::ResourcesNestedClient::list_by_top_level_tracked_resource())
+    /// for the resources in the collection.
+    pub fn list(
+        &self,
+        options: Option<ResourcesNestedClientListByTopLevelTrackedResourceOptions<'_>>,
+    ) -> Result<Pager<NestedProxyResourceListResult>> {
+        self.client
+            .get_resources_nested_client()
+            .list_by_top_level_tracked_resource_by_id(&self.parent, options)
+    }
+
+    /// Returns the ID of the resource or scope that contains the collection.
+    pub fn parent(&self) -> &ResourceId {
+        &self.parent
+    }
+}
+
+/// A handle to a [`SingletonTrackedResource`](crate::generated::models::SingletonTrackedResource) resource.
+///
+/// Creating a handle doesn't send any requests. The resource ID is validated when an operation is called.
+#[derive(Clone)]
+pub struct SingletonTrackedResourceHandle<'a> {
+    client: &'a ResourcesClient,
+    id: ResourceId,
+}
+
+impl<'a> SingletonTrackedResourceHandle<'a> {
+    /// Creates a handle to the resource with the specified ID.
+    pub fn new(client: &'a ResourcesClient, id: ResourceId) -> Self {
+        Self { client, id }
+    }
+
+    /// Returns the ID of the resource.
+    pub fn id(&self) -> &ResourceId {
+        &self.id
+    }
+
+    /// Calls [`ResourcesSingletonClient::begin_create_or_update()`](crate::generated::clients::ResourcesSingletonClient::begin_create_or_update())
+    /// for this resource.
+    pub fn begin_create_or_update(
+        &self,
+        resource: RequestContent<SingletonTrackedResource>,
+        options: Option<ResourcesSingletonClientBeginCreateOrUpdateOptions<'_>>,
+    ) -> Result<Poller<ResourcesSingletonClientBeginCreateOrUpdateOperationStatus>> {
+        self.client
+            .get_resources_singleton_client()
+            .begin_create_or_update_by_id(&self.id, resource, options)
+    }
+
+    /// Calls [`ResourcesSingletonClient::get_by_resource_group()`](crate::generated::clients::ResourcesSingletonClient::get_by_resource_group())
+    /// for this resource.
+    pub async fn get_by_resource_group(
+        &self,
+        options: Option<ResourcesSingletonClientGetByResourceGroupOptions<'_>>,
+    ) -> Result<Response<SingletonTrackedResource>> {
+        self.client
+            .get_resources_singleton_client()
+            .get_by_resource_group_by_id(&self.id, options)
+            .await
+    }
+
+    /// Calls [`ResourcesSingletonClient::update()`](crate::generated::clients::ResourcesSingletonClient::update()) for this resource.
+    pub async fn update(
+        &self,
+        properties: RequestContent<SingletonTrackedResource>,
+        options: Option<ResourcesSingletonClientUpdateOptions<'_>>,
+    ) -> Result<Response<SingletonTrackedResource>> {
+        self.client
+            .get_resources_singleton_client()
+            .update_by_id(&self.id, properties, options)
+            .await
+    }
+}
+
+/// A handle to a [`TopLevelTrackedResource`](crate::generated::models::TopLevelTrackedResource) resource.
+///
+/// Creating a handle doesn't send any requests. The resource ID is validated when an operation is called.
+#[derive(Clone)]
+pub struct TopLevelTrackedResourceHandle<'a> {
+    client: &'a ResourcesClient,
+    id: ResourceId,
+}
+
+impl<'a> TopLevelTrackedResourceHandle<'a> {
+    /// Creates a handle to the resource with the specified ID.
+    pub fn new(client: &'a ResourcesClient, id: ResourceId) -> Self {
+        Self { client, id }
+    }
+
+    /// Returns the ID of the resource.
+    pub fn id(&self) -> &ResourceId {
+        &self.id
+    }
+
+    /// Calls [`ResourcesTopLevelClient::begin_create_or_replace()`](crate::generated::clients::ResourcesTopLevelClient::begin_create_or_replace())
+    /// for this resource.
+    pub fn begin_create_or_replace(
+        &self,
+        resource: RequestContent<TopLevelTrackedResource>,
+        options: Option<ResourcesTopLevelClientBeginCreateOrReplaceOptions<'_>>,
+    ) -> Result<Poller<ResourcesTopLevelClientBeginCreateOrReplaceOperationStatus>> {
+        self.client
+            .get_resources_top_level_client()
+            .begin_create_or_replace_by_id(&self.id, resource, options)
+    }
+
+    /// Calls [`ResourcesTopLevelClient::begin_delete()`](crate::generated::clients::ResourcesTopLevelClient::begin_delete())
+    /// for this resource.
+    pub fn begin_delete(
+        &self,
+        options: Option<ResourcesTopLevelClientBeginDeleteOptions<'_>>,
+    ) -> Result<Poller<ResourcesTopLevelClientBeginDeleteOperationStatus>> {
+        self.client
+            .get_resources_top_level_client()
+            .begin_delete_by_id(&self.id, options)
+    }
+
+    /// Calls [`ResourcesTopLevelClient::begin_update()`](crate::generated::clients::ResourcesTopLevelClient::begin_update())
+    /// for this resource.
+    pub fn begin_update(
+        &self,
+        properties: RequestContent<TopLevelTrackedResource>,
+        options: Option<ResourcesTopLevelClientBeginUpdateOptions<'_>>,
+    ) -> Result<Poller<ResourcesTopLevelClientBeginUpdateOperationStatus>> {
+        self.client
+            .get_resources_top_level_client()
+            .begin_update_by_id(&self.id, properties, options)
+    }
+
+    /// Calls [`ResourcesTopLevelClient::get()`](crate::generated::clients::ResourcesTopLevelClient::get()) for this resource.
+    pub async fn get(
+        &self,
+        options: Option<ResourcesTopLevelClientGetOptions<'_>>,
+    ) -> Result<Response<TopLevelTrackedResource>> {
+        self.client
+            .get_resources_top_level_client()
+            .get_by_id(&self.id, options)
+            .await
+    }
+
+    /// Returns the [`NestedProxyResource`](crate::generated::models::NestedProxyResource) resources nested under this resource.
+    pub fn nested_proxy_resources(&self) -> NestedProxyResourceCollection<'a> {
+        NestedProxyResourceCollection::new(self.client, self.id.clone())
+    }
+}
+
+/// The [`TopLevelTrackedResource`](crate::generated::models::TopLevelTrackedResource) resources contained in a parent resource
+/// or scope.
+#[derive(Clone)]
+pub struct TopLevelTrackedResourceCollection<'a> {
+    client: &'a ResourcesClient,
+    parent: ResourceId,
+}
+
+impl<'a> TopLevelTrackedResourceCollection<'a> {
+    /// Creates the collection of resources contained in the specified parent, e.g. a resource group.
+    pub fn new(client: &'a ResourcesClient, parent: ResourceId) -> Self {
+        Self { client, parent }
+    }
+
+    /// Returns a handle to the resource with the specified name. No request is sent.
+    pub fn handle(&self, name: &str) -> Result<TopLevelTrackedResourceHandle<'a>> {
+        Ok(TopLevelTrackedResourceHandle::new(
+            self.client,
+            self.parent
+                .child("providers", "Azure.ResourceManager.Resources")?
+                .child("topLevelTrackedResources", name)?,
+        ))
+    }
+
+    /// Calls [`ResourcesTopLevelClient::begin_create_or_replace()`](crate::generated::clients::ResourcesTopLevelClient::begin_create_or_replace())
+    /// for the resource with the specified name.
+    pub fn begin_create_or_replace(
+        &self,
+        name: &str,
+        resource: RequestContent<TopLevelTrackedResource>,
+        options: Option<ResourcesTopLevelClientBeginCreateOrReplaceOptions<'_>>,
+    ) -> Result<Poller<ResourcesTopLevelClientBeginCreateOrReplaceOperationStatus>> {
+        self.handle(name)?
+            .begin_create_or_replace(resource, options)
+    }
+
+    /// Calls [`ResourcesTopLevelClient::get()`](crate::generated::clients::ResourcesTopLevelClient::get()) for the resource with
+    /// the specified name.
+    pub async fn get(
+        &self,
+        name: &str,
+        options: Option<ResourcesTopLevelClientGetOptions<'_>>,
+    ) -> Result<Response<TopLevelTrackedResource>> {
+        self.handle(name)?.get(options).await
+    }
+
+    /// Calls [`ResourcesTopLevelClient::list_by_resource_group()`](crate::generated::clients::ResourcesTopLevelClient::list_by_resource_group())
+    /// for the resources in the collection.
+    pub fn list(
+        &self,
+        options: Option<ResourcesTopLevelClientListByResourceGroupOptions<'_>>,
+    ) -> Result<Pager<TopLevelTrackedResourceListResult>> {
+        self.client
+            .get_resources_top_level_client()
+            .list_by_resource_group_by_id(&self.parent, options)
+    }
+
+    /// Returns the ID of the resource or scope that contains the collection.
+    pub fn parent(&self) -> &ResourceId {
+        &self.parent
+    }
+}
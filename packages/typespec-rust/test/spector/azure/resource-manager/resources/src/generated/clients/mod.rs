@@ -3,12 +3,14 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
+mod arm_resources;
 mod resources_client;
 mod resources_extensions_resources_client;
 mod resources_location_resources_client;
 mod resources_nested_client;
 mod resources_singleton_client;
 mod resources_top_level_client;
+pub use arm_resources::*;
 pub use resources_client::*;
 pub use resources_extensions_resources_client::*;
 pub use resources_location_resources_client::*;
@@ -4,7 +4,7 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    LocationResource, LocationResourceListResult,
+    LocationResource, LocationResourceListResult, ResourceId,
     ResourcesLocationResourcesClientCreateOrUpdateOptions,
     ResourcesLocationResourcesClientDeleteOptions, ResourcesLocationResourcesClientGetOptions,
     ResourcesLocationResourcesClientListByLocationOptions,
@@ -14,8 +14,8 @@ use azure_core::{
     error::CheckSuccessOptions,
     http::{
         pager::{PagerContinuation, PagerResult, PagerState},
-        Method, NoFormat, Pager, Pipeline, PipelineSendOptions, RawResponse, Request,
-        RequestContent, Response, Url, UrlExt,
+        ClientMethodOptions, Method, NoFormat, Pager, Pipeline, PipelineSendOptions, RawResponse,
+        Request, RequestContent, Response, Url, UrlExt,
     },
     json, tracing, Result,
 };
@@ -34,6 +34,30 @@ impl ResourcesLocationResourcesClient {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     /// Create a LocationResource
     ///
     /// # Arguments
@@ -93,6 +117,36 @@ impl ResourcesLocationResourcesClient {
         Ok(rsp.into())
     }
 
+    /// Calls [`ResourcesLocationResourcesClient::create_or_update()`](crate::generated::clients::ResourcesLocationResourcesClient::create_or_update())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/providers/Azure.ResourceManager.Resources/locations/{location}/locationResources/{locationResourceName}`.
+    /// * `resource` - Resource create parameters.
+    /// * `options` - Optional parameters for the request.
+    pub async fn create_or_update_by_id(
+        &self,
+        resource_id: &ResourceId,
+        resource: RequestContent<LocationResource>,
+        options: Option<ResourcesLocationResourcesClientCreateOrUpdateOptions<'_>>,
+    ) -> Result<Response<LocationResource>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/providers/Azure.ResourceManager.Resources/locations/{location}/locationResources/{locationResourceName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.create_or_update(
+            path_params["location"],
+            path_params["locationResourceName"],
+            resource,
+            options,
+        )
+        .await
+    }
+
     /// Delete a LocationResource
     ///
     /// # Arguments
@@ -147,6 +201,33 @@ impl ResourcesLocationResourcesClient {
         Ok(rsp.into())
     }
 
+    /// Calls [`ResourcesLocationResourcesClient::delete()`](crate::generated::clients::ResourcesLocationResourcesClient::delete())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/providers/Azure.ResourceManager.Resources/locations/{location}/locationResources/{locationResourceName}`.
+    /// * `options` - Optional parameters for the request.
+    pub async fn delete_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<ResourcesLocationResourcesClientDeleteOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/providers/Azure.ResourceManager.Resources/locations/{location}/locationResources/{locationResourceName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.delete(
+            path_params["location"],
+            path_params["locationResourceName"],
+            options,
+        )
+        .await
+    }
+
     /// Get a LocationResource
     ///
     /// # Arguments
@@ -202,6 +283,33 @@ impl ResourcesLocationResourcesClient {
         Ok(rsp.into())
     }
 
+    /// Calls [`ResourcesLocationResourcesClient::get()`](crate::generated::clients::ResourcesLocationResourcesClient::get())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/providers/Azure.ResourceManager.Resources/locations/{location}/locationResources/{locationResourceName}`.
+    /// * `options` - Optional parameters for the request.
+    pub async fn get_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<ResourcesLocationResourcesClientGetOptions<'_>>,
+    ) -> Result<Response<LocationResource>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/providers/Azure.ResourceManager.Resources/locations/{location}/locationResources/{locationResourceName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.get(
+            path_params["location"],
+            path_params["locationResourceName"],
+            options,
+        )
+        .await
+    }
+
     /// List LocationResource resources by SubscriptionLocationResource
     ///
     /// # Arguments
@@ -287,6 +395,28 @@ impl ResourcesLocationResourcesClient {
         ))
     }
 
+    /// Calls [`ResourcesLocationResourcesClient::list_by_location()`](crate::generated::clients::ResourcesLocationResourcesClient::list_by_location())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/providers/Azure.ResourceManager.Resources/locations/{location}/locationResources`.
+    /// * `options` - Optional parameters for the request.
+    pub fn list_by_location_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<ResourcesLocationResourcesClientListByLocationOptions<'_>>,
+    ) -> Result<Pager<LocationResourceListResult>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/providers/Azure.ResourceManager.Resources/locations/{location}/locationResources")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.list_by_location(path_params["location"], options)
+    }
+
     /// Update a LocationResource
     ///
     /// # Arguments
@@ -345,4 +475,34 @@ impl ResourcesLocationResourcesClient {
             .await?;
         Ok(rsp.into())
     }
+
+    /// Calls [`ResourcesLocationResourcesClient::update()`](crate::generated::clients::ResourcesLocationResourcesClient::update())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/providers/Azure.ResourceManager.Resources/locations/{location}/locationResources/{locationResourceName}`.
+    /// * `properties` - The resource properties to be updated.
+    /// * `options` - Optional parameters for the request.
+    pub async fn update_by_id(
+        &self,
+        resource_id: &ResourceId,
+        properties: RequestContent<LocationResource>,
+        options: Option<ResourcesLocationResourcesClientUpdateOptions<'_>>,
+    ) -> Result<Response<LocationResource>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/providers/Azure.ResourceManager.Resources/locations/{location}/locationResources/{locationResourceName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.update(
+            path_params["location"],
+            path_params["locationResourceName"],
+            properties,
+            options,
+        )
+        .await
+    }
 }
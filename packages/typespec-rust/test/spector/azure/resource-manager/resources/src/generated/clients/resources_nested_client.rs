@@ -4,7 +4,7 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    NestedProxyResource, NestedProxyResourceListResult,
+    NestedProxyResource, NestedProxyResourceListResult, ResourceId,
     ResourcesNestedClientBeginCreateOrReplaceOperationStatus,
     ResourcesNestedClientBeginCreateOrReplaceOptions,
     ResourcesNestedClientBeginDeleteOperationStatus, ResourcesNestedClientBeginDeleteOptions,
@@ -20,8 +20,8 @@ use azure_core::{
             get_retry_after, PollerContinuation, PollerResult, PollerState, PollerStatus,
             StatusMonitor,
         },
-        Method, Pager, Pipeline, PipelineSendOptions, Poller, RawResponse, Request, RequestContent,
-        Response, StatusCode, Url, UrlExt,
+        ClientMethodOptions, Method, Pager, Pipeline, PipelineSendOptions, Poller, RawResponse,
+        Request, RequestContent, Response, StatusCode, Url, UrlExt,
     },
     json, tracing, Result,
 };
@@ -40,6 +40,30 @@ impl ResourcesNestedClient {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     /// Create a NestedProxyResource
     ///
     /// # Arguments
@@ -236,6 +260,36 @@ impl ResourcesNestedClient {
         ))
     }
 
+    /// Calls [`ResourcesNestedClient::begin_create_or_replace()`](crate::generated::clients::ResourcesNestedClient::begin_create_or_replace())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/topLevelTrackedResources/{topLevelTrackedResourceName}/nestedProxyResources/{nextedProxyResourceName}`.
+    /// * `resource` - Resource create parameters.
+    /// * `options` - Optional parameters for the request.
+    pub fn begin_create_or_replace_by_id(
+        &self,
+        resource_id: &ResourceId,
+        resource: RequestContent<NestedProxyResource>,
+        options: Option<ResourcesNestedClientBeginCreateOrReplaceOptions<'_>>,
+    ) -> Result<Poller<ResourcesNestedClientBeginCreateOrReplaceOperationStatus>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/topLevelTrackedResources/{topLevelTrackedResourceName}/nestedProxyResources/{nextedProxyResourceName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.begin_create_or_replace(
+            path_params["resourceGroupName"],
+            path_params["topLevelTrackedResourceName"],
+            path_params["nextedProxyResourceName"],
+            resource,
+            options,
+        )
+    }
+
     /// Delete a NestedProxyResource
     ///
     /// # Arguments
@@ -416,6 +470,33 @@ impl ResourcesNestedClient {
         ))
     }
 
+    /// Calls [`ResourcesNestedClient::begin_delete()`](crate::generated::clients::ResourcesNestedClient::begin_delete()) with
+    /// its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/topLevelTrackedResources/{topLevelTrackedResourceName}/nestedProxyResources/{nextedProxyResourceName}`.
+    /// * `options` - Optional parameters for the request.
+    pub fn begin_delete_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<ResourcesNestedClientBeginDeleteOptions<'_>>,
+    ) -> Result<Poller<ResourcesNestedClientBeginDeleteOperationStatus>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/topLevelTrackedResources/{topLevelTrackedResourceName}/nestedProxyResources/{nextedProxyResourceName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.begin_delete(
+            path_params["resourceGroupName"],
+            path_params["topLevelTrackedResourceName"],
+            path_params["nextedProxyResourceName"],
+            options,
+        )
+    }
+
     /// Update a NestedProxyResource
     ///
     /// # Arguments
@@ -612,6 +693,36 @@ impl ResourcesNestedClient {
         ))
     }
 
+    /// Calls [`ResourcesNestedClient::begin_update()`](crate::generated::clients::ResourcesNestedClient::begin_update()) with
+    /// its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/topLevelTrackedResources/{topLevelTrackedResourceName}/nestedProxyResources/{nextedProxyResourceName}`.
+    /// * `properties` - The resource properties to be updated.
+    /// * `options` - Optional parameters for the request.
+    pub fn begin_update_by_id(
+        &self,
+        resource_id: &ResourceId,
+        properties: RequestContent<NestedProxyResource>,
+        options: Option<ResourcesNestedClientBeginUpdateOptions<'_>>,
+    ) -> Result<Poller<ResourcesNestedClientBeginUpdateOperationStatus>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/topLevelTrackedResources/{topLevelTrackedResourceName}/nestedProxyResources/{nextedProxyResourceName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.begin_update(
+            path_params["resourceGroupName"],
+            path_params["topLevelTrackedResourceName"],
+            path_params["nextedProxyResourceName"],
+            properties,
+            options,
+        )
+    }
+
     /// Get a NestedProxyResource
     ///
     /// # Arguments
@@ -679,6 +790,34 @@ impl ResourcesNestedClient {
         Ok(rsp.into())
     }
 
+    /// Calls [`ResourcesNestedClient::get()`](crate::generated::clients::ResourcesNestedClient::get()) with its path parameters
+    /// taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/topLevelTrackedResources/{topLevelTrackedResourceName}/nestedProxyResources/{nextedProxyResourceName}`.
+    /// * `options` - Optional parameters for the request.
+    pub async fn get_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<ResourcesNestedClientGetOptions<'_>>,
+    ) -> Result<Response<NestedProxyResource>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/topLevelTrackedResources/{topLevelTrackedResourceName}/nestedProxyResources/{nextedProxyResourceName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.get(
+            path_params["resourceGroupName"],
+            path_params["topLevelTrackedResourceName"],
+            path_params["nextedProxyResourceName"],
+            options,
+        )
+        .await
+    }
+
     /// List NestedProxyResource resources by TopLevelTrackedResource
     ///
     /// # Arguments
@@ -775,4 +914,30 @@ impl ResourcesNestedClient {
             Some(options.method_options),
         ))
     }
+
+    /// Calls [`ResourcesNestedClient::list_by_top_level_tracked_resource()`](crate::generated::clients::ResourcesNestedClient::list_by_top_level_tracked_resource())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/topLevelTrackedResources/{topLevelTrackedResourceName}/nestedProxyResources`.
+    /// * `options` - Optional parameters for the request.
+    pub fn list_by_top_level_tracked_resource_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<ResourcesNestedClientListByTopLevelTrackedResourceOptions<'_>>,
+    ) -> Result<Pager<NestedProxyResourceListResult>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/topLevelTrackedResources/{topLevelTrackedResourceName}/nestedProxyResources")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.list_by_top_level_tracked_resource(
+            path_params["resourceGroupName"],
+            path_params["topLevelTrackedResourceName"],
+            options,
+        )
+    }
 }
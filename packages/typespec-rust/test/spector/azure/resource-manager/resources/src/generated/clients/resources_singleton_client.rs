@@ -4,7 +4,7 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    ResourcesSingletonClientBeginCreateOrUpdateOperationStatus,
+    ResourceId, ResourcesSingletonClientBeginCreateOrUpdateOperationStatus,
     ResourcesSingletonClientBeginCreateOrUpdateOptions,
     ResourcesSingletonClientGetByResourceGroupOptions,
     ResourcesSingletonClientListByResourceGroupOptions, ResourcesSingletonClientUpdateOptions,
@@ -19,8 +19,8 @@ use azure_core::{
             get_retry_after, PollerContinuation, PollerResult, PollerState, PollerStatus,
             StatusMonitor,
         },
-        Method, Pager, Pipeline, PipelineSendOptions, Poller, RawResponse, Request, RequestContent,
-        Response, StatusCode, Url, UrlExt,
+        ClientMethodOptions, Method, Pager, Pipeline, PipelineSendOptions, Poller, RawResponse,
+        Request, RequestContent, Response, StatusCode, Url, UrlExt,
     },
     json, tracing, Result,
 };
@@ -39,6 +39,30 @@ impl ResourcesSingletonClient {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     /// Create a SingletonTrackedResource
     ///
     /// # Arguments
@@ -226,6 +250,30 @@ impl ResourcesSingletonClient {
         ))
     }
 
+    /// Calls [`ResourcesSingletonClient::begin_create_or_update()`](crate::generated::clients::ResourcesSingletonClient::begin_create_or_update())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/singletonTrackedResources/default`.
+    /// * `resource` - Resource create parameters.
+    /// * `options` - Optional parameters for the request.
+    pub fn begin_create_or_update_by_id(
+        &self,
+        resource_id: &ResourceId,
+        resource: RequestContent<SingletonTrackedResource>,
+        options: Option<ResourcesSingletonClientBeginCreateOrUpdateOptions<'_>>,
+    ) -> Result<Poller<ResourcesSingletonClientBeginCreateOrUpdateOperationStatus>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/singletonTrackedResources/default")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.begin_create_or_update(path_params["resourceGroupName"], resource, options)
+    }
+
     /// Get a SingletonTrackedResource
     ///
     /// # Arguments
@@ -272,6 +320,29 @@ impl ResourcesSingletonClient {
         Ok(rsp.into())
     }
 
+    /// Calls [`ResourcesSingletonClient::get_by_resource_group()`](crate::generated::clients::ResourcesSingletonClient::get_by_resource_group())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/singletonTrackedResources/default`.
+    /// * `options` - Optional parameters for the request.
+    pub async fn get_by_resource_group_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<ResourcesSingletonClientGetByResourceGroupOptions<'_>>,
+    ) -> Result<Response<SingletonTrackedResource>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/singletonTrackedResources/default")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.get_by_resource_group(path_params["resourceGroupName"], options)
+            .await
+    }
+
     /// List SingletonTrackedResource resources by resource group
     ///
     /// # Arguments
@@ -357,6 +428,28 @@ impl ResourcesSingletonClient {
         ))
     }
 
+    /// Calls [`ResourcesSingletonClient::list_by_resource_group()`](crate::generated::clients::ResourcesSingletonClient::list_by_resource_group())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/singletonTrackedResources`.
+    /// * `options` - Optional parameters for the request.
+    pub fn list_by_resource_group_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<ResourcesSingletonClientListByResourceGroupOptions<'_>>,
+    ) -> Result<Pager<SingletonTrackedResourceListResult>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/singletonTrackedResources")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.list_by_resource_group(path_params["resourceGroupName"], options)
+    }
+
     /// Update a SingletonTrackedResource
     ///
     /// # Arguments
@@ -406,4 +499,29 @@ impl ResourcesSingletonClient {
             .await?;
         Ok(rsp.into())
     }
+
+    /// Calls [`ResourcesSingletonClient::update()`](crate::generated::clients::ResourcesSingletonClient::update()) with its path
+    /// parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/singletonTrackedResources/default`.
+    /// * `properties` - The resource properties to be updated.
+    /// * `options` - Optional parameters for the request.
+    pub async fn update_by_id(
+        &self,
+        resource_id: &ResourceId,
+        properties: RequestContent<SingletonTrackedResource>,
+        options: Option<ResourcesSingletonClientUpdateOptions<'_>>,
+    ) -> Result<Response<SingletonTrackedResource>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/singletonTrackedResources/default")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.update(path_params["resourceGroupName"], properties, options)
+            .await
+    }
 }
@@ -4,7 +4,7 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    NotificationDetails, ResourcesTopLevelClientActionSyncOptions,
+    NotificationDetails, ResourceId, ResourcesTopLevelClientActionSyncOptions,
     ResourcesTopLevelClientBeginCreateOrReplaceOperationStatus,
     ResourcesTopLevelClientBeginCreateOrReplaceOptions,
     ResourcesTopLevelClientBeginDeleteOperationStatus, ResourcesTopLevelClientBeginDeleteOptions,
@@ -22,8 +22,8 @@ use azure_core::{
             get_retry_after, PollerContinuation, PollerResult, PollerState, PollerStatus,
             StatusMonitor,
         },
-        Method, NoFormat, Pager, Pipeline, PipelineSendOptions, Poller, RawResponse, Request,
-        RequestContent, Response, StatusCode, Url, UrlExt,
+        ClientMethodOptions, Method, NoFormat, Pager, Pipeline, PipelineSendOptions, Poller,
+        RawResponse, Request, RequestContent, Response, StatusCode, Url, UrlExt,
     },
     json, tracing, Result,
 };
@@ -42,6 +42,30 @@ impl ResourcesTopLevelClient {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     /// A synchronous resource action that returns no content.
     ///
     /// # Arguments
@@ -103,6 +127,36 @@ impl ResourcesTopLevelClient {
         Ok(rsp.into())
     }
 
+    /// Calls [`ResourcesTopLevelClient::action_sync()`](crate::generated::clients::ResourcesTopLevelClient::action_sync()) with
+    /// its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/topLevelTrackedResources/{topLevelTrackedResourceName}/actionSync`.
+    /// * `body` - The content of the action request
+    /// * `options` - Optional parameters for the request.
+    pub async fn action_sync_by_id(
+        &self,
+        resource_id: &ResourceId,
+        body: RequestContent<NotificationDetails>,
+        options: Option<ResourcesTopLevelClientActionSyncOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/topLevelTrackedResources/{topLevelTrackedResourceName}/actionSync")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.action_sync(
+            path_params["resourceGroupName"],
+            path_params["topLevelTrackedResourceName"],
+            body,
+            options,
+        )
+        .await
+    }
+
     /// Create a TopLevelTrackedResource
     ///
     /// # Arguments
@@ -296,6 +350,35 @@ impl ResourcesTopLevelClient {
         ))
     }
 
+    /// Calls [`ResourcesTopLevelClient::begin_create_or_replace()`](crate::generated::clients::ResourcesTopLevelClient::begin_create_or_replace())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/topLevelTrackedResources/{topLevelTrackedResourceName}`.
+    /// * `resource` - Resource create parameters.
+    /// * `options` - Optional parameters for the request.
+    pub fn begin_create_or_replace_by_id(
+        &self,
+        resource_id: &ResourceId,
+        resource: RequestContent<TopLevelTrackedResource>,
+        options: Option<ResourcesTopLevelClientBeginCreateOrReplaceOptions<'_>>,
+    ) -> Result<Poller<ResourcesTopLevelClientBeginCreateOrReplaceOperationStatus>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/topLevelTrackedResources/{topLevelTrackedResourceName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.begin_create_or_replace(
+            path_params["resourceGroupName"],
+            path_params["topLevelTrackedResourceName"],
+            resource,
+            options,
+        )
+    }
+
     /// Delete a TopLevelTrackedResource
     ///
     /// # Arguments
@@ -473,6 +556,32 @@ impl ResourcesTopLevelClient {
         ))
     }
 
+    /// Calls [`ResourcesTopLevelClient::begin_delete()`](crate::generated::clients::ResourcesTopLevelClient::begin_delete())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/topLevelTrackedResources/{topLevelTrackedResourceName}`.
+    /// * `options` - Optional parameters for the request.
+    pub fn begin_delete_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<ResourcesTopLevelClientBeginDeleteOptions<'_>>,
+    ) -> Result<Poller<ResourcesTopLevelClientBeginDeleteOperationStatus>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/topLevelTrackedResources/{topLevelTrackedResourceName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.begin_delete(
+            path_params["resourceGroupName"],
+            path_params["topLevelTrackedResourceName"],
+            options,
+        )
+    }
+
     /// Update a TopLevelTrackedResource
     ///
     /// # Arguments
@@ -666,6 +775,35 @@ impl ResourcesTopLevelClient {
         ))
     }
 
+    /// Calls [`ResourcesTopLevelClient::begin_update()`](crate::generated::clients::ResourcesTopLevelClient::begin_update())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/topLevelTrackedResources/{topLevelTrackedResourceName}`.
+    /// * `properties` - The resource properties to be updated.
+    /// * `options` - Optional parameters for the request.
+    pub fn begin_update_by_id(
+        &self,
+        resource_id: &ResourceId,
+        properties: RequestContent<TopLevelTrackedResource>,
+        options: Option<ResourcesTopLevelClientBeginUpdateOptions<'_>>,
+    ) -> Result<Poller<ResourcesTopLevelClientBeginUpdateOperationStatus>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/topLevelTrackedResources/{topLevelTrackedResourceName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.begin_update(
+            path_params["resourceGroupName"],
+            path_params["topLevelTrackedResourceName"],
+            properties,
+            options,
+        )
+    }
+
     /// Get a TopLevelTrackedResource
     ///
     /// # Arguments
@@ -724,6 +862,33 @@ impl ResourcesTopLevelClient {
         Ok(rsp.into())
     }
 
+    /// Calls [`ResourcesTopLevelClient::get()`](crate::generated::clients::ResourcesTopLevelClient::get()) with its path parameters
+    /// taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/topLevelTrackedResources/{topLevelTrackedResourceName}`.
+    /// * `options` - Optional parameters for the request.
+    pub async fn get_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<ResourcesTopLevelClientGetOptions<'_>>,
+    ) -> Result<Response<TopLevelTrackedResource>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/topLevelTrackedResources/{topLevelTrackedResourceName}")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.get(
+            path_params["resourceGroupName"],
+            path_params["topLevelTrackedResourceName"],
+            options,
+        )
+        .await
+    }
+
     /// List TopLevelTrackedResource resources by resource group
     ///
     /// # Arguments
@@ -809,6 +974,28 @@ impl ResourcesTopLevelClient {
         ))
     }
 
+    /// Calls [`ResourcesTopLevelClient::list_by_resource_group()`](crate::generated::clients::ResourcesTopLevelClient::list_by_resource_group())
+    /// with its path parameters taken from a resource ID.
+    ///
+    /// # Arguments
+    ///
+    /// * `resource_id` - The resource ID from which the path parameters are taken. It must match the start of the path `/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/topLevelTrackedResources`.
+    /// * `options` - Optional parameters for the request.
+    pub fn list_by_resource_group_by_id(
+        &self,
+        resource_id: &ResourceId,
+        options: Option<ResourcesTopLevelClientListByResourceGroupOptions<'_>>,
+    ) -> Result<Pager<TopLevelTrackedResourceListResult>> {
+        let path_params = resource_id.path_params("/subscriptions/{subscriptionId}/resourceGroups/{resourceGroupName}/providers/Azure.ResourceManager.Resources/topLevelTrackedResources")?;
+        if !path_params["subscriptionId"].eq_ignore_ascii_case(&self.subscription_id) {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::DataConversion,
+                format!("resource ID {resource_id} doesn't match the client's subscription_id"),
+            ));
+        }
+        self.list_by_resource_group(path_params["resourceGroupName"], options)
+    }
+
     /// List TopLevelTrackedResource resources by subscription ID
     ///
     /// # Arguments
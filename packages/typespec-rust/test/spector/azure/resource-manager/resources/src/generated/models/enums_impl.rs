@@ -26,10 +26,10 @@ impl FromStr for CreatedByType {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "Application" => CreatedByType::Application,
-            "Key" => CreatedByType::Key,
-            "ManagedIdentity" => CreatedByType::ManagedIdentity,
-            "User" => CreatedByType::User,
+            _ if s.eq_ignore_ascii_case("Application") => CreatedByType::Application,
+            _ if s.eq_ignore_ascii_case("Key") => CreatedByType::Key,
+            _ if s.eq_ignore_ascii_case("ManagedIdentity") => CreatedByType::ManagedIdentity,
+            _ if s.eq_ignore_ascii_case("User") => CreatedByType::User,
             _ => CreatedByType::UnknownValue(s.to_string()),
         })
     }
@@ -47,6 +47,12 @@ impl AsRef<str> for CreatedByType {
     }
 }
 
+impl PartialEq<str> for CreatedByType {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for CreatedByType {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -78,13 +84,13 @@ impl FromStr for ProvisioningState {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "Accepted" => ProvisioningState::Accepted,
-            "Canceled" => ProvisioningState::Canceled,
-            "Deleting" => ProvisioningState::Deleting,
-            "Failed" => ProvisioningState::Failed,
-            "Provisioning" => ProvisioningState::Provisioning,
-            "Succeeded" => ProvisioningState::Succeeded,
-            "Updating" => ProvisioningState::Updating,
+            _ if s.eq_ignore_ascii_case("Accepted") => ProvisioningState::Accepted,
+            _ if s.eq_ignore_ascii_case("Canceled") => ProvisioningState::Canceled,
+            _ if s.eq_ignore_ascii_case("Deleting") => ProvisioningState::Deleting,
+            _ if s.eq_ignore_ascii_case("Failed") => ProvisioningState::Failed,
+            _ if s.eq_ignore_ascii_case("Provisioning") => ProvisioningState::Provisioning,
+            _ if s.eq_ignore_ascii_case("Succeeded") => ProvisioningState::Succeeded,
+            _ if s.eq_ignore_ascii_case("Updating") => ProvisioningState::Updating,
             _ => ProvisioningState::UnknownValue(s.to_string()),
         })
     }
@@ -105,6 +111,12 @@ impl AsRef<str> for ProvisioningState {
     }
 }
 
+impl PartialEq<str> for ProvisioningState {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for ProvisioningState {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -135,9 +147,9 @@ impl FromStr for ResourceProvisioningState {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "Canceled" => ResourceProvisioningState::Canceled,
-            "Failed" => ResourceProvisioningState::Failed,
-            "Succeeded" => ResourceProvisioningState::Succeeded,
+            _ if s.eq_ignore_ascii_case("Canceled") => ResourceProvisioningState::Canceled,
+            _ if s.eq_ignore_ascii_case("Failed") => ResourceProvisioningState::Failed,
+            _ if s.eq_ignore_ascii_case("Succeeded") => ResourceProvisioningState::Succeeded,
             _ => ResourceProvisioningState::UnknownValue(s.to_string()),
         })
     }
@@ -154,6 +166,12 @@ impl AsRef<str> for ResourceProvisioningState {
     }
 }
 
+impl PartialEq<str> for ResourceProvisioningState {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for ResourceProvisioningState {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
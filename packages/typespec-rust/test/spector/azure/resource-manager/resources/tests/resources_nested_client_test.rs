@@ -12,10 +12,13 @@ use azure_core::{
     time::{Duration, OffsetDateTime},
 };
 use futures::StreamExt;
-use spector_armresources::models::{
-    CreatedByType, NestedProxyResource, NestedProxyResourceProperties, ProvisioningState,
-    ResourcesNestedClientBeginCreateOrReplaceOptions, ResourcesNestedClientBeginDeleteOptions,
-    ResourcesNestedClientBeginUpdateOptions,
+use spector_armresources::{
+    models::{
+        CreatedByType, NestedProxyResource, NestedProxyResourceProperties, ProvisioningState,
+        ResourceId, ResourcesNestedClientBeginCreateOrReplaceOptions,
+        ResourcesNestedClientBeginDeleteOptions, ResourcesNestedClientBeginUpdateOptions,
+    },
+    TopLevelTrackedResourceCollection,
 };
 use time::{Date, Month, Time};
 
@@ -60,6 +63,28 @@ async fn get() {
     );
 }
 
+#[tokio::test]
+async fn get_through_parent_handle() {
+    let client = common::create_client();
+    let resource_group: ResourceId =
+        "/subscriptions/00000000-0000-0000-0000-000000000000/resourceGroups/test-rg"
+            .parse()
+            .unwrap();
+    let nested = TopLevelTrackedResourceCollection::new(&client, resource_group)
+        .handle("top")
+        .unwrap()
+        .nested_proxy_resources()
+        .handle("nested")
+        .unwrap();
+    assert_eq!(
+        "/subscriptions/00000000-0000-0000-0000-000000000000/resourceGroups/test-rg/providers/Azure.ResourceManager.Resources/topLevelTrackedResources/top/nestedProxyResources/nested",
+        nested.id().to_string()
+    );
+
+    let resource: NestedProxyResource = nested.get(None).await.unwrap().into_model().unwrap();
+    assert_eq!(get_valid_nested_resource().id, resource.id);
+}
+
 #[tokio::test]
 async fn list_by_top_level_tracked_resource() {
     let client = common::create_client();
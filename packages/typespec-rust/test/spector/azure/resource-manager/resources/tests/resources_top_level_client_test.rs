@@ -13,9 +13,10 @@ use azure_core::{
 };
 use futures::StreamExt;
 use spector_armresources::models::{
-    CreatedByType, ProvisioningState, ResourcesTopLevelClientBeginCreateOrReplaceOptions,
-    ResourcesTopLevelClientBeginDeleteOptions, ResourcesTopLevelClientBeginUpdateOptions,
-    TopLevelTrackedResource, TopLevelTrackedResourceProperties,
+    CreatedByType, ProvisioningState, ResourceId,
+    ResourcesTopLevelClientBeginCreateOrReplaceOptions, ResourcesTopLevelClientBeginDeleteOptions,
+    ResourcesTopLevelClientBeginUpdateOptions, TopLevelTrackedResource,
+    TopLevelTrackedResourceProperties,
 };
 use time::{Date, Month, Time};
 
@@ -286,6 +287,49 @@ async fn list_by_subscription_pages() {
     }
 }
 
+#[tokio::test]
+async fn get_by_id() {
+    let client = common::create_client();
+    let resource_id: ResourceId = get_valid_top_level_resource().id.unwrap().parse().unwrap();
+    assert_eq!(
+        "Azure.ResourceManager.Resources/topLevelTrackedResources",
+        resource_id.resource_type()
+    );
+    assert_eq!(Some("test-rg"), resource_id.resource_group_name());
+    assert_eq!("top", resource_id.name());
+
+    let resp = client
+        .get_resources_top_level_client()
+        .get_by_id(&resource_id, None)
+        .await
+        .unwrap();
+
+    let resource: TopLevelTrackedResource = resp.into_model().unwrap();
+    assert_eq!(Some(resource_id.to_string()), resource.id);
+}
+
+#[tokio::test]
+async fn get_by_id_wrong_type() {
+    let client = common::create_client();
+    let resource_id: ResourceId = "/subscriptions/00000000-0000-0000-0000-000000000000/resourceGroups/test-rg/providers/Azure.ResourceManager.Resources/topLevelTrackedResources/top/nestedProxyResources/nested"
+        .parse()
+        .unwrap();
+    client
+        .get_resources_top_level_client()
+        .get_by_id(&resource_id, None)
+        .await
+        .unwrap_err();
+
+    let resource_id: ResourceId = "/subscriptions/11111111-1111-1111-1111-111111111111/resourceGroups/test-rg/providers/Azure.ResourceManager.Resources/topLevelTrackedResources/top"
+        .parse()
+        .unwrap();
+    client
+        .get_resources_top_level_client()
+        .get_by_id(&resource_id, None)
+        .await
+        .unwrap_err();
+}
+
 fn get_valid_top_level_resource() -> TopLevelTrackedResource {
     TopLevelTrackedResource {
         id: Some("/subscriptions/00000000-0000-0000-0000-000000000000/resourceGroups/test-rg/providers/Azure.ResourceManager.Resources/topLevelTrackedResources/top".to_string()),
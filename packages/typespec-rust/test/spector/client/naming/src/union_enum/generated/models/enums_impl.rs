@@ -24,7 +24,7 @@ impl FromStr for ClientExtensibleEnum {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "value1" => ClientExtensibleEnum::EnumValue1,
+            _ if s.eq_ignore_ascii_case("value1") => ClientExtensibleEnum::EnumValue1,
             _ => ClientExtensibleEnum::UnknownValue(s.to_string()),
         })
     }
@@ -39,6 +39,12 @@ impl AsRef<str> for ClientExtensibleEnum {
     }
 }
 
+impl PartialEq<str> for ClientExtensibleEnum {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for ClientExtensibleEnum {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -62,8 +68,8 @@ impl FromStr for ExtensibleEnum {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "value1" => ExtensibleEnum::ClientEnumValue1,
-            "value2" => ExtensibleEnum::ClientEnumValue2,
+            _ if s.eq_ignore_ascii_case("value1") => ExtensibleEnum::ClientEnumValue1,
+            _ if s.eq_ignore_ascii_case("value2") => ExtensibleEnum::ClientEnumValue2,
             _ => ExtensibleEnum::UnknownValue(s.to_string()),
         })
     }
@@ -79,6 +85,12 @@ impl AsRef<str> for ExtensibleEnum {
     }
 }
 
+impl PartialEq<str> for ExtensibleEnum {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for ExtensibleEnum {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -14,11 +14,12 @@ use azure_core::{
     error::CheckSuccessOptions,
     fmt::SafeDebug,
     http::{
-        ClientOptions, Method, NoFormat, Pipeline, PipelineSendOptions, Request, Response, Url,
-        UrlExt,
+        ClientMethodOptions, ClientOptions, Method, NoFormat, Pipeline, PipelineSendOptions,
+        RawResponse, Request, Response, Url, UrlExt,
     },
     tracing, Result,
 };
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
 
 #[tracing::client]
 pub struct ClientAClient {
@@ -56,7 +57,10 @@ impl ClientAClient {
             ));
         }
         let mut host = String::from("client/structure/{client}");
-        host = host.replace("{client}", client.as_ref());
+        host = host.replace(
+            "{client}",
+            &utf8_percent_encode(client.as_ref(), ENDPOINT_PATH_ENCODE_SET).to_string(),
+        );
         endpoint = endpoint.join(&host)?;
         Ok(Self {
             endpoint,
@@ -71,11 +75,67 @@ impl ClientAClient {
         })
     }
 
+    /// Creates a new ClientAClient from a complete endpoint URL requiring no authentication.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - The complete service endpoint URL. It's used as-is without any host template substitution.
+    /// * `options` - Optional configuration for the client.
+    #[tracing::new("Client.Structure.MultiClient.ClientA")]
+    pub fn with_no_credential_and_endpoint_url(
+        endpoint: &str,
+        options: Option<ClientAClientOptions>,
+    ) -> Result<Self> {
+        let options = options.unwrap_or_default();
+        let endpoint = Url::parse(endpoint)?;
+        if !endpoint.scheme().starts_with("http") {
+            return Err(azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                format!("{endpoint} must use http(s)"),
+            ));
+        }
+        Ok(Self {
+            endpoint,
+            pipeline: Pipeline::new(
+                option_env!("CARGO_PKG_NAME"),
+                option_env!("CARGO_PKG_VERSION"),
+                options.client_options,
+                Vec::default(),
+                Vec::default(),
+                None,
+            ),
+        })
+    }
+
     /// Returns the Url associated with this client.
     pub fn endpoint(&self) -> &Url {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     ///
     /// # Arguments
     ///
@@ -166,3 +226,20 @@ impl ClientAClient {
         Ok(rsp.into())
     }
 }
+
+/// Characters that are percent-encoded when substituting parameters into path segments of the endpoint.
+const ENDPOINT_PATH_ENCODE_SET: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'&')
+    .add(b'+')
+    .add(b'/')
+    .add(b'<')
+    .add(b'=')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
@@ -62,9 +62,9 @@ impl FromStr for ColorsExtensibleEnum {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "blue" => ColorsExtensibleEnum::Blue,
-            "green" => ColorsExtensibleEnum::Green,
-            "red" => ColorsExtensibleEnum::Red,
+            _ if s.eq_ignore_ascii_case("blue") => ColorsExtensibleEnum::Blue,
+            _ if s.eq_ignore_ascii_case("green") => ColorsExtensibleEnum::Green,
+            _ if s.eq_ignore_ascii_case("red") => ColorsExtensibleEnum::Red,
             _ => ColorsExtensibleEnum::UnknownValue(s.to_string()),
         })
     }
@@ -81,6 +81,12 @@ impl AsRef<str> for ColorsExtensibleEnum {
     }
 }
 
+impl PartialEq<str> for ColorsExtensibleEnum {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for ColorsExtensibleEnum {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -9,11 +9,14 @@ use crate::request_body::generated::models::{
     BytesRequestBodyClientOctetStreamOptions,
 };
 use azure_core::{
+    base64,
     error::CheckSuccessOptions,
     http::{
-        Method, NoFormat, Pipeline, PipelineSendOptions, Request, RequestContent, Response, Url,
-        UrlExt,
+        ClientMethodOptions, Method, NoFormat, Pipeline, PipelineSendOptions, RawResponse, Request,
+        RequestContent, Response, Url, UrlExt,
     },
+    json::to_json,
+    stream::SeekableStream,
     tracing, Bytes, Result,
 };
 
@@ -29,6 +32,30 @@ impl BytesRequestBodyClient {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     ///
     /// # Arguments
     ///
@@ -36,7 +63,7 @@ impl BytesRequestBodyClient {
     #[tracing::function("Encode.Bytes.RequestBody.base64")]
     pub async fn base64(
         &self,
-        value: RequestContent<Vec<u8>>,
+        value: &[u8],
         options: Option<BytesRequestBodyClientBase64Options<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
@@ -45,7 +72,7 @@ impl BytesRequestBodyClient {
         url.append_path("/encode/bytes/body/request/base64");
         let mut request = Request::new(url, Method::Post);
         request.insert_header("content-type", "application/json");
-        request.set_body(value);
+        request.set_body(to_json(&base64::encode(value))?);
         let rsp = self
             .pipeline
             .send(
@@ -69,7 +96,7 @@ impl BytesRequestBodyClient {
     #[tracing::function("Encode.Bytes.RequestBody.base64url")]
     pub async fn base64_url(
         &self,
-        value: RequestContent<Vec<u8>>,
+        value: &[u8],
         options: Option<BytesRequestBodyClientBase64UrlOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
@@ -78,7 +105,7 @@ impl BytesRequestBodyClient {
         url.append_path("/encode/bytes/body/request/base64url");
         let mut request = Request::new(url, Method::Post);
         request.insert_header("content-type", "application/json");
-        request.set_body(value);
+        request.set_body(to_json(&base64::encode_url_safe(value))?);
         let rsp = self
             .pipeline
             .send(
@@ -128,6 +155,44 @@ impl BytesRequestBodyClient {
         Ok(rsp.into())
     }
 
+    /// Streaming variant of [`BytesRequestBodyClient::custom_content_type()`](crate::request_body::generated::clients::BytesRequestBodyClient::custom_content_type()).
+    ///
+    /// The request body is read from a seekable stream and its length is sent as the content length. The stream is rewound
+    /// when the request is retried.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Encode.Bytes.RequestBody.customContentType")]
+    pub async fn custom_content_type_from_stream(
+        &self,
+        value: Box<dyn SeekableStream>,
+        options: Option<BytesRequestBodyClientCustomContentTypeOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/encode/bytes/body/request/custom-content-type");
+        let mut request = Request::new(url, Method::Post);
+        request.insert_header("content-type", "image/png");
+        request.insert_header("content-length", value.len().to_string());
+        request.set_body(value);
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
     ///
     /// # Arguments
     ///
@@ -161,6 +226,44 @@ impl BytesRequestBodyClient {
         Ok(rsp.into())
     }
 
+    /// Streaming variant of [`BytesRequestBodyClient::default()`](crate::request_body::generated::clients::BytesRequestBodyClient::default()).
+    ///
+    /// The request body is read from a seekable stream and its length is sent as the content length. The stream is rewound
+    /// when the request is retried.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Encode.Bytes.RequestBody.default")]
+    pub async fn default_from_stream(
+        &self,
+        value: Box<dyn SeekableStream>,
+        options: Option<BytesRequestBodyClientDefaultOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/encode/bytes/body/request/default");
+        let mut request = Request::new(url, Method::Post);
+        request.insert_header("content-type", "application/octet-stream");
+        request.insert_header("content-length", value.len().to_string());
+        request.set_body(value);
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
+
     ///
     /// # Arguments
     ///
@@ -193,4 +296,42 @@ impl BytesRequestBodyClient {
             .await?;
         Ok(rsp.into())
     }
+
+    /// Streaming variant of [`BytesRequestBodyClient::octet_stream()`](crate::request_body::generated::clients::BytesRequestBodyClient::octet_stream()).
+    ///
+    /// The request body is read from a seekable stream and its length is sent as the content length. The stream is rewound
+    /// when the request is retried.
+    ///
+    /// # Arguments
+    ///
+    /// * `options` - Optional parameters for the request.
+    #[tracing::function("Encode.Bytes.RequestBody.octetStream")]
+    pub async fn octet_stream_from_stream(
+        &self,
+        value: Box<dyn SeekableStream>,
+        options: Option<BytesRequestBodyClientOctetStreamOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        let options = options.unwrap_or_default();
+        let ctx = options.method_options.context.to_borrowed();
+        let mut url = self.endpoint.clone();
+        url.append_path("/encode/bytes/body/request/octet-stream");
+        let mut request = Request::new(url, Method::Post);
+        request.insert_header("content-type", "application/octet-stream");
+        request.insert_header("content-length", value.len().to_string());
+        request.set_body(value);
+        let rsp = self
+            .pipeline
+            .send(
+                &ctx,
+                &mut request,
+                Some(PipelineSendOptions {
+                    check_success: CheckSuccessOptions {
+                        success_codes: &[204],
+                    },
+                    ..Default::default()
+                }),
+            )
+            .await?;
+        Ok(rsp.into())
+    }
 }
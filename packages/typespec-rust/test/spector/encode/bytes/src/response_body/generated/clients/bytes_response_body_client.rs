@@ -4,15 +4,15 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::response_body::generated::models::{
-    BytesResponseBodyClientBase64Options, BytesResponseBodyClientBase64UrlOptions,
-    BytesResponseBodyClientCustomContentTypeOptions, BytesResponseBodyClientDefaultOptions,
-    BytesResponseBodyClientOctetStreamOptions,
+    Base64Format, Base64UrlFormat, BytesResponseBodyClientBase64Options,
+    BytesResponseBodyClientBase64UrlOptions, BytesResponseBodyClientCustomContentTypeOptions,
+    BytesResponseBodyClientDefaultOptions, BytesResponseBodyClientOctetStreamOptions,
 };
 use azure_core::{
     error::CheckSuccessOptions,
     http::{
-        AsyncResponse, Method, Pipeline, PipelineSendOptions, PipelineStreamOptions, Request,
-        Response, Url, UrlExt,
+        AsyncResponse, ClientMethodOptions, Method, Pipeline, PipelineSendOptions,
+        PipelineStreamOptions, RawResponse, Request, Response, Url, UrlExt,
     },
     tracing, Result,
 };
@@ -29,6 +29,30 @@ impl BytesResponseBodyClient {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     ///
     /// # Arguments
     ///
@@ -37,7 +61,7 @@ impl BytesResponseBodyClient {
     pub async fn base64(
         &self,
         options: Option<BytesResponseBodyClientBase64Options<'_>>,
-    ) -> Result<Response<Vec<u8>>> {
+    ) -> Result<Response<Vec<u8>, Base64Format>> {
         let options = options.unwrap_or_default();
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
@@ -68,7 +92,7 @@ impl BytesResponseBodyClient {
     pub async fn base64_url(
         &self,
         options: Option<BytesResponseBodyClientBase64UrlOptions<'_>>,
-    ) -> Result<Response<Vec<u8>>> {
+    ) -> Result<Response<Vec<u8>, Base64UrlFormat>> {
         let options = options.unwrap_or_default();
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
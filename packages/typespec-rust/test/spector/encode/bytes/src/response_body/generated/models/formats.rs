@@ -0,0 +1,40 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{
+    base64,
+    error::{Error, ErrorKind},
+    http::{
+        response::{DeserializeWith, ResponseBody},
+        Format,
+    },
+    Result,
+};
+
+/// The format for payloads that are a JSON string containing base64 encoded bytes.
+#[derive(Clone, Debug, Default)]
+pub struct Base64Format;
+
+impl Format for Base64Format {}
+
+impl DeserializeWith<Base64Format> for Vec<u8> {
+    fn deserialize_with(body: ResponseBody) -> Result<Self> {
+        let encoded: String = body.json()?;
+        base64::decode(encoded).map_err(|err| Error::new(ErrorKind::DataConversion, err))
+    }
+}
+
+/// The format for payloads that are a JSON string containing base64url encoded bytes.
+#[derive(Clone, Debug, Default)]
+pub struct Base64UrlFormat;
+
+impl Format for Base64UrlFormat {}
+
+impl DeserializeWith<Base64UrlFormat> for Vec<u8> {
+    fn deserialize_with(body: ResponseBody) -> Result<Self> {
+        let encoded: String = body.json()?;
+        base64::decode_url_safe(encoded).map_err(|err| Error::new(ErrorKind::DataConversion, err))
+    }
+}
@@ -3,5 +3,7 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
+mod formats;
 mod method_options;
+pub use formats::*;
 pub use method_options::*;
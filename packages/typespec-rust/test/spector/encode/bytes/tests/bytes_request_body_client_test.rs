@@ -0,0 +1,99 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use azure_core::stream::BytesStream;
+use spector_bytes::BytesClient;
+use std::fs;
+
+#[tokio::test]
+async fn base64() {
+    let client = BytesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    client
+        .get_bytes_request_body_client()
+        .base64("test".as_bytes(), None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn base64_url() {
+    let client = BytesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    client
+        .get_bytes_request_body_client()
+        .base64_url("test".as_bytes(), None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn custom_content_type() {
+    let client = BytesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let image_png =
+        fs::read("../../../../node_modules/@typespec/http-specs/assets/image.png").unwrap();
+    client
+        .get_bytes_request_body_client()
+        .custom_content_type(image_png.into(), None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn custom_content_type_from_stream() {
+    let client = BytesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let image_png =
+        fs::read("../../../../node_modules/@typespec/http-specs/assets/image.png").unwrap();
+    client
+        .get_bytes_request_body_client()
+        .custom_content_type_from_stream(Box::new(BytesStream::new(image_png)), None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn default() {
+    let client = BytesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let image_png =
+        fs::read("../../../../node_modules/@typespec/http-specs/assets/image.png").unwrap();
+    client
+        .get_bytes_request_body_client()
+        .default(image_png.into(), None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn default_from_stream() {
+    let client = BytesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let image_png =
+        fs::read("../../../../node_modules/@typespec/http-specs/assets/image.png").unwrap();
+    client
+        .get_bytes_request_body_client()
+        .default_from_stream(Box::new(BytesStream::new(image_png)), None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn octet_stream() {
+    let client = BytesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let image_png =
+        fs::read("../../../../node_modules/@typespec/http-specs/assets/image.png").unwrap();
+    client
+        .get_bytes_request_body_client()
+        .octet_stream(image_png.into(), None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn octet_stream_from_stream() {
+    let client = BytesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let image_png =
+        fs::read("../../../../node_modules/@typespec/http-specs/assets/image.png").unwrap();
+    client
+        .get_bytes_request_body_client()
+        .octet_stream_from_stream(Box::new(BytesStream::new(image_png)), None)
+        .await
+        .unwrap();
+}
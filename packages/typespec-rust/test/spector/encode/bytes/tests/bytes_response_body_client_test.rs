@@ -0,0 +1,72 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use spector_bytes::BytesClient;
+use std::fs;
+
+#[tokio::test]
+async fn base64() {
+    let client = BytesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let resp = client
+        .get_bytes_response_body_client()
+        .base64(None)
+        .await
+        .unwrap();
+    let value: Vec<u8> = resp.into_model().unwrap();
+    assert_eq!(value, "test".as_bytes());
+}
+
+#[tokio::test]
+async fn base64_url() {
+    let client = BytesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let resp = client
+        .get_bytes_response_body_client()
+        .base64_url(None)
+        .await
+        .unwrap();
+    let value: Vec<u8> = resp.into_model().unwrap();
+    assert_eq!(value, "test".as_bytes());
+}
+
+#[tokio::test]
+async fn custom_content_type() {
+    let client = BytesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let resp = client
+        .get_bytes_response_body_client()
+        .custom_content_type(None)
+        .await
+        .unwrap();
+    let body = resp.into_body().collect().await.unwrap();
+    let image_png =
+        fs::read("../../../../node_modules/@typespec/http-specs/assets/image.png").unwrap();
+    assert_eq!(body, image_png);
+}
+
+#[tokio::test]
+async fn default() {
+    let client = BytesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let resp = client
+        .get_bytes_response_body_client()
+        .default(None)
+        .await
+        .unwrap();
+    let body = resp.into_body().collect().await.unwrap();
+    let image_png =
+        fs::read("../../../../node_modules/@typespec/http-specs/assets/image.png").unwrap();
+    assert_eq!(body, image_png);
+}
+
+#[tokio::test]
+async fn octet_stream() {
+    let client = BytesClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let resp = client
+        .get_bytes_response_body_client()
+        .octet_stream(None)
+        .await
+        .unwrap();
+    let body = resp.into_body().collect().await.unwrap();
+    let image_png =
+        fs::read("../../../../node_modules/@typespec/http-specs/assets/image.png").unwrap();
+    assert_eq!(body, image_png);
+}
@@ -4,14 +4,15 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::string_body::generated::models::{
-    MediaTypeStringBodyClientGetAsJsonOptions, MediaTypeStringBodyClientGetAsTextOptions,
-    MediaTypeStringBodyClientSendAsJsonOptions, MediaTypeStringBodyClientSendAsTextOptions,
+    check_text_charset, MediaTypeStringBodyClientGetAsJsonOptions,
+    MediaTypeStringBodyClientGetAsTextOptions, MediaTypeStringBodyClientSendAsJsonOptions,
+    MediaTypeStringBodyClientSendAsTextOptions, TextFormat,
 };
 use azure_core::{
     error::CheckSuccessOptions,
     http::{
-        Method, NoFormat, Pipeline, PipelineSendOptions, Request, RequestContent, Response, Url,
-        UrlExt,
+        ClientMethodOptions, Method, NoFormat, Pipeline, PipelineSendOptions, RawResponse, Request,
+        RequestContent, Response, Url, UrlExt,
     },
     tracing, Result,
 };
@@ -28,6 +29,30 @@ impl MediaTypeStringBodyClient {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     ///
     /// # Arguments
     ///
@@ -67,7 +92,7 @@ impl MediaTypeStringBodyClient {
     pub async fn get_as_text(
         &self,
         options: Option<MediaTypeStringBodyClientGetAsTextOptions<'_>>,
-    ) -> Result<Response<String, NoFormat>> {
+    ) -> Result<Response<String, TextFormat>> {
         let options = options.unwrap_or_default();
         let ctx = options.method_options.context.to_borrowed();
         let mut url = self.endpoint.clone();
@@ -87,6 +112,7 @@ impl MediaTypeStringBodyClient {
                 }),
             )
             .await?;
+        check_text_charset(rsp.headers())?;
         Ok(rsp.into())
     }
 
@@ -130,7 +156,7 @@ impl MediaTypeStringBodyClient {
     #[tracing::function("Payload.MediaType.StringBody.sendAsText")]
     pub async fn send_as_text(
         &self,
-        text: RequestContent<String, NoFormat>,
+        text: RequestContent<String, TextFormat>,
         options: Option<MediaTypeStringBodyClientSendAsTextOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
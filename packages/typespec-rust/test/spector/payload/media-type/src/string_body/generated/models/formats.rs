@@ -0,0 +1,51 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use azure_core::{
+    error::{Error, ErrorKind},
+    http::{
+        headers::{Headers, CONTENT_TYPE},
+        response::{DeserializeWith, ResponseBody},
+        Format,
+    },
+    Result,
+};
+
+/// The format for textual payloads (e.g. `text/plain`) which are exchanged as UTF-8 strings.
+#[derive(Clone, Debug, Default)]
+pub struct TextFormat;
+
+impl Format for TextFormat {}
+
+impl DeserializeWith<TextFormat> for String {
+    fn deserialize_with(body: ResponseBody) -> Result<Self> {
+        body.into_string()
+    }
+}
+
+/// Returns an error if the `content-type` of a textual response specifies a charset that isn't UTF-8.
+///
+/// A missing charset is treated as UTF-8 as is US-ASCII, which is a subset of UTF-8.
+pub(crate) fn check_text_charset(headers: &Headers) -> Result<()> {
+    let Some(content_type) = headers.get_optional_str(&CONTENT_TYPE) else {
+        return Ok(());
+    };
+    for param in content_type.split(';').skip(1) {
+        let Some((name, value)) = param.split_once('=') else {
+            continue;
+        };
+        if !name.trim().eq_ignore_ascii_case("charset") {
+            continue;
+        }
+        let charset = value.trim().trim_matches('"');
+        if !charset.eq_ignore_ascii_case("utf-8") && !charset.eq_ignore_ascii_case("us-ascii") {
+            return Err(Error::with_message(
+                ErrorKind::DataConversion,
+                format!("unsupported charset '{charset}' for textual response"),
+            ));
+        }
+    }
+    Ok(())
+}
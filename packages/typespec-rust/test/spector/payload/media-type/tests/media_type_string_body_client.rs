@@ -25,7 +25,7 @@ async fn get_as_text() {
         .get_as_text(None)
         .await
         .unwrap();
-    let resp = resp.into_body().into_string().unwrap();
+    let resp: String = resp.into_model().unwrap();
     assert_eq!(resp, "{cat}".to_string());
 }
 
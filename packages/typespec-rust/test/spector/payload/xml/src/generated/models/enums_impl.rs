@@ -25,9 +25,9 @@ impl FromStr for Status {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "error" => Status::Error,
-            "pending" => Status::Pending,
-            "success" => Status::Success,
+            _ if s.eq_ignore_ascii_case("error") => Status::Error,
+            _ if s.eq_ignore_ascii_case("pending") => Status::Pending,
+            _ if s.eq_ignore_ascii_case("success") => Status::Success,
             _ => Status::UnknownValue(s.to_string()),
         })
     }
@@ -44,6 +44,12 @@ impl AsRef<str> for Status {
     }
 }
 
+impl PartialEq<str> for Status {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for Status {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
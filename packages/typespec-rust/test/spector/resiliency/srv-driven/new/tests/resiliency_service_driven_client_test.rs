@@ -140,3 +140,29 @@ async fn from_one_required_v2() {
         .await
         .unwrap();
 }
+
+#[test]
+fn invalid_service_deployment_version() {
+    for version in ["", ".", ".."] {
+        assert!(ResiliencyServiceDrivenClient::with_no_credential(
+            "http://localhost:3000",
+            version.to_string(),
+            None,
+        )
+        .is_err());
+    }
+}
+
+#[test]
+fn encoded_service_deployment_version() {
+    let client = ResiliencyServiceDrivenClient::with_no_credential(
+        "http://localhost:3000",
+        "v2/../v1".to_string(),
+        None,
+    )
+    .unwrap();
+    assert_eq!(
+        client.endpoint().as_str(),
+        "http://localhost:3000/resiliency/service-driven/client:v2/service:v2%2F..%2Fv1/api-version:v2"
+    );
+}
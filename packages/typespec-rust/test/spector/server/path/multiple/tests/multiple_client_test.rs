@@ -18,3 +18,17 @@ async fn with_operation_path_param() {
         .await
         .unwrap();
 }
+
+#[tokio::test]
+async fn no_operation_params_with_endpoint_url() {
+    let client = MultipleClient::with_no_credential_and_endpoint_url(
+        "http://localhost:3000/server/path/multiple/v1.0",
+        None,
+    )
+    .unwrap();
+    assert_eq!(
+        client.endpoint().as_str(),
+        "http://localhost:3000/server/path/multiple/v1.0"
+    );
+    client.no_operation_params(None).await.unwrap();
+}
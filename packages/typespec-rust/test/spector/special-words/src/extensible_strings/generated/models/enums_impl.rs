@@ -56,39 +56,39 @@ impl FromStr for ExtensibleString {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "and" => ExtensibleString::And,
-            "as" => ExtensibleString::As,
-            "assert" => ExtensibleString::Assert,
-            "async" => ExtensibleString::Async,
-            "await" => ExtensibleString::Await,
-            "break" => ExtensibleString::Break,
-            "class" => ExtensibleString::Class,
-            "constructor" => ExtensibleString::Constructor,
-            "continue" => ExtensibleString::Continue,
-            "def" => ExtensibleString::Def,
-            "del" => ExtensibleString::Del,
-            "elif" => ExtensibleString::Elif,
-            "else" => ExtensibleString::Else,
-            "except" => ExtensibleString::Except,
-            "exec" => ExtensibleString::Exec,
-            "finally" => ExtensibleString::Finally,
-            "for" => ExtensibleString::For,
-            "from" => ExtensibleString::From,
-            "global" => ExtensibleString::Global,
-            "if" => ExtensibleString::If,
-            "import" => ExtensibleString::Import,
-            "in" => ExtensibleString::In,
-            "is" => ExtensibleString::Is,
-            "lambda" => ExtensibleString::Lambda,
-            "not" => ExtensibleString::Not,
-            "or" => ExtensibleString::Or,
-            "pass" => ExtensibleString::Pass,
-            "raise" => ExtensibleString::Raise,
-            "return" => ExtensibleString::Return,
-            "try" => ExtensibleString::Try,
-            "while" => ExtensibleString::While,
-            "with" => ExtensibleString::With,
-            "yield" => ExtensibleString::Yield,
+            _ if s.eq_ignore_ascii_case("and") => ExtensibleString::And,
+            _ if s.eq_ignore_ascii_case("as") => ExtensibleString::As,
+            _ if s.eq_ignore_ascii_case("assert") => ExtensibleString::Assert,
+            _ if s.eq_ignore_ascii_case("async") => ExtensibleString::Async,
+            _ if s.eq_ignore_ascii_case("await") => ExtensibleString::Await,
+            _ if s.eq_ignore_ascii_case("break") => ExtensibleString::Break,
+            _ if s.eq_ignore_ascii_case("class") => ExtensibleString::Class,
+            _ if s.eq_ignore_ascii_case("constructor") => ExtensibleString::Constructor,
+            _ if s.eq_ignore_ascii_case("continue") => ExtensibleString::Continue,
+            _ if s.eq_ignore_ascii_case("def") => ExtensibleString::Def,
+            _ if s.eq_ignore_ascii_case("del") => ExtensibleString::Del,
+            _ if s.eq_ignore_ascii_case("elif") => ExtensibleString::Elif,
+            _ if s.eq_ignore_ascii_case("else") => ExtensibleString::Else,
+            _ if s.eq_ignore_ascii_case("except") => ExtensibleString::Except,
+            _ if s.eq_ignore_ascii_case("exec") => ExtensibleString::Exec,
+            _ if s.eq_ignore_ascii_case("finally") => ExtensibleString::Finally,
+            _ if s.eq_ignore_ascii_case("for") => ExtensibleString::For,
+            _ if s.eq_ignore_ascii_case("from") => ExtensibleString::From,
+            _ if s.eq_ignore_ascii_case("global") => ExtensibleString::Global,
+            _ if s.eq_ignore_ascii_case("if") => ExtensibleString::If,
+            _ if s.eq_ignore_ascii_case("import") => ExtensibleString::Import,
+            _ if s.eq_ignore_ascii_case("in") => ExtensibleString::In,
+            _ if s.eq_ignore_ascii_case("is") => ExtensibleString::Is,
+            _ if s.eq_ignore_ascii_case("lambda") => ExtensibleString::Lambda,
+            _ if s.eq_ignore_ascii_case("not") => ExtensibleString::Not,
+            _ if s.eq_ignore_ascii_case("or") => ExtensibleString::Or,
+            _ if s.eq_ignore_ascii_case("pass") => ExtensibleString::Pass,
+            _ if s.eq_ignore_ascii_case("raise") => ExtensibleString::Raise,
+            _ if s.eq_ignore_ascii_case("return") => ExtensibleString::Return,
+            _ if s.eq_ignore_ascii_case("try") => ExtensibleString::Try,
+            _ if s.eq_ignore_ascii_case("while") => ExtensibleString::While,
+            _ if s.eq_ignore_ascii_case("with") => ExtensibleString::With,
+            _ if s.eq_ignore_ascii_case("yield") => ExtensibleString::Yield,
             _ => ExtensibleString::UnknownValue(s.to_string()),
         })
     }
@@ -135,6 +135,12 @@ impl AsRef<str> for ExtensibleString {
     }
 }
 
+impl PartialEq<str> for ExtensibleString {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for ExtensibleString {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
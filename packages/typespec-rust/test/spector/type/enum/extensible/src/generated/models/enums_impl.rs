@@ -30,13 +30,13 @@ impl FromStr for DaysOfWeekExtensibleEnum {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "Friday" => DaysOfWeekExtensibleEnum::Friday,
-            "Monday" => DaysOfWeekExtensibleEnum::Monday,
-            "Saturday" => DaysOfWeekExtensibleEnum::Saturday,
-            "Sunday" => DaysOfWeekExtensibleEnum::Sunday,
-            "Thursday" => DaysOfWeekExtensibleEnum::Thursday,
-            "Tuesday" => DaysOfWeekExtensibleEnum::Tuesday,
-            "Wednesday" => DaysOfWeekExtensibleEnum::Wednesday,
+            _ if s.eq_ignore_ascii_case("Friday") => DaysOfWeekExtensibleEnum::Friday,
+            _ if s.eq_ignore_ascii_case("Monday") => DaysOfWeekExtensibleEnum::Monday,
+            _ if s.eq_ignore_ascii_case("Saturday") => DaysOfWeekExtensibleEnum::Saturday,
+            _ if s.eq_ignore_ascii_case("Sunday") => DaysOfWeekExtensibleEnum::Sunday,
+            _ if s.eq_ignore_ascii_case("Thursday") => DaysOfWeekExtensibleEnum::Thursday,
+            _ if s.eq_ignore_ascii_case("Tuesday") => DaysOfWeekExtensibleEnum::Tuesday,
+            _ if s.eq_ignore_ascii_case("Wednesday") => DaysOfWeekExtensibleEnum::Wednesday,
             _ => DaysOfWeekExtensibleEnum::UnknownValue(s.to_string()),
         })
     }
@@ -57,6 +57,12 @@ impl AsRef<str> for DaysOfWeekExtensibleEnum {
     }
 }
 
+impl PartialEq<str> for DaysOfWeekExtensibleEnum {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for DaysOfWeekExtensibleEnum {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
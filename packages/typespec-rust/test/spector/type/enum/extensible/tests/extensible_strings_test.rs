@@ -59,3 +59,18 @@ async fn put_unknown_value() {
 
     assert_eq!(resp.status(), 204);
 }
+
+#[test]
+fn parse_ignores_case() {
+    let value: DaysOfWeekExtensibleEnum = "monday".parse().unwrap();
+    assert_eq!(value, DaysOfWeekExtensibleEnum::Monday);
+    assert!(DaysOfWeekExtensibleEnum::Monday == *"MONDAY");
+
+    // unknown values keep their original spelling
+    let value: DaysOfWeekExtensibleEnum = "weekend".parse().unwrap();
+    assert_eq!(
+        value,
+        DaysOfWeekExtensibleEnum::UnknownValue("weekend".to_string())
+    );
+    assert_eq!(value.as_ref(), "weekend");
+}
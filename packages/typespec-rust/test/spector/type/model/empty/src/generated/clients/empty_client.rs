@@ -7,12 +7,13 @@ use crate::generated::models::{
     EmptyClientGetEmptyOptions, EmptyClientPostRoundTripEmptyOptions, EmptyClientPutEmptyOptions,
     EmptyInput, EmptyInputOutput, EmptyOutput,
 };
+use async_trait::async_trait;
 use azure_core::{
     error::CheckSuccessOptions,
     fmt::SafeDebug,
     http::{
-        ClientOptions, Method, NoFormat, Pipeline, PipelineSendOptions, Request, RequestContent,
-        Response, Url, UrlExt,
+        ClientMethodOptions, ClientOptions, Method, NoFormat, Pipeline, PipelineSendOptions,
+        RawResponse, Request, RequestContent, Response, Url, UrlExt,
     },
     tracing, Result,
 };
@@ -66,6 +67,30 @@ impl EmptyClient {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let ctx = options.context.to_borrowed();
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     ///
     /// # Arguments
     ///
@@ -164,3 +189,130 @@ impl EmptyClient {
         Ok(rsp.into())
     }
 }
+
+/// The methods of [`EmptyClient`] as a trait so that consumers can substitute a fake.
+///
+/// An in-memory implementation, `MockEmptyClient`, is available with the `mock` feature.
+#[async_trait]
+pub trait EmptyClientMethods: Send + Sync {
+    async fn get_empty(
+        &self,
+        options: Option<EmptyClientGetEmptyOptions<'_>>,
+    ) -> Result<Response<EmptyOutput>>;
+
+    async fn post_round_trip_empty(
+        &self,
+        body: RequestContent<EmptyInputOutput>,
+        options: Option<EmptyClientPostRoundTripEmptyOptions<'_>>,
+    ) -> Result<Response<EmptyInputOutput>>;
+
+    async fn put_empty(
+        &self,
+        input: RequestContent<EmptyInput>,
+        options: Option<EmptyClientPutEmptyOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>>;
+}
+
+#[async_trait]
+impl EmptyClientMethods for EmptyClient {
+    async fn get_empty(
+        &self,
+        options: Option<EmptyClientGetEmptyOptions<'_>>,
+    ) -> Result<Response<EmptyOutput>> {
+        EmptyClient::get_empty(self, options).await
+    }
+
+    async fn post_round_trip_empty(
+        &self,
+        body: RequestContent<EmptyInputOutput>,
+        options: Option<EmptyClientPostRoundTripEmptyOptions<'_>>,
+    ) -> Result<Response<EmptyInputOutput>> {
+        EmptyClient::post_round_trip_empty(self, body, options).await
+    }
+
+    async fn put_empty(
+        &self,
+        input: RequestContent<EmptyInput>,
+        options: Option<EmptyClientPutEmptyOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        EmptyClient::put_empty(self, input, options).await
+    }
+}
+
+/// An in-memory implementation of [`EmptyClientMethods`] that returns canned responses.
+///
+/// Each method returns the responses queued for it in order, and an error once none remain.
+#[cfg(feature = "mock")]
+#[derive(Default)]
+pub struct MockEmptyClient {
+    get_empty: std::sync::Mutex<std::collections::VecDeque<Response<EmptyOutput>>>,
+    post_round_trip_empty: std::sync::Mutex<std::collections::VecDeque<Response<EmptyInputOutput>>>,
+    put_empty: std::sync::Mutex<std::collections::VecDeque<Response<(), NoFormat>>>,
+}
+
+#[cfg(feature = "mock")]
+impl MockEmptyClient {
+    /// Queues a response for [`EmptyClientMethods::get_empty()`].
+    pub fn push_get_empty(&self, response: Response<EmptyOutput>) {
+        self.get_empty.lock().unwrap().push_back(response);
+    }
+
+    /// Queues a response for [`EmptyClientMethods::post_round_trip_empty()`].
+    pub fn push_post_round_trip_empty(&self, response: Response<EmptyInputOutput>) {
+        self.post_round_trip_empty
+            .lock()
+            .unwrap()
+            .push_back(response);
+    }
+
+    /// Queues a response for [`EmptyClientMethods::put_empty()`].
+    pub fn push_put_empty(&self, response: Response<(), NoFormat>) {
+        self.put_empty.lock().unwrap().push_back(response);
+    }
+}
+
+#[cfg(feature = "mock")]
+#[async_trait]
+impl EmptyClientMethods for MockEmptyClient {
+    async fn get_empty(
+        &self,
+        _options: Option<EmptyClientGetEmptyOptions<'_>>,
+    ) -> Result<Response<EmptyOutput>> {
+        self.get_empty.lock().unwrap().pop_front().ok_or_else(|| {
+            azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "no response is queued for MockEmptyClient::get_empty()",
+            )
+        })
+    }
+
+    async fn post_round_trip_empty(
+        &self,
+        _body: RequestContent<EmptyInputOutput>,
+        _options: Option<EmptyClientPostRoundTripEmptyOptions<'_>>,
+    ) -> Result<Response<EmptyInputOutput>> {
+        self.post_round_trip_empty
+            .lock()
+            .unwrap()
+            .pop_front()
+            .ok_or_else(|| {
+                azure_core::Error::with_message(
+                    azure_core::error::ErrorKind::Other,
+                    "no response is queued for MockEmptyClient::post_round_trip_empty()",
+                )
+            })
+    }
+
+    async fn put_empty(
+        &self,
+        _input: RequestContent<EmptyInput>,
+        _options: Option<EmptyClientPutEmptyOptions<'_>>,
+    ) -> Result<Response<(), NoFormat>> {
+        self.put_empty.lock().unwrap().pop_front().ok_or_else(|| {
+            azure_core::Error::with_message(
+                azure_core::error::ErrorKind::Other,
+                "no response is queued for MockEmptyClient::put_empty()",
+            )
+        })
+    }
+}
@@ -0,0 +1,225 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use axum::{
+    body::Bytes,
+    extract::{Query, State},
+    http::{header::CONTENT_TYPE, HeaderValue},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Router,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use spector_empty::models::{EmptyInput, EmptyInputOutput, EmptyOutput};
+use std::{collections::HashMap, io, net::Ipv4Addr, sync::Arc};
+use tokio::{net::TcpListener, task::JoinHandle};
+
+pub use async_trait::async_trait;
+pub use axum::http::{HeaderMap, StatusCode};
+
+/// The parts of a request that aren't passed to a handler as a model.
+#[derive(Debug, Default)]
+pub struct StubRequest {
+    /// The values of the path parameters keyed by parameter name.
+    pub path: HashMap<String, String>,
+
+    /// The query parameters in the order they appear in the URL.
+    pub query: Vec<(String, String)>,
+
+    /// The request headers.
+    pub headers: HeaderMap,
+}
+
+/// The response returned by a handler.
+#[derive(Debug)]
+pub struct StubResponse<T> {
+    /// The HTTP status code.
+    pub status: StatusCode,
+
+    /// The response headers.
+    pub headers: HeaderMap,
+
+    /// The response body. `None` sends an empty body.
+    pub body: Option<T>,
+}
+
+impl<T> StubResponse<T> {
+    /// Creates a response with the specified status code and no body.
+    pub fn new(status: StatusCode) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: None,
+        }
+    }
+
+    /// Creates a response with the specified status code and body.
+    pub fn with_body(status: StatusCode, body: T) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: Some(body),
+        }
+    }
+
+    /// Adds the specified header to the response.
+    ///
+    /// Panics if `name` isn't lowercase or `value` isn't a valid header value.
+    pub fn with_header(mut self, name: &'static str, value: impl Into<String>) -> Self {
+        let value = HeaderValue::try_from(value.into()).expect("invalid header value");
+        self.headers.insert(name, value);
+        self
+    }
+}
+
+/// Handles the operations of `EmptyClient`.
+///
+/// Operations that aren't implemented respond with `501 Not Implemented`.
+#[async_trait]
+pub trait EmptyClientHandler: Send + Sync {
+    /// Handles `GET /type/model/empty/alone`.
+    async fn get_empty(&self, _request: StubRequest) -> StubResponse<EmptyOutput> {
+        StubResponse::new(StatusCode::NOT_IMPLEMENTED)
+    }
+
+    /// Handles `POST /type/model/empty/round-trip`.
+    async fn post_round_trip_empty(
+        &self,
+        _request: StubRequest,
+        _body: EmptyInputOutput,
+    ) -> StubResponse<EmptyInputOutput> {
+        StubResponse::new(StatusCode::NOT_IMPLEMENTED)
+    }
+
+    /// Handles `PUT /type/model/empty/alone`.
+    async fn put_empty(&self, _request: StubRequest, _input: EmptyInput) -> StubResponse<()> {
+        StubResponse::new(StatusCode::NOT_IMPLEMENTED)
+    }
+}
+
+/// Returns a [`Router`] that routes requests to the operations of `handler`.
+pub fn router<H>(handler: H) -> Router
+where
+    H: EmptyClientHandler + 'static,
+{
+    Router::new()
+        .route(
+            "/type/model/empty/alone",
+            get(empty_client_get_empty::<H>).put(empty_client_put_empty::<H>),
+        )
+        .route(
+            "/type/model/empty/round-trip",
+            post(empty_client_post_round_trip_empty::<H>),
+        )
+        .with_state(Arc::new(handler))
+}
+
+/// An in-process HTTP server that routes requests to a handler.
+///
+/// The server stops when it's dropped.
+pub struct StubServer {
+    endpoint: String,
+    task: JoinHandle<()>,
+}
+
+impl StubServer {
+    /// Starts a server for `handler` on an unused local port.
+    pub async fn start<H>(handler: H) -> io::Result<Self>
+    where
+        H: EmptyClientHandler + 'static,
+    {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await?;
+        let endpoint = format!("http://{}", listener.local_addr()?);
+        let router = router(handler);
+        let task = tokio::spawn(async move {
+            let _ = axum::serve(listener, router).await;
+        });
+        Ok(Self { endpoint, task })
+    }
+
+    /// Returns the endpoint to pass to a client constructor.
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+}
+
+impl Drop for StubServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn empty_client_get_empty<H: EmptyClientHandler>(
+    State(handler): State<Arc<H>>,
+    Query(query): Query<Vec<(String, String)>>,
+    headers: HeaderMap,
+) -> Response {
+    let request = StubRequest {
+        path: HashMap::new(),
+        query,
+        headers,
+    };
+    json_response(handler.get_empty(request).await)
+}
+
+async fn empty_client_put_empty<H: EmptyClientHandler>(
+    State(handler): State<Arc<H>>,
+    Query(query): Query<Vec<(String, String)>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let request = StubRequest {
+        path: HashMap::new(),
+        query,
+        headers,
+    };
+    let body = match json_request(&body) {
+        Ok(body) => body,
+        Err(response) => return response,
+    };
+    empty_response(handler.put_empty(request, body).await)
+}
+
+async fn empty_client_post_round_trip_empty<H: EmptyClientHandler>(
+    State(handler): State<Arc<H>>,
+    Query(query): Query<Vec<(String, String)>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Response {
+    let request = StubRequest {
+        path: HashMap::new(),
+        query,
+        headers,
+    };
+    let body = match json_request(&body) {
+        Ok(body) => body,
+        Err(response) => return response,
+    };
+    json_response(handler.post_round_trip_empty(request, body).await)
+}
+
+/// Converts a response without a body.
+fn empty_response(response: StubResponse<()>) -> Response {
+    (response.status, response.headers).into_response()
+}
+
+/// Deserializes a JSON request body, returning a `400 Bad Request` response on failure.
+fn json_request<T: DeserializeOwned>(body: &Bytes) -> Result<T, Response> {
+    serde_json::from_slice(body)
+        .map_err(|err| (StatusCode::BAD_REQUEST, err.to_string()).into_response())
+}
+
+/// Converts a response with a JSON body.
+fn json_response<T: Serialize>(response: StubResponse<T>) -> Response {
+    let mut headers = response.headers;
+    let body = match response.body.as_ref().map(serde_json::to_vec).transpose() {
+        Ok(body) => body,
+        Err(err) => return (StatusCode::INTERNAL_SERVER_ERROR, err.to_string()).into_response(),
+    };
+    if body.is_some() && !headers.contains_key(CONTENT_TYPE) {
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    }
+    (response.status, headers, body.unwrap_or_default()).into_response()
+}
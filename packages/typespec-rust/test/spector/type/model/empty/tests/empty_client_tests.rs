@@ -2,9 +2,15 @@
 //
 // Licensed under the MIT License. See License.txt in the project root for license information.
 
+use azure_core::http::{headers::Headers, RawResponse, StatusCode};
+use spector_empty::clients::{EmptyClientMethods, MockEmptyClient};
 use spector_empty::models::{EmptyInput, EmptyInputOutput, EmptyOutput};
 use spector_empty::EmptyClient;
 
+async fn get_empty_output(client: &dyn EmptyClientMethods) -> azure_core::Result<EmptyOutput> {
+    client.get_empty(None).await?.into_model()
+}
+
 #[tokio::test]
 async fn get_empty() {
     let client = EmptyClient::with_no_credential("http://localhost:3000", None).unwrap();
@@ -26,3 +32,18 @@ async fn put_empty() {
     let req = EmptyInput::try_into(EmptyInput::default()).unwrap();
     let _resp = client.put_empty(req, None).await.unwrap();
 }
+
+#[tokio::test]
+async fn get_empty_through_trait() {
+    let client = EmptyClient::with_no_credential("http://localhost:3000", None).unwrap();
+    let _value = get_empty_output(&client).await.unwrap();
+}
+
+#[tokio::test]
+async fn mock_get_empty() {
+    let client = MockEmptyClient::default();
+    client.push_get_empty(RawResponse::from_bytes(StatusCode::Ok, Headers::new(), "{}").into());
+    let _value = get_empty_output(&client).await.unwrap();
+    // the queued response was consumed
+    assert!(get_empty_output(&client).await.is_err());
+}
@@ -0,0 +1,56 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+//
+// Licensed under the MIT License. See License.txt in the project root for license information.
+
+use azure_core::http::StatusCode;
+use spector_empty::models::{EmptyInput, EmptyInputOutput, EmptyOutput};
+use spector_empty::EmptyClient;
+use spector_empty_stub_server::{
+    async_trait, EmptyClientHandler, StubRequest, StubResponse, StubServer,
+};
+
+struct Handler;
+
+#[async_trait]
+impl EmptyClientHandler for Handler {
+    async fn get_empty(&self, _request: StubRequest) -> StubResponse<EmptyOutput> {
+        StubResponse::with_body(
+            spector_empty_stub_server::StatusCode::OK,
+            EmptyOutput::default(),
+        )
+    }
+
+    async fn post_round_trip_empty(
+        &self,
+        _request: StubRequest,
+        body: EmptyInputOutput,
+    ) -> StubResponse<EmptyInputOutput> {
+        StubResponse::with_body(spector_empty_stub_server::StatusCode::OK, body)
+    }
+}
+
+#[tokio::test]
+async fn get_empty() {
+    let server = StubServer::start(Handler).await.unwrap();
+    let client = EmptyClient::with_no_credential(server.endpoint(), None).unwrap();
+    let resp = client.get_empty(None).await.unwrap();
+    let _value: EmptyOutput = resp.into_model().unwrap();
+}
+
+#[tokio::test]
+async fn post_round_trip_empty() {
+    let server = StubServer::start(Handler).await.unwrap();
+    let client = EmptyClient::with_no_credential(server.endpoint(), None).unwrap();
+    let req = EmptyInputOutput::try_into(EmptyInputOutput::default()).unwrap();
+    let resp = client.post_round_trip_empty(req, None).await.unwrap();
+    let _value: EmptyInputOutput = resp.into_model().unwrap();
+}
+
+#[tokio::test]
+async fn put_empty_not_implemented() {
+    let server = StubServer::start(Handler).await.unwrap();
+    let client = EmptyClient::with_no_credential(server.endpoint(), None).unwrap();
+    let req = EmptyInput::try_into(EmptyInput::default()).unwrap();
+    let err = client.put_empty(req, None).await.unwrap_err();
+    assert_eq!(err.http_status(), Some(StatusCode::NotImplemented));
+}
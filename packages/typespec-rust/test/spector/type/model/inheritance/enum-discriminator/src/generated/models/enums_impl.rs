@@ -24,7 +24,7 @@ impl FromStr for DogKind {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "golden" => DogKind::Golden,
+            _ if s.eq_ignore_ascii_case("golden") => DogKind::Golden,
             _ => DogKind::UnknownValue(s.to_string()),
         })
     }
@@ -39,6 +39,12 @@ impl AsRef<str> for DogKind {
     }
 }
 
+impl PartialEq<str> for DogKind {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for DogKind {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
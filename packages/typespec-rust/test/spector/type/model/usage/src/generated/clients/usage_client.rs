@@ -4,18 +4,19 @@
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
 use crate::generated::models::{
-    InputOutputRecord, InputRecord, OutputRecord, UsageClientInputAndOutputOptions,
-    UsageClientInputOptions, UsageClientOutputOptions,
+    Idempotency, IdempotencyPolicy, InputOutputRecord, InputRecord, OutputRecord,
+    UsageClientInputAndOutputOptions, UsageClientInputOptions, UsageClientOutputOptions,
 };
 use azure_core::{
     error::CheckSuccessOptions,
     fmt::SafeDebug,
     http::{
-        ClientOptions, Method, NoFormat, Pipeline, PipelineSendOptions, Request, RequestContent,
-        Response, Url, UrlExt,
+        policies::Policy, ClientMethodOptions, ClientOptions, Method, NoFormat, Pipeline,
+        PipelineSendOptions, RawResponse, Request, RequestContent, Response, Url, UrlExt,
     },
     tracing, Result,
 };
+use std::sync::Arc;
 
 /// Illustrates usage of Record in different places(Operation parameters, return type or both).
 #[tracing::client]
@@ -48,6 +49,7 @@ impl UsageClient {
                 format!("{endpoint} must use http(s)"),
             ));
         }
+        let idempotency_policy: Arc<dyn Policy> = Arc::new(IdempotencyPolicy);
         Ok(Self {
             endpoint,
             pipeline: Pipeline::new(
@@ -55,7 +57,7 @@ impl UsageClient {
                 option_env!("CARGO_PKG_VERSION"),
                 options.client_options,
                 Vec::default(),
-                Vec::default(),
+                vec![idempotency_policy],
                 None,
             ),
         })
@@ -66,6 +68,37 @@ impl UsageClient {
         &self.endpoint
     }
 
+    /// Sends the request through the client's pipeline.
+    ///
+    /// The request is sent with the client's configured policies (e.g. authentication, retries, and telemetry).
+    /// This allows sending requests that were built or modified by the caller, e.g. to add a parameter
+    /// the client doesn't support yet. The [`RawResponse`] can be converted to a typed
+    /// [`Response<T>`](azure_core::http::Response) with `into()`.
+    ///
+    /// Any 2xx status code is treated as success. Other status codes return an error of kind
+    /// [`ErrorKind::HttpResponse`](azure_core::error::ErrorKind::HttpResponse).
+    ///
+    /// If the context in `options` doesn't contain an [`Idempotency`] value, one is added based on the
+    /// request's method. POST and PATCH requests are treated as non-idempotent and won't be retried.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request to send.
+    /// * `options` - Optional parameters for the request.
+    pub async fn send_request(
+        &self,
+        mut request: Request,
+        options: Option<ClientMethodOptions<'_>>,
+    ) -> Result<RawResponse> {
+        let options = options.unwrap_or_default();
+        let mut ctx = options.context.to_borrowed();
+        if ctx.value::<Idempotency>().is_none() {
+            let idempotent = !matches!(request.method(), Method::Post | Method::Patch);
+            ctx = ctx.with_value(Idempotency::new(idempotent, idempotent));
+        }
+        self.pipeline.send(&ctx, &mut request, None).await
+    }
+
     ///
     /// # Arguments
     ///
@@ -77,7 +110,14 @@ impl UsageClient {
         options: Option<UsageClientInputOptions<'_>>,
     ) -> Result<Response<(), NoFormat>> {
         let options = options.unwrap_or_default();
-        let ctx = options.method_options.context.to_borrowed();
+        let ctx = options
+            .method_options
+            .context
+            .to_borrowed()
+            .with_value(Idempotency {
+                idempotent: false,
+                retry_allowed: options.allow_retry,
+            });
         let mut url = self.endpoint.clone();
         url.append_path("/type/model/usage/input");
         let mut request = Request::new(url, Method::Post);
@@ -110,7 +150,14 @@ impl UsageClient {
         options: Option<UsageClientInputAndOutputOptions<'_>>,
     ) -> Result<Response<InputOutputRecord>> {
         let options = options.unwrap_or_default();
-        let ctx = options.method_options.context.to_borrowed();
+        let ctx = options
+            .method_options
+            .context
+            .to_borrowed()
+            .with_value(Idempotency {
+                idempotent: false,
+                retry_allowed: options.allow_retry,
+            });
         let mut url = self.endpoint.clone();
         url.append_path("/type/model/usage/input-output");
         let mut request = Request::new(url, Method::Post);
@@ -143,7 +190,14 @@ impl UsageClient {
         options: Option<UsageClientOutputOptions<'_>>,
     ) -> Result<Response<OutputRecord>> {
         let options = options.unwrap_or_default();
-        let ctx = options.method_options.context.to_borrowed();
+        let ctx = options
+            .method_options
+            .context
+            .to_borrowed()
+            .with_value(Idempotency {
+                idempotent: true,
+                retry_allowed: true,
+            });
         let mut url = self.endpoint.clone();
         url.append_path("/type/model/usage/output");
         let mut request = Request::new(url, Method::Get);
@@ -8,6 +8,9 @@ use azure_core::{fmt::SafeDebug, http::ClientMethodOptions};
 /// Options to be passed to [`UsageClient::input_and_output()`](crate::generated::clients::UsageClient::input_and_output())
 #[derive(Clone, Default, SafeDebug)]
 pub struct UsageClientInputAndOutputOptions<'a> {
+    /// Allows retry policies to retry the request even though the operation isn't idempotent.
+    pub allow_retry: bool,
+
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 }
@@ -15,6 +18,9 @@ pub struct UsageClientInputAndOutputOptions<'a> {
 /// Options to be passed to [`UsageClient::input()`](crate::generated::clients::UsageClient::input())
 #[derive(Clone, Default, SafeDebug)]
 pub struct UsageClientInputOptions<'a> {
+    /// Allows retry policies to retry the request even though the operation isn't idempotent.
+    pub allow_retry: bool,
+
     /// Allows customization of the method call.
     pub method_options: ClientMethodOptions<'a>,
 }
@@ -3,9 +3,11 @@
 //
 // Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
 
+mod idempotency;
 mod method_options;
 #[allow(clippy::module_inception)]
 mod models;
 mod models_impl;
+pub use idempotency::*;
 pub use method_options::*;
 pub use models::*;
@@ -2,8 +2,25 @@
 //
 // Licensed under the MIT License. See License.txt in the project root for license information.
 
-use spector_usage::models::{InputOutputRecord, InputRecord, OutputRecord};
-use spector_usage::UsageClient;
+use async_trait::async_trait;
+use azure_core::{
+    error::ErrorKind,
+    http::{
+        headers::Headers,
+        policies::{Policy, PolicyResult},
+        AsyncRawResponse, ClientMethodOptions, ClientOptions, Context, FixedRetryOptions, Method,
+        Request, RetryOptions, StatusCode, Url,
+    },
+    time::Duration,
+};
+use spector_usage::models::{
+    Idempotency, InputOutputRecord, InputRecord, OutputRecord, UsageClientInputOptions,
+};
+use spector_usage::{UsageClient, UsageClientOptions};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
 
 #[tokio::test]
 async fn input() {
@@ -34,3 +51,174 @@ async fn output() {
     let value: OutputRecord = resp.into_model().unwrap();
     assert_eq!(value.required_prop, Some(String::from("example-value")));
 }
+
+/// Verifies the request's idempotency in place of the transport.
+#[derive(Debug)]
+struct IdempotencyTransport {
+    idempotent: bool,
+    retry_allowed: bool,
+}
+
+#[async_trait]
+impl Policy for IdempotencyTransport {
+    async fn send(
+        &self,
+        ctx: &Context,
+        _request: &mut Request,
+        _next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        let idempotency = ctx.value::<Idempotency>().unwrap();
+        assert_eq!(idempotency.idempotent, self.idempotent);
+        assert_eq!(idempotency.retry_allowed, self.retry_allowed);
+        Ok(AsyncRawResponse::from_bytes(
+            StatusCode::NoContent,
+            Headers::new(),
+            "",
+        ))
+    }
+}
+
+fn idempotency_client(idempotent: bool, retry_allowed: bool) -> UsageClient {
+    let options = UsageClientOptions {
+        client_options: ClientOptions {
+            per_call_policies: vec![Arc::new(IdempotencyTransport {
+                idempotent,
+                retry_allowed,
+            })],
+            ..Default::default()
+        },
+    };
+    UsageClient::with_no_credential("http://localhost:3000", Some(options)).unwrap()
+}
+
+fn input_record() -> InputRecord {
+    InputRecord {
+        required_prop: Some(String::from("example-value")),
+    }
+}
+
+#[tokio::test]
+async fn post_is_not_retried_by_default() {
+    let client = idempotency_client(false, false);
+    client
+        .input(input_record().try_into().unwrap(), None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn post_retry_is_allowed_by_options() {
+    let client = idempotency_client(false, true);
+    let options = UsageClientInputOptions {
+        allow_retry: true,
+        ..Default::default()
+    };
+    client
+        .input(input_record().try_into().unwrap(), Some(options))
+        .await
+        .unwrap();
+}
+
+fn raw_request(method: Method) -> Request {
+    let url = Url::parse("http://localhost:3000/type/model/usage/input").unwrap();
+    Request::new(url, method)
+}
+
+#[tokio::test]
+async fn send_request_post_is_not_retried_by_default() {
+    let client = idempotency_client(false, false);
+    client
+        .send_request(raw_request(Method::Post), None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn send_request_get_is_retried_by_default() {
+    let client = idempotency_client(true, true);
+    client
+        .send_request(raw_request(Method::Get), None)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn send_request_keeps_caller_idempotency() {
+    let client = idempotency_client(false, true);
+    let options = ClientMethodOptions {
+        context: Context::new().with_value(Idempotency::new(false, true)),
+        ..Default::default()
+    };
+    client
+        .send_request(raw_request(Method::Post), Some(options))
+        .await
+        .unwrap();
+}
+
+/// Fails every try with a transient error in place of the transport.
+#[derive(Debug, Default)]
+struct TransientFailureTransport {
+    tries: AtomicUsize,
+}
+
+#[async_trait]
+impl Policy for TransientFailureTransport {
+    async fn send(
+        &self,
+        _ctx: &Context,
+        _request: &mut Request,
+        _next: &[Arc<dyn Policy>],
+    ) -> PolicyResult {
+        self.tries.fetch_add(1, Ordering::SeqCst);
+        Ok(AsyncRawResponse::from_bytes(
+            StatusCode::ServiceUnavailable,
+            Headers::new(),
+            "",
+        ))
+    }
+}
+
+fn transient_failure_client(transport: Arc<TransientFailureTransport>) -> UsageClient {
+    let options = UsageClientOptions {
+        client_options: ClientOptions {
+            per_try_policies: vec![transport],
+            retry: RetryOptions::fixed(FixedRetryOptions {
+                delay: Duration::milliseconds(1),
+                max_retries: 3,
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+    };
+    UsageClient::with_no_credential("http://localhost:3000", Some(options)).unwrap()
+}
+
+#[tokio::test]
+async fn post_is_not_retried_after_transient_failure() {
+    let transport = Arc::new(TransientFailureTransport::default());
+    let client = transient_failure_client(transport.clone());
+    let err = client
+        .input(input_record().try_into().unwrap(), None)
+        .await
+        .unwrap_err();
+    assert!(matches!(
+        err.kind(),
+        ErrorKind::HttpResponse { status, .. } if *status == StatusCode::ServiceUnavailable
+    ));
+    assert_eq!(transport.tries.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn post_is_retried_after_transient_failure_when_allowed() {
+    let transport = Arc::new(TransientFailureTransport::default());
+    let client = transient_failure_client(transport.clone());
+    let options = UsageClientInputOptions {
+        allow_retry: true,
+        ..Default::default()
+    };
+    client
+        .input(input_record().try_into().unwrap(), Some(options))
+        .await
+        .unwrap_err();
+    assert!(transport.tries.load(Ordering::SeqCst) > 1);
+}
@@ -55,35 +55,35 @@ pub enum IsUnknownAdditionalPropertiesDiscriminated {
     },
 }
 
-#[derive(Clone, Deserialize, SafeDebug, Serialize)]
+#[derive(Clone, SafeDebug, Serialize)]
 #[serde(untagged)]
 pub enum MultipleSpreadRecordAdditionalProperty {
     Float32(f32),
     String(String),
 }
 
-#[derive(Clone, Deserialize, SafeDebug, Serialize)]
+#[derive(Clone, SafeDebug, Serialize)]
 #[serde(untagged)]
 pub enum SpreadRecordForNonDiscriminatedUnion2AdditionalProperty {
     WidgetData2(WidgetData2),
     WidgetData1(WidgetData1),
 }
 
-#[derive(Clone, Deserialize, SafeDebug, Serialize)]
+#[derive(Clone, SafeDebug, Serialize)]
 #[serde(untagged)]
 pub enum SpreadRecordForNonDiscriminatedUnion3AdditionalProperty {
     WidgetData2Array(Vec<WidgetData2>),
     WidgetData1(WidgetData1),
 }
 
-#[derive(Clone, Deserialize, SafeDebug, Serialize)]
+#[derive(Clone, SafeDebug, Serialize)]
 #[serde(untagged)]
 pub enum SpreadRecordForNonDiscriminatedUnionAdditionalProperty {
     WidgetData0(WidgetData0),
     WidgetData1(WidgetData1),
 }
 
-#[derive(Clone, Deserialize, SafeDebug, Serialize)]
+#[derive(Clone, SafeDebug, Serialize)]
 #[serde(untagged)]
 pub enum SpreadRecordForUnionAdditionalProperty {
     Float32(f32),
@@ -5,9 +5,294 @@
 
 use super::{
     ExtendsUnknownAdditionalPropertiesDiscriminated, IsUnknownAdditionalPropertiesDiscriminated,
+    MultipleSpreadRecordAdditionalProperty,
+    SpreadRecordForNonDiscriminatedUnion2AdditionalProperty,
+    SpreadRecordForNonDiscriminatedUnion3AdditionalProperty,
+    SpreadRecordForNonDiscriminatedUnionAdditionalProperty, SpreadRecordForUnionAdditionalProperty,
+    WidgetData0, WidgetData1, WidgetData2,
 };
 use azure_core::{http::RequestContent, json::to_json, Result};
 
+impl MultipleSpreadRecordAdditionalProperty {
+    /// Returns `true` if this is the [`MultipleSpreadRecordAdditionalProperty::Float32`] variant.
+    pub fn is_float32(&self) -> bool {
+        matches!(self, Self::Float32(_))
+    }
+
+    /// Returns a reference to the value if this is the [`MultipleSpreadRecordAdditionalProperty::Float32`] variant.
+    pub fn as_float32(&self) -> Option<&f32> {
+        match self {
+            Self::Float32(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`MultipleSpreadRecordAdditionalProperty::Float32`] variant.
+    pub fn into_float32(self) -> Option<f32> {
+        match self {
+            Self::Float32(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this is the [`MultipleSpreadRecordAdditionalProperty::String`] variant.
+    pub fn is_string(&self) -> bool {
+        matches!(self, Self::String(_))
+    }
+
+    /// Returns a reference to the value if this is the [`MultipleSpreadRecordAdditionalProperty::String`] variant.
+    pub fn as_string(&self) -> Option<&String> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`MultipleSpreadRecordAdditionalProperty::String`] variant.
+    pub fn into_string(self) -> Option<String> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl From<f32> for MultipleSpreadRecordAdditionalProperty {
+    fn from(value: f32) -> Self {
+        Self::Float32(value)
+    }
+}
+
+impl From<String> for MultipleSpreadRecordAdditionalProperty {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl SpreadRecordForNonDiscriminatedUnion2AdditionalProperty {
+    /// Returns `true` if this is the [`SpreadRecordForNonDiscriminatedUnion2AdditionalProperty::WidgetData2`] variant.
+    pub fn is_widget_data2(&self) -> bool {
+        matches!(self, Self::WidgetData2(_))
+    }
+
+    /// Returns a reference to the value if this is the [`SpreadRecordForNonDiscriminatedUnion2AdditionalProperty::WidgetData2`] variant.
+    pub fn as_widget_data2(&self) -> Option<&WidgetData2> {
+        match self {
+            Self::WidgetData2(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`SpreadRecordForNonDiscriminatedUnion2AdditionalProperty::WidgetData2`] variant.
+    pub fn into_widget_data2(self) -> Option<WidgetData2> {
+        match self {
+            Self::WidgetData2(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this is the [`SpreadRecordForNonDiscriminatedUnion2AdditionalProperty::WidgetData1`] variant.
+    pub fn is_widget_data1(&self) -> bool {
+        matches!(self, Self::WidgetData1(_))
+    }
+
+    /// Returns a reference to the value if this is the [`SpreadRecordForNonDiscriminatedUnion2AdditionalProperty::WidgetData1`] variant.
+    pub fn as_widget_data1(&self) -> Option<&WidgetData1> {
+        match self {
+            Self::WidgetData1(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`SpreadRecordForNonDiscriminatedUnion2AdditionalProperty::WidgetData1`] variant.
+    pub fn into_widget_data1(self) -> Option<WidgetData1> {
+        match self {
+            Self::WidgetData1(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl From<WidgetData2> for SpreadRecordForNonDiscriminatedUnion2AdditionalProperty {
+    fn from(value: WidgetData2) -> Self {
+        Self::WidgetData2(value)
+    }
+}
+
+impl From<WidgetData1> for SpreadRecordForNonDiscriminatedUnion2AdditionalProperty {
+    fn from(value: WidgetData1) -> Self {
+        Self::WidgetData1(value)
+    }
+}
+
+impl SpreadRecordForNonDiscriminatedUnion3AdditionalProperty {
+    /// Returns `true` if this is the [`SpreadRecordForNonDiscriminatedUnion3AdditionalProperty::WidgetData2Array`] variant.
+    pub fn is_widget_data2_array(&self) -> bool {
+        matches!(self, Self::WidgetData2Array(_))
+    }
+
+    /// Returns a reference to the value if this is the [`SpreadRecordForNonDiscriminatedUnion3AdditionalProperty::WidgetData2Array`] variant.
+    pub fn as_widget_data2_array(&self) -> Option<&Vec<WidgetData2>> {
+        match self {
+            Self::WidgetData2Array(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`SpreadRecordForNonDiscriminatedUnion3AdditionalProperty::WidgetData2Array`] variant.
+    pub fn into_widget_data2_array(self) -> Option<Vec<WidgetData2>> {
+        match self {
+            Self::WidgetData2Array(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this is the [`SpreadRecordForNonDiscriminatedUnion3AdditionalProperty::WidgetData1`] variant.
+    pub fn is_widget_data1(&self) -> bool {
+        matches!(self, Self::WidgetData1(_))
+    }
+
+    /// Returns a reference to the value if this is the [`SpreadRecordForNonDiscriminatedUnion3AdditionalProperty::WidgetData1`] variant.
+    pub fn as_widget_data1(&self) -> Option<&WidgetData1> {
+        match self {
+            Self::WidgetData1(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`SpreadRecordForNonDiscriminatedUnion3AdditionalProperty::WidgetData1`] variant.
+    pub fn into_widget_data1(self) -> Option<WidgetData1> {
+        match self {
+            Self::WidgetData1(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl From<Vec<WidgetData2>> for SpreadRecordForNonDiscriminatedUnion3AdditionalProperty {
+    fn from(value: Vec<WidgetData2>) -> Self {
+        Self::WidgetData2Array(value)
+    }
+}
+
+impl From<WidgetData1> for SpreadRecordForNonDiscriminatedUnion3AdditionalProperty {
+    fn from(value: WidgetData1) -> Self {
+        Self::WidgetData1(value)
+    }
+}
+
+impl SpreadRecordForNonDiscriminatedUnionAdditionalProperty {
+    /// Returns `true` if this is the [`SpreadRecordForNonDiscriminatedUnionAdditionalProperty::WidgetData0`] variant.
+    pub fn is_widget_data0(&self) -> bool {
+        matches!(self, Self::WidgetData0(_))
+    }
+
+    /// Returns a reference to the value if this is the [`SpreadRecordForNonDiscriminatedUnionAdditionalProperty::WidgetData0`] variant.
+    pub fn as_widget_data0(&self) -> Option<&WidgetData0> {
+        match self {
+            Self::WidgetData0(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`SpreadRecordForNonDiscriminatedUnionAdditionalProperty::WidgetData0`] variant.
+    pub fn into_widget_data0(self) -> Option<WidgetData0> {
+        match self {
+            Self::WidgetData0(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this is the [`SpreadRecordForNonDiscriminatedUnionAdditionalProperty::WidgetData1`] variant.
+    pub fn is_widget_data1(&self) -> bool {
+        matches!(self, Self::WidgetData1(_))
+    }
+
+    /// Returns a reference to the value if this is the [`SpreadRecordForNonDiscriminatedUnionAdditionalProperty::WidgetData1`] variant.
+    pub fn as_widget_data1(&self) -> Option<&WidgetData1> {
+        match self {
+            Self::WidgetData1(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`SpreadRecordForNonDiscriminatedUnionAdditionalProperty::WidgetData1`] variant.
+    pub fn into_widget_data1(self) -> Option<WidgetData1> {
+        match self {
+            Self::WidgetData1(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl From<WidgetData0> for SpreadRecordForNonDiscriminatedUnionAdditionalProperty {
+    fn from(value: WidgetData0) -> Self {
+        Self::WidgetData0(value)
+    }
+}
+
+impl From<WidgetData1> for SpreadRecordForNonDiscriminatedUnionAdditionalProperty {
+    fn from(value: WidgetData1) -> Self {
+        Self::WidgetData1(value)
+    }
+}
+
+impl SpreadRecordForUnionAdditionalProperty {
+    /// Returns `true` if this is the [`SpreadRecordForUnionAdditionalProperty::Float32`] variant.
+    pub fn is_float32(&self) -> bool {
+        matches!(self, Self::Float32(_))
+    }
+
+    /// Returns a reference to the value if this is the [`SpreadRecordForUnionAdditionalProperty::Float32`] variant.
+    pub fn as_float32(&self) -> Option<&f32> {
+        match self {
+            Self::Float32(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`SpreadRecordForUnionAdditionalProperty::Float32`] variant.
+    pub fn into_float32(self) -> Option<f32> {
+        match self {
+            Self::Float32(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this is the [`SpreadRecordForUnionAdditionalProperty::String`] variant.
+    pub fn is_string(&self) -> bool {
+        matches!(self, Self::String(_))
+    }
+
+    /// Returns a reference to the value if this is the [`SpreadRecordForUnionAdditionalProperty::String`] variant.
+    pub fn as_string(&self) -> Option<&String> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`SpreadRecordForUnionAdditionalProperty::String`] variant.
+    pub fn into_string(self) -> Option<String> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl From<f32> for SpreadRecordForUnionAdditionalProperty {
+    fn from(value: f32) -> Self {
+        Self::Float32(value)
+    }
+}
+
+impl From<String> for SpreadRecordForUnionAdditionalProperty {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
 impl TryFrom<ExtendsUnknownAdditionalPropertiesDiscriminated>
     for RequestContent<ExtendsUnknownAdditionalPropertiesDiscriminated>
 {
@@ -7,10 +7,15 @@ use super::{
     ExtendsUnknownAdditionalPropertiesDiscriminated,
     ExtendsUnknownAdditionalPropertiesDiscriminatedDerived,
     IsUnknownAdditionalPropertiesDiscriminated, IsUnknownAdditionalPropertiesDiscriminatedDerived,
+    MultipleSpreadRecordAdditionalProperty,
+    SpreadRecordForNonDiscriminatedUnion2AdditionalProperty,
+    SpreadRecordForNonDiscriminatedUnion3AdditionalProperty,
+    SpreadRecordForNonDiscriminatedUnionAdditionalProperty, SpreadRecordForUnionAdditionalProperty,
     UnknownExtendsUnknownAdditionalPropertiesDiscriminatedKind,
     UnknownIsUnknownAdditionalPropertiesDiscriminatedKind,
 };
-use serde::{Serialize, Serializer};
+use azure_core::Value;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 impl Serialize for ExtendsUnknownAdditionalPropertiesDiscriminated {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -43,3 +48,98 @@ impl Serialize for IsUnknownAdditionalPropertiesDiscriminated {
         }
     }
 }
+
+impl<'de> Deserialize<'de> for MultipleSpreadRecordAdditionalProperty {
+    fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let mut errors = Vec::new();
+        match Deserialize::deserialize(&value).map(Self::Float32) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("Float32: {err}")),
+        }
+        match Deserialize::deserialize(&value).map(Self::String) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("String: {err}")),
+        }
+        Err(serde::de::Error::custom(format!("data did not match any variant of untagged enum MultipleSpreadRecordAdditionalProperty ({})", errors.join("; "))))
+    }
+}
+
+impl<'de> Deserialize<'de> for SpreadRecordForNonDiscriminatedUnion2AdditionalProperty {
+    fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let mut errors = Vec::new();
+        match Deserialize::deserialize(&value).map(Self::WidgetData2) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("WidgetData2: {err}")),
+        }
+        match Deserialize::deserialize(&value).map(Self::WidgetData1) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("WidgetData1: {err}")),
+        }
+        Err(serde::de::Error::custom(format!("data did not match any variant of untagged enum SpreadRecordForNonDiscriminatedUnion2AdditionalProperty ({})", errors.join("; "))))
+    }
+}
+
+impl<'de> Deserialize<'de> for SpreadRecordForNonDiscriminatedUnion3AdditionalProperty {
+    fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let mut errors = Vec::new();
+        match Deserialize::deserialize(&value).map(Self::WidgetData2Array) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("WidgetData2Array: {err}")),
+        }
+        match Deserialize::deserialize(&value).map(Self::WidgetData1) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("WidgetData1: {err}")),
+        }
+        Err(serde::de::Error::custom(format!("data did not match any variant of untagged enum SpreadRecordForNonDiscriminatedUnion3AdditionalProperty ({})", errors.join("; "))))
+    }
+}
+
+impl<'de> Deserialize<'de> for SpreadRecordForNonDiscriminatedUnionAdditionalProperty {
+    fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let mut errors = Vec::new();
+        match Deserialize::deserialize(&value).map(Self::WidgetData0) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("WidgetData0: {err}")),
+        }
+        match Deserialize::deserialize(&value).map(Self::WidgetData1) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("WidgetData1: {err}")),
+        }
+        Err(serde::de::Error::custom(format!("data did not match any variant of untagged enum SpreadRecordForNonDiscriminatedUnionAdditionalProperty ({})", errors.join("; "))))
+    }
+}
+
+impl<'de> Deserialize<'de> for SpreadRecordForUnionAdditionalProperty {
+    fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let mut errors = Vec::new();
+        match Deserialize::deserialize(&value).map(Self::Float32) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("Float32: {err}")),
+        }
+        match Deserialize::deserialize(&value).map(Self::String) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("String: {err}")),
+        }
+        Err(serde::de::Error::custom(format!("data did not match any variant of untagged enum SpreadRecordForUnionAdditionalProperty ({})", errors.join("; "))))
+    }
+}
@@ -27,7 +27,7 @@ impl FromStr for ExtendedEnum {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "value2" => ExtendedEnum::EnumValue2,
+            _ if s.eq_ignore_ascii_case("value2") => ExtendedEnum::EnumValue2,
             _ => ExtendedEnum::UnknownValue(s.to_string()),
         })
     }
@@ -42,6 +42,12 @@ impl AsRef<str> for ExtendedEnum {
     }
 }
 
+impl PartialEq<str> for ExtendedEnum {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for ExtendedEnum {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -98,8 +104,8 @@ impl FromStr for InnerEnum {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "ValueOne" => InnerEnum::ValueOne,
-            "ValueTwo" => InnerEnum::ValueTwo,
+            _ if s.eq_ignore_ascii_case("ValueOne") => InnerEnum::ValueOne,
+            _ if s.eq_ignore_ascii_case("ValueTwo") => InnerEnum::ValueTwo,
             _ => InnerEnum::UnknownValue(s.to_string()),
         })
     }
@@ -115,6 +121,12 @@ impl AsRef<str> for InnerEnum {
     }
 }
 
+impl PartialEq<str> for InnerEnum {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for InnerEnum {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
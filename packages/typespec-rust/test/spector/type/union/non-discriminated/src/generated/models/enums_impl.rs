@@ -136,8 +136,8 @@ impl FromStr for GetResponseProp1 {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "b" => GetResponseProp1::B,
-            "c" => GetResponseProp1::C,
+            _ if s.eq_ignore_ascii_case("b") => GetResponseProp1::B,
+            _ if s.eq_ignore_ascii_case("c") => GetResponseProp1::C,
             _ => GetResponseProp1::UnknownValue(s.to_string()),
         })
     }
@@ -153,6 +153,12 @@ impl AsRef<str> for GetResponseProp1 {
     }
 }
 
+impl PartialEq<str> for GetResponseProp1 {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for GetResponseProp1 {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
@@ -245,8 +251,8 @@ impl FromStr for StringExtensibleNamedUnion {
     type Err = Infallible;
     fn from_str(s: &str) -> ::core::result::Result<Self, <Self as FromStr>::Err> {
         Ok(match s {
-            "c" => StringExtensibleNamedUnion::C,
-            "b" => StringExtensibleNamedUnion::OptionB,
+            _ if s.eq_ignore_ascii_case("c") => StringExtensibleNamedUnion::C,
+            _ if s.eq_ignore_ascii_case("b") => StringExtensibleNamedUnion::OptionB,
             _ => StringExtensibleNamedUnion::UnknownValue(s.to_string()),
         })
     }
@@ -262,6 +268,12 @@ impl AsRef<str> for StringExtensibleNamedUnion {
     }
 }
 
+impl PartialEq<str> for StringExtensibleNamedUnion {
+    fn eq(&self, other: &str) -> bool {
+        self.as_ref().eq_ignore_ascii_case(other)
+    }
+}
+
 impl Display for StringExtensibleNamedUnion {
     fn fmt(&self, f: &mut Formatter<'_>) -> ::std::fmt::Result {
         match self {
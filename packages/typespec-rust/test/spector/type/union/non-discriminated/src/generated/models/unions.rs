@@ -5,16 +5,16 @@
 
 use super::{Cat, Dog};
 use azure_core::fmt::SafeDebug;
-use serde::{Deserialize, Serialize};
+use serde::Serialize;
 
-#[derive(Clone, Deserialize, SafeDebug, Serialize)]
+#[derive(Clone, SafeDebug, Serialize)]
 #[serde(untagged)]
 pub enum GetResponseProp4 {
     Cat(Cat),
     Dog(Dog),
 }
 
-#[derive(Clone, Deserialize, SafeDebug, Serialize)]
+#[derive(Clone, SafeDebug, Serialize)]
 #[serde(untagged)]
 pub enum MixedLiteralsCasesStringLiteral {
     Boolean(bool),
@@ -23,7 +23,7 @@ pub enum MixedLiteralsCasesStringLiteral {
     String(String),
 }
 
-#[derive(Clone, Deserialize, SafeDebug, Serialize)]
+#[derive(Clone, SafeDebug, Serialize)]
 #[serde(untagged)]
 pub enum MixedTypesCasesModel {
     Boolean(bool),
@@ -32,14 +32,14 @@ pub enum MixedTypesCasesModel {
     Cat(Cat),
 }
 
-#[derive(Clone, Deserialize, SafeDebug, Serialize)]
+#[derive(Clone, SafeDebug, Serialize)]
 #[serde(untagged)]
 pub enum StringAndArrayCasesArray {
     String(String),
     StringArray(Vec<String>),
 }
 
-#[derive(Clone, Deserialize, SafeDebug, Serialize)]
+#[derive(Clone, SafeDebug, Serialize)]
 #[serde(untagged)]
 pub enum StringAndArrayCasesString {
     String(String),
@@ -0,0 +1,397 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::{
+    Cat, Dog, GetResponseProp4, MixedLiteralsCasesStringLiteral, MixedTypesCasesModel,
+    StringAndArrayCasesArray, StringAndArrayCasesString,
+};
+
+impl GetResponseProp4 {
+    /// Returns `true` if this is the [`GetResponseProp4::Cat`] variant.
+    pub fn is_cat(&self) -> bool {
+        matches!(self, Self::Cat(_))
+    }
+
+    /// Returns a reference to the value if this is the [`GetResponseProp4::Cat`] variant.
+    pub fn as_cat(&self) -> Option<&Cat> {
+        match self {
+            Self::Cat(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`GetResponseProp4::Cat`] variant.
+    pub fn into_cat(self) -> Option<Cat> {
+        match self {
+            Self::Cat(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this is the [`GetResponseProp4::Dog`] variant.
+    pub fn is_dog(&self) -> bool {
+        matches!(self, Self::Dog(_))
+    }
+
+    /// Returns a reference to the value if this is the [`GetResponseProp4::Dog`] variant.
+    pub fn as_dog(&self) -> Option<&Dog> {
+        match self {
+            Self::Dog(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`GetResponseProp4::Dog`] variant.
+    pub fn into_dog(self) -> Option<Dog> {
+        match self {
+            Self::Dog(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl From<Cat> for GetResponseProp4 {
+    fn from(value: Cat) -> Self {
+        Self::Cat(value)
+    }
+}
+
+impl From<Dog> for GetResponseProp4 {
+    fn from(value: Dog) -> Self {
+        Self::Dog(value)
+    }
+}
+
+impl MixedLiteralsCasesStringLiteral {
+    /// Returns `true` if this is the [`MixedLiteralsCasesStringLiteral::Boolean`] variant.
+    pub fn is_boolean(&self) -> bool {
+        matches!(self, Self::Boolean(_))
+    }
+
+    /// Returns a reference to the value if this is the [`MixedLiteralsCasesStringLiteral::Boolean`] variant.
+    pub fn as_boolean(&self) -> Option<&bool> {
+        match self {
+            Self::Boolean(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`MixedLiteralsCasesStringLiteral::Boolean`] variant.
+    pub fn into_boolean(self) -> Option<bool> {
+        match self {
+            Self::Boolean(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this is the [`MixedLiteralsCasesStringLiteral::Int32`] variant.
+    pub fn is_int32(&self) -> bool {
+        matches!(self, Self::Int32(_))
+    }
+
+    /// Returns a reference to the value if this is the [`MixedLiteralsCasesStringLiteral::Int32`] variant.
+    pub fn as_int32(&self) -> Option<&i32> {
+        match self {
+            Self::Int32(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`MixedLiteralsCasesStringLiteral::Int32`] variant.
+    pub fn into_int32(self) -> Option<i32> {
+        match self {
+            Self::Int32(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this is the [`MixedLiteralsCasesStringLiteral::Float32`] variant.
+    pub fn is_float32(&self) -> bool {
+        matches!(self, Self::Float32(_))
+    }
+
+    /// Returns a reference to the value if this is the [`MixedLiteralsCasesStringLiteral::Float32`] variant.
+    pub fn as_float32(&self) -> Option<&f32> {
+        match self {
+            Self::Float32(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`MixedLiteralsCasesStringLiteral::Float32`] variant.
+    pub fn into_float32(self) -> Option<f32> {
+        match self {
+            Self::Float32(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this is the [`MixedLiteralsCasesStringLiteral::String`] variant.
+    pub fn is_string(&self) -> bool {
+        matches!(self, Self::String(_))
+    }
+
+    /// Returns a reference to the value if this is the [`MixedLiteralsCasesStringLiteral::String`] variant.
+    pub fn as_string(&self) -> Option<&String> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`MixedLiteralsCasesStringLiteral::String`] variant.
+    pub fn into_string(self) -> Option<String> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl From<bool> for MixedLiteralsCasesStringLiteral {
+    fn from(value: bool) -> Self {
+        Self::Boolean(value)
+    }
+}
+
+impl From<i32> for MixedLiteralsCasesStringLiteral {
+    fn from(value: i32) -> Self {
+        Self::Int32(value)
+    }
+}
+
+impl From<f32> for MixedLiteralsCasesStringLiteral {
+    fn from(value: f32) -> Self {
+        Self::Float32(value)
+    }
+}
+
+impl From<String> for MixedLiteralsCasesStringLiteral {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl MixedTypesCasesModel {
+    /// Returns `true` if this is the [`MixedTypesCasesModel::Boolean`] variant.
+    pub fn is_boolean(&self) -> bool {
+        matches!(self, Self::Boolean(_))
+    }
+
+    /// Returns a reference to the value if this is the [`MixedTypesCasesModel::Boolean`] variant.
+    pub fn as_boolean(&self) -> Option<&bool> {
+        match self {
+            Self::Boolean(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`MixedTypesCasesModel::Boolean`] variant.
+    pub fn into_boolean(self) -> Option<bool> {
+        match self {
+            Self::Boolean(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this is the [`MixedTypesCasesModel::Int32`] variant.
+    pub fn is_int32(&self) -> bool {
+        matches!(self, Self::Int32(_))
+    }
+
+    /// Returns a reference to the value if this is the [`MixedTypesCasesModel::Int32`] variant.
+    pub fn as_int32(&self) -> Option<&i32> {
+        match self {
+            Self::Int32(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`MixedTypesCasesModel::Int32`] variant.
+    pub fn into_int32(self) -> Option<i32> {
+        match self {
+            Self::Int32(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this is the [`MixedTypesCasesModel::String`] variant.
+    pub fn is_string(&self) -> bool {
+        matches!(self, Self::String(_))
+    }
+
+    /// Returns a reference to the value if this is the [`MixedTypesCasesModel::String`] variant.
+    pub fn as_string(&self) -> Option<&String> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`MixedTypesCasesModel::String`] variant.
+    pub fn into_string(self) -> Option<String> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this is the [`MixedTypesCasesModel::Cat`] variant.
+    pub fn is_cat(&self) -> bool {
+        matches!(self, Self::Cat(_))
+    }
+
+    /// Returns a reference to the value if this is the [`MixedTypesCasesModel::Cat`] variant.
+    pub fn as_cat(&self) -> Option<&Cat> {
+        match self {
+            Self::Cat(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`MixedTypesCasesModel::Cat`] variant.
+    pub fn into_cat(self) -> Option<Cat> {
+        match self {
+            Self::Cat(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl From<bool> for MixedTypesCasesModel {
+    fn from(value: bool) -> Self {
+        Self::Boolean(value)
+    }
+}
+
+impl From<i32> for MixedTypesCasesModel {
+    fn from(value: i32) -> Self {
+        Self::Int32(value)
+    }
+}
+
+impl From<String> for MixedTypesCasesModel {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<Cat> for MixedTypesCasesModel {
+    fn from(value: Cat) -> Self {
+        Self::Cat(value)
+    }
+}
+
+impl StringAndArrayCasesArray {
+    /// Returns `true` if this is the [`StringAndArrayCasesArray::String`] variant.
+    pub fn is_string(&self) -> bool {
+        matches!(self, Self::String(_))
+    }
+
+    /// Returns a reference to the value if this is the [`StringAndArrayCasesArray::String`] variant.
+    pub fn as_string(&self) -> Option<&String> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`StringAndArrayCasesArray::String`] variant.
+    pub fn into_string(self) -> Option<String> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this is the [`StringAndArrayCasesArray::StringArray`] variant.
+    pub fn is_string_array(&self) -> bool {
+        matches!(self, Self::StringArray(_))
+    }
+
+    /// Returns a reference to the value if this is the [`StringAndArrayCasesArray::StringArray`] variant.
+    pub fn as_string_array(&self) -> Option<&Vec<String>> {
+        match self {
+            Self::StringArray(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`StringAndArrayCasesArray::StringArray`] variant.
+    pub fn into_string_array(self) -> Option<Vec<String>> {
+        match self {
+            Self::StringArray(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl From<String> for StringAndArrayCasesArray {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<Vec<String>> for StringAndArrayCasesArray {
+    fn from(value: Vec<String>) -> Self {
+        Self::StringArray(value)
+    }
+}
+
+impl StringAndArrayCasesString {
+    /// Returns `true` if this is the [`StringAndArrayCasesString::String`] variant.
+    pub fn is_string(&self) -> bool {
+        matches!(self, Self::String(_))
+    }
+
+    /// Returns a reference to the value if this is the [`StringAndArrayCasesString::String`] variant.
+    pub fn as_string(&self) -> Option<&String> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`StringAndArrayCasesString::String`] variant.
+    pub fn into_string(self) -> Option<String> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this is the [`StringAndArrayCasesString::StringArray`] variant.
+    pub fn is_string_array(&self) -> bool {
+        matches!(self, Self::StringArray(_))
+    }
+
+    /// Returns a reference to the value if this is the [`StringAndArrayCasesString::StringArray`] variant.
+    pub fn as_string_array(&self) -> Option<&Vec<String>> {
+        match self {
+            Self::StringArray(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is the [`StringAndArrayCasesString::StringArray`] variant.
+    pub fn into_string_array(self) -> Option<Vec<String>> {
+        match self {
+            Self::StringArray(value) => Some(value),
+            _ => None,
+        }
+    }
+}
+
+impl From<String> for StringAndArrayCasesString {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<Vec<String>> for StringAndArrayCasesString {
+    fn from(value: Vec<String>) -> Self {
+        Self::StringArray(value)
+    }
+}
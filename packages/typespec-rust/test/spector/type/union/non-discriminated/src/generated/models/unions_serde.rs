@@ -0,0 +1,137 @@
+// Copyright (c) Microsoft Corporation. All rights reserved.
+// Licensed under the MIT License.
+//
+// Code generated by Microsoft (R) Rust Code Generator. DO NOT EDIT.
+
+use super::{
+    GetResponseProp4, MixedLiteralsCasesStringLiteral, MixedTypesCasesModel,
+    StringAndArrayCasesArray, StringAndArrayCasesString,
+};
+use azure_core::Value;
+use serde::{Deserialize, Deserializer};
+
+impl<'de> Deserialize<'de> for GetResponseProp4 {
+    fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let mut errors = Vec::new();
+        match Deserialize::deserialize(&value).map(Self::Cat) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("Cat: {err}")),
+        }
+        match Deserialize::deserialize(&value).map(Self::Dog) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("Dog: {err}")),
+        }
+        Err(serde::de::Error::custom(format!(
+            "data did not match any variant of untagged enum GetResponseProp4 ({})",
+            errors.join("; ")
+        )))
+    }
+}
+
+impl<'de> Deserialize<'de> for MixedLiteralsCasesStringLiteral {
+    fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let mut errors = Vec::new();
+        match Deserialize::deserialize(&value).map(Self::Boolean) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("Boolean: {err}")),
+        }
+        match Deserialize::deserialize(&value).map(Self::Int32) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("Int32: {err}")),
+        }
+        match Deserialize::deserialize(&value).map(Self::Float32) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("Float32: {err}")),
+        }
+        match Deserialize::deserialize(&value).map(Self::String) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("String: {err}")),
+        }
+        Err(serde::de::Error::custom(format!(
+            "data did not match any variant of untagged enum MixedLiteralsCasesStringLiteral ({})",
+            errors.join("; ")
+        )))
+    }
+}
+
+impl<'de> Deserialize<'de> for MixedTypesCasesModel {
+    fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let mut errors = Vec::new();
+        match Deserialize::deserialize(&value).map(Self::Boolean) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("Boolean: {err}")),
+        }
+        match Deserialize::deserialize(&value).map(Self::Int32) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("Int32: {err}")),
+        }
+        match Deserialize::deserialize(&value).map(Self::String) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("String: {err}")),
+        }
+        match Deserialize::deserialize(&value).map(Self::Cat) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("Cat: {err}")),
+        }
+        Err(serde::de::Error::custom(format!(
+            "data did not match any variant of untagged enum MixedTypesCasesModel ({})",
+            errors.join("; ")
+        )))
+    }
+}
+
+impl<'de> Deserialize<'de> for StringAndArrayCasesArray {
+    fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let mut errors = Vec::new();
+        match Deserialize::deserialize(&value).map(Self::String) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("String: {err}")),
+        }
+        match Deserialize::deserialize(&value).map(Self::StringArray) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("StringArray: {err}")),
+        }
+        Err(serde::de::Error::custom(format!(
+            "data did not match any variant of untagged enum StringAndArrayCasesArray ({})",
+            errors.join("; ")
+        )))
+    }
+}
+
+impl<'de> Deserialize<'de> for StringAndArrayCasesString {
+    fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let mut errors = Vec::new();
+        match Deserialize::deserialize(&value).map(Self::String) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("String: {err}")),
+        }
+        match Deserialize::deserialize(&value).map(Self::StringArray) {
+            Ok(variant) => return Ok(variant),
+            Err(err) => errors.push(format!("StringArray: {err}")),
+        }
+        Err(serde::de::Error::custom(format!(
+            "data did not match any variant of untagged enum StringAndArrayCasesString ({})",
+            errors.join("; ")
+        )))
+    }
+}
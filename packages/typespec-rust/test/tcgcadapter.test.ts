@@ -9,6 +9,7 @@ import * as rust from '../src/codemodel/index.js';
 import * as helpers from '../src/tcgcadapter/helpers.js';
 import { formatVisibility } from '../src/tcgcadapter/adapter.js';
 import * as naming from '../src/tcgcadapter/naming.js';
+import * as tcgc from '@azure-tools/typespec-client-generator-core';
 import { Visibility } from '@typespec/http';
 import { deepEqual, strictEqual } from 'assert';
 import { describe, it } from 'vitest';
@@ -126,5 +127,55 @@ describe('typespec-rust: tcgcadapter', () => {
       strictEqual(formatVisibility([Visibility.Read, Visibility.Create]), 'Create, Read');
       strictEqual(formatVisibility([Visibility.Create, Visibility.Update, Visibility.Delete]), 'Create, Delete, Update');
     });
+
+    it('getArmScopeParams', () => {
+      // only the fields read by getArmScopeParams are populated
+      const pathParam = (name: string, options?: { onClient?: boolean, optional?: boolean, kind?: string }): tcgc.SdkPathParameter => {
+        return <tcgc.SdkPathParameter><unknown>{
+          kind: 'path',
+          name: name,
+          onClient: options?.onClient ?? false,
+          optional: options?.optional ?? false,
+          allowReserved: false,
+          type: { kind: options?.kind ?? 'string' },
+          methodParameterSegments: [[{ name: name }]],
+        };
+      };
+      const method = (kind: string, ...params: Array<tcgc.SdkPathParameter>): tcgc.SdkServiceMethod<tcgc.SdkHttpOperation> => {
+        return <tcgc.SdkServiceMethod<tcgc.SdkHttpOperation>><unknown>{ kind: kind, operation: { parameters: params } };
+      };
+
+      // no methods means no scope params
+      deepEqual(helpers.getArmScopeParams([]), { scopeParams: [], scopeOpParams: [] });
+
+      // the scope params are the params shared by all methods
+      const getSubscriptionId = pathParam('subscriptionId', { onClient: true });
+      const getResourceGroupName = pathParam('resourceGroupName');
+      const getWidgetName = pathParam('widgetName');
+      const getPartName = pathParam('partName');
+      const getCount = pathParam('count', { kind: 'int32' });
+      const listResourceGroupName = pathParam('resourceGroupName');
+      const listWidgetName = pathParam('widgetName');
+      const listFilter = pathParam('filter', { optional: true });
+      const getMethod = method('basic', getSubscriptionId, getResourceGroupName, getWidgetName, getPartName, getCount);
+      const listMethod = method('paging', listResourceGroupName, listWidgetName, listFilter);
+      let result = helpers.getArmScopeParams([getMethod, listMethod]);
+      deepEqual(result.scopeParams, [getResourceGroupName, getWidgetName]);
+      deepEqual(result.scopeOpParams, [getResourceGroupName, getWidgetName, listResourceGroupName, listWidgetName]);
+
+      // paging LROs don't narrow the scope params but their params are still classified
+      const lroPagingResourceGroupName = pathParam('resourceGroupName');
+      const lroPagingMethod = method('lropaging', lroPagingResourceGroupName);
+      result = helpers.getArmScopeParams([getMethod, lroPagingMethod, listMethod]);
+      deepEqual(result.scopeParams, [getResourceGroupName, getWidgetName]);
+      deepEqual(result.scopeOpParams, [getResourceGroupName, getWidgetName, lroPagingResourceGroupName, listResourceGroupName, listWidgetName]);
+
+      // a client with only paging LROs has no scope params
+      deepEqual(helpers.getArmScopeParams([lroPagingMethod]), { scopeParams: [], scopeOpParams: [] });
+
+      // a single method's required string params are all scope params
+      result = helpers.getArmScopeParams([getMethod]);
+      deepEqual(result.scopeParams, [getResourceGroupName, getWidgetName, getPartName]);
+    });
   });
 });